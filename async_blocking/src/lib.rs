@@ -7,6 +7,11 @@ pub fn impl_async(attr: TokenStream, item: TokenStream) -> TokenStream {
     let func_code = item.to_string();
     // println!("func_code: {func_code}");
 
+    // doc comments are printed as `///` lines, they would comment out the rest of the joined lines
+    let func_code: String = func_code.lines().map(|line| match line.split_once("///") {
+        Some((code, doc)) => format!("{code}#[doc = {doc:?}]\n"),
+        None => format!("{line}\n"),
+    }).collect();
     let func_code = func_code.replace("\n", " ");
     let func_code = func_code.replace("     ", " ");
     let func_code = func_code.replace("    ", " ");
@@ -14,10 +19,46 @@ pub fn impl_async(attr: TokenStream, item: TokenStream) -> TokenStream {
     let func_code = func_code.replace("  ", " ");
     let func_code = func_code.replace("  ", " ");
     let func_code = func_code.replace("fn ", "async fn ");
-    let func_code = func_code.replace(": std :: io :: Read >", ": tokio::io::AsyncRead + Send + Unpin>");
+    let func_code = async_bounds(&func_code);
     let func_code = func_code.replace(".map(| await_ | await_)", ".await");
+    // blocking functions are named `x_blocking` or called through the `blocking` modules, traits `XBlocking`
+    let func_code = func_code.replace("_blocking", "");
+    let func_code = func_code.replace("Blocking", "");
+    let func_code = func_code.replace("blocking ::", "async_ ::");
 
     println!("func_code: {func_code}");
     // panic!("{func_code}");
     TokenStream::from_str(&func_code).unwrap()
-}
\ No newline at end of file
+}
+
+/// Bounds of the blocking code, e.g. `R: std::io::Read + std::io::Seek`, become the tokio ones with `Send + Unpin`
+fn async_bounds(func_code: &str) -> String {
+    let tokens: Vec<&str> = func_code.split(' ').collect();
+    // the comma after the last bound is printed without a space, e.g. `std :: io :: Seek,`
+    let io_trait = |i: usize| match tokens.get(i..i + 5) {
+        Some(["std", "::", "io", "::", name]) => match name.strip_suffix(',').unwrap_or(name) {
+            name @ ("Read" | "Write" | "Seek") => Some((name, name.len() < tokens[i + 4].len())),
+            _ => None,
+        },
+        _ => None,
+    };
+    let mut code = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        let Some((name, mut comma)) = io_trait(i) else {
+            code.push(tokens[i].to_string());
+            i += 1;
+            continue;
+        };
+        let mut bounds = vec![format!("tokio::io::Async{name}")];
+        i += 5;
+        while let (false, Some(&"+"), Some((name, next_comma))) = (comma, tokens.get(i), io_trait(i + 1)) {
+            bounds.push(format!("tokio::io::Async{name}"));
+            comma = next_comma;
+            i += 6;
+        }
+        bounds.push("Send + Unpin".to_string());
+        code.push(bounds.join(" + ") + if comma { "," } else { "" });
+    }
+    code.join(" ")
+}
@@ -0,0 +1,27 @@
+#[macro_use] extern crate log;
+
+use anyhow::Context;
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let env = env_logger::Env::default()
+        .filter_or("MY_LOG_LEVEL", "debug")
+        .write_style_or("MY_LOG_STYLE", "always");
+    env_logger::init_from_env(env);
+
+    let input = "./movies/sample1.mkv";
+    let file = tokio::fs::File::open(input).await.context(format!("Failed open '{input}'"))?;
+    let mut demuxer = mkv::Demuxer::open(file).await.context("Failed Demuxer::open")?
+        .with_recovery(true);
+
+    debug!("Info: {:#?}", demuxer.info());
+    debug!("Tracks: {:#?}", demuxer.tracks());
+
+    while let Some(frame) = demuxer.next_frame().await? {
+        debug!("track {} pts {}ns duration {:?} keyframe {} len {}", frame.track, frame.pts_ns, frame.duration, frame.keyframe, frame.data.len());
+    }
+    for skipped in demuxer.skipped() {
+        warn!("corrupt data skipped: {}..{}: {}", skipped.start, skipped.end, skipped.error);
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+#[macro_use] extern crate log;
+
+use anyhow::Context;
+
+fn main() -> Result<(), anyhow::Error> {
+    let env = env_logger::Env::default()
+        .filter_or("MY_LOG_LEVEL", "debug")
+        .write_style_or("MY_LOG_STYLE", "always");
+    env_logger::init_from_env(env);
+
+    let input = "./movies/sample1.mkv";
+    let file = std::fs::File::open(input).context(format!("Failed open '{input}'"))?;
+    let mut demuxer = mkv::Demuxer::open_blocking(std::io::BufReader::new(file)).context("Failed Demuxer::open_blocking")?
+        .with_recovery(true);
+
+    debug!("Info: {:#?}", demuxer.info());
+    debug!("Tracks: {:#?}", demuxer.tracks());
+
+    while let Some(frame) = demuxer.next_frame_blocking()? {
+        debug!("track {} pts {}ns duration {:?} keyframe {} len {}", frame.track, frame.pts_ns, frame.duration, frame.keyframe, frame.data.len());
+    }
+    for skipped in demuxer.skipped() {
+        warn!("corrupt data skipped: {}..{}: {}", skipped.start, skipped.end, skipped.error);
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anyhow::Context;
+
+const USAGE: &str = "\
+Usage: mkvtool <command> [args]
+
+Commands:
+    cues <file>                       Scan the clusters and write Cues, so the file becomes seekable
+    extract <file> <track> <output>   Write the H.264 or HEVC track as an Annex-B .h264/.h265 stream
+";
+
+fn main() -> Result<(), anyhow::Error> {
+    let env = env_logger::Env::default()
+        .filter_or("MY_LOG_LEVEL", "info")
+        .write_style_or("MY_LOG_STYLE", "always");
+    env_logger::init_from_env(env);
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["cues", path] => cues(path),
+        ["extract", path, track, output] => extract(path, track, output),
+        _ => {
+            eprint!("{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn cues(path: &str) -> Result<(), anyhow::Error> {
+    let mut file = std::fs::OpenOptions::new().read(true).write(true).open(path)
+        .context(format!("Failed open '{path}'"))?;
+    let cues = mkv::cues::write_cues_blocking(&mut file).context("Failed write_cues")?;
+    println!("{path}: {} cue points written", cues.cue_point.len());
+    Ok(())
+}
+
+fn extract(path: &str, track: &str, output: &str) -> Result<(), anyhow::Error> {
+    let track: u64 = track.parse().context(format!("Invalid track number '{track}'"))?;
+    let file = std::fs::File::open(path).context(format!("Failed open '{path}'"))?;
+    let out = std::fs::File::create(output).context(format!("Failed create '{output}'"))?;
+    let mut out = std::io::BufWriter::new(out);
+    let frames = mkv::extract::extract_annex_b_blocking(std::io::BufReader::new(file), track, &mut out)
+        .context(format!("Failed extract track {track}"))?;
+    println!("{output}: {frames} frames of track {track} written");
+    Ok(())
+}
@@ -115,7 +115,9 @@ async fn main() -> Result<(), anyhow::Error> {
         //     debug!("el {:?}", el.index());
         // }
 
-        segment.write(&mut output).await.context("Failed Segment::write")?;
+        // the elements filled with the spec defaults by the reader are not written back
+        let options = mkv::WriteOptions { omit_defaults: true, ..Default::default() };
+        segment.write_with(&mut output, options).await.context("Failed Segment::write")?;
 
         // let len = segment.write_header(&mut output, buf.len() as u64).context("Failed Segment::write_header")?;
         // output.write_all(&buf).context("Failed write_all")?;
@@ -191,7 +191,9 @@ fn remux(input: &str, output: &str) -> Result<(), anyhow::Error> {
         //     debug!("el {:?}", el.index());
         // }
 
-        segment.write_blocking(&mut output).context("Failed Segment::write")?;
+        // the elements filled with the spec defaults by the reader are not written back
+        let options = mkv::WriteOptions { omit_defaults: true, ..Default::default() };
+        segment.write_with_blocking(&mut output, options).context("Failed Segment::write")?;
 
         // let len = segment.write_header(&mut output, buf.len() as u64).context("Failed Segment::write_header")?;
         // output.write_all(&buf).context("Failed write_all")?;
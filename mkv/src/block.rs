@@ -0,0 +1,190 @@
+use super::io;
+use super::lacing;
+use super::MatroskaError;
+
+// https://www.rfc-editor.org/rfc/rfc9559.html#name-block-structure
+// https://www.rfc-editor.org/rfc/rfc9559.html#name-simpleblock-structure
+const FLAG_KEYFRAME: u8    = 0b_1000_0000;
+const FLAG_INVISIBLE: u8   = 0b_0000_1000;
+const FLAG_LACING: u8      = 0b_0000_0110;
+const FLAG_DISCARDABLE: u8 = 0b_0000_0001;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lacing {
+    None,
+    Xiph,
+    FixedSize,
+    Ebml,
+}
+impl Lacing {
+    fn from_flags(flags: u8) -> Self {
+        match (flags & FLAG_LACING) >> 1 {
+            0b00 => Lacing::None,
+            0b01 => Lacing::Xiph,
+            0b10 => Lacing::FixedSize,
+            _ => Lacing::Ebml,
+        }
+    }
+    fn to_flags(self) -> u8 {
+        let bits = match self {
+            Lacing::None => 0b00,
+            Lacing::Xiph => 0b01,
+            Lacing::FixedSize => 0b10,
+            Lacing::Ebml => 0b11,
+        };
+        bits << 1
+    }
+}
+
+/// Payload of a `SimpleBlock` or a `BlockGroup/Block` element.
+///
+/// `data` holds everything after the flags byte, so for laced blocks it starts with the lace header.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Block {
+    pub track_number: u64,
+    /// Timestamp relative to the Cluster timestamp, in TimestampScale units
+    pub timecode: i16,
+    pub flags: u8,
+    pub data: Vec<u8>,
+}
+/// `SimpleBlock` shares the `Block` layout, only the keyframe and discardable flags are specific to it
+pub type SimpleBlock = Block;
+
+impl Block {
+    pub fn new(track_number: u64, timecode: i16, data: Vec<u8>) -> Self {
+        Self { track_number, timecode, flags: 0, data }
+    }
+    /// Block with frames laced by the lacing which gives the smallest block
+    pub fn from_frames(track_number: u64, timecode: i16, frames: &[&[u8]]) -> Result<Self, MatroskaError> {
+        let mut block = Self::new(track_number, timecode, vec![]);
+        block.set_frames(frames)?;
+        Ok(block)
+    }
+
+    pub fn parse(mut buf: Vec<u8>) -> Result<Self, MatroskaError> {
+        let BlockView { track_number, timecode, flags, data } = BlockView::parse(&buf)?;
+        buf.drain(..buf.len() - data.len());
+        Ok(Self { track_number, timecode, flags, data: buf })
+    }
+
+    pub fn header(&self) -> Result<Vec<u8>, MatroskaError> {
+        let mut buf = io::gen_vint(self.track_number)?;
+        buf.extend_from_slice(&self.timecode.to_be_bytes());
+        buf.push(self.flags);
+        Ok(buf)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, MatroskaError> {
+        let mut buf = self.header()?;
+        buf.extend_from_slice(&self.data);
+        Ok(buf)
+    }
+
+    /// Only meaningful for `SimpleBlock`, the bit is reserved in `BlockGroup/Block`
+    pub fn keyframe(&self) -> bool { self.flags & FLAG_KEYFRAME != 0 }
+    pub fn invisible(&self) -> bool { self.flags & FLAG_INVISIBLE != 0 }
+    /// Only meaningful for `SimpleBlock`, the bit is reserved in `BlockGroup/Block`
+    pub fn discardable(&self) -> bool { self.flags & FLAG_DISCARDABLE != 0 }
+    pub fn lacing(&self) -> Lacing { Lacing::from_flags(self.flags) }
+
+    pub fn set_keyframe(&mut self, val: bool) { self.set_flag(FLAG_KEYFRAME, val) }
+    pub fn set_invisible(&mut self, val: bool) { self.set_flag(FLAG_INVISIBLE, val) }
+    pub fn set_discardable(&mut self, val: bool) { self.set_flag(FLAG_DISCARDABLE, val) }
+    pub fn set_lacing(&mut self, lacing: Lacing) {
+        self.flags = (self.flags & !FLAG_LACING) | lacing.to_flags();
+    }
+    fn set_flag(&mut self, flag: u8, val: bool) {
+        if val { self.flags |= flag } else { self.flags &= !flag }
+    }
+
+    /// Frames stored in the block, laced blocks are split into separate frames
+    pub fn frames(&self) -> Result<Vec<&[u8]>, MatroskaError> {
+        lacing::decode(self.lacing(), &self.data)
+    }
+    pub fn set_frames(&mut self, frames: &[&[u8]]) -> Result<(), MatroskaError> {
+        let (lacing, data) = lacing::encode(frames)?;
+        self.set_lacing(lacing);
+        self.data = data;
+        Ok(())
+    }
+}
+
+
+/// `Block` borrowing its frame data from the parsed buffer, see `slice::ElementView::block`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockView<'a> {
+    pub track_number: u64,
+    /// Timestamp relative to the Cluster timestamp, in TimestampScale units
+    pub timecode: i16,
+    pub flags: u8,
+    pub data: &'a [u8],
+}
+
+impl<'a> BlockView<'a> {
+    pub fn parse(buf: &'a [u8]) -> Result<Self, MatroskaError> {
+        let mut r = buf;
+        let (track_number, track_len) = io::blocking::read_vint(&mut r)?;
+        if r.len() < 3 {
+            return Err(MatroskaError::InvalidBlock("block header is truncated"));
+        }
+        let timecode = i16::from_be_bytes([r[0], r[1]]);
+        let flags = r[2];
+        Ok(Self { track_number, timecode, flags, data: &buf[track_len as usize + 3..] })
+    }
+
+    pub fn keyframe(&self) -> bool { self.flags & FLAG_KEYFRAME != 0 }
+    pub fn invisible(&self) -> bool { self.flags & FLAG_INVISIBLE != 0 }
+    pub fn discardable(&self) -> bool { self.flags & FLAG_DISCARDABLE != 0 }
+    pub fn lacing(&self) -> Lacing { Lacing::from_flags(self.flags) }
+
+    pub fn frames(&self) -> Result<Vec<&'a [u8]>, MatroskaError> {
+        lacing::decode(self.lacing(), self.data)
+    }
+    pub fn to_block(&self) -> Block {
+        Block { track_number: self.track_number, timecode: self.timecode, flags: self.flags, data: self.data.to_vec() }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block() {
+        let buf = vec![0x81, 0x00, 0x10, 0b_1000_0000, 0xAA, 0xBB];
+        let block = Block::parse(buf.clone()).unwrap();
+        assert_eq!(block.track_number, 1);
+        assert_eq!(block.timecode, 16);
+        assert!(block.keyframe());
+        assert!(!block.invisible());
+        assert!(!block.discardable());
+        assert_eq!(block.lacing(), Lacing::None);
+        assert_eq!(block.frames().unwrap(), vec![&[0xAA, 0xBB][..]]);
+        assert_eq!(block.to_bytes().unwrap(), buf);
+
+        let buf = vec![0x40, 0x81, 0xFF, 0xFE, 0b_0000_1001];
+        let mut block = Block::parse(buf.clone()).unwrap();
+        assert_eq!(block.track_number, 129);
+        assert_eq!(block.timecode, -2);
+        assert!(!block.keyframe());
+        assert!(block.invisible());
+        assert!(block.discardable());
+        assert!(block.data.is_empty());
+        assert_eq!(block.to_bytes().unwrap(), buf);
+
+        block.set_lacing(Lacing::Ebml);
+        block.set_invisible(false);
+        assert_eq!(block.lacing(), Lacing::Ebml);
+        assert_eq!(block.flags, 0b_0000_0111);
+
+        let frames: Vec<&[u8]> = vec![&[1, 2, 3], &[4, 5], &[6]];
+        let block = Block::from_frames(2, 0, &frames).unwrap();
+        assert_eq!(block.lacing(), Lacing::Xiph);
+        let block = Block::parse(block.to_bytes().unwrap()).unwrap();
+        assert_eq!(block.frames().unwrap(), frames);
+
+        assert!(Block::parse(vec![0x81, 0x00]).is_err());
+        assert!(Block::parse(vec![]).is_err());
+    }
+}
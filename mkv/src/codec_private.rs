@@ -0,0 +1,1049 @@
+use super::structs::TrackEntry;
+use super::lacing;
+use super::{Lacing, MatroskaError};
+
+pub const CODEC_AVC: &str = "V_MPEG4/ISO/AVC";
+pub const CODEC_HEVC: &str = "V_MPEGH/ISO/HEVC";
+pub const CODEC_AV1: &str = "V_AV1";
+pub const CODEC_VP9: &str = "V_VP9";
+pub const CODEC_THEORA: &str = "V_THEORA";
+pub const CODEC_OPUS: &str = "A_OPUS";
+pub const CODEC_VORBIS: &str = "A_VORBIS";
+pub const CODEC_FLAC: &str = "A_FLAC";
+pub const CODEC_AAC: &str = "A_AAC";
+
+const HEVC_NAL_VPS: u8 = 32;
+const HEVC_NAL_SPS: u8 = 33;
+const HEVC_NAL_PPS: u8 = 34;
+const OPUS_SAMPLE_RATE: u64 = 48000;
+
+/// Typed CodecPrivate of a track, selected by its CodecID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodecPrivate {
+    Avc(AvcConfig),
+    Hevc(HevcConfig),
+    Av1(Av1Config),
+    Vp9(Vp9Features),
+    Theora(XiphHeaders),
+    Opus(OpusHead),
+    Vorbis(XiphHeaders),
+    Flac(FlacConfig),
+    Aac(AacConfig),
+}
+
+impl CodecPrivate {
+    /// `None` for the codecs without a parser
+    pub fn parse(codec_id: &str, data: &[u8]) -> Result<Option<Self>, MatroskaError> {
+        Ok(Some(match codec_id {
+            CODEC_AVC => CodecPrivate::Avc(AvcConfig::parse(data)?),
+            CODEC_HEVC => CodecPrivate::Hevc(HevcConfig::parse(data)?),
+            CODEC_AV1 => CodecPrivate::Av1(Av1Config::parse(data)?),
+            CODEC_VP9 => CodecPrivate::Vp9(Vp9Features::parse(data)?),
+            CODEC_THEORA => CodecPrivate::Theora(XiphHeaders::parse(data)?),
+            CODEC_OPUS => CodecPrivate::Opus(OpusHead::parse(data)?),
+            CODEC_VORBIS => {
+                let headers = XiphHeaders::parse(data)?;
+                headers.vorbis()?;
+                CodecPrivate::Vorbis(headers)
+            }
+            CODEC_FLAC => CodecPrivate::Flac(FlacConfig::parse(data)?),
+            CODEC_AAC => CodecPrivate::Aac(AacConfig::parse(data)?),
+            _ => return Ok(None),
+        }))
+    }
+    /// CodecPrivate of the track, a missing one is parsed as empty, e.g. VP9 doesn't require it
+    pub fn from_track(track: &TrackEntry) -> Result<Option<Self>, MatroskaError> {
+        let data = track.codec_private.as_ref().map_or(&[][..], |codec_private| &codec_private.v[..]);
+        Self::parse(&track.codec_id.v, data)
+    }
+
+    pub fn codec_id(&self) -> &'static str {
+        match self {
+            CodecPrivate::Avc(_) => CODEC_AVC,
+            CodecPrivate::Hevc(_) => CODEC_HEVC,
+            CodecPrivate::Av1(_) => CODEC_AV1,
+            CodecPrivate::Vp9(_) => CODEC_VP9,
+            CodecPrivate::Theora(_) => CODEC_THEORA,
+            CodecPrivate::Opus(_) => CODEC_OPUS,
+            CodecPrivate::Vorbis(_) => CODEC_VORBIS,
+            CodecPrivate::Flac(_) => CODEC_FLAC,
+            CodecPrivate::Aac(_) => CODEC_AAC,
+        }
+    }
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            CodecPrivate::Avc(config) => config.to_bytes(),
+            CodecPrivate::Hevc(config) => config.to_bytes(),
+            CodecPrivate::Av1(config) => config.to_bytes(),
+            CodecPrivate::Vp9(features) => features.to_bytes(),
+            CodecPrivate::Theora(headers) | CodecPrivate::Vorbis(headers) => headers.to_bytes(),
+            CodecPrivate::Opus(head) => head.to_bytes(),
+            CodecPrivate::Flac(config) => config.to_bytes(),
+            CodecPrivate::Aac(config) => config.to_bytes(),
+        }
+    }
+
+    /// Audio parameters of the track which disagree with the CodecPrivate, as the element path
+    /// relative to the TrackEntry and the message, e.g. `Audio\Channels`
+    pub fn check(&self, track: &TrackEntry) -> Vec<(&'static str, String)> {
+        let (sampling_frequencies, channels, bit_depth) = match self {
+            CodecPrivate::Opus(head) => {
+                let mut mismatches = vec![];
+                if *track.codec_delay.v != head.codec_delay() {
+                    mismatches.push(("CodecDelay", format!("{} ns, OpusHead pre-skip is {} ns", track.codec_delay.v, head.codec_delay())));
+                }
+                return [mismatches, self.check_audio(track, &[OPUS_SAMPLE_RATE as u32], Some(head.channels), None)].concat();
+            }
+            CodecPrivate::Vorbis(headers) => match headers.vorbis() {
+                Ok(vorbis) => (vec![vorbis.sample_rate], Some(vorbis.channels), None),
+                Err(_) => return vec![],
+            },
+            CodecPrivate::Flac(config) => {
+                let info = &config.stream_info;
+                (vec![info.sample_rate], Some(info.channels), Some(info.bits_per_sample))
+            }
+            CodecPrivate::Aac(config) => {
+                let mut frequencies = vec![config.sampling_frequency];
+                frequencies.extend(config.extension.as_ref().map(|extension| extension.sampling_frequency));
+                (frequencies, config.channels(), None)
+            }
+            _ => return vec![],
+        };
+        self.check_audio(track, &sampling_frequencies, channels, bit_depth)
+    }
+
+    fn check_audio(&self, track: &TrackEntry, sampling_frequencies: &[u32], channels: Option<u8>, bit_depth: Option<u8>) -> Vec<(&'static str, String)> {
+        let mut mismatches = vec![];
+        let Some(audio) = &track.audio else {
+            mismatches.push(("Audio", format!("{} track has no Audio element", self.codec_id())));
+            return mismatches;
+        };
+        let frequency = *audio.v.sampling_frequency.v;
+        if !sampling_frequencies.iter().any(|f| *f as f64 == frequency) {
+            mismatches.push(("Audio\\SamplingFrequency", format!("{frequency} Hz, CodecPrivate has {sampling_frequencies:?}")));
+        }
+        if let Some(channels) = channels.filter(|channels| *channels as u64 != *audio.v.channels.v) {
+            mismatches.push(("Audio\\Channels", format!("{}, CodecPrivate has {channels}", audio.v.channels.v)));
+        }
+        let track_bit_depth = audio.v.bit_depth.as_ref().map(|bit_depth| *bit_depth.v);
+        if let (Some(track_bit_depth), Some(bit_depth)) = (track_bit_depth, bit_depth) {
+            if track_bit_depth != bit_depth as u64 {
+                mismatches.push(("Audio\\BitDepth", format!("{track_bit_depth}, CodecPrivate has {bit_depth}")));
+            }
+        }
+        mismatches
+    }
+}
+
+/// AVCDecoderConfigurationRecord, ISO/IEC 14496-15 5.3.3.1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AvcConfig {
+    pub profile: u8,
+    pub profile_compatibility: u8,
+    pub level: u8,
+    /// Size of the NAL unit length prefix of the frames, 1, 2 or 4
+    pub nal_length_size: u8,
+    pub sps: Vec<Vec<u8>>,
+    pub pps: Vec<Vec<u8>>,
+    /// Present for the High profiles only
+    pub ext: Option<AvcConfigExt>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AvcConfigExt {
+    pub chroma_format: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub sps_ext: Vec<Vec<u8>>,
+}
+
+impl AvcConfig {
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "AVCDecoderConfigurationRecord");
+        if r.u8()? != 1 {
+            return Err(r.invalid("configurationVersion isn't 1"));
+        }
+        let profile = r.u8()?;
+        let profile_compatibility = r.u8()?;
+        let level = r.u8()?;
+        let nal_length_size = (r.u8()? & 0b11) + 1;
+        let sps_count = r.u8()? & 0b1_1111;
+        let sps = r.nal_units(sps_count as usize)?;
+        let pps_count = r.u8()?;
+        let pps = r.nal_units(pps_count as usize)?;
+        // older muxers omit the extension of the High profiles
+        let ext = match profile {
+            100 | 110 | 122 | 144 if !r.is_empty() => {
+                let chroma_format = r.u8()? & 0b11;
+                let bit_depth_luma = (r.u8()? & 0b111) + 8;
+                let bit_depth_chroma = (r.u8()? & 0b111) + 8;
+                let count = r.u8()?;
+                Some(AvcConfigExt { chroma_format, bit_depth_luma, bit_depth_chroma, sps_ext: r.nal_units(count as usize)? })
+            }
+            _ => None,
+        };
+        Ok(Self { profile, profile_compatibility, level, nal_length_size, sps, pps, ext })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![1, self.profile, self.profile_compatibility, self.level];
+        buf.push(0b1111_1100 | (self.nal_length_size.max(1) - 1));
+        buf.push(0b1110_0000 | self.sps.len() as u8);
+        put_nal_units(&mut buf, &self.sps);
+        buf.push(self.pps.len() as u8);
+        put_nal_units(&mut buf, &self.pps);
+        if let Some(ext) = &self.ext {
+            buf.push(0b1111_1100 | ext.chroma_format);
+            buf.push(0b1111_1000 | (ext.bit_depth_luma.max(8) - 8));
+            buf.push(0b1111_1000 | (ext.bit_depth_chroma.max(8) - 8));
+            buf.push(ext.sps_ext.len() as u8);
+            put_nal_units(&mut buf, &ext.sps_ext);
+        }
+        buf
+    }
+
+    /// Luma bit depth, 8 without the extension
+    pub fn bit_depth(&self) -> u8 {
+        self.ext.as_ref().map_or(8, |ext| ext.bit_depth_luma)
+    }
+}
+
+/// HEVCDecoderConfigurationRecord, ISO/IEC 14496-15 8.3.3.1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HevcConfig {
+    pub profile_space: u8,
+    pub tier: bool,
+    pub profile_idc: u8,
+    pub profile_compatibility: u32,
+    /// 48 bits
+    pub constraint_indicator: u64,
+    pub level_idc: u8,
+    pub min_spatial_segmentation: u16,
+    pub parallelism_type: u8,
+    pub chroma_format: u8,
+    pub bit_depth_luma: u8,
+    pub bit_depth_chroma: u8,
+    pub avg_frame_rate: u16,
+    pub constant_frame_rate: u8,
+    pub num_temporal_layers: u8,
+    pub temporal_id_nested: bool,
+    /// Size of the NAL unit length prefix of the frames, 1, 2 or 4
+    pub nal_length_size: u8,
+    pub arrays: Vec<HevcNalArray>,
+}
+
+/// NAL units of one type, e.g. the VPS, SPS or PPS
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HevcNalArray {
+    pub complete: bool,
+    pub nal_unit_type: u8,
+    pub nal_units: Vec<Vec<u8>>,
+}
+
+impl HevcConfig {
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "HEVCDecoderConfigurationRecord");
+        if r.u8()? != 1 {
+            return Err(r.invalid("configurationVersion isn't 1"));
+        }
+        let byte = r.u8()?;
+        let profile_compatibility = r.u32()?;
+        let constraint_indicator = (r.u16()? as u64) << 32 | r.u32()? as u64;
+        let level_idc = r.u8()?;
+        let min_spatial_segmentation = r.u16()? & 0x0FFF;
+        let parallelism_type = r.u8()? & 0b11;
+        let chroma_format = r.u8()? & 0b11;
+        let bit_depth_luma = (r.u8()? & 0b111) + 8;
+        let bit_depth_chroma = (r.u8()? & 0b111) + 8;
+        let avg_frame_rate = r.u16()?;
+        let flags = r.u8()?;
+        let mut arrays = vec![];
+        for _ in 0..r.u8()? {
+            let header = r.u8()?;
+            let count = r.u16()?;
+            arrays.push(HevcNalArray { complete: header & 0x80 != 0, nal_unit_type: header & 0x3F, nal_units: r.nal_units(count as usize)? });
+        }
+        Ok(Self {
+            profile_space: byte >> 6,
+            tier: byte & 0x20 != 0,
+            profile_idc: byte & 0x1F,
+            profile_compatibility,
+            constraint_indicator,
+            level_idc,
+            min_spatial_segmentation,
+            parallelism_type,
+            chroma_format,
+            bit_depth_luma,
+            bit_depth_chroma,
+            avg_frame_rate,
+            constant_frame_rate: flags >> 6,
+            num_temporal_layers: (flags >> 3) & 0b111,
+            temporal_id_nested: flags & 0b100 != 0,
+            nal_length_size: (flags & 0b11) + 1,
+            arrays,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![1, self.profile_space << 6 | (self.tier as u8) << 5 | self.profile_idc & 0x1F];
+        buf.extend_from_slice(&self.profile_compatibility.to_be_bytes());
+        buf.extend_from_slice(&self.constraint_indicator.to_be_bytes()[2..]);
+        buf.push(self.level_idc);
+        buf.extend_from_slice(&(0xF000 | self.min_spatial_segmentation).to_be_bytes());
+        buf.push(0b1111_1100 | self.parallelism_type);
+        buf.push(0b1111_1100 | self.chroma_format);
+        buf.push(0b1111_1000 | (self.bit_depth_luma.max(8) - 8));
+        buf.push(0b1111_1000 | (self.bit_depth_chroma.max(8) - 8));
+        buf.extend_from_slice(&self.avg_frame_rate.to_be_bytes());
+        buf.push(self.constant_frame_rate << 6 | (self.num_temporal_layers & 0b111) << 3
+            | (self.temporal_id_nested as u8) << 2 | (self.nal_length_size.max(1) - 1));
+        buf.push(self.arrays.len() as u8);
+        for array in &self.arrays {
+            buf.push((array.complete as u8) << 7 | array.nal_unit_type & 0x3F);
+            buf.extend_from_slice(&(array.nal_units.len() as u16).to_be_bytes());
+            put_nal_units(&mut buf, &array.nal_units);
+        }
+        buf
+    }
+
+    pub fn vps(&self) -> Vec<&[u8]> { self.nal_units(HEVC_NAL_VPS) }
+    pub fn sps(&self) -> Vec<&[u8]> { self.nal_units(HEVC_NAL_SPS) }
+    pub fn pps(&self) -> Vec<&[u8]> { self.nal_units(HEVC_NAL_PPS) }
+
+    fn nal_units(&self, nal_unit_type: u8) -> Vec<&[u8]> {
+        self.arrays.iter()
+            .filter(|array| array.nal_unit_type == nal_unit_type)
+            .flat_map(|array| array.nal_units.iter().map(|nal| &nal[..]))
+            .collect()
+    }
+}
+
+/// AV1CodecConfigurationRecord, https://aomediacodec.github.io/av1-isobmff/#av1codecconfigurationbox-syntax
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Av1Config {
+    pub seq_profile: u8,
+    pub seq_level_idx_0: u8,
+    pub seq_tier_0: bool,
+    pub high_bitdepth: bool,
+    pub twelve_bit: bool,
+    pub monochrome: bool,
+    pub chroma_subsampling_x: bool,
+    pub chroma_subsampling_y: bool,
+    pub chroma_sample_position: u8,
+    pub initial_presentation_delay: Option<u8>,
+    /// Sequence Header OBU and the metadata OBUs
+    pub config_obus: Vec<u8>,
+}
+
+impl Av1Config {
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "AV1CodecConfigurationRecord");
+        if r.u8()? != 0x81 {
+            return Err(r.invalid("marker isn't set or version isn't 1"));
+        }
+        let byte = r.u8()?;
+        let flags = r.u8()?;
+        let delay = r.u8()?;
+        Ok(Self {
+            seq_profile: byte >> 5,
+            seq_level_idx_0: byte & 0x1F,
+            seq_tier_0: flags & 0x80 != 0,
+            high_bitdepth: flags & 0x40 != 0,
+            twelve_bit: flags & 0x20 != 0,
+            monochrome: flags & 0x10 != 0,
+            chroma_subsampling_x: flags & 0x08 != 0,
+            chroma_subsampling_y: flags & 0x04 != 0,
+            chroma_sample_position: flags & 0b11,
+            initial_presentation_delay: (delay & 0x10 != 0).then_some((delay & 0x0F) + 1),
+            config_obus: r.rest().to_vec(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let flags = (self.seq_tier_0 as u8) << 7 | (self.high_bitdepth as u8) << 6 | (self.twelve_bit as u8) << 5
+            | (self.monochrome as u8) << 4 | (self.chroma_subsampling_x as u8) << 3
+            | (self.chroma_subsampling_y as u8) << 2 | self.chroma_sample_position & 0b11;
+        let delay = self.initial_presentation_delay.map_or(0, |delay| 0x10 | (delay.max(1) - 1) & 0x0F);
+        let mut buf = vec![0x81, self.seq_profile << 5 | self.seq_level_idx_0 & 0x1F, flags, delay];
+        buf.extend_from_slice(&self.config_obus);
+        buf
+    }
+
+    pub fn bit_depth(&self) -> u8 {
+        match (self.high_bitdepth, self.twelve_bit) {
+            (true, true) => 12,
+            (true, false) => 10,
+            _ => 8,
+        }
+    }
+}
+
+/// VP9 codec features, https://www.webmproject.org/docs/container/#vp9-codec-feature-metadata-codecprivate
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vp9Features {
+    pub profile: Option<u8>,
+    pub level: Option<u8>,
+    pub bit_depth: Option<u8>,
+    pub chroma_subsampling: Option<u8>,
+}
+
+impl Vp9Features {
+    const PROFILE: u8 = 1;
+    const LEVEL: u8 = 2;
+    const BIT_DEPTH: u8 = 3;
+    const CHROMA_SUBSAMPLING: u8 = 4;
+
+    /// Features with unknown IDs are skipped
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "VP9 codec features");
+        let mut features = Self::default();
+        while !r.is_empty() {
+            let id = r.u8()?;
+            let len = r.u8()?;
+            let value = r.take(len as usize)?;
+            let value = match value {
+                [value] => Some(*value),
+                _ => None,
+            };
+            let feature = match id {
+                Self::PROFILE => &mut features.profile,
+                Self::LEVEL => &mut features.level,
+                Self::BIT_DEPTH => &mut features.bit_depth,
+                Self::CHROMA_SUBSAMPLING => &mut features.chroma_subsampling,
+                _ => continue,
+            };
+            *feature = Some(value.ok_or_else(|| r.invalid(&format!("feature {id} isn't 1 byte long")))?);
+        }
+        Ok(features)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let features = [
+            (Self::PROFILE, self.profile),
+            (Self::LEVEL, self.level),
+            (Self::BIT_DEPTH, self.bit_depth),
+            (Self::CHROMA_SUBSAMPLING, self.chroma_subsampling),
+        ];
+        features.into_iter()
+            .filter_map(|(id, value)| value.map(|value| [id, 1, value]))
+            .flatten()
+            .collect()
+    }
+}
+
+/// OpusHead identification header, https://www.rfc-editor.org/rfc/rfc7845#section-5.1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpusHead {
+    pub version: u8,
+    pub channels: u8,
+    /// Samples at 48 kHz to discard from the decoder output, stored in ns by `TrackEntry::codec_delay`
+    pub pre_skip: u16,
+    /// Sample rate of the source, informational only, Opus is always decoded at 48 kHz
+    pub input_sample_rate: u32,
+    /// Q7.8 dB
+    pub output_gain: i16,
+    pub mapping_family: u8,
+    /// Channel mapping table, present for the families other than 0
+    pub mapping: Option<OpusMapping>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpusMapping {
+    pub stream_count: u8,
+    pub coupled_count: u8,
+    /// Decoded channel of every output channel
+    pub channel_mapping: Vec<u8>,
+}
+
+impl OpusHead {
+    const MAGIC: &'static [u8] = b"OpusHead";
+
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "OpusHead");
+        if r.take(Self::MAGIC.len())? != Self::MAGIC {
+            return Err(r.invalid("magic signature isn't 'OpusHead'"));
+        }
+        let version = r.u8()?;
+        if version >> 4 != 0 {
+            return Err(r.invalid(&format!("unsupported version {version}")));
+        }
+        let channels = r.u8()?;
+        let pre_skip = r.u16_le()?;
+        let input_sample_rate = r.u32_le()?;
+        let output_gain = r.u16_le()? as i16;
+        let mapping_family = r.u8()?;
+        let mapping = match mapping_family {
+            0 => None,
+            _ => Some(OpusMapping {
+                stream_count: r.u8()?,
+                coupled_count: r.u8()?,
+                channel_mapping: r.take(channels as usize)?.to_vec(),
+            }),
+        };
+        Ok(Self { version, channels, pre_skip, input_sample_rate, output_gain, mapping_family, mapping })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Self::MAGIC.to_vec();
+        buf.extend_from_slice(&[self.version, self.channels]);
+        buf.extend_from_slice(&self.pre_skip.to_le_bytes());
+        buf.extend_from_slice(&self.input_sample_rate.to_le_bytes());
+        buf.extend_from_slice(&self.output_gain.to_le_bytes());
+        buf.push(self.mapping_family);
+        if let Some(mapping) = &self.mapping {
+            buf.extend_from_slice(&[mapping.stream_count, mapping.coupled_count]);
+            buf.extend_from_slice(&mapping.channel_mapping);
+        }
+        buf
+    }
+
+    /// `pre_skip` in nanoseconds, the expected `TrackEntry::codec_delay`
+    pub fn codec_delay(&self) -> u64 {
+        self.pre_skip as u64 * 1_000_000_000 / OPUS_SAMPLE_RATE
+    }
+}
+
+/// Identification, comment and setup headers of Vorbis and Theora, Xiph laced in CodecPrivate
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XiphHeaders {
+    pub identification: Vec<u8>,
+    pub comment: Vec<u8>,
+    pub setup: Vec<u8>,
+}
+
+impl XiphHeaders {
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let invalid = |reason: String| MatroskaError::InvalidCodecPrivate(format!("Xiph headers: {reason}"));
+        let headers = lacing::decode(Lacing::Xiph, data).map_err(|err| invalid(err.to_string()))?;
+        match &headers[..] {
+            [identification, comment, setup] => Ok(Self {
+                identification: identification.to_vec(),
+                comment: comment.to_vec(),
+                setup: setup.to_vec(),
+            }),
+            headers => Err(invalid(format!("{} headers instead of 3", headers.len()))),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        lacing::encode_with(Lacing::Xiph, &[&self.identification, &self.comment, &self.setup])
+            .expect("3 frames can be Xiph laced")
+    }
+
+    /// Parameters of a Vorbis stream from the identification header
+    pub fn vorbis(&self) -> Result<VorbisIdentification, MatroskaError> {
+        VorbisIdentification::parse(&self.identification)
+    }
+}
+
+/// Vorbis identification header, https://xiph.org/vorbis/doc/Vorbis_I_spec.html#x1-630004.2.2
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VorbisIdentification {
+    pub channels: u8,
+    pub sample_rate: u32,
+    pub bitrate_maximum: i32,
+    pub bitrate_nominal: i32,
+    pub bitrate_minimum: i32,
+    pub blocksize_0: u16,
+    pub blocksize_1: u16,
+}
+
+impl VorbisIdentification {
+    const MAGIC: &'static [u8] = b"\x01vorbis";
+
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "Vorbis identification header");
+        if r.take(Self::MAGIC.len())? != Self::MAGIC {
+            return Err(r.invalid("packet isn't a Vorbis identification header"));
+        }
+        if r.u32_le()? != 0 {
+            return Err(r.invalid("vorbis_version isn't 0"));
+        }
+        let channels = r.u8()?;
+        let sample_rate = r.u32_le()?;
+        let bitrate_maximum = r.u32_le()? as i32;
+        let bitrate_nominal = r.u32_le()? as i32;
+        let bitrate_minimum = r.u32_le()? as i32;
+        let blocksizes = r.u8()?;
+        Ok(Self {
+            channels, sample_rate, bitrate_maximum, bitrate_nominal, bitrate_minimum,
+            blocksize_0: 1 << (blocksizes & 0x0F),
+            blocksize_1: 1 << (blocksizes >> 4),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Self::MAGIC.to_vec();
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        buf.push(self.channels);
+        buf.extend_from_slice(&self.sample_rate.to_le_bytes());
+        for bitrate in [self.bitrate_maximum, self.bitrate_nominal, self.bitrate_minimum] {
+            buf.extend_from_slice(&bitrate.to_le_bytes());
+        }
+        let exponent = |blocksize: u16| blocksize.max(1).trailing_zeros() as u8;
+        buf.push(exponent(self.blocksize_1) << 4 | exponent(self.blocksize_0));
+        // framing flag
+        buf.push(1);
+        buf
+    }
+}
+
+/// `fLaC` signature followed by the metadata blocks, STREAMINFO first,
+/// https://www.rfc-editor.org/rfc/rfc9639#section-8
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlacConfig {
+    pub stream_info: FlacStreamInfo,
+    /// Metadata blocks after STREAMINFO, e.g. VORBIS_COMMENT or SEEKTABLE
+    pub blocks: Vec<FlacMetadataBlock>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlacStreamInfo {
+    pub min_block_size: u16,
+    pub max_block_size: u16,
+    /// 24 bits, 0 if unknown
+    pub min_frame_size: u32,
+    /// 24 bits, 0 if unknown
+    pub max_frame_size: u32,
+    /// 20 bits
+    pub sample_rate: u32,
+    pub channels: u8,
+    pub bits_per_sample: u8,
+    /// 36 bits, 0 if unknown
+    pub total_samples: u64,
+    pub md5: [u8; 16],
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlacMetadataBlock {
+    pub block_type: u8,
+    pub data: Vec<u8>,
+}
+
+impl FlacConfig {
+    const MAGIC: &'static [u8] = b"fLaC";
+    const STREAM_INFO: u8 = 0;
+    const STREAM_INFO_LEN: usize = 34;
+
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "FLAC metadata");
+        if r.take(Self::MAGIC.len())? != Self::MAGIC {
+            return Err(r.invalid("signature isn't 'fLaC'"));
+        }
+        let mut blocks = vec![];
+        loop {
+            let header = r.u8()?;
+            let len = r.u24()?;
+            blocks.push(FlacMetadataBlock { block_type: header & 0x7F, data: r.take(len as usize)?.to_vec() });
+            if header & 0x80 != 0 || r.is_empty() {
+                break;
+            }
+        }
+        let stream_info = blocks.remove(0);
+        if stream_info.block_type != Self::STREAM_INFO || stream_info.data.len() != Self::STREAM_INFO_LEN {
+            return Err(r.invalid("first metadata block isn't STREAMINFO"));
+        }
+        Ok(Self { stream_info: FlacStreamInfo::parse(&stream_info.data)?, blocks })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Self::MAGIC.to_vec();
+        let stream_info = FlacMetadataBlock { block_type: Self::STREAM_INFO, data: self.stream_info.to_bytes() };
+        let blocks: Vec<&FlacMetadataBlock> = std::iter::once(&stream_info).chain(&self.blocks).collect();
+        for (i, block) in blocks.iter().enumerate() {
+            let last = if i + 1 == blocks.len() { 0x80 } else { 0 };
+            buf.push(last | block.block_type & 0x7F);
+            buf.extend_from_slice(&(block.data.len() as u32).to_be_bytes()[1..]);
+            buf.extend_from_slice(&block.data);
+        }
+        buf
+    }
+}
+
+impl FlacStreamInfo {
+    fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bytes::new(data, "FLAC STREAMINFO");
+        let min_block_size = r.u16()?;
+        let max_block_size = r.u16()?;
+        let min_frame_size = r.u24()?;
+        let max_frame_size = r.u24()?;
+        // sample rate 20 bits, channels - 1 3 bits, bits per sample - 1 5 bits, total samples 36 bits
+        let packed = (r.u32()? as u64) << 32 | r.u32()? as u64;
+        let md5 = r.take(16)?.try_into().expect("16 bytes");
+        Ok(Self {
+            min_block_size, max_block_size, min_frame_size, max_frame_size,
+            sample_rate: (packed >> 44) as u32,
+            channels: ((packed >> 41) & 0b111) as u8 + 1,
+            bits_per_sample: ((packed >> 36) & 0b1_1111) as u8 + 1,
+            total_samples: packed & 0xF_FFFF_FFFF,
+            md5,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        buf.extend_from_slice(&self.min_block_size.to_be_bytes());
+        buf.extend_from_slice(&self.max_block_size.to_be_bytes());
+        buf.extend_from_slice(&self.min_frame_size.to_be_bytes()[1..]);
+        buf.extend_from_slice(&self.max_frame_size.to_be_bytes()[1..]);
+        let packed = (self.sample_rate as u64 & 0xF_FFFF) << 44
+            | ((self.channels.max(1) - 1) as u64 & 0b111) << 41
+            | ((self.bits_per_sample.max(1) - 1) as u64 & 0b1_1111) << 36
+            | self.total_samples & 0xF_FFFF_FFFF;
+        buf.extend_from_slice(&packed.to_be_bytes());
+        buf.extend_from_slice(&self.md5);
+        buf
+    }
+}
+
+/// AAC AudioSpecificConfig, ISO/IEC 14496-3 1.6.2.1, with the GASpecificConfig of the AAC object types
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AacConfig {
+    /// Object type of the core, e.g. 2 for AAC LC
+    pub object_type: u8,
+    /// Sampling frequency of the core
+    pub sampling_frequency: u32,
+    /// 1-6 channels, 7 for 7.1, 0 if the channels are defined by a program config element
+    pub channel_configuration: u8,
+    /// SBR or PS signaled explicitly, e.g. by HE-AAC
+    pub extension: Option<AacExtension>,
+    /// 960 samples per frame instead of 1024
+    pub frame_length_flag: bool,
+    pub core_coder_delay: Option<u16>,
+    pub extension_flag: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AacExtension {
+    /// 5 for SBR, 29 for PS
+    pub object_type: u8,
+    /// Output sampling frequency of SBR
+    pub sampling_frequency: u32,
+}
+
+impl AacConfig {
+    const SAMPLING_FREQUENCIES: [u32; 13] = [96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350];
+    const SBR: u8 = 5;
+    const PS: u8 = 29;
+    /// Object types starting with a GASpecificConfig
+    const GENERAL_AUDIO: [u8; 12] = [1, 2, 3, 4, 6, 7, 17, 19, 20, 21, 22, 23];
+
+    pub fn parse(data: &[u8]) -> Result<Self, MatroskaError> {
+        let mut r = Bits { buf: data, pos: 0 };
+        let mut object_type = r.object_type()?;
+        let sampling_frequency = r.sampling_frequency()?;
+        let channel_configuration = r.read(4)? as u8;
+        let mut extension = None;
+        if object_type == Self::SBR || object_type == Self::PS {
+            extension = Some(AacExtension { object_type, sampling_frequency: r.sampling_frequency()? });
+            object_type = r.object_type()?;
+        }
+        let mut config = Self { object_type, sampling_frequency, channel_configuration, extension, ..Default::default() };
+        if Self::GENERAL_AUDIO.contains(&object_type) {
+            config.frame_length_flag = r.read(1)? != 0;
+            if r.read(1)? != 0 {
+                config.core_coder_delay = Some(r.read(14)? as u16);
+            }
+            config.extension_flag = r.read(1)? != 0;
+        }
+        Ok(config)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = BitWriter::default();
+        match &self.extension {
+            Some(extension) => {
+                self.write_object_type(&mut w, extension.object_type);
+                self.write_sampling_frequency(&mut w, self.sampling_frequency);
+                w.write(self.channel_configuration as u32, 4);
+                self.write_sampling_frequency(&mut w, extension.sampling_frequency);
+                self.write_object_type(&mut w, self.object_type);
+            }
+            None => {
+                self.write_object_type(&mut w, self.object_type);
+                self.write_sampling_frequency(&mut w, self.sampling_frequency);
+                w.write(self.channel_configuration as u32, 4);
+            }
+        }
+        if Self::GENERAL_AUDIO.contains(&self.object_type) {
+            w.write(self.frame_length_flag as u32, 1);
+            w.write(self.core_coder_delay.is_some() as u32, 1);
+            if let Some(delay) = self.core_coder_delay {
+                w.write(delay as u32, 14);
+            }
+            w.write(self.extension_flag as u32, 1);
+        }
+        w.finish()
+    }
+
+    /// Channels of the channel configuration, `None` for a program config element
+    pub fn channels(&self) -> Option<u8> {
+        match self.channel_configuration {
+            1..=6 => Some(self.channel_configuration),
+            7 => Some(8),
+            _ => None,
+        }
+    }
+
+    fn write_object_type(&self, w: &mut BitWriter, object_type: u8) {
+        if object_type < 31 {
+            w.write(object_type as u32, 5);
+        } else {
+            w.write(31, 5);
+            w.write(object_type as u32 - 32, 6);
+        }
+    }
+    fn write_sampling_frequency(&self, w: &mut BitWriter, frequency: u32) {
+        match Self::SAMPLING_FREQUENCIES.iter().position(|f| *f == frequency) {
+            Some(index) => w.write(index as u32, 4),
+            None => {
+                w.write(15, 4);
+                w.write(frequency, 24);
+            }
+        }
+    }
+}
+
+/// Big-endian bit reader of AudioSpecificConfig
+struct Bits<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl Bits<'_> {
+    fn read(&mut self, bits: usize) -> Result<u32, MatroskaError> {
+        if self.pos + bits > self.buf.len() * 8 {
+            return Err(MatroskaError::InvalidCodecPrivate("AudioSpecificConfig: truncated".to_string()));
+        }
+        let mut value = 0;
+        for _ in 0..bits {
+            let bit = self.buf[self.pos / 8] >> (7 - self.pos % 8) & 1;
+            value = value << 1 | bit as u32;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+    fn object_type(&mut self) -> Result<u8, MatroskaError> {
+        Ok(match self.read(5)? as u8 {
+            31 => 32 + self.read(6)? as u8,
+            object_type => object_type,
+        })
+    }
+    fn sampling_frequency(&mut self) -> Result<u32, MatroskaError> {
+        match self.read(4)? as usize {
+            15 => self.read(24),
+            index => AacConfig::SAMPLING_FREQUENCIES.get(index).copied()
+                .ok_or_else(|| MatroskaError::InvalidCodecPrivate(format!("AudioSpecificConfig: reserved sampling frequency index {index}"))),
+        }
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    buf: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: usize) {
+        for i in (0..bits).rev() {
+            if self.bits.is_multiple_of(8) {
+                self.buf.push(0);
+            }
+            let bit = (value >> i & 1) as u8;
+            *self.buf.last_mut().expect("pushed above") |= bit << (7 - self.bits % 8);
+            self.bits += 1;
+        }
+    }
+    fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+fn put_nal_units(buf: &mut Vec<u8>, nal_units: &[Vec<u8>]) {
+    for nal in nal_units {
+        buf.extend_from_slice(&(nal.len() as u16).to_be_bytes());
+        buf.extend_from_slice(nal);
+    }
+}
+
+/// Reader of a record, big-endian unless the method says otherwise, errors name the record
+struct Bytes<'a> {
+    buf: &'a [u8],
+    record: &'static str,
+}
+
+impl<'a> Bytes<'a> {
+    fn new(buf: &'a [u8], record: &'static str) -> Self {
+        Self { buf, record }
+    }
+    fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    fn invalid(&self, reason: &str) -> MatroskaError {
+        MatroskaError::InvalidCodecPrivate(format!("{}: {reason}", self.record))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], MatroskaError> {
+        if self.buf.len() < len {
+            return Err(self.invalid("truncated"));
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+    fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.buf)
+    }
+    fn u8(&mut self) -> Result<u8, MatroskaError> {
+        Ok(self.take(1)?[0])
+    }
+    fn u16(&mut self) -> Result<u16, MatroskaError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+    fn u32(&mut self) -> Result<u32, MatroskaError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+    fn u24(&mut self) -> Result<u32, MatroskaError> {
+        let bytes = self.take(3)?;
+        Ok(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+    fn u16_le(&mut self) -> Result<u16, MatroskaError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+    fn u32_le(&mut self) -> Result<u32, MatroskaError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+    /// NAL units prefixed by their 16 bits length
+    fn nal_units(&mut self, count: usize) -> Result<Vec<Vec<u8>>, MatroskaError> {
+        (0..count).map(|_| {
+            let len = self.u16()?;
+            Ok(self.take(len as usize)?.to_vec())
+        }).collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_video_codec_private() -> Result<(), anyhow::Error> {
+        // x264 High 4:2:0 8-bit, level 4.0
+        let avc = [
+            0x01, 0x64, 0x00, 0x28, 0xFF, 0xE1, 0x00, 0x04, 0x67, 0x64, 0x00, 0x28,
+            0x01, 0x00, 0x03, 0x68, 0xEE, 0x3C, 0xFD, 0xF8, 0xF8, 0x00,
+        ];
+        let Some(CodecPrivate::Avc(config)) = CodecPrivate::parse(CODEC_AVC, &avc)? else { panic!("AVC") };
+        assert_eq!((config.profile, config.level, config.nal_length_size, config.bit_depth()), (100, 40, 4, 8));
+        assert_eq!(config.sps, vec![vec![0x67, 0x64, 0x00, 0x28]]);
+        assert_eq!(config.pps, vec![vec![0x68, 0xEE, 0x3C]]);
+        assert_eq!(config.ext.as_ref().map(|ext| ext.chroma_format), Some(1));
+        assert_eq!(config.to_bytes(), avc);
+        assert!(matches!(AvcConfig::parse(&avc[..10]), Err(MatroskaError::InvalidCodecPrivate(_))));
+
+        let hevc = HevcConfig {
+            profile_idc: 2,
+            profile_compatibility: 0x2000_0000,
+            constraint_indicator: 0x9000_0000_0000,
+            level_idc: 93,
+            chroma_format: 1,
+            bit_depth_luma: 10,
+            bit_depth_chroma: 10,
+            num_temporal_layers: 1,
+            temporal_id_nested: true,
+            nal_length_size: 4,
+            arrays: [HEVC_NAL_VPS, HEVC_NAL_SPS, HEVC_NAL_PPS].into_iter()
+                .map(|nal_unit_type| HevcNalArray { complete: true, nal_unit_type, nal_units: vec![vec![nal_unit_type << 1, 1]] })
+                .collect(),
+            ..Default::default()
+        };
+        let bytes = hevc.to_bytes();
+        assert_eq!(bytes.len(), 23 + 3 * 7);
+        assert_eq!(CodecPrivate::parse(CODEC_HEVC, &bytes)?, Some(CodecPrivate::Hevc(hevc.clone())));
+        assert_eq!(hevc.sps(), vec![&[HEVC_NAL_SPS << 1, 1][..]]);
+
+        // main profile, level 4.0, 10 bits 4:2:0 and a Sequence Header OBU
+        let av1 = [0x81, 0x08, 0x4C, 0x00, 0x0A, 0x02, 0x00, 0x00];
+        let Some(CodecPrivate::Av1(config)) = CodecPrivate::parse(CODEC_AV1, &av1)? else { panic!("AV1") };
+        assert_eq!((config.seq_profile, config.seq_level_idx_0, config.bit_depth()), (0, 8, 10));
+        assert!(config.chroma_subsampling_x && config.chroma_subsampling_y);
+        assert_eq!(config.config_obus, [0x0A, 0x02, 0x00, 0x00]);
+        assert_eq!(config.to_bytes(), av1);
+
+        let vp9 = [1, 1, 2, 3, 1, 10, 9, 2, 0, 0];
+        let Some(CodecPrivate::Vp9(features)) = CodecPrivate::parse(CODEC_VP9, &vp9)? else { panic!("VP9") };
+        assert_eq!(features, Vp9Features { profile: Some(2), level: None, bit_depth: Some(10), chroma_subsampling: None });
+        assert_eq!(features.to_bytes(), [1, 1, 2, 3, 1, 10]);
+        assert_eq!(CodecPrivate::parse(CODEC_VP9, &[])?, Some(CodecPrivate::Vp9(Vp9Features::default())));
+
+        assert_eq!(CodecPrivate::parse("A_PCM/INT/LIT", &[1, 2])?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_audio_codec_private() -> Result<(), anyhow::Error> {
+        let opus = [b"OpusHead".as_slice(), &[1, 2, 0x38, 0x01, 0x80, 0xBB, 0, 0, 0, 0, 0]].concat();
+        let Some(CodecPrivate::Opus(head)) = CodecPrivate::parse(CODEC_OPUS, &opus)? else { panic!("Opus") };
+        assert_eq!((head.channels, head.pre_skip, head.input_sample_rate, head.mapping_family), (2, 312, 48000, 0));
+        assert_eq!(head.codec_delay(), 6_500_000);
+        assert_eq!(head.to_bytes(), opus);
+
+        let mut track = TrackEntry::default();
+        *track.codec_id.v = CODEC_OPUS.to_string();
+        let mut audio = crate::structs::Audio::default();
+        *audio.sampling_frequency.v = 48000.0;
+        *audio.channels.v = 2;
+        track.audio = Some(crate::Ebml::new(audio));
+        *track.codec_delay.v = 6_500_000;
+        track.codec_private = Some(crate::Ebml::new(opus));
+        let codec_private = CodecPrivate::from_track(&track)?.expect("Opus");
+        assert!(codec_private.check(&track).is_empty());
+        *track.codec_delay.v = 0;
+        *track.audio.as_mut().expect("Audio").v.channels.v = 6;
+        let elements: Vec<&str> = codec_private.check(&track).into_iter().map(|(element, _)| element).collect();
+        assert_eq!(elements, ["CodecDelay", "Audio\\Channels"]);
+
+        let vorbis = VorbisIdentification {
+            channels: 2, sample_rate: 44100, bitrate_maximum: 0, bitrate_nominal: 128000, bitrate_minimum: 0,
+            blocksize_0: 256, blocksize_1: 2048,
+        };
+        let headers = XiphHeaders { identification: vorbis.to_bytes(), comment: b"\x03vorbis".to_vec(), setup: vec![5; 300] };
+        let bytes = headers.to_bytes();
+        // 2 lace sizes, the identification header is 30 bytes
+        assert_eq!(bytes[..3], [2, 30, 7]);
+        let Some(CodecPrivate::Vorbis(parsed)) = CodecPrivate::parse(CODEC_VORBIS, &bytes)? else { panic!("Vorbis") };
+        assert_eq!(parsed.vorbis()?, vorbis);
+        assert!(CodecPrivate::parse(CODEC_VORBIS, &bytes[..1]).is_err());
+
+        let stream_info = FlacStreamInfo {
+            min_block_size: 4096, max_block_size: 4096, min_frame_size: 14, max_frame_size: 12000,
+            sample_rate: 96000, channels: 6, bits_per_sample: 24, total_samples: 0x1_2345_6789, md5: [9; 16],
+        };
+        let flac = FlacConfig { stream_info, blocks: vec![FlacMetadataBlock { block_type: 4, data: vec![0; 8] }] };
+        let bytes = flac.to_bytes();
+        assert_eq!(bytes[4..8], [0, 0, 0, 34]);
+        assert_eq!(bytes[42..46], [0x84, 0, 0, 8]);
+        assert_eq!(FlacConfig::parse(&bytes)?, flac);
+
+        // AAC LC 44.1 kHz stereo
+        let Some(CodecPrivate::Aac(lc)) = CodecPrivate::parse(CODEC_AAC, &[0x12, 0x10])? else { panic!("AAC") };
+        assert_eq!((lc.object_type, lc.sampling_frequency, lc.channels()), (2, 44100, Some(2)));
+        assert_eq!(lc.to_bytes(), [0x12, 0x10]);
+        // HE-AAC, SBR signaled explicitly: 24 kHz core, 48 kHz output
+        let he = AacConfig { extension: Some(AacExtension { object_type: 5, sampling_frequency: 48000 }), sampling_frequency: 24000, ..lc };
+        let bytes = he.to_bytes();
+        assert_eq!(bytes, [0x2B, 0x11, 0x88, 0x00]);
+        assert_eq!(AacConfig::parse(&bytes)?, he);
+        let mut aac = TrackEntry { codec_id: crate::Ebml::new(CODEC_AAC.to_string()), audio: track.audio.clone(), ..Default::default() };
+        *aac.audio.as_mut().expect("Audio").v.channels.v = 2;
+        assert!(CodecPrivate::Aac(he).check(&aac).is_empty());
+        assert_eq!(CodecPrivate::Aac(lc).check(&aac)[0].0, "Audio\\SamplingFrequency");
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use std::io::{Read, Write};
+
+use super::structs::*;
+use super::enums::*;
+use super::{Ebml, MatroskaError};
+use super::encryption::{self, KeyLookup};
+
+// https://www.rfc-editor.org/rfc/rfc9559.html#name-contentencoding-element
+const SCOPE_BLOCK: u64 = 1;
+const SCOPE_PRIVATE: u64 = 2;
+
+/// Compression of the frames of a track written by the Muxer, see `Muxer::with_compression`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Compression {
+    Zlib,
+    /// Bytes every frame starts with, they are stripped from the frames and stored once in the track
+    HeaderStripping(Vec<u8>),
+}
+
+impl Compression {
+    /// ContentEncodings of the track announcing the compression of its frames
+    pub fn content_encodings(&self) -> ContentEncodings {
+        let (algo, settings) = match self {
+            Compression::Zlib => (ContentCompAlgo::Zlib, None),
+            Compression::HeaderStripping(header) => (ContentCompAlgo::HeaderStripping, Some(Ebml::new_index(1, header.clone()))),
+        };
+        let compression = ContentCompression {
+            content_comp_algo: Ebml::new_index(0, algo),
+            content_comp_settings: settings,
+            ..Default::default()
+        };
+        let encoding = ContentEncoding {
+            content_encoding_order: Ebml::new_index(0, 0),
+            content_encoding_scope: Ebml::new_index(1, ContentEncodingScope::Block),
+            content_encoding_type: Ebml::new_index(2, ContentEncodingType::Compression),
+            content_compression: Some(Ebml::new_index(3, compression)),
+            ..Default::default()
+        };
+        ContentEncodings { content_encoding: vec![Ebml::new_index(0, encoding)], ..Default::default() }
+    }
+}
+
+/// Undo the encodings of the track on a frame, starting with the highest ContentEncodingOrder,
+/// the keys of the encrypted tracks are looked up by their ContentEncKeyID
+pub fn decode_frame(encodings: &ContentEncodings, frame: Vec<u8>, keys: &KeyLookup) -> Result<Vec<u8>, MatroskaError> {
+    decode(encodings, SCOPE_BLOCK, frame, Some(keys))
+}
+/// Undo the encodings of the track on its CodecPrivate
+pub fn decode_private(encodings: &ContentEncodings, codec_private: Vec<u8>) -> Result<Vec<u8>, MatroskaError> {
+    decode(encodings, SCOPE_PRIVATE, codec_private, None)
+}
+/// Apply the encodings of the track to a frame, starting with the lowest ContentEncodingOrder,
+/// every encrypted frame gets a random IV
+pub fn encode_frame(encodings: &ContentEncodings, frame: Vec<u8>, keys: &KeyLookup) -> Result<Vec<u8>, MatroskaError> {
+    let mut data = frame;
+    for encoding in sorted(encodings, SCOPE_BLOCK) {
+        data = match (*encoding.content_encoding_type.v, &encoding.content_compression, &encoding.content_encryption) {
+            (ContentEncodingType::Compression, Some(compression), _) => compress(&compression.v, data)?,
+            (ContentEncodingType::Encryption, _, Some(encryption)) => {
+                let key = keys(key_id(&encryption.v)?).ok_or_else(|| missing_key(&encryption.v))?;
+                encryption::encrypt_frame(&key, rand::random(), &data, &[])?
+            }
+            _ => return Err(unsupported(encoding)),
+        };
+    }
+    Ok(data)
+}
+
+fn decode(encodings: &ContentEncodings, scope: u64, data: Vec<u8>, keys: Option<&KeyLookup>) -> Result<Vec<u8>, MatroskaError> {
+    let mut data = data;
+    for encoding in sorted(encodings, scope).into_iter().rev() {
+        data = match (*encoding.content_encoding_type.v, &encoding.content_compression, &encoding.content_encryption, keys) {
+            (ContentEncodingType::Compression, Some(compression), _, _) => decompress(&compression.v, data)?,
+            (ContentEncodingType::Encryption, _, Some(encryption), Some(keys)) => {
+                let key = keys(key_id(&encryption.v)?);
+                encryption::decrypt_frame(key.as_ref(), &data).map_err(|err| match key {
+                    None => missing_key(&encryption.v),
+                    Some(_) => err,
+                })?
+            }
+            _ => return Err(unsupported(encoding)),
+        };
+    }
+    Ok(data)
+}
+
+/// Encodings of the scope by ContentEncodingOrder
+fn sorted(encodings: &ContentEncodings, scope: u64) -> Vec<&ContentEncoding> {
+    let mut sorted: Vec<&ContentEncoding> = encodings.content_encoding.iter()
+        .map(|encoding| &*encoding.v)
+        .filter(|encoding| encoding.content_encoding_scope.v.value() & scope != 0)
+        .collect();
+    sorted.sort_by_key(|encoding| *encoding.content_encoding_order.v);
+    sorted
+}
+
+fn unsupported(encoding: &ContentEncoding) -> MatroskaError {
+    MatroskaError::InvalidContentEncoding(format!("unsupported {:?}", encoding.content_encoding_type.v))
+}
+
+/// ContentEncKeyID of the WebM Encryption, AES in the CTR mode
+fn key_id(encryption: &ContentEncryption) -> Result<&[u8], MatroskaError> {
+    let mode = encryption.content_enc_aes_settings.as_ref().map(|settings| *settings.v.aes_settings_cipher_mode.v);
+    match (*encryption.content_enc_algo.v, mode) {
+        (ContentEncAlgo::Aes, None | Some(AesSettingsCipherMode::AesCtr)) => {
+            Ok(encryption.content_enc_key_id.as_ref().map_or(&[], |key_id| &key_id.v[..]))
+        }
+        (algo, mode) => Err(MatroskaError::InvalidContentEncoding(format!("unsupported encryption {algo:?} {mode:?}"))),
+    }
+}
+
+fn missing_key(encryption: &ContentEncryption) -> MatroskaError {
+    MatroskaError::InvalidContentEncoding(format!("no key for the ContentEncKeyID {:02X?}", key_id(encryption).unwrap_or_default()))
+}
+
+fn compress(compression: &ContentCompression, data: Vec<u8>) -> Result<Vec<u8>, MatroskaError> {
+    match *compression.content_comp_algo.v {
+        ContentCompAlgo::Zlib => {
+            let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&data)?;
+            Ok(encoder.finish()?)
+        }
+        ContentCompAlgo::HeaderStripping => {
+            let header = stripped_header(compression);
+            match data.strip_prefix(header) {
+                Some(rest) => Ok(rest.to_vec()),
+                None => Err(MatroskaError::InvalidContentEncoding("frame doesn't start with the stripped header".to_string())),
+            }
+        }
+        algo => Err(MatroskaError::InvalidContentEncoding(format!("unsupported compression {algo:?}"))),
+    }
+}
+
+fn decompress(compression: &ContentCompression, data: Vec<u8>) -> Result<Vec<u8>, MatroskaError> {
+    match *compression.content_comp_algo.v {
+        ContentCompAlgo::Zlib => {
+            let mut buf = vec![];
+            flate2::read::ZlibDecoder::new(&data[..]).read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+        ContentCompAlgo::HeaderStripping => {
+            let mut buf = stripped_header(compression).to_vec();
+            buf.extend_from_slice(&data);
+            Ok(buf)
+        }
+        algo => Err(MatroskaError::InvalidContentEncoding(format!("unsupported compression {algo:?}"))),
+    }
+}
+
+fn stripped_header(compression: &ContentCompression) -> &[u8] {
+    compression.content_comp_settings.as_ref().map_or(&[], |settings| &settings.v[..])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, Demuxer, Frame, Muxer};
+
+    #[test]
+    fn test_content_encoding() -> Result<(), anyhow::Error> {
+        let frame = b"\x00\x00\x00\x01frame data frame data frame data".to_vec();
+        for compression in [Compression::Zlib, Compression::HeaderStripping(vec![0, 0, 0, 1])] {
+            let encodings = compression.content_encodings();
+            let encoded = encode_frame(&encodings, frame.clone(), &|_| None)?;
+            assert!(encoded.len() < frame.len());
+            assert_eq!(decode_frame(&encodings, encoded, &|_| None)?, frame);
+            // the Block scope doesn't cover CodecPrivate
+            assert_eq!(decode_private(&encodings, vec![1, 2])?, vec![1, 2]);
+        }
+        let encodings = Compression::HeaderStripping(vec![0, 0, 0, 1]).content_encodings();
+        assert!(matches!(encode_frame(&encodings, vec![1, 2], &|_| None), Err(MatroskaError::InvalidContentEncoding(_))));
+
+        // zlib applied first, the stripped header is restored last
+        let mut encodings = Compression::Zlib.content_encodings();
+        let mut stripping = Compression::HeaderStripping(vec![0x78]).content_encodings().content_encoding.remove(0);
+        *stripping.v.content_encoding_order.v = 1;
+        encodings.content_encoding.push(stripping);
+        let encoded = encode_frame(&encodings, frame.clone(), &|_| None)?;
+        assert_eq!(decode_frame(&encodings, encoded, &|_| None)?, frame);
+        Ok(())
+    }
+
+    #[test]
+    fn test_muxer_compression() -> Result<(), anyhow::Error> {
+        let track = test_util::video_track(1, "V_TEST");
+        let header = vec![0xAA, 0xBB];
+        let mut muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track])
+            .with_compression(1, Compression::HeaderStripping(header.clone()));
+        for i in 0..3u8 {
+            let data = [&header[..], &[i; 10]].concat();
+            muxer.write_frame_blocking(Frame { track: 1, pts_ns: i as i64 * 40_000_000, duration: None, keyframe: true, data })?;
+        }
+        let buf = muxer.finish_seekable_blocking()?.into_inner();
+        // the header is stored once in the track
+        assert_eq!(buf.windows(3).filter(|w| *w == [0xAA, 0xBB, 0]).count(), 0);
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf))?;
+        let encodings = demuxer.track(1).and_then(|track| track.content_encodings.as_ref()).expect("ContentEncodings");
+        assert_eq!(encodings.v.content_encoding.len(), 1);
+        for i in 0..3u8 {
+            let frame = demuxer.next_frame_blocking()?.expect("frame");
+            assert_eq!(frame.data, [&header[..], &[i; 10]].concat());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_muxer_encryption() -> Result<(), anyhow::Error> {
+        let key_id = b"key 1".to_vec();
+        let key = [0x42; 16];
+        let track = test_util::video_track(1, "V_TEST");
+        let mut muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track])
+            .with_compression(1, Compression::Zlib)
+            .with_encryption(1, key_id.clone(), key);
+        let frames: Vec<Vec<u8>> = (0..3u8).map(|i| [b"secret frame".to_vec(), vec![i; 10]].concat()).collect();
+        for (i, data) in frames.iter().enumerate() {
+            muxer.write_frame_blocking(Frame { track: 1, pts_ns: i as i64 * 40_000_000, duration: None, keyframe: true, data: data.clone() })?;
+        }
+        let buf = muxer.finish_seekable_blocking()?.into_inner();
+
+        let demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf.clone()))?;
+        let encodings = &demuxer.track(1).and_then(|track| track.content_encodings.as_ref()).expect("ContentEncodings").v;
+        // compressed first, encrypted last
+        let encryption = encodings.content_encoding.iter().find(|encoding| *encoding.v.content_encoding_order.v == 1).expect("encryption");
+        assert_eq!(*encryption.v.content_encoding_type.v, ContentEncodingType::Encryption);
+        assert_eq!(encryption.v.content_encryption.as_ref().and_then(|encryption| encryption.v.content_enc_key_id.as_ref()).map(|id| &*id.v), Some(&key_id));
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf.clone()))?
+            .with_key_lookup(move |id| (id == b"key 1").then_some(key));
+        for data in &frames {
+            assert_eq!(&demuxer.next_frame_blocking()?.expect("frame").data, data);
+        }
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf))?;
+        assert!(demuxer.next_frame_blocking().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use super::{io, ElementRead, ElementReadBlocking, ElementSize, MatroskaError, ReadError, ReadOptions};
+use super::ids::EbmlId;
+
+/// CRC-32 of the IEEE 802.3 polynomial, stored little-endian in the `CRC-32` element
+pub fn checksum(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+    !data.iter().fold(!0u32, |crc, byte| TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+/// Check the CRC-32 of the master body if it starts with one, the CRC covers the rest of the body
+pub fn verify(id: EbmlId, body: &[u8]) -> Result<(), MatroskaError> {
+    let mut r = body;
+    let Ok((raw_id, ElementSize::Sized(4), header_len)) = io::blocking::read_element_raw_id_size(&mut r) else { return Ok(()) };
+    if raw_id != EbmlId::Crc32 as u64 {
+        return Ok(());
+    }
+    let (crc, data) = body[header_len as usize..].split_at(4);
+    let stored = u32::from_le_bytes([crc[0], crc[1], crc[2], crc[3]]);
+    let computed = checksum(data);
+    if stored != computed {
+        return Err(MatroskaError::Crc32Mismatch { id, stored, computed });
+    }
+    Ok(())
+}
+
+/// Read the body into memory to verify its CRC-32 before the children are parsed
+pub fn read_body_verified_blocking<T, R>(r: &mut R, id: EbmlId, size: u64, options: ReadOptions) -> Result<(T, usize), ReadError>
+    where T: ElementReadBlocking<std::io::Cursor<Vec<u8>>>, R: std::io::Read
+{
+    let body = io::blocking::read_bin(r, size)?;
+    verify(id, &body)?;
+    T::read_body_with(&mut std::io::Cursor::new(body), ElementSize::Sized(size), options)
+}
+
+/// Read the body into memory to verify its CRC-32 before the children are parsed
+pub async fn read_body_verified<T, R>(r: &mut R, id: EbmlId, size: u64, options: ReadOptions) -> Result<(T, usize), ReadError>
+    where T: ElementRead<std::io::Cursor<Vec<u8>>>, R: tokio::io::AsyncRead + Send + Unpin
+{
+    let body = io::async_::read_bin(r, size).await?;
+    verify(id, &body)?;
+    T::read_body_with(&mut std::io::Cursor::new(body), ElementSize::Sized(size), options).await
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, EbmlId, MatroskaError, ReadOptions};
+    use crate::{test_util, ElementReadBlocking, Muxer, WriteOptions};
+    use crate::structs::{EbmlHeader, Info, Segment};
+
+    #[test]
+    fn test_crc32() -> Result<(), anyhow::Error> {
+        assert_eq!(checksum(b"123456789"), 0xCBF4_3926);
+
+        let info = test_util::info();
+        let options = WriteOptions { crc32: true, ..Default::default() };
+        let mut buf = vec![];
+        assert_eq!(info.write_with_blocking(&mut buf, options)? as u64, info.encoded_size_with(options)?);
+        // CRC-32 is the first child
+        assert_eq!(&buf[5..7], &[0xBF, 0x84]);
+        assert_eq!(buf[7..11], checksum(&buf[11..]).to_le_bytes());
+
+        let verify = ReadOptions { verify_crc32: true, ..Default::default() };
+        let (read, _) = Info::read_with(&mut &buf[..], verify)?;
+        assert_eq!(*read.muxing_app.v, "mkv-rs");
+        assert!(read.crc_32.is_some());
+
+        buf[7] ^= 0xFF;
+        let err = Info::read_with(&mut &buf[..], verify).expect_err("CRC-32 mismatch");
+        assert!(matches!(err.kind, MatroskaError::Crc32Mismatch { id: EbmlId::Info, .. }));
+        assert_eq!(err.path, "\\Info");
+        // without the option the CRC-32 is stored as is
+        Info::read(&mut &buf[..])?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_crc32_muxer() -> Result<(), anyhow::Error> {
+        let options = WriteOptions { crc32: true, ..Default::default() };
+        let track = test_util::video_track(1, "V_TEST");
+        let muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track]).with_write_options(options);
+        // Info is rewritten with the duration and a new CRC-32
+        let buf = test_util::recording_with(muxer)?;
+
+        let verify = ReadOptions { verify_crc32: true, ..Default::default() };
+        let mut r = &buf[..];
+        EbmlHeader::read_with(&mut r, verify)?;
+        let (segment, _) = Segment::read_with(&mut r, verify)?;
+        let tracks = &segment.tracks.as_ref().expect("Tracks").v;
+        assert!(segment.info.v.crc_32.is_some());
+        assert!(tracks.crc_32.is_some());
+        assert!(segment.cluster[0].v.crc_32.is_some());
+        // nested masters get no CRC-32
+        assert!(tracks.track_entry[0].v.crc_32.is_none());
+        Ok(())
+    }
+}
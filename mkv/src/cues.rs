@@ -0,0 +1,427 @@
+use super::io;
+use super::structs::*;
+use super::ids::EbmlId;
+use super::enums::TrackType;
+use super::demuxer::{cluster_error, ends_cluster};
+use super::{Ebml, ElementSize, MatroskaError, ReadError};
+
+pub use blocking::write_cues_blocking;
+pub use async_::write_cues;
+
+/// Position of an element and its length with the header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Slot {
+    pub(crate) pos: u64,
+    pub(crate) len: u64,
+}
+impl Slot {
+    pub(crate) fn end(&self) -> u64 { self.pos + self.len }
+}
+
+struct ScannedBlock {
+    track: u64,
+    timecode: i16,
+    keyframe: bool,
+    /// Position of the block element relative to the Cluster data
+    relative: u64,
+}
+
+struct ScannedCluster {
+    pos: u64,
+    timestamp: u64,
+    blocks: Vec<ScannedBlock>,
+}
+
+/// Segment layout and blocks of all clusters, enough to build the Cues and place them in the file
+struct Scan {
+    segment_size_pos: u64,
+    segment_size: ElementSize,
+    segment_size_len: u64,
+    /// Position of the first byte of the Segment data, SeekHead and Cues positions are relative to it
+    segment_start: u64,
+    file_end: u64,
+
+    seek_head: Option<SeekHead>,
+    tracks: Option<Tracks>,
+    clusters: Vec<ScannedCluster>,
+    /// Level 1 elements in the file order
+    level1: Vec<(Option<EbmlId>, Slot)>,
+}
+
+impl Scan {
+    fn new(segment_size_pos: u64, segment_size: ElementSize, segment_size_len: u64, file_end: u64) -> Self {
+        Self {
+            segment_size_pos, segment_size, segment_size_len,
+            segment_start: segment_size_pos + segment_size_len,
+            file_end,
+            seek_head: None,
+            tracks: None,
+            clusters: vec![],
+            level1: vec![],
+        }
+    }
+
+    /// End of the Segment data, the file end for the unknown size
+    fn segment_end(&self) -> u64 {
+        match self.segment_size {
+            ElementSize::Sized(size) => self.file_end.min(self.segment_start + size),
+            ElementSize::Unknown(_) => self.file_end,
+        }
+    }
+
+    fn find(&self, id: EbmlId) -> Option<Slot> {
+        self.level1.iter().find(|(id_, _)| *id_ == Some(id)).map(|(_, slot)| *slot)
+    }
+
+    /// The first level 1 element if it's a SeekHead or a Void, with the Void following the SeekHead
+    fn seek_head_slot(&self) -> Option<Slot> {
+        match self.level1.as_slice() {
+            [(Some(EbmlId::SeekHead), seek_head), (Some(EbmlId::Void), void), ..] if void.pos == seek_head.end() => {
+                Some(Slot { pos: seek_head.pos, len: seek_head.len + void.len })
+            }
+            [(Some(EbmlId::SeekHead | EbmlId::Void), slot), ..] => Some(*slot),
+            _ => None,
+        }
+    }
+
+    /// CuePoints for every keyframe of the video tracks, or for the first block of each cluster
+    /// of the audio tracks if there is no video
+    fn cues(&self) -> Cues {
+        let tracks_of = |type_: TrackType| -> Vec<u64> {
+            self.tracks.iter()
+                .flat_map(|tracks| &tracks.track_entry)
+                .filter(|track| *track.v.track_type.v == type_)
+                .map(|track| *track.v.track_number.v)
+                .collect()
+        };
+        let video = tracks_of(TrackType::Video);
+        let (cue_tracks, keyframes) = match video.is_empty() {
+            false => (video, true),
+            true => (tracks_of(TrackType::Audio), false),
+        };
+
+        let mut cue_points = vec![];
+        for cluster in &self.clusters {
+            let mut indexed = vec![];
+            for block in &cluster.blocks {
+                if !cue_tracks.contains(&block.track) || (keyframes && !block.keyframe) || (!keyframes && indexed.contains(&block.track)) {
+                    continue;
+                }
+                indexed.push(block.track);
+                let positions = CueTrackPositions {
+                    cue_track: Ebml::new_index(0, block.track),
+                    cue_cluster_position: Ebml::new_index(1, cluster.pos - self.segment_start),
+                    cue_relative_position: Some(Ebml::new_index(2, block.relative)),
+                    ..Default::default()
+                };
+                let cue_point = CuePoint {
+                    cue_time: Ebml::new_index(0, (cluster.timestamp as i64 + block.timecode as i64).max(0) as u64),
+                    cue_track_positions: vec![Ebml::new_index(1, positions)],
+                    ..Default::default()
+                };
+                cue_points.push(cue_point);
+            }
+        }
+        cue_points.sort_by_key(|cue_point| *cue_point.cue_time.v);
+
+        let mut cues = Cues::default();
+        for (index, cue_point) in cue_points.into_iter().enumerate() {
+            cues.cue_point.push(Ebml::new_index(index as u64, cue_point));
+        }
+        cues
+    }
+
+    /// Positions and bytes to write the Cues and the SeekHead referencing them.
+    ///
+    /// The Cues replace the old ones or a trailing Void if they fit, otherwise they are
+    /// appended and the old ones are turned into a Void.
+    fn patches(&self, cues: &Cues) -> Result<Vec<(u64, Vec<u8>)>, MatroskaError> {
+        let seek_head_slot = self.seek_head_slot()
+            .ok_or_else(|| MatroskaError::InPlaceUpdate("No SeekHead or Void at the Segment start to reference the Cues".to_string()))?;
+        let mut body = vec![];
+        cues.write_body_blocking(&mut body)?;
+        let mut patches = vec![];
+
+        let old = self.find(EbmlId::Cues);
+        let trailing_void = match self.level1.last() {
+            Some((Some(EbmlId::Void), slot)) if slot.pos != seek_head_slot.pos => Some(*slot),
+            _ => None,
+        };
+        let placed = [old, trailing_void].into_iter().flatten()
+            .find_map(|slot| io::gen_element_padded(EbmlId::Cues, &body, slot.len).ok().map(|buf| (slot.pos, buf)));
+        let cues_pos = match placed {
+            Some((pos, buf)) => {
+                patches.push((pos, buf));
+                pos
+            }
+            None => {
+                let end = self.segment_end();
+                if end < self.file_end {
+                    return Err(MatroskaError::InPlaceUpdate(format!("Segment is followed by other data at {end}, Cues can't be appended")));
+                }
+                let mut buf = io::gen_element_id_size(EbmlId::Cues as u64, body.len() as u64);
+                buf.append(&mut body);
+                if let ElementSize::Sized(_) = self.segment_size {
+                    let size = end + buf.len() as u64 - self.segment_start;
+                    patches.push((self.segment_size_pos, io::gen_vint_with_len(size, self.segment_size_len)?));
+                }
+                if let Some(old) = old {
+                    patches.push((old.pos, io::gen_void(old.len)?));
+                }
+                patches.push((end, buf));
+                end
+            }
+        };
+
+        let mut seek_head = self.seek_head.clone().unwrap_or_else(|| {
+            let mut seek_head = SeekHead::default();
+            for id in [EbmlId::Info, EbmlId::Tracks] {
+                if let Some(slot) = self.find(id) {
+                    set_seek_entry(&mut seek_head, id, slot.pos - self.segment_start);
+                }
+            }
+            seek_head
+        });
+        set_seek_entry(&mut seek_head, EbmlId::Cues, cues_pos - self.segment_start);
+        let mut body = vec![];
+        seek_head.write_body_blocking(&mut body)?;
+        let buf = io::gen_element_padded(EbmlId::SeekHead, &body, seek_head_slot.len)
+            .map_err(|_| MatroskaError::InPlaceUpdate(format!("SeekHead referencing the Cues doesn't fit {} bytes at {}", seek_head_slot.len, seek_head_slot.pos)))?;
+        patches.push((seek_head_slot.pos, buf));
+        Ok(patches)
+    }
+}
+
+pub(crate) fn set_seek_entry(seek_head: &mut SeekHead, id: EbmlId, position: u64) {
+    let seek_id = io::gen_uint(id as u64);
+    if let Some(seek) = seek_head.seek.iter_mut().find(|seek| *seek.v.seek_id.v == seek_id) {
+        *seek.v.seek_position.v = position;
+        return;
+    }
+    let index = seek_head.seek.iter().filter_map(|seek| seek.index).max().map_or(0, |index| index + 1);
+    let seek = Seek {
+        seek_id: Ebml::new_index(0, seek_id),
+        seek_position: Ebml::new_index(1, position),
+        ..Default::default()
+    };
+    seek_head.seek.push(Ebml::new_index(index, seek));
+}
+
+fn is_eof(err: &MatroskaError) -> bool {
+    matches!(err, MatroskaError::Io(err) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+}
+
+/// Size of the element at `pos` in the master at `path`
+fn sized(raw_id: u64, size: ElementSize, pos: u64, path: &str) -> Result<u64, ReadError> {
+    match size {
+        ElementSize::Sized(size) => Ok(size),
+        ElementSize::Unknown(_) => Err(ReadError::new(MatroskaError::UnknownSizeNotAllowed(raw_id), pos, path)),
+    }
+}
+
+macro_rules! define_write_cues {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        /// Scan all clusters and write Cues for the keyframes of the video tracks, or for every
+        /// cluster of audio-only files, so the file written without Cues becomes seekable.
+        ///
+        /// The file is rewritten in place, the SeekHead is updated to reference the new Cues.
+        pub fn write_cues_blocking<F: std::io::Read + std::io::Write + std::io::Seek>(f: &mut F) -> Result<Cues, ReadError> {
+            let scan = scan_blocking(f).map(|await_|await_)?;
+            let cues = scan.cues();
+            let patches = scan.patches(&cues).map_err(|err| ReadError::new(err, scan.segment_start, "Segment"))?;
+            for (pos, buf) in patches {
+                f.seek(SeekFrom::Start(pos)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), pos, "Segment"))?;
+                f.write_all(&buf).map(|await_|await_).map_err(|err| ReadError::new(err.into(), pos, "Segment"))?;
+            }
+            f.flush().map(|await_|await_).map_err(MatroskaError::Io)?;
+            Ok(cues)
+        }
+
+        $(#[async_blocking::$impl_async])?
+        fn scan_blocking<F: std::io::Read + std::io::Seek>(f: &mut F) -> Result<Scan, ReadError> {
+            let file_end = f.seek(SeekFrom::End(0)).map(|await_|await_).map_err(MatroskaError::Io)?;
+            f.seek(SeekFrom::Start(0)).map(|await_|await_).map_err(MatroskaError::Io)?;
+            let (_, header_len) = EbmlHeader::read(f).map(|await_|await_)?;
+            let header_len = header_len as u64;
+            let (id, id_len) = io::blocking::read_element_id(f).map(|await_|await_).map_err(|err| ReadError::new(err, header_len, "Segment"))?;
+            if id != EbmlId::Segment as u64 {
+                return Err(ReadError::new(MatroskaError::UnexpectedId { expected: EbmlId::Segment, found: id }, header_len, "Segment"));
+            }
+            let (size, size_len) = io::blocking::read_element_size(f).map(|await_|await_).map_err(|err| ReadError::new(err, header_len, "Segment"))?;
+            let mut scan = Scan::new(header_len + id_len, size, size_len, file_end);
+
+            let mut pos = scan.segment_start;
+            while pos < scan.segment_end() {
+                let (raw_id, size, header_len) = match read_header_blocking(f, pos, "Segment").map(|await_|await_)? {
+                    Some(header) => header,
+                    None => break,
+                };
+                let data = pos + header_len;
+                let id = EbmlId::from_u64(raw_id).ok();
+                let end = match id {
+                    Some(EbmlId::Cluster) => {
+                        let (cluster, end) = scan_cluster_blocking(f, pos, data, size).map(|await_|await_)?;
+                        scan.clusters.push(cluster);
+                        end
+                    }
+                    Some(EbmlId::SeekHead) if scan.seek_head.is_none() => {
+                        let (seek_head, read) = SeekHead::read_body(f, size).map(|await_|await_).map_err(|err| err.at("Segment\\SeekHead", data))?;
+                        scan.seek_head = Some(seek_head);
+                        data + read as u64
+                    }
+                    Some(EbmlId::Tracks) => {
+                        let (tracks, read) = Tracks::read_body(f, size).map(|await_|await_).map_err(|err| err.at("Segment\\Tracks", data))?;
+                        scan.tracks = Some(tracks);
+                        data + read as u64
+                    }
+                    _ => data + sized(raw_id, size, pos, "Segment")?,
+                };
+                scan.level1.push((id, Slot { pos, len: end - pos }));
+                f.seek(SeekFrom::Start(end)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), end, "Segment"))?;
+                pos = end;
+            }
+            Ok(scan)
+        }
+
+        $(#[async_blocking::$impl_async])?
+        /// Blocks of the Cluster and the position after it
+        fn scan_cluster_blocking<F: std::io::Read + std::io::Seek>(f: &mut F, pos: u64, data: u64, size: ElementSize) -> Result<(ScannedCluster, u64), ReadError> {
+            let end = match size {
+                ElementSize::Sized(size) => Some(data + size),
+                ElementSize::Unknown(_) => None,
+            };
+            let mut cluster = ScannedCluster { pos, timestamp: 0, blocks: vec![] };
+            let mut child = data;
+            while end.is_none_or(|end| child < end) {
+                let (raw_id, size, header_len) = match read_header_blocking(f, child, "Segment\\Cluster").map(|await_|await_)? {
+                    Some(header) => header,
+                    None => break,
+                };
+                let id = EbmlId::from_u64(raw_id).ok();
+                if end.is_none() && ends_cluster(id) {
+                    break;
+                }
+                let size = sized(raw_id, size, child, "Segment\\Cluster")?;
+                let body = child + header_len;
+                match id {
+                    Some(EbmlId::Timestamp) => {
+                        cluster.timestamp = io::blocking::read_uint(f, size).map(|await_|await_).map_err(|err| cluster_error(err, body, EbmlId::Timestamp))?;
+                    }
+                    Some(EbmlId::SimpleBlock) => {
+                        let block = io::blocking::read_block(f, size).map(|await_|await_).map_err(|err| cluster_error(err, body, EbmlId::SimpleBlock))?;
+                        let keyframe = block.keyframe();
+                        cluster.blocks.push(ScannedBlock { track: block.track_number, timecode: block.timecode, keyframe, relative: child - data });
+                    }
+                    Some(EbmlId::BlockGroup) => {
+                        let (group, _) = BlockGroup::read_body(f, ElementSize::Sized(size)).map(|await_|await_)
+                            .map_err(|err| err.at("Segment\\Cluster\\BlockGroup", body))?;
+                        let keyframe = group.reference_block.is_empty();
+                        cluster.blocks.push(ScannedBlock { track: group.block.v.track_number, timecode: group.block.v.timecode, keyframe, relative: child - data });
+                    }
+                    _ => {}
+                }
+                child = body + size;
+                f.seek(SeekFrom::Start(child)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), child, "Segment\\Cluster"))?;
+            }
+            Ok((cluster, child))
+        }
+
+        $(#[async_blocking::$impl_async])?
+        /// Header of the element at `pos` in the master at `path`, `None` at the end of the file
+        fn read_header_blocking<F: std::io::Read>(f: &mut F, pos: u64, path: &str) -> Result<Option<(u64, ElementSize, u64)>, ReadError> {
+            match io::blocking::read_element_raw_id_size(f).map(|await_|await_) {
+                Ok(header) => Ok(Some(header)),
+                Err(err) if is_eof(&err) => Ok(None),
+                Err(err) => Err(ReadError::new(err, pos, path)),
+            }
+        }
+    }
+}
+
+mod blocking {
+    use std::io::SeekFrom;
+
+    use super::*;
+    use crate::ElementReadBlocking;
+
+    define_write_cues!();
+}
+
+mod async_ {
+    use std::io::SeekFrom;
+    use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::ElementRead;
+
+    define_write_cues!(impl_async);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, Demuxer, LazySegment, Muxer};
+
+    /// Live recording of the audio track 1
+    fn audio_recording() -> Result<Vec<u8>, anyhow::Error> {
+        let mut track = test_util::video_track(1, "A_TEST");
+        *track.track_type.v = TrackType::Audio;
+        test_util::recording_with(Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track]).live())
+    }
+
+    #[test]
+    fn test_write_cues() -> Result<(), anyhow::Error> {
+        let mut file = std::io::Cursor::new(test_util::recording(test_util::info(), true)?);
+        let cues = write_cues_blocking(&mut file)?;
+        let times: Vec<u64> = cues.cue_point.iter().map(|cue_point| *cue_point.v.cue_time.v).collect();
+        assert_eq!(times, vec![0, 400, 800]);
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(file.into_inner()))?;
+        demuxer.seek_blocking(1, 500_000_000)?;
+        assert_eq!(demuxer.cues().unwrap().cue_point.len(), 3);
+        let frame = demuxer.next_frame_blocking()?.unwrap();
+        assert_eq!((frame.pts_ns, frame.keyframe), (400_000_000, true));
+        assert_eq!(demuxer.count(), 19);
+
+        // The old Cues of the muxer don't have relative positions, so they don't fit and are replaced by a Void
+        let mut file = std::io::Cursor::new(test_util::recording(test_util::info(), false)?);
+        write_cues_blocking(&mut file)?;
+        let len = file.get_ref().len();
+        file.set_position(0);
+        let mut demuxer = Demuxer::open_blocking(file)?;
+        demuxer.seek_blocking(1, 900_000_000)?;
+        assert_eq!(demuxer.next_frame_blocking()?.unwrap().pts_ns, 800_000_000);
+        assert!(demuxer.cues().unwrap().cue_point[0].v.cue_track_positions[0].v.cue_relative_position.is_some());
+
+        // Cues of the same size are rewritten in place
+        let mut file = demuxer.into_inner();
+        write_cues_blocking(&mut file)?;
+        assert_eq!(file.get_ref().len(), len);
+
+        // Audio only: a CuePoint for each cluster
+        let mut file = std::io::Cursor::new(audio_recording()?);
+        let cues = write_cues_blocking(&mut file)?;
+        assert_eq!(cues.cue_point.len(), 1);
+
+        // the first child of Tracks gets an invalid size
+        let mut buf = test_util::recording(test_util::info(), false)?;
+        let tracks = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?.find(EbmlId::Tracks).expect("Tracks");
+        buf[tracks.body_position() as usize + 1] = 0;
+        let err = write_cues_blocking(&mut std::io::Cursor::new(buf)).expect_err("invalid size");
+        assert_eq!((err.offset, err.path.as_str()), (tracks.body_position(), "\\Segment\\Tracks"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_cues_async() -> Result<(), anyhow::Error> {
+        let mut file = std::io::Cursor::new(test_util::recording(test_util::info(), true)?);
+        let cues = write_cues(&mut file).await?;
+        assert_eq!(cues.cue_point.len(), 3);
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(file.into_inner())).await?;
+        demuxer.seek(1, 1_000_000_000).await?;
+        assert_eq!(demuxer.next_frame().await?.unwrap().pts_ns, 800_000_000);
+        Ok(())
+    }
+}
@@ -0,0 +1,882 @@
+use std::collections::VecDeque;
+
+use super::io;
+use super::structs::*;
+use super::ids::EbmlId;
+use super::content_encoding;
+use super::encryption::{Key, KeyLookup};
+use super::{Block, ElementSize, MatroskaError, ReadError};
+
+/// A single frame with the absolute timestamp
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub track: u64,
+    /// Presentation timestamp in nanoseconds
+    pub pts_ns: i64,
+    /// Duration in nanoseconds, taken from BlockDuration or the track DefaultDuration
+    pub duration: Option<u64>,
+    pub keyframe: bool,
+    /// Frame data with the compression of the track ContentEncodings undone
+    pub data: Vec<u8>,
+}
+
+/// Bytes the recovering reader skipped to resynchronize after an error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRange {
+    /// Position of the element which couldn't be read
+    pub start: u64,
+    /// Position of the level 1 element the reading continued from, the end of the file if none was found
+    pub end: u64,
+    pub error: String,
+}
+
+/// Bytes read at once while scanning for a level 1 element
+const RESYNC_CHUNK: usize = 64 * 1024;
+/// Longest header checked by `plausible_level1`: 4 bytes ID, 8 bytes size and the ID of the first child
+const RESYNC_TAIL: usize = 16;
+
+struct ElementHeader {
+    raw_id: u64,
+    /// `None` for element IDs unknown to the Matroska schema
+    id: Option<EbmlId>,
+    size: ElementSize,
+    header_len: u64,
+}
+
+/// Reads the segment metadata and iterates frames of all tracks in the file order.
+///
+/// Level 1 elements are discovered in any order, elements placed after the clusters
+/// are loaded through the SeekHead.
+pub struct Demuxer<R> {
+    r: R,
+    pos: u64,
+
+    header: EbmlHeader,
+    /// Position of the first byte of the Segment data, SeekHead and Cues positions are relative to it
+    segment_start: u64,
+    segment_end: Option<u64>,
+
+    seek_head: Vec<SeekHead>,
+    info: Option<Info>,
+    tracks: Option<Tracks>,
+    chapters: Option<Chapters>,
+    tags: Vec<Tags>,
+    /// Loaded on the first seek if they are placed after the clusters
+    cues: Option<Cues>,
+    first_cluster: Option<u64>,
+
+    /// Positions of level 1 elements already read, to not read them twice through the SeekHead
+    loaded: Vec<u64>,
+    /// SeekHead targets already followed by `open`, a target past the end of a truncated file is tried once
+    followed: Vec<u64>,
+    cluster_timestamp: Option<u64>,
+    frames: VecDeque<Frame>,
+
+    recover: bool,
+    /// Length of the file, known once the recovering reader needed it
+    file_end: Option<u64>,
+    skipped: Vec<SkippedRange>,
+    /// Keys of the encrypted tracks, see `with_key_lookup`
+    keys: Option<Box<KeyLookup<'static>>>,
+}
+
+impl<R> Demuxer<R> {
+    fn new(r: R, header: EbmlHeader, pos: u64, segment_size: ElementSize) -> Self {
+        let segment_end = match segment_size {
+            ElementSize::Sized(size) => Some(pos + size),
+            ElementSize::Unknown(_) => None,
+        };
+        Self {
+            r, pos,
+            header,
+            segment_start: pos,
+            segment_end,
+            seek_head: vec![],
+            info: None,
+            tracks: None,
+            chapters: None,
+            tags: vec![],
+            cues: None,
+            first_cluster: None,
+            loaded: vec![],
+            followed: vec![],
+            cluster_timestamp: None,
+            frames: VecDeque::new(),
+            recover: false,
+            file_end: None,
+            skipped: vec![],
+            keys: None,
+        }
+    }
+
+    /// Resynchronize on corrupt data instead of failing: the clusters are scanned forward for
+    /// the next level 1 element and a truncated file ends with the last complete block.
+    /// The skipped bytes are reported by `skipped`
+    pub fn with_recovery(mut self, recover: bool) -> Self {
+        self.recover = recover;
+        self
+    }
+    /// Keys to decrypt the frames of the tracks encrypted by the WebM Encryption scheme,
+    /// looked up by the ContentEncKeyID. Encrypted frames without a key fail with `InvalidContentEncoding`
+    pub fn with_key_lookup(mut self, keys: impl Fn(&[u8]) -> Option<Key> + Send + Sync + 'static) -> Self {
+        self.keys = Some(Box::new(keys));
+        self
+    }
+    pub fn skipped(&self) -> &[SkippedRange] { &self.skipped }
+
+    pub fn header(&self) -> &EbmlHeader { &self.header }
+    pub fn info(&self) -> &Info { self.info.as_ref().expect("Info is loaded by open") }
+    pub fn tracks(&self) -> Option<&Tracks> { self.tracks.as_ref() }
+    pub fn chapters(&self) -> Option<&Chapters> { self.chapters.as_ref() }
+    pub fn tags(&self) -> &[Tags] { &self.tags }
+    pub fn seek_head(&self) -> &[SeekHead] { &self.seek_head }
+    pub fn cues(&self) -> Option<&Cues> { self.cues.as_ref() }
+    pub fn segment_start(&self) -> u64 { self.segment_start }
+
+    pub fn track(&self, number: u64) -> Option<&TrackEntry> {
+        self.tracks.as_ref()?.track_entry.iter()
+            .map(|track| &*track.v)
+            .find(|track| *track.track_number.v == number)
+    }
+
+    pub fn into_inner(self) -> R { self.r }
+
+    fn skip_range(&mut self, start: u64, end: u64, error: ReadError) {
+        warn!("Skipped bytes {start}..{end}: {error}");
+        self.skipped.push(SkippedRange { start, end, error: error.to_string() });
+    }
+
+    fn segment_ended(&self) -> bool {
+        matches!(self.segment_end, Some(end) if self.pos >= end)
+    }
+
+    /// Positions of level 1 elements from all SeekHeads, absolute in the file
+    fn seek_entries(&self) -> Vec<(EbmlId, u64)> {
+        let mut entries = vec![];
+        for seek_head in &self.seek_head {
+            for seek in &seek_head.seek {
+                let id = seek.v.seek_id.v.iter().fold(0u64, |id, byte| (id << 8) | *byte as u64);
+                if let Ok(id) = EbmlId::from_u64(id) {
+                    entries.push((id, self.segment_start + *seek.v.seek_position.v));
+                }
+            }
+        }
+        entries
+    }
+
+    /// Elements referenced by the SeekHead which were not met before the first Cluster
+    fn missing_entries(&self) -> Vec<(EbmlId, u64)> {
+        self.seek_entries().into_iter()
+            .filter(|(id, pos)| !self.loaded.contains(pos) && !self.followed.contains(pos) && match id {
+                EbmlId::SeekHead => true,
+                EbmlId::Info => self.info.is_none(),
+                EbmlId::Tracks => self.tracks.is_none(),
+                EbmlId::Chapters => self.chapters.is_none(),
+                EbmlId::Tags => true,
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Position of the Cues from the SeekHead if they are not read yet
+    fn cues_entry(&self) -> Option<u64> {
+        if self.cues.is_some() {
+            return None;
+        }
+        self.seek_entries().into_iter()
+            .find(|(id, pos)| *id == EbmlId::Cues && !self.loaded.contains(pos))
+            .map(|(_, pos)| pos)
+    }
+
+    /// Cluster position and the block position relative to the Cluster data of the last
+    /// CuePoint of the track at or before the timestamp in `TimestampScale` units
+    fn cue_position(&self, track: u64, timestamp: u64) -> Option<(u64, Option<u64>)> {
+        let mut points: Vec<(u64, &CueTrackPositions)> = self.cues.as_ref()?.cue_point.iter()
+            .filter_map(|point| point.v.cue_track_positions.iter()
+                .find(|positions| *positions.v.cue_track.v == track)
+                .map(|positions| (*point.v.cue_time.v, &*positions.v)))
+            .collect();
+        points.sort_by_key(|(time, _)| *time);
+        let i = points.partition_point(|(time, _)| *time <= timestamp);
+        let (_, positions) = points.get(i.saturating_sub(1))?;
+        let relative = positions.cue_relative_position.as_ref().map(|position| *position.v);
+        Some((self.segment_start + *positions.cue_cluster_position.v, relative))
+    }
+
+    /// Check the track and convert the timestamp to `TimestampScale` units
+    fn seek_timestamp(&mut self, track: u64, timestamp_ns: u64) -> Result<u64, ReadError> {
+        if self.track(track).is_none() {
+            return Err(ReadError::new(MatroskaError::UnknownTrack(track), self.pos, "Segment"));
+        }
+        self.frames.clear();
+        self.cluster_timestamp = None;
+        Ok(timestamp_ns / *self.info().timestamp_scale.v)
+    }
+
+    /// CodecPrivate of the tracks with ContentEncodings is decoded, the frames are decoded as they are read
+    fn set_tracks(&mut self, mut tracks: Tracks) -> Result<(), MatroskaError> {
+        for track in &mut tracks.track_entry {
+            let track = &mut *track.v;
+            if let (Some(encodings), Some(codec_private)) = (&track.content_encodings, &mut track.codec_private) {
+                *codec_private.v = content_encoding::decode_private(&encodings.v, std::mem::take(&mut *codec_private.v))?;
+            }
+        }
+        self.tracks = Some(tracks);
+        Ok(())
+    }
+
+    fn push_block(&mut self, block: Block, block_duration: Option<u64>, keyframe: bool) -> Result<(), MatroskaError> {
+        let cluster_timestamp = self.cluster_timestamp.ok_or(MatroskaError::InvalidBlock("block is out of a Cluster"))?;
+        let timestamp_scale = *self.info().timestamp_scale.v as i64;
+        let default_duration = self.track(block.track_number)
+            .and_then(|track| track.default_duration.as_ref())
+            .map(|duration| *duration.v);
+
+        let encodings = self.track(block.track_number).and_then(|track| track.content_encodings.as_ref()).map(|encodings| &*encodings.v);
+
+        let pts_ns = (cluster_timestamp as i64 + block.timecode as i64) * timestamp_scale;
+        let frames = block.frames()?.into_iter()
+            .map(|frame| match encodings {
+                Some(encodings) => content_encoding::decode_frame(encodings, frame.to_vec(), &|key_id| self.keys.as_ref().and_then(|keys| keys(key_id))),
+                None => Ok(frame.to_vec()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let count = frames.len() as u64;
+        let duration = match block_duration.map(|duration| duration * timestamp_scale as u64) {
+            Some(duration) if count == 1 => Some(duration),
+            // DefaultDuration is the one of each laced frame, otherwise the BlockDuration is split between them
+            block_duration => default_duration.or(block_duration.map(|duration| duration / count)),
+        };
+        for (i, data) in frames.into_iter().enumerate() {
+            self.frames.push_back(Frame {
+                track: block.track_number,
+                pts_ns: pts_ns + (i as u64 * duration.unwrap_or(0)) as i64,
+                duration,
+                keyframe,
+                data,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn is_eof(err: &MatroskaError) -> bool {
+    matches!(err, MatroskaError::Io(err) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+}
+
+/// Level 1 elements end an unknown-size Cluster, Void and CRC-32 may be its children
+pub(crate) fn ends_cluster(id: Option<EbmlId>) -> bool {
+    match id {
+        Some(EbmlId::Void | EbmlId::Crc32) => false,
+        Some(id) => id.parent() == Some(EbmlId::Segment),
+        None => false,
+    }
+}
+
+/// Sizes beyond the end of the file are corrupt, except for the Cluster cut by a truncation
+fn check_fits(header: &ElementHeader, data: u64, end: u64) -> Result<(), ReadError> {
+    match header.size {
+        ElementSize::Sized(size) if header.id != Some(EbmlId::Cluster) && data + size > end => {
+            Err(ReadError::new(MatroskaError::ElementOverflow(header.raw_id), data - header.header_len, "Segment"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Offset of the first level 1 element before `limit` in the buffer read from `pos`
+fn find_level1(buf: &[u8], limit: usize, pos: u64, end: u64) -> Option<usize> {
+    (0..limit).find(|i| plausible_level1(&buf[*i..], pos + *i as u64, end))
+}
+
+/// A level 1 ID with a size which fits the file, a Cluster must start with one of its children
+fn plausible_level1(buf: &[u8], pos: u64, end: u64) -> bool {
+    let mut r = buf;
+    let Ok((id, 4)) = io::blocking::read_element_id(&mut r) else { return false };
+    let Ok(id) = EbmlId::from_u64(id) else { return false };
+    if !ends_cluster(Some(id)) {
+        return false;
+    }
+    let Ok((size, size_len)) = io::blocking::read_element_size(&mut r) else { return false };
+    let data = pos + 4 + size_len;
+    if id != EbmlId::Cluster {
+        return matches!(size, ElementSize::Sized(size) if data + size <= end);
+    }
+    let Ok((child, _)) = io::blocking::read_element_id(&mut r) else { return false };
+    matches!(EbmlId::from_u64(child), Ok(child) if child.parent() == Some(EbmlId::Cluster) || child == EbmlId::Crc32 || child == EbmlId::Void)
+}
+
+fn skip_size(header: &ElementHeader) -> Result<u64, MatroskaError> {
+    match header.size {
+        ElementSize::Sized(size) => Ok(size),
+        ElementSize::Unknown(_) => Err(MatroskaError::UnknownSizeNotAllowed(header.raw_id)),
+    }
+}
+
+/// Errors of the Timestamp and the blocks are at their body in the Cluster
+pub(crate) fn cluster_error(kind: MatroskaError, pos: u64, id: EbmlId) -> ReadError {
+    ReadError::new(kind, pos, &format!("Segment\\Cluster\\{id:?}"))
+}
+
+macro_rules! define_demuxer {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        impl<R: std::io::Read + std::io::Seek> Demuxer<R> {
+            pub fn open_blocking(mut r: R) -> Result<Self, ReadError> {
+                let start = r.stream_position().map(|await_|await_).map_err(MatroskaError::Io)?;
+                let (header, header_len) = EbmlHeader::read(&mut r).map(|await_|await_).map_err(|err| err.offset_by(start))?;
+                let (segment_size, segment_header_len) = Segment::read_header(&mut r).map(|await_|await_)
+                    .map_err(|err| err.offset_by(start + header_len as u64))?;
+                let pos = start + (header_len + segment_header_len) as u64;
+                let mut demuxer = Self::new(r, header, pos, segment_size);
+
+                loop {
+                    let pos = demuxer.pos;
+                    let header = match demuxer.read_element_header_blocking().map(|await_|await_)? {
+                        Some(header) => header,
+                        None => break,
+                    };
+                    if header.id == Some(EbmlId::Cluster) {
+                        demuxer.first_cluster = Some(pos);
+                        demuxer.seek_to_blocking(pos).map(|await_|await_)?;
+                        break;
+                    }
+                    demuxer.read_level1_blocking(&header).map(|await_|await_)?;
+                }
+
+                let resume = demuxer.pos;
+                loop {
+                    let missing = demuxer.missing_entries();
+                    if missing.is_empty() { break }
+                    for (_, pos) in missing {
+                        demuxer.followed.push(pos);
+                        demuxer.seek_to_blocking(pos).map(|await_|await_)?;
+                        if let Some(header) = demuxer.read_element_header_blocking().map(|await_|await_)? {
+                            demuxer.read_level1_blocking(&header).map(|await_|await_)?;
+                        }
+                    }
+                }
+                demuxer.seek_to_blocking(resume).map(|await_|await_)?;
+
+                if demuxer.info.is_none() {
+                    return Err(ReadError::new(MatroskaError::MissingElement(EbmlId::Info), demuxer.segment_start, "Segment"));
+                }
+                Ok(demuxer)
+            }
+
+            pub fn next_frame_blocking(&mut self) -> Result<Option<Frame>, ReadError> {
+                loop {
+                    if let Some(frame) = self.frames.pop_front() {
+                        return Ok(Some(frame));
+                    }
+                    if self.segment_ended() {
+                        return Ok(None);
+                    }
+                    let start = self.pos;
+                    match self.read_next_blocking().map(|await_|await_) {
+                        Ok(true) => {}
+                        Ok(false) => return Ok(None),
+                        Err(err) if self.recover => self.resync_blocking(start, err).map(|await_|await_)?,
+                        Err(err) => return Err(err),
+                    }
+                }
+            }
+
+            /// Read the next element of the clusters, `false` at the end of the file
+            fn read_next_blocking(&mut self) -> Result<bool, ReadError> {
+                let header = match self.read_element_header_blocking().map(|await_|await_)? {
+                    Some(header) => header,
+                    None => return Ok(false),
+                };
+                if self.recover {
+                    check_fits(&header, self.pos, self.file_end_blocking().map(|await_|await_)?)?;
+                }
+                match header.id {
+                    Some(EbmlId::Cluster) => {
+                        self.cluster_timestamp = None;
+                    }
+                    Some(EbmlId::Timestamp) => {
+                        self.cluster_timestamp = Some(self.read_timestamp_blocking(&header).map(|await_|await_)?);
+                    }
+                    Some(EbmlId::SimpleBlock) => {
+                        let pos = self.pos;
+                        let size = header.size.try_sized(EbmlId::SimpleBlock).map_err(|err| cluster_error(err, pos, EbmlId::SimpleBlock))?;
+                        let block = io::blocking::read_block(&mut self.r, size).map(|await_|await_).map_err(|err| cluster_error(err, pos, EbmlId::SimpleBlock))?;
+                        self.pos += size;
+                        let keyframe = block.keyframe();
+                        self.push_block(block, None, keyframe).map_err(|err| cluster_error(err, pos, EbmlId::SimpleBlock))?;
+                    }
+                    Some(EbmlId::BlockGroup) => {
+                        let pos = self.pos;
+                        let (group, read) = BlockGroup::read_body(&mut self.r, header.size).map(|await_|await_)
+                            .map_err(|err| err.at("Segment\\Cluster\\BlockGroup", pos))?;
+                        self.pos += read as u64;
+                        let keyframe = group.reference_block.is_empty();
+                        let duration = group.block_duration.as_ref().map(|duration| *duration.v);
+                        self.push_block(*group.block.v, duration, keyframe).map_err(|err| cluster_error(err, pos, EbmlId::BlockGroup))?;
+                    }
+                    Some(id) if id.parent() == Some(EbmlId::Segment) => {
+                        self.cluster_timestamp = None;
+                        self.read_level1_blocking(&header).map(|await_|await_)?;
+                    }
+                    _ => self.skip_blocking(&header).map(|await_|await_)?,
+                }
+                Ok(true)
+            }
+
+            /// Scan for the next level 1 element from the byte after `start` and continue from it
+            fn resync_blocking(&mut self, start: u64, error: ReadError) -> Result<(), ReadError> {
+                let end = self.file_end_blocking().map(|await_|await_)?;
+                let mut pos = start + 1;
+                self.seek_to_blocking(pos).map(|await_|await_)?;
+                let mut buf = vec![];
+                let found = loop {
+                    let read = (&mut self.r).take(RESYNC_CHUNK as u64).read_to_end(&mut buf).map(|await_|await_)
+                        .map_err(|err| ReadError::new(err.into(), pos, "Segment"))?;
+                    let eof = read < RESYNC_CHUNK;
+                    // headers which may continue in the next chunk are checked with it
+                    let limit = if eof { buf.len() } else { buf.len() - RESYNC_TAIL };
+                    if let Some(i) = find_level1(&buf, limit, pos, end) {
+                        break pos + i as u64;
+                    }
+                    if eof {
+                        break end;
+                    }
+                    buf.drain(..limit);
+                    pos += limit as u64;
+                };
+                self.seek_to_blocking(found).map(|await_|await_)?;
+                self.cluster_timestamp = None;
+                self.skip_range(start, found, error);
+                Ok(())
+            }
+
+            fn file_end_blocking(&mut self) -> Result<u64, ReadError> {
+                if let Some(end) = self.file_end {
+                    return Ok(end);
+                }
+                let end = self.r.seek(SeekFrom::End(0)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), self.pos, "Segment"))?;
+                self.seek_to_blocking(self.pos).map(|await_|await_)?;
+                self.file_end = Some(end);
+                Ok(end)
+            }
+
+            /// Read the Cues referenced by the SeekHead after the first Cluster,
+            /// the position of the next frame is kept
+            pub fn load_cues_blocking(&mut self) -> Result<Option<&Cues>, ReadError> {
+                if let Some(pos) = self.cues_entry() {
+                    let resume = self.pos;
+                    self.seek_to_blocking(pos).map(|await_|await_)?;
+                    if let Some(header) = self.read_element_header_blocking().map(|await_|await_)? {
+                        self.read_level1_blocking(&header).map(|await_|await_)?;
+                    }
+                    self.seek_to_blocking(resume).map(|await_|await_)?;
+                }
+                Ok(self.cues.as_ref())
+            }
+
+            /// Move to the keyframe of the track at or before the timestamp in nanoseconds,
+            /// the next frames of all tracks are read from there.
+            ///
+            /// The keyframe is found through the Cues, the clusters are scanned from the first one
+            /// if the file has no Cues for the track.
+            pub fn seek_blocking(&mut self, track: u64, timestamp_ns: u64) -> Result<(), ReadError> {
+                let timestamp = self.seek_timestamp(track, timestamp_ns)?;
+                self.load_cues_blocking().map(|await_|await_)?;
+                match self.cue_position(track, timestamp) {
+                    Some((cluster, None)) => self.seek_to_blocking(cluster).map(|await_|await_),
+                    Some((cluster, Some(relative))) => {
+                        self.seek_to_blocking(cluster).map(|await_|await_)?;
+                        let found = self.read_element_header_blocking().map(|await_|await_)?.map(|header| header.raw_id);
+                        if found != Some(EbmlId::Cluster as u64) {
+                            let kind = match found {
+                                Some(found) => MatroskaError::UnexpectedId { expected: EbmlId::Cluster, found },
+                                None => std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into(),
+                            };
+                            return Err(ReadError::new(kind, cluster, "Segment"));
+                        }
+                        let data = self.pos;
+                        self.cluster_timestamp = Some(self.read_cluster_timestamp_blocking().map(|await_|await_)?);
+                        self.seek_to_blocking(data + relative).map(|await_|await_)
+                    }
+                    None => {
+                        let cluster = self.scan_clusters_blocking(timestamp).map(|await_|await_)?;
+                        self.seek_to_blocking(cluster).map(|await_|await_)
+                    }
+                }
+            }
+
+            /// Last Cluster with the timestamp at or before the given one
+            fn scan_clusters_blocking(&mut self, timestamp: u64) -> Result<u64, ReadError> {
+                let first = self.first_cluster
+                    .ok_or_else(|| ReadError::new(MatroskaError::MissingElement(EbmlId::Cluster), self.segment_start, "Segment"))?;
+                let mut found = first;
+                self.seek_to_blocking(first).map(|await_|await_)?;
+                while !self.segment_ended() {
+                    let start = self.pos;
+                    let header = match self.read_element_header_blocking().map(|await_|await_)? {
+                        Some(header) => header,
+                        None => break,
+                    };
+                    if header.id != Some(EbmlId::Cluster) {
+                        self.skip_blocking(&header).map(|await_|await_)?;
+                        continue;
+                    }
+                    let data = self.pos;
+                    if self.read_cluster_timestamp_blocking().map(|await_|await_)? > timestamp {
+                        break;
+                    }
+                    found = start;
+                    match header.size {
+                        ElementSize::Sized(size) => self.seek_to_blocking(data + size).map(|await_|await_)?,
+                        ElementSize::Unknown(_) => loop {
+                            let pos = self.pos;
+                            match self.read_element_header_blocking().map(|await_|await_)? {
+                                Some(child) if ends_cluster(child.id) => break self.seek_to_blocking(pos).map(|await_|await_)?,
+                                Some(child) => self.skip_blocking(&child).map(|await_|await_)?,
+                                None => break,
+                            }
+                        },
+                    }
+                }
+                Ok(found)
+            }
+
+            /// Read the Cluster children up to the Timestamp
+            fn read_cluster_timestamp_blocking(&mut self) -> Result<u64, ReadError> {
+                loop {
+                    let header = self.read_element_header_blocking().map(|await_|await_)?
+                        .ok_or_else(|| ReadError::new(MatroskaError::MissingElement(EbmlId::Timestamp), self.pos, "Segment\\Cluster"))?;
+                    if header.id == Some(EbmlId::Timestamp) {
+                        return self.read_timestamp_blocking(&header).map(|await_|await_);
+                    }
+                    self.skip_blocking(&header).map(|await_|await_)?;
+                }
+            }
+
+            fn read_timestamp_blocking(&mut self, header: &ElementHeader) -> Result<u64, ReadError> {
+                let pos = self.pos;
+                let size = header.size.try_sized(EbmlId::Timestamp).map_err(|err| cluster_error(err, pos, EbmlId::Timestamp))?;
+                let timestamp = io::blocking::read_uint(&mut self.r, size).map(|await_|await_).map_err(|err| cluster_error(err, pos, EbmlId::Timestamp))?;
+                self.pos += size;
+                Ok(timestamp)
+            }
+
+            fn read_element_header_blocking(&mut self) -> Result<Option<ElementHeader>, ReadError> {
+                let pos = self.pos;
+                let (raw_id, id_len) = match io::blocking::read_element_id(&mut self.r).map(|await_|await_) {
+                    Ok(id) => id,
+                    Err(err) if is_eof(&err) => return Ok(None),
+                    Err(err) => return Err(ReadError::new(err, pos, "Segment")),
+                };
+                let (size, size_len) = io::blocking::read_element_size(&mut self.r).map(|await_|await_).map_err(|err| ReadError::new(err, pos, "Segment"))?;
+                let header_len = id_len + size_len;
+                self.pos += header_len;
+                Ok(Some(ElementHeader { raw_id, id: EbmlId::from_u64(raw_id).ok(), size, header_len }))
+            }
+
+            /// Store Info, Tracks, Chapters, Tags, Cues and SeekHead, skip all other level 1 elements
+            fn read_level1_blocking(&mut self, header: &ElementHeader) -> Result<(), ReadError> {
+                let start = self.pos - header.header_len;
+                if self.loaded.contains(&start) {
+                    return self.skip_blocking(header).map(|await_|await_);
+                }
+                self.loaded.push(start);
+                let pos = self.pos;
+                let r = &mut self.r;
+                let read = match header.id {
+                    Some(EbmlId::SeekHead) => {
+                        let (val, read) = SeekHead::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\SeekHead", pos))?;
+                        self.seek_head.push(val);
+                        read
+                    }
+                    Some(EbmlId::Info) => {
+                        let (val, read) = Info::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\Info", pos))?;
+                        self.info = Some(val);
+                        read
+                    }
+                    Some(EbmlId::Tracks) => {
+                        let (val, read) = Tracks::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\Tracks", pos))?;
+                        self.set_tracks(val).map_err(|err| ReadError::new(err, pos, "Segment\\Tracks"))?;
+                        read
+                    }
+                    Some(EbmlId::Chapters) => {
+                        let (val, read) = Chapters::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\Chapters", pos))?;
+                        self.chapters = Some(val);
+                        read
+                    }
+                    Some(EbmlId::Tags) => {
+                        let (val, read) = Tags::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\Tags", pos))?;
+                        self.tags.push(val);
+                        read
+                    }
+                    Some(EbmlId::Cues) => {
+                        let (val, read) = Cues::read_body(r, header.size).map(|await_|await_).map_err(|err| err.at("Segment\\Cues", pos))?;
+                        self.cues = Some(val);
+                        read
+                    }
+                    _ => return self.skip_blocking(header).map(|await_|await_),
+                };
+                self.pos += read as u64;
+                Ok(())
+            }
+
+            fn skip_blocking(&mut self, header: &ElementHeader) -> Result<(), ReadError> {
+                let size = skip_size(header).map_err(|err| ReadError::new(err, self.pos - header.header_len, "Segment"))?;
+                self.seek_to_blocking(self.pos + size).map(|await_|await_)
+            }
+
+            fn seek_to_blocking(&mut self, pos: u64) -> Result<(), ReadError> {
+                self.r.seek(SeekFrom::Start(pos)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), pos, "Segment"))?;
+                self.pos = pos;
+                Ok(())
+            }
+        }
+    }
+}
+
+mod blocking {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use super::*;
+    use crate::ElementReadBlocking;
+
+    define_demuxer!();
+
+    impl<R: Read + Seek> Iterator for Demuxer<R> {
+        type Item = Result<Frame, ReadError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.next_frame_blocking().transpose()
+        }
+    }
+}
+
+mod async_ {
+    use std::io::SeekFrom;
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    use super::*;
+    use crate::ElementRead;
+
+    define_demuxer!(impl_async);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, Ebml};
+    use crate::enums::TrackType;
+
+    fn sample() -> Vec<u8> {
+        let video = test_util::video_track(1, "V_TEST");
+        let mut audio = TrackEntry::default();
+        *audio.track_number.v = 2;
+        *audio.track_type.v = TrackType::Audio;
+        *audio.codec_id.v = "A_TEST".to_string();
+        audio.default_duration = Some(Ebml::new(10_000_000));
+        let mut tracks = Tracks::default();
+        tracks.track_entry.push(Ebml::new_index(0, video));
+        tracks.track_entry.push(Ebml::new_index(1, audio));
+
+        let mut segment = Segment {
+            info: Ebml::new_index(1, test_util::info()),
+            tracks: Some(Ebml::new_index(2, tracks)),
+            ..Default::default()
+        };
+        for (index, timestamp) in [(3, 0), (4, 1000)] {
+            let mut cluster = Cluster { timestamp: Ebml::new_index(0, timestamp), ..Default::default() };
+            let mut keyframe = Block::new(1, 0, vec![1, 2, 3]);
+            keyframe.set_keyframe(true);
+            cluster.simple_block.push(Ebml::new_index(1, keyframe));
+            let mut audio = Block::from_frames(2, 5, &[&[4, 4], &[5]]).unwrap();
+            audio.set_keyframe(true);
+            cluster.simple_block.push(Ebml::new_index(2, audio));
+            let mut group = BlockGroup {
+                block: Ebml::new_index(0, Block::new(1, 40, vec![6])),
+                block_duration: Some(Ebml::new_index(1, 40)),
+                ..Default::default()
+            };
+            group.reference_block.push(Ebml::new_index(2, -40));
+            cluster.block_group.push(Ebml::new_index(3, group));
+            segment.cluster.push(Ebml::new_index(index, cluster));
+        }
+
+        file(&segment)
+    }
+
+    fn file(segment: &Segment) -> Vec<u8> {
+        let mut buf = vec![];
+        crate::muxer::matroska_header().write_blocking(&mut buf).unwrap();
+        segment.write_blocking(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_demuxer() -> Result<(), anyhow::Error> {
+        let demuxer = Demuxer::open_blocking(std::io::Cursor::new(sample()))?;
+        assert_eq!(*demuxer.info().timestamp_scale.v, 1_000_000);
+        assert_eq!(demuxer.tracks().unwrap().track_entry.len(), 2);
+
+        let frames = demuxer.collect::<Result<Vec<Frame>, _>>()?;
+        assert_eq!(frames.len(), 8);
+        let frame = &frames[0];
+        assert_eq!((frame.track, frame.pts_ns, frame.keyframe, frame.duration), (1, 0, true, None));
+        assert_eq!(frame.data, vec![1, 2, 3]);
+        let frame = &frames[2];
+        assert_eq!((frame.track, frame.pts_ns, frame.keyframe, frame.duration), (2, 15_000_000, true, Some(10_000_000)));
+        assert_eq!(frame.data, vec![5]);
+        let frame = &frames[7];
+        assert_eq!((frame.track, frame.pts_ns, frame.keyframe, frame.duration), (1, 1_040_000_000, false, Some(40_000_000)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_laced_frames() -> Result<(), anyhow::Error> {
+        let mut audio = test_util::video_track(1, "A_TEST");
+        *audio.track_type.v = TrackType::Audio;
+        let mut tracks = Tracks::default();
+        tracks.track_entry.push(Ebml::new_index(0, audio));
+        let mut cluster = Cluster { timestamp: Ebml::new_index(0, 0), ..Default::default() };
+        let mut keyframes = Block::from_frames(1, 0, &[&[1], &[2]])?;
+        keyframes.set_keyframe(true);
+        cluster.simple_block.push(Ebml::new_index(1, keyframes));
+        // without DefaultDuration the BlockDuration is split between the frames of the lace
+        let group = BlockGroup {
+            block: Ebml::new_index(0, Block::from_frames(1, 100, &[&[3], &[4], &[5]])?),
+            block_duration: Some(Ebml::new_index(1, 60)),
+            ..Default::default()
+        };
+        cluster.block_group.push(Ebml::new_index(2, group));
+        let mut segment = Segment {
+            info: Ebml::new_index(0, test_util::info()),
+            tracks: Some(Ebml::new_index(1, tracks)),
+            ..Default::default()
+        };
+        segment.cluster.push(Ebml::new_index(2, cluster));
+
+        let demuxer = Demuxer::open_blocking(std::io::Cursor::new(file(&segment)))?;
+        let frames: Vec<_> = demuxer.map(|frame| frame.map(|frame| (frame.pts_ns, frame.keyframe, frame.duration)))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(frames, [
+            (0, true, None), (0, true, None),
+            (100_000_000, true, Some(20_000_000)), (120_000_000, true, Some(20_000_000)), (140_000_000, true, Some(20_000_000)),
+        ]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_demuxer_async() -> Result<(), anyhow::Error> {
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(sample())).await?;
+        let mut count = 0;
+        while let Some(frame) = demuxer.next_frame().await? {
+            assert!(frame.track == 1 || frame.track == 2);
+            count += 1;
+        }
+        assert_eq!(count, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recovery() -> Result<(), anyhow::Error> {
+        let buf = sample();
+        let clusters: Vec<usize> = buf.windows(4).enumerate()
+            .filter(|(_, id)| *id == [0x1F, 0x43, 0xB6, 0x75])
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(clusters.len(), 2);
+        // the first SimpleBlock after the Cluster header and Timestamp gets an invalid ID
+        let mut corrupt = buf.clone();
+        let block = clusters[0] + 5 + 3;
+        corrupt[block..block + 4].fill(0);
+        let demuxer = Demuxer::open_blocking(std::io::Cursor::new(corrupt.clone()))?;
+        let err = demuxer.collect::<Result<Vec<Frame>, _>>().expect_err("corrupt block");
+        assert!(matches!(err.kind, MatroskaError::InvalidVarInt));
+        assert_eq!((err.offset, err.path.as_str()), (block as u64, "\\Segment"));
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(corrupt))?.with_recovery(true);
+        let frames = (&mut demuxer).collect::<Result<Vec<Frame>, _>>()?;
+        assert_eq!(frames.len(), 4);
+        assert!(frames.iter().all(|frame| frame.pts_ns >= 1_000_000_000));
+        assert_eq!(demuxer.skipped().len(), 1);
+        assert_eq!((demuxer.skipped()[0].start, demuxer.skipped()[0].end), (block as u64, clusters[1] as u64));
+
+        // the last BlockGroup is cut, the Segment size exceeds the file
+        let truncated = &buf[..buf.len() - 2];
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(truncated))?.with_recovery(true);
+        let frames = (&mut demuxer).collect::<Result<Vec<Frame>, _>>()?;
+        assert_eq!(frames.len(), 7);
+        assert_eq!(demuxer.skipped()[0].end, truncated.len() as u64);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recovery_async() -> Result<(), anyhow::Error> {
+        let buf = sample();
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(&buf[..buf.len() - 2])).await?.with_recovery(true);
+        let mut count = 0;
+        while demuxer.next_frame().await?.is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 7);
+        assert_eq!(demuxer.skipped().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> Result<(), anyhow::Error> {
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(test_util::recording(test_util::info(), false)?))?;
+        assert!(demuxer.cues().is_none());
+        for (timestamp, keyframe) in [(500_000_000, 400_000_000), (0, 0), (5_000_000_000, 800_000_000), (399_999_999, 0)] {
+            demuxer.seek_blocking(1, timestamp)?;
+            let frame = demuxer.next_frame_blocking()?.unwrap();
+            assert_eq!((frame.pts_ns, frame.keyframe), (keyframe, true));
+        }
+        assert_eq!(demuxer.cues().unwrap().cue_point.len(), 3);
+        assert_eq!(demuxer.count(), 29);
+
+        // No Cues, the clusters are scanned
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(sample()))?;
+        demuxer.seek_blocking(1, 1_020_000_000)?;
+        assert_eq!(demuxer.next_frame_blocking()?.unwrap().pts_ns, 1_000_000_000);
+        demuxer.seek_blocking(1, 999_000_000)?;
+        assert_eq!(demuxer.next_frame_blocking()?.unwrap().pts_ns, 0);
+        let err = demuxer.seek_blocking(3, 0).expect_err("no track 3");
+        assert!(matches!(err.kind, MatroskaError::UnknownTrack(3)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dangling_seek_head() -> Result<(), anyhow::Error> {
+        // the Tags appended by the editor are cut, their SeekHead entry points at the end of the file
+        let buf = crate::test_util::recording(crate::test_util::info(), false)?;
+        let len = buf.len();
+        let mut editor = crate::MetadataEditor::open_blocking(std::io::Cursor::new(buf))?;
+        *editor.tags_mut() = crate::structs::Tags::default();
+        let mut buf = editor.save_blocking()?.into_inner();
+        buf.truncate(len);
+
+        let demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf.clone()))?.with_recovery(true);
+        assert!(demuxer.tags().is_empty());
+        assert_eq!(demuxer.count(), 30);
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(buf)).await?.with_recovery(true);
+        let mut count = 0;
+        while demuxer.next_frame().await?.is_some() {
+            count += 1;
+        }
+        assert_eq!(count, 30);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_seek_async() -> Result<(), anyhow::Error> {
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(test_util::recording(test_util::info(), false)?)).await?;
+        demuxer.seek(1, 900_000_000).await?;
+        assert_eq!(demuxer.next_frame().await?.unwrap().pts_ns, 800_000_000);
+
+        let mut demuxer = Demuxer::open(std::io::Cursor::new(sample())).await?;
+        demuxer.seek(2, 2_000_000_000).await?;
+        assert_eq!(demuxer.next_frame().await?.unwrap().pts_ns, 1_000_000_000);
+        Ok(())
+    }
+}
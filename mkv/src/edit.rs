@@ -0,0 +1,307 @@
+use super::io;
+use super::structs::*;
+use super::ids::EbmlId;
+use super::cues::{set_seek_entry, Slot};
+use super::lazy::{LazySegment, Level1Element};
+use super::{ElementSize, MatroskaError, ReadError, ReadOptions, WriteOptions};
+
+/// Level 1 elements the editor can rewrite
+const EDITABLE: [EbmlId; 3] = [EbmlId::Info, EbmlId::Tracks, EbmlId::Tags];
+
+/// Edits Info, Tracks and Tags of a finished file in place, without remuxing the clusters.
+///
+/// An edited element is rewritten over the old one and the Voids following it if it fits,
+/// otherwise it's appended at the end of the Segment, the old one becomes a Void and the
+/// SeekHead and the Segment size are updated.
+pub struct MetadataEditor<F> {
+    f: F,
+    elements: Vec<Level1Element>,
+    segment_start: u64,
+    segment_size: ElementSize,
+    /// Position of the Segment size field, rewritten when an element is appended
+    segment_size_pos: u64,
+    file_end: u64,
+
+    seek_head: Option<SeekHead>,
+    info: Info,
+    tracks: Option<Tracks>,
+    tags: Option<Tags>,
+    /// Elements borrowed mutably, they are written by `save`
+    modified: Vec<EbmlId>,
+}
+
+/// Unedited elements are written back byte-for-byte, with the unknown ones and the Voids
+const READ_OPTIONS: ReadOptions = ReadOptions { keep_unknown: true, lossless: true, verify_crc32: false };
+
+impl<F> MetadataEditor<F> {
+    fn new(segment: LazySegment<F>, file_end: u64, seek_head: Option<SeekHead>, info: Info, tracks: Option<Tracks>, tags: Option<Tags>) -> Self {
+        let elements = segment.elements().to_vec();
+        let segment_start = segment.segment_start();
+        let segment_size = segment.segment_size();
+        let segment_size_pos = segment_start - (segment.segment_header_len() - io::gen_uint(EbmlId::Segment as u64).len() as u64);
+        Self {
+            f: segment.into_inner(),
+            elements, segment_start, segment_size, segment_size_pos, file_end,
+            seek_head, info, tracks, tags,
+            modified: vec![],
+        }
+    }
+
+    pub fn info(&self) -> &Info { &self.info }
+    pub fn tracks(&self) -> Option<&Tracks> { self.tracks.as_ref() }
+    pub fn tags(&self) -> Option<&Tags> { self.tags.as_ref() }
+
+    pub fn info_mut(&mut self) -> &mut Info {
+        self.modify(EbmlId::Info);
+        &mut self.info
+    }
+    pub fn tracks_mut(&mut self) -> Option<&mut Tracks> {
+        self.modify(EbmlId::Tracks);
+        self.tracks.as_mut()
+    }
+    pub fn track_mut(&mut self, number: u64) -> Option<&mut TrackEntry> {
+        self.tracks_mut()?.track_entry.iter_mut()
+            .map(|track| &mut *track.v)
+            .find(|track| *track.track_number.v == number)
+    }
+    /// The first Tags of the Segment, created if the file has none
+    pub fn tags_mut(&mut self) -> &mut Tags {
+        self.modify(EbmlId::Tags);
+        self.tags.get_or_insert_with(Default::default)
+    }
+
+    fn modify(&mut self, id: EbmlId) {
+        if !self.modified.contains(&id) {
+            self.modified.push(id);
+        }
+    }
+
+    /// End of the Segment data, the file end for the unknown size
+    fn segment_end(&self) -> u64 {
+        match self.segment_size {
+            ElementSize::Sized(size) => self.file_end.min(self.segment_start + size),
+            ElementSize::Unknown(_) => self.file_end,
+        }
+    }
+
+    /// The level 1 element at `index` with the Voids following it
+    fn slot_at(&self, index: usize) -> Slot {
+        let element = self.elements[index];
+        let end = self.elements[index + 1..].iter()
+            .take_while(|next| next.id == Some(EbmlId::Void))
+            .last()
+            .map_or(element.end(), |void| void.end());
+        Slot { pos: element.position, len: end - element.position }
+    }
+    fn slot(&self, id: EbmlId) -> Option<Slot> {
+        let index = self.elements.iter().position(|element| element.id == Some(id))?;
+        Some(self.slot_at(index))
+    }
+    /// The SeekHead, or a Void reserving its space at the Segment start
+    fn seek_head_slot(&self) -> Option<Slot> {
+        match self.elements.first()?.id {
+            Some(EbmlId::Void) => Some(self.slot_at(0)),
+            _ => self.slot(EbmlId::SeekHead),
+        }
+    }
+
+    fn body(&self, id: EbmlId) -> Result<Vec<u8>, MatroskaError> {
+        let mut body = vec![];
+        match id {
+            EbmlId::Info => self.info.write_body_with_blocking(&mut body, crc32_options(self.info.crc_32.is_some()))?,
+            EbmlId::Tracks => match &self.tracks {
+                Some(tracks) => tracks.write_body_with_blocking(&mut body, crc32_options(tracks.crc_32.is_some()))?,
+                None => return Err(MatroskaError::MissingElement(EbmlId::Tracks)),
+            },
+            EbmlId::Tags => match &self.tags {
+                Some(tags) => tags.write_body_with_blocking(&mut body, crc32_options(tags.crc_32.is_some()))?,
+                None => return Err(MatroskaError::MissingElement(EbmlId::Tags)),
+            },
+            id => unreachable!("'{id:?}' isn't editable"),
+        };
+        Ok(body)
+    }
+
+    /// Positions and bytes to write the modified elements
+    fn patches(&self) -> Result<Vec<(u64, Vec<u8>)>, MatroskaError> {
+        let mut patches = vec![];
+        let mut end = self.segment_end();
+        let mut seek_head = self.seek_head.clone().unwrap_or_else(|| {
+            let mut seek_head = SeekHead::default();
+            for id in [EbmlId::Info, EbmlId::Tracks, EbmlId::Tags, EbmlId::Chapters, EbmlId::Cues] {
+                if let Some(slot) = self.slot(id) {
+                    set_seek_entry(&mut seek_head, id, slot.pos - self.segment_start);
+                }
+            }
+            seek_head
+        });
+        let mut moved = false;
+
+        for id in EDITABLE.into_iter().filter(|id| self.modified.contains(id)) {
+            let mut body = self.body(id)?;
+            let old = self.slot(id);
+            if let Some((pos, buf)) = old.and_then(|slot| io::gen_element_padded(id, &body, slot.len).ok().map(|buf| (slot.pos, buf))) {
+                patches.push((pos, buf));
+                continue;
+            }
+            if end < self.file_end {
+                return Err(MatroskaError::InPlaceUpdate(format!("Segment is followed by other data at {end}, '{id:?}' can't be appended")));
+            }
+            let mut buf = io::gen_element_id_size(id as u64, body.len() as u64);
+            buf.append(&mut body);
+            if let Some(old) = old {
+                patches.push((old.pos, io::gen_void(old.len)?));
+            }
+            set_seek_entry(&mut seek_head, id, end - self.segment_start);
+            end += buf.len() as u64;
+            patches.push((end - buf.len() as u64, buf));
+            moved = true;
+        }
+        if !moved {
+            return Ok(patches);
+        }
+
+        if let ElementSize::Sized(_) = self.segment_size {
+            let size_len = self.segment_start - self.segment_size_pos;
+            patches.push((self.segment_size_pos, io::gen_vint_with_len(end - self.segment_start, size_len)?));
+        }
+        let seek_head_slot = self.seek_head_slot()
+            .ok_or_else(|| MatroskaError::InPlaceUpdate("No SeekHead or Void at the Segment start to reference the moved elements".to_string()))?;
+        let mut body = vec![];
+        seek_head.write_body_with_blocking(&mut body, crc32_options(seek_head.crc_32.is_some()))?;
+        let buf = io::gen_element_padded(EbmlId::SeekHead, &body, seek_head_slot.len)
+            .map_err(|_| MatroskaError::InPlaceUpdate(format!("SeekHead doesn't fit {} bytes at {}", seek_head_slot.len, seek_head_slot.pos)))?;
+        patches.push((seek_head_slot.pos, buf));
+        Ok(patches)
+    }
+}
+
+/// The CRC-32 of an edited element is recomputed if it had one
+fn crc32_options(crc32: bool) -> WriteOptions {
+    WriteOptions { crc32, ..Default::default() }
+}
+
+macro_rules! define_metadata_editor {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        impl<F: std::io::Read + std::io::Write + std::io::Seek> MetadataEditor<F> {
+            pub fn open_blocking(f: F) -> Result<Self, ReadError> {
+                let mut segment = LazySegment::open_blocking(f).map(|await_|await_)?;
+                let seek_head = segment.load_with_blocking(EbmlId::SeekHead, READ_OPTIONS).map(|await_|await_)?;
+                let info = segment.load_with_blocking(EbmlId::Info, READ_OPTIONS).map(|await_|await_)?
+                    .ok_or_else(|| ReadError::new(MatroskaError::MissingElement(EbmlId::Info), segment.segment_start(), "Segment"))?;
+                let tracks = segment.load_with_blocking(EbmlId::Tracks, READ_OPTIONS).map(|await_|await_)?;
+                let tags = segment.load_with_blocking(EbmlId::Tags, READ_OPTIONS).map(|await_|await_)?;
+                let file_end = segment.get_mut().seek(SeekFrom::End(0)).map(|await_|await_).map_err(MatroskaError::Io)?;
+                Ok(Self::new(segment, file_end, seek_head, info, tracks, tags))
+            }
+
+            /// Write the modified elements, the clusters are left untouched
+            pub fn save_blocking(mut self) -> Result<F, MatroskaError> {
+                for (pos, buf) in self.patches()? {
+                    self.f.seek(SeekFrom::Start(pos)).map(|await_|await_)?;
+                    self.f.write_all(&buf).map(|await_|await_)?;
+                }
+                self.f.flush().map(|await_|await_)?;
+                Ok(self.f)
+            }
+        }
+    }
+}
+
+mod blocking {
+    use std::io::SeekFrom;
+
+    use super::*;
+
+    define_metadata_editor!();
+}
+
+mod async_ {
+    use std::io::SeekFrom;
+    use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+    use super::*;
+
+    define_metadata_editor!(impl_async);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::Demuxer;
+    use crate::test_util;
+
+    type Recording = Demuxer<Cursor<Vec<u8>>>;
+
+    /// Demuxer which read all frames of the file and the number of frames
+    fn frames(buf: Vec<u8>) -> Result<(Recording, usize), anyhow::Error> {
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf))?;
+        let mut count = 0;
+        while demuxer.next_frame_blocking()?.is_some() {
+            count += 1;
+        }
+        Ok((demuxer, count))
+    }
+
+    #[test]
+    fn test_edit_in_place() -> Result<(), anyhow::Error> {
+        let mut info = test_util::info();
+        info.title = Some(crate::Ebml::new("title".to_string()));
+        let buf = test_util::recording(info, false)?;
+        let len = buf.len();
+        let mut editor = MetadataEditor::open_blocking(std::io::Cursor::new(buf))?;
+        *editor.info_mut().title.as_mut().expect("title").v = "new".to_string();
+        let buf = editor.save_blocking()?.into_inner();
+        // the shorter Info is padded by a Void
+        assert_eq!(buf.len(), len);
+        let segment = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?;
+        let info = segment.elements().iter().position(|element| element.id == Some(EbmlId::Info)).expect("Info");
+        assert_eq!(segment.elements()[info + 1].id, Some(EbmlId::Void));
+
+        // the longer title takes the Void back
+        let mut editor = MetadataEditor::open_blocking(std::io::Cursor::new(buf))?;
+        *editor.info_mut().title.as_mut().expect("title").v = "title".to_string();
+        let buf = editor.save_blocking()?.into_inner();
+        assert_eq!(buf.len(), len);
+
+        let (demuxer, count) = frames(buf)?;
+        assert_eq!(count, 30);
+        assert_eq!(demuxer.info().title.as_ref().map(|title| title.v.as_str()), Some("title"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_moved() -> Result<(), anyhow::Error> {
+        for live in [false, true] {
+            let buf = test_util::recording(test_util::info(), live)?;
+            let len = buf.len();
+            let mut editor = MetadataEditor::open_blocking(std::io::Cursor::new(buf))?;
+            editor.track_mut(1).expect("track").name = Some(crate::Ebml::new("x".repeat(1000)));
+            *editor.tags_mut() = Tags::default();
+            let buf = editor.save_blocking()?.into_inner();
+            assert!(buf.len() > len + 1000);
+
+            let segment = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?;
+            let ids: Vec<_> = segment.elements().iter().rev().take(2).map(|element| element.id).collect();
+            assert_eq!(ids, vec![Some(EbmlId::Tags), Some(EbmlId::Tracks)]);
+            assert_eq!(segment.elements().last().map(|element| element.end()), Some(buf.len() as u64));
+
+            let (demuxer, count) = frames(buf)?;
+            assert_eq!(count, 30);
+            assert_eq!(demuxer.track(1).and_then(|track| track.name.as_ref()).map(|name| name.v.len()), Some(1000));
+            assert_eq!(demuxer.tags().len(), 1);
+        }
+
+        // the data after the Segment isn't overwritten by the moved Tracks
+        let mut buf = test_util::recording(test_util::info(), false)?;
+        buf.extend_from_slice(&[0xEC, 0x80]);
+        let mut editor = MetadataEditor::open_blocking(Cursor::new(buf))?;
+        editor.track_mut(1).expect("track").name = Some(crate::Ebml::new("x".repeat(1000)));
+        let err = editor.save_blocking().expect_err("Tracks can't be appended");
+        assert!(matches!(err, MatroskaError::InPlaceUpdate(_)));
+        Ok(())
+    }
+}
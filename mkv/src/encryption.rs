@@ -0,0 +1,146 @@
+use ctr::cipher::{KeyIvInit, StreamCipher};
+
+use super::structs::*;
+use super::enums::*;
+use super::{Ebml, MatroskaError};
+
+// https://www.webmproject.org/docs/webm-encryption/
+const SIGNAL_ENCRYPTED: u8 = 0b_0000_0001;
+const SIGNAL_PARTITIONED: u8 = 0b_0000_0010;
+const IV_LEN: usize = 8;
+
+/// AES-128 key of a `ContentEncKeyID`
+pub type Key = [u8; 16];
+/// Key of the ContentEncKeyID, `None` if it's unknown
+pub type KeyLookup<'a> = dyn Fn(&[u8]) -> Option<Key> + Send + Sync + 'a;
+
+/// Counter block is the 8 bytes IV followed by the 8 bytes big-endian block counter
+type Aes128Ctr = ctr::Ctr64BE<aes::Aes128>;
+
+/// ContentEncoding of a track encrypted by the WebM Encryption scheme with the key of `key_id`
+pub fn content_encoding(key_id: &[u8]) -> ContentEncoding {
+    let aes_settings = ContentEncAesSettings {
+        aes_settings_cipher_mode: Ebml::new_index(0, AesSettingsCipherMode::AesCtr),
+        ..Default::default()
+    };
+    let encryption = ContentEncryption {
+        content_enc_algo: Ebml::new_index(0, ContentEncAlgo::Aes),
+        content_enc_key_id: Some(Ebml::new_index(1, key_id.to_vec())),
+        content_enc_aes_settings: Some(Ebml::new_index(2, aes_settings)),
+        ..Default::default()
+    };
+    ContentEncoding {
+        content_encoding_order: Ebml::new_index(0, 0),
+        content_encoding_scope: Ebml::new_index(1, ContentEncodingScope::Block),
+        content_encoding_type: Ebml::new_index(2, ContentEncodingType::Encryption),
+        content_encryption: Some(Ebml::new_index(3, encryption)),
+        ..Default::default()
+    }
+}
+
+/// Encrypted frame: the signal byte, the IV, the partition offsets if any and the data.
+///
+/// Partitions are split by the offsets, the first one and every other one after it stay clear,
+/// the encrypted ones are a single AES-CTR stream
+pub fn encrypt_frame(key: &Key, iv: [u8; IV_LEN], frame: &[u8], partitions: &[u32]) -> Result<Vec<u8>, MatroskaError> {
+    if partitions.len() > u8::MAX as usize {
+        return Err(MatroskaError::InvalidContentEncoding(format!("{} partitions, at most 255 are allowed", partitions.len())));
+    }
+    let mut buf = vec![if partitions.is_empty() { SIGNAL_ENCRYPTED } else { SIGNAL_ENCRYPTED | SIGNAL_PARTITIONED }];
+    buf.extend_from_slice(&iv);
+    if !partitions.is_empty() {
+        buf.push(partitions.len() as u8);
+        for offset in partitions {
+            buf.extend_from_slice(&offset.to_be_bytes());
+        }
+    }
+    let start = buf.len();
+    buf.extend_from_slice(frame);
+    apply_keystream(key, iv, &mut buf[start..], partitions)?;
+    Ok(buf)
+}
+
+/// Frame of the WebM Encryption scheme decrypted, frames with the signal byte only are returned as is
+pub fn decrypt_frame(key: Option<&Key>, data: &[u8]) -> Result<Vec<u8>, MatroskaError> {
+    let invalid = |reason: &str| MatroskaError::InvalidContentEncoding(reason.to_string());
+    let (&signal, rest) = data.split_first().ok_or_else(|| invalid("encrypted frame without the signal byte"))?;
+    if signal & SIGNAL_ENCRYPTED == 0 {
+        return Ok(rest.to_vec());
+    }
+    let key = key.ok_or_else(|| invalid("no key to decrypt the frame"))?;
+    if rest.len() < IV_LEN {
+        return Err(invalid("encrypted frame is truncated"));
+    }
+    let (iv, mut rest) = rest.split_at(IV_LEN);
+    let mut partitions = vec![];
+    if signal & SIGNAL_PARTITIONED != 0 {
+        let (&count, offsets) = rest.split_first().ok_or_else(|| invalid("encrypted frame is truncated"))?;
+        let len = count as usize * 4;
+        if offsets.len() < len {
+            return Err(invalid("encrypted frame is truncated"));
+        }
+        partitions = offsets[..len].chunks(4).map(|offset| u32::from_be_bytes([offset[0], offset[1], offset[2], offset[3]])).collect();
+        rest = &offsets[len..];
+    }
+    let mut frame = rest.to_vec();
+    apply_keystream(key, iv.try_into().expect("IV_LEN bytes"), &mut frame, &partitions)?;
+    Ok(frame)
+}
+
+/// Encrypt or decrypt the odd partitions in place
+fn apply_keystream(key: &Key, iv: [u8; IV_LEN], data: &mut [u8], partitions: &[u32]) -> Result<(), MatroskaError> {
+    let mut counter_block = [0u8; 16];
+    counter_block[..IV_LEN].copy_from_slice(&iv);
+    let mut cipher = Aes128Ctr::new(key.into(), &counter_block.into());
+    if partitions.is_empty() {
+        cipher.apply_keystream(data);
+        return Ok(());
+    }
+    let mut bounds = vec![0];
+    bounds.extend(partitions.iter().map(|offset| *offset as usize));
+    bounds.push(data.len());
+    if bounds.windows(2).any(|pair| pair[0] > pair[1]) {
+        return Err(MatroskaError::InvalidContentEncoding("partition offsets are out of order or out of the frame".to_string()));
+    }
+    for pair in bounds.windows(2).skip(1).step_by(2) {
+        cipher.apply_keystream(&mut data[pair[0]..pair[1]]);
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encryption() -> Result<(), anyhow::Error> {
+        let key = [7u8; 16];
+        let iv = [1, 2, 3, 4, 5, 6, 7, 8];
+        let frame: Vec<u8> = (0..100).collect();
+
+        let encrypted = encrypt_frame(&key, iv, &frame, &[])?;
+        assert_eq!(encrypted[0], SIGNAL_ENCRYPTED);
+        assert_eq!(encrypted[1..9], iv);
+        assert_ne!(encrypted[9..], frame[..]);
+        assert_eq!(decrypt_frame(Some(&key), &encrypted)?, frame);
+        assert!(decrypt_frame(None, &encrypted).is_err());
+        assert_ne!(decrypt_frame(Some(&[8; 16]), &encrypted)?, frame);
+
+        // clear partitions 0..10 and 30..40, the encrypted ones continue the same counter
+        let encrypted = encrypt_frame(&key, iv, &frame, &[10, 30, 40])?;
+        assert_eq!(encrypted[..10], [SIGNAL_ENCRYPTED | SIGNAL_PARTITIONED, 1, 2, 3, 4, 5, 6, 7, 8, 3]);
+        let data = &encrypted[10 + 12..];
+        assert_eq!(data[..10], frame[..10]);
+        assert_eq!(data[30..40], frame[30..40]);
+        let whole = encrypt_frame(&key, iv, &[&frame[10..30], &frame[40..]].concat(), &[])?;
+        assert_eq!(data[10..30], whole[9..29]);
+        assert_eq!(data[40..], whole[29..]);
+        assert_eq!(decrypt_frame(Some(&key), &encrypted)?, frame);
+
+        // clear frame of an encrypted track
+        assert_eq!(decrypt_frame(None, &[0, 1, 2])?, vec![1, 2]);
+        assert!(encrypt_frame(&key, iv, &frame, &[30, 10]).is_err());
+        Ok(())
+    }
+}
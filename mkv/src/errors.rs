@@ -23,6 +23,28 @@ pub enum MatroskaError {
     InvalidDate,
     #[error("Invalid seek head entry (0x{id:02X?})")]
     InvalidSeekHead { id: u32, },
+    #[error("invalid block: {0}")]
+    InvalidBlock(&'static str),
+    #[error("CRC-32 mismatch in element '{id:?}': stored 0x{stored:08X}, computed 0x{computed:08X}")]
+    Crc32Mismatch { id: EbmlId, stored: u32, computed: u32 },
+    #[error("Expected element '{expected:?}', found ID 0x{found:X}")]
+    UnexpectedId { expected: EbmlId, found: u64 },
+    #[error("Element ID 0x{0:X} with unknown data size is not allowed here")]
+    UnknownSizeNotAllowed(u64),
+    #[error("Required element '{0:?}' not found")]
+    MissingElement(EbmlId),
+    #[error("Only one element '{id:?}' is allowed, found {count}")]
+    TooManyElements { id: EbmlId, count: usize },
+    #[error("invalid content encoding: {0}")]
+    InvalidContentEncoding(String),
+    #[error("invalid CodecPrivate: {0}")]
+    InvalidCodecPrivate(String),
+    #[error("Element ID 0x{0:X} overflows its parent")]
+    ElementOverflow(u64),
+    #[error("Track {0} not found")]
+    UnknownTrack(u64),
+    #[error("can't update the file in place: {0}")]
+    InPlaceUpdate(String),
 }
 
 impl From<std::io::Error> for MatroskaError {
@@ -31,3 +53,89 @@ impl From<std::io::Error> for MatroskaError {
         Self::Io(err)
     }
 }
+
+/// Error of the element readers with the location of the failed bytes
+#[derive(thiserror::Error, Debug)]
+#[error("{kind} at offset {offset} in '{path}'")]
+pub struct ReadError {
+    pub kind: MatroskaError,
+    /// Offset of the failed bytes from the start of the element read by `read`, or from the body
+    /// read by `read_body`; absolute when the Demuxer reports it
+    pub offset: u64,
+    /// EBML path of the element in the form of the specification and the validator,
+    /// e.g. `\Segment\Tracks\TrackEntry[2]\Video`
+    pub path: String,
+}
+
+impl ReadError {
+    /// `path` is relative, e.g. `Tracks\TrackEntry[2]`, or empty for the body being read
+    pub fn new(kind: MatroskaError, offset: u64, path: &str) -> Self {
+        let path = if path.is_empty() { String::new() } else { format!("\\{path}") };
+        Self { kind, offset, path }
+    }
+    /// The error of a child element whose body starts at `offset` in the parent named `name`,
+    /// or at the relative path `name` such as `Segment\Tracks`
+    pub fn at(mut self, name: &str, offset: u64) -> Self {
+        self.path = format!("\\{name}{}", self.path);
+        self.offset += offset;
+        self
+    }
+    /// The error of an element read at `offset`, e.g. in the file
+    pub fn offset_by(mut self, offset: u64) -> Self {
+        self.offset += offset;
+        self
+    }
+}
+
+/// Errors of the body itself, e.g. a missing required element, are at the start of the body
+impl From<MatroskaError> for ReadError {
+    fn from(kind: MatroskaError) -> Self {
+        Self::new(kind, 0, "")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{EbmlId, MatroskaError};
+    use crate::{Ebml, ElementReadBlocking};
+    use crate::structs::{Info, TrackEntry, Tracks, Video};
+
+    #[test]
+    fn test_read_error_path() -> Result<(), anyhow::Error> {
+        let mut tracks = Tracks::default();
+        for i in 1..=2 {
+            let mut track = TrackEntry::default();
+            *track.track_number.v = i;
+            *track.track_uid.v = i;
+            *track.codec_id.v = "V_TEST".to_string();
+            let mut video = Video::default();
+            *video.pixel_width.v = 0x1234;
+            *video.pixel_height.v = 0x0100;
+            track.video = Some(Ebml::new(video));
+            tracks.track_entry.push(Ebml::new_index(i - 1, track));
+        }
+        let mut buf = vec![];
+        tracks.write_blocking(&mut buf)?;
+
+        // PixelWidth of the second track gets a 9 bytes long size
+        let pixel_width = buf.windows(4).rposition(|w| w == [0xB0, 0x82, 0x12, 0x34]).expect("PixelWidth");
+        buf[pixel_width + 1] = 0x89;
+        let err = Tracks::read(&mut &buf[..]).expect_err("invalid PixelWidth");
+        assert!(matches!(err.kind, MatroskaError::InvalidUint));
+        assert_eq!(err.path, "\\Tracks\\TrackEntry[1]\\Video\\PixelWidth");
+        assert_eq!(err.offset, pixel_width as u64 + 2);
+
+        // the required PixelHeight is gone when its ID is changed to an unknown one
+        buf[pixel_width + 1] = 0x82;
+        let pixel_height = buf.windows(4).rposition(|w| w == [0xBA, 0x82, 0x01, 0x00]).expect("PixelHeight");
+        buf[pixel_height] = 0xBB;
+        let err = Tracks::read(&mut &buf[..]).expect_err("missing PixelHeight");
+        assert!(matches!(err.kind, MatroskaError::MissingElement(EbmlId::PixelHeight)));
+        assert_eq!(err.path, "\\Tracks\\TrackEntry[1]\\Video");
+
+        let err = Info::read(&mut &buf[..]).expect_err("not Info");
+        assert!(matches!(err.kind, MatroskaError::UnexpectedId { expected: EbmlId::Info, found: 0x1654AE6B }));
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use super::codec_private::{AvcConfig, HevcConfig};
+use super::structs::TrackEntry;
+use super::{CodecPrivate, MatroskaError};
+
+pub use blocking::extract_annex_b_blocking;
+pub use async_::extract_annex_b;
+
+const START_CODE: [u8; 4] = [0, 0, 0, 1];
+
+const AVC_NAL_SPS: u8 = 7;
+const AVC_NAL_AUD: u8 = 9;
+const HEVC_NAL_SPS: u8 = 33;
+const HEVC_NAL_AUD: u8 = 35;
+
+/// Converter of the frames of an H.264 or HEVC track from the length-prefixed NAL units of
+/// Matroska to the Annex-B byte stream of `.h264`/`.h265` files
+#[derive(Debug, Clone)]
+pub struct AnnexB {
+    hevc: bool,
+    nal_length_size: usize,
+    /// VPS, SPS and PPS with start codes, written before the keyframes without in-band SPS
+    parameter_sets: Vec<u8>,
+}
+
+impl AnnexB {
+    pub fn new(codec_private: &CodecPrivate) -> Result<Self, MatroskaError> {
+        match codec_private {
+            CodecPrivate::Avc(config) => Self::avc(config),
+            CodecPrivate::Hevc(config) => Self::hevc(config),
+            other => Err(MatroskaError::InvalidCodecPrivate(format!("{} isn't H.264 or HEVC", other.codec_id()))),
+        }
+    }
+    pub fn from_track(track: &TrackEntry) -> Result<Self, MatroskaError> {
+        match CodecPrivate::from_track(track)? {
+            Some(codec_private) => Self::new(&codec_private),
+            None => Err(MatroskaError::InvalidCodecPrivate(format!("{} isn't H.264 or HEVC", track.codec_id.v))),
+        }
+    }
+    fn avc(config: &AvcConfig) -> Result<Self, MatroskaError> {
+        let nal_units = config.sps.iter().chain(&config.pps);
+        Ok(Self { hevc: false, nal_length_size: nal_length_size(config.nal_length_size)?, parameter_sets: annex_b(nal_units) })
+    }
+    fn hevc(config: &HevcConfig) -> Result<Self, MatroskaError> {
+        let nal_units = config.vps().into_iter().chain(config.sps()).chain(config.pps());
+        Ok(Self { hevc: true, nal_length_size: nal_length_size(config.nal_length_size)?, parameter_sets: annex_b(nal_units) })
+    }
+
+    /// `h264` or `h265`
+    pub fn extension(&self) -> &'static str {
+        if self.hevc { "h265" } else { "h264" }
+    }
+
+    /// Annex-B of the frame, the parameter sets are injected into keyframes after the access unit delimiter
+    pub fn convert(&self, frame: &[u8], keyframe: bool) -> Result<Vec<u8>, MatroskaError> {
+        let nal_units = self.nal_units(frame)?;
+        let inject = keyframe && !nal_units.iter().any(|nal| self.nal_type(nal) == Some(self.sps_type()));
+        let mut buf = Vec::with_capacity(frame.len() + self.parameter_sets.len());
+        let mut injected = !inject;
+        for nal in nal_units {
+            if !injected && self.nal_type(nal) != Some(self.aud_type()) {
+                buf.extend_from_slice(&self.parameter_sets);
+                injected = true;
+            }
+            buf.extend_from_slice(&START_CODE);
+            buf.extend_from_slice(nal);
+        }
+        if !injected {
+            buf.extend_from_slice(&self.parameter_sets);
+        }
+        Ok(buf)
+    }
+
+    fn nal_units<'a>(&self, mut frame: &'a [u8]) -> Result<Vec<&'a [u8]>, MatroskaError> {
+        let mut nal_units = vec![];
+        while !frame.is_empty() {
+            if frame.len() < self.nal_length_size {
+                return Err(MatroskaError::InvalidBlock("NAL unit length is truncated"));
+            }
+            let (len, rest) = frame.split_at(self.nal_length_size);
+            let len = len.iter().fold(0usize, |len, byte| len << 8 | *byte as usize);
+            if len > rest.len() {
+                return Err(MatroskaError::InvalidBlock("NAL unit length exceeds the frame"));
+            }
+            let (nal, rest) = rest.split_at(len);
+            nal_units.push(nal);
+            frame = rest;
+        }
+        Ok(nal_units)
+    }
+    fn nal_type(&self, nal: &[u8]) -> Option<u8> {
+        let header = *nal.first()?;
+        Some(if self.hevc { (header >> 1) & 0x3F } else { header & 0x1F })
+    }
+    fn sps_type(&self) -> u8 {
+        if self.hevc { HEVC_NAL_SPS } else { AVC_NAL_SPS }
+    }
+    fn aud_type(&self) -> u8 {
+        if self.hevc { HEVC_NAL_AUD } else { AVC_NAL_AUD }
+    }
+}
+
+/// Size of the length prefix of the NAL units, the decoder configuration record stores 1..=4
+fn nal_length_size(size: u8) -> Result<usize, MatroskaError> {
+    match size {
+        1..=4 => Ok(size as usize),
+        _ => Err(MatroskaError::InvalidCodecPrivate(format!("NAL unit length size {size} isn't 1..=4"))),
+    }
+}
+
+fn annex_b<T: AsRef<[u8]>>(nal_units: impl Iterator<Item = T>) -> Vec<u8> {
+    let mut buf = vec![];
+    for nal in nal_units {
+        buf.extend_from_slice(&START_CODE);
+        buf.extend_from_slice(nal.as_ref());
+    }
+    buf
+}
+
+fn converter(track: Option<&TrackEntry>, number: u64) -> Result<AnnexB, anyhow::Error> {
+    let track = track.ok_or_else(|| anyhow::anyhow!("Track {number} not found"))?;
+    Ok(AnnexB::from_track(track)?)
+}
+
+macro_rules! define_extract_annex_b {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        /// Write the frames of the H.264 or HEVC track as an Annex-B elementary stream,
+        /// returns the number of frames written
+        pub fn extract_annex_b_blocking<R: std::io::Read + std::io::Seek, W: std::io::Write>(r: R, track: u64, w: &mut W) -> Result<u64, anyhow::Error> {
+            let mut demuxer = Demuxer::open_blocking(r).map(|await_|await_)?;
+            let annex_b = converter(demuxer.track(track), track)?;
+            let mut frames = 0;
+            while let Some(frame) = demuxer.next_frame_blocking().map(|await_|await_)? {
+                if frame.track == track {
+                    w.write_all(&annex_b.convert(&frame.data, frame.keyframe)?).map(|await_|await_)?;
+                    frames += 1;
+                }
+            }
+            w.flush().map(|await_|await_)?;
+            Ok(frames)
+        }
+    }
+}
+
+mod blocking {
+    use super::*;
+    use crate::Demuxer;
+
+    define_extract_annex_b!();
+}
+
+mod async_ {
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+    use crate::Demuxer;
+
+    define_extract_annex_b!(impl_async);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec_private::CODEC_AVC;
+    use crate::{test_util, Ebml, Frame, Muxer};
+
+    fn avc() -> AvcConfig {
+        AvcConfig { profile: 66, level: 30, nal_length_size: 4, sps: vec![vec![0x67, 0x42]], pps: vec![vec![0x68, 0xCE]], ..Default::default() }
+    }
+
+    #[test]
+    fn test_annex_b() -> Result<(), anyhow::Error> {
+        let annex_b = AnnexB::new(&CodecPrivate::Avc(avc()))?;
+        assert_eq!(annex_b.extension(), "h264");
+        let idr = [0, 0, 0, 2, 0x65, 0xAA, 0, 0, 0, 1, 0x06];
+        assert_eq!(annex_b.convert(&idr, true)?, [
+            0, 0, 0, 1, 0x67, 0x42, 0, 0, 0, 1, 0x68, 0xCE, 0, 0, 0, 1, 0x65, 0xAA, 0, 0, 0, 1, 0x06,
+        ]);
+        assert_eq!(annex_b.convert(&idr, false)?, [0, 0, 0, 1, 0x65, 0xAA, 0, 0, 0, 1, 0x06]);
+        // after the access unit delimiter
+        let aud = [0, 0, 0, 2, 0x09, 0xF0, 0, 0, 0, 1, 0x65];
+        assert_eq!(annex_b.convert(&aud, true)?, [
+            0, 0, 0, 1, 0x09, 0xF0, 0, 0, 0, 1, 0x67, 0x42, 0, 0, 0, 1, 0x68, 0xCE, 0, 0, 0, 1, 0x65,
+        ]);
+        // in-band SPS
+        let in_band = [0, 0, 0, 1, 0x67, 0, 0, 0, 1, 0x65];
+        assert_eq!(annex_b.convert(&in_band, true)?, [0, 0, 0, 1, 0x67, 0, 0, 0, 1, 0x65]);
+        assert!(matches!(annex_b.convert(&[0, 0, 0, 9, 0x65], false), Err(MatroskaError::InvalidBlock(_))));
+        assert!(AnnexB::new(&CodecPrivate::Vp9(Default::default())).is_err());
+        for nal_length_size in [0, 5] {
+            let config = AvcConfig { nal_length_size, ..avc() };
+            assert!(matches!(AnnexB::new(&CodecPrivate::Avc(config)), Err(MatroskaError::InvalidCodecPrivate(_))));
+        }
+        let hevc = HevcConfig { nal_length_size: 0, ..Default::default() };
+        assert!(matches!(AnnexB::new(&CodecPrivate::Hevc(hevc)), Err(MatroskaError::InvalidCodecPrivate(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_annex_b() -> Result<(), anyhow::Error> {
+        let mut track = test_util::video_track(1, CODEC_AVC);
+        track.codec_private = Some(Ebml::new(avc().to_bytes()));
+        let mut muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track]);
+        for i in 0..4u8 {
+            let data = vec![0, 0, 0, 2, if i % 2 == 0 { 0x65 } else { 0x41 }, i];
+            muxer.write_frame_blocking(Frame { track: 1, pts_ns: i as i64 * 40_000_000, duration: None, keyframe: i % 2 == 0, data })?;
+        }
+        let file = muxer.finish_seekable_blocking()?.into_inner();
+
+        let mut stream = vec![];
+        assert_eq!(extract_annex_b_blocking(std::io::Cursor::new(file.clone()), 1, &mut stream)?, 4);
+        let parameter_sets = [0, 0, 0, 1, 0x67, 0x42, 0, 0, 0, 1, 0x68, 0xCE];
+        assert_eq!(stream, [
+            &parameter_sets[..], &[0, 0, 0, 1, 0x65, 0], &[0, 0, 0, 1, 0x41, 1],
+            &parameter_sets[..], &[0, 0, 0, 1, 0x65, 2], &[0, 0, 0, 1, 0x41, 3],
+        ].concat());
+        assert!(extract_annex_b_blocking(std::io::Cursor::new(file), 2, &mut vec![]).is_err());
+        Ok(())
+    }
+}
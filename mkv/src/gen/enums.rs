@@ -0,0 +1,1774 @@
+// The code was generated by codegen!
+
+/// Values of the `ChapterTranslateCodec` element
+#[derive(Debug, Clone, Copy)]
+pub enum ChapterTranslateCodec {
+    /// Matroska Script
+    MatroskaScript,
+    /// DVD-menu
+    DvdMenu,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ChapterTranslateCodec {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::MatroskaScript,
+            1 => Self::DvdMenu,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ChapterTranslateCodec {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::MatroskaScript => 0,
+            Self::DvdMenu => 1,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ChapterTranslateCodec {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ChapterTranslateCodec {}
+impl std::hash::Hash for ChapterTranslateCodec {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ChapterTranslateCodec> for u64 {
+    fn from(val: ChapterTranslateCodec) -> Self { val.value() }
+}
+impl Default for ChapterTranslateCodec {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TrackType` element
+#[derive(Debug, Clone, Copy)]
+pub enum TrackType {
+    /// video
+    Video,
+    /// audio
+    Audio,
+    /// complex
+    Complex,
+    /// logo
+    Logo,
+    /// subtitle
+    Subtitle,
+    /// buttons
+    Buttons,
+    /// control
+    Control,
+    /// metadata
+    Metadata,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for TrackType {
+    fn from(val: u64) -> Self {
+        match val {
+            1 => Self::Video,
+            2 => Self::Audio,
+            3 => Self::Complex,
+            16 => Self::Logo,
+            17 => Self::Subtitle,
+            18 => Self::Buttons,
+            32 => Self::Control,
+            33 => Self::Metadata,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TrackType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Video => 1,
+            Self::Audio => 2,
+            Self::Complex => 3,
+            Self::Logo => 16,
+            Self::Subtitle => 17,
+            Self::Buttons => 18,
+            Self::Control => 32,
+            Self::Metadata => 33,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for TrackType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for TrackType {}
+impl std::hash::Hash for TrackType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<TrackType> for u64 {
+    fn from(val: TrackType) -> Self { val.value() }
+}
+impl Default for TrackType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TrackTranslateCodec` element
+#[derive(Debug, Clone, Copy)]
+pub enum TrackTranslateCodec {
+    /// Matroska Script
+    MatroskaScript,
+    /// DVD-menu
+    DvdMenu,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for TrackTranslateCodec {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::MatroskaScript,
+            1 => Self::DvdMenu,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TrackTranslateCodec {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::MatroskaScript => 0,
+            Self::DvdMenu => 1,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for TrackTranslateCodec {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for TrackTranslateCodec {}
+impl std::hash::Hash for TrackTranslateCodec {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<TrackTranslateCodec> for u64 {
+    fn from(val: TrackTranslateCodec) -> Self { val.value() }
+}
+impl Default for TrackTranslateCodec {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `FlagInterlaced` element
+#[derive(Debug, Clone, Copy)]
+pub enum FlagInterlaced {
+    /// undetermined
+    Undetermined,
+    /// interlaced
+    Interlaced,
+    /// progressive
+    Progressive,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for FlagInterlaced {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Undetermined,
+            1 => Self::Interlaced,
+            2 => Self::Progressive,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl FlagInterlaced {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Undetermined => 0,
+            Self::Interlaced => 1,
+            Self::Progressive => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for FlagInterlaced {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for FlagInterlaced {}
+impl std::hash::Hash for FlagInterlaced {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<FlagInterlaced> for u64 {
+    fn from(val: FlagInterlaced) -> Self { val.value() }
+}
+impl Default for FlagInterlaced {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `FieldOrder` element
+#[derive(Debug, Clone, Copy)]
+pub enum FieldOrder {
+    /// progressive
+    Progressive,
+    /// tff
+    Tff,
+    /// undetermined
+    Undetermined,
+    /// bff
+    Bff,
+    /// bff(swapped)
+    BffSwapped,
+    /// tff(swapped)
+    TffSwapped,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for FieldOrder {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Progressive,
+            1 => Self::Tff,
+            2 => Self::Undetermined,
+            6 => Self::Bff,
+            9 => Self::BffSwapped,
+            14 => Self::TffSwapped,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl FieldOrder {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Progressive => 0,
+            Self::Tff => 1,
+            Self::Undetermined => 2,
+            Self::Bff => 6,
+            Self::BffSwapped => 9,
+            Self::TffSwapped => 14,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for FieldOrder {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for FieldOrder {}
+impl std::hash::Hash for FieldOrder {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<FieldOrder> for u64 {
+    fn from(val: FieldOrder) -> Self { val.value() }
+}
+impl Default for FieldOrder {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `StereoMode` element
+#[derive(Debug, Clone, Copy)]
+pub enum StereoMode {
+    /// mono
+    Mono,
+    /// side by side (left eye first)
+    SideBySideLeftEyeFirst,
+    /// top - bottom (right eye is first)
+    TopBottomRightEyeIsFirst,
+    /// top - bottom (left eye is first)
+    TopBottomLeftEyeIsFirst,
+    /// checkboard (right eye is first)
+    CheckboardRightEyeIsFirst,
+    /// checkboard (left eye is first)
+    CheckboardLeftEyeIsFirst,
+    /// row interleaved (right eye is first)
+    RowInterleavedRightEyeIsFirst,
+    /// row interleaved (left eye is first)
+    RowInterleavedLeftEyeIsFirst,
+    /// column interleaved (right eye is first)
+    ColumnInterleavedRightEyeIsFirst,
+    /// column interleaved (left eye is first)
+    ColumnInterleavedLeftEyeIsFirst,
+    /// anaglyph (cyan/red)
+    AnaglyphCyanRed,
+    /// side by side (right eye first)
+    SideBySideRightEyeFirst,
+    /// anaglyph (green/magenta)
+    AnaglyphGreenMagenta,
+    /// both eyes laced in one Block (left eye is first)
+    BothEyesLacedInOneBlockLeftEyeIsFirst,
+    /// both eyes laced in one Block (right eye is first)
+    BothEyesLacedInOneBlockRightEyeIsFirst,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for StereoMode {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Mono,
+            1 => Self::SideBySideLeftEyeFirst,
+            2 => Self::TopBottomRightEyeIsFirst,
+            3 => Self::TopBottomLeftEyeIsFirst,
+            4 => Self::CheckboardRightEyeIsFirst,
+            5 => Self::CheckboardLeftEyeIsFirst,
+            6 => Self::RowInterleavedRightEyeIsFirst,
+            7 => Self::RowInterleavedLeftEyeIsFirst,
+            8 => Self::ColumnInterleavedRightEyeIsFirst,
+            9 => Self::ColumnInterleavedLeftEyeIsFirst,
+            10 => Self::AnaglyphCyanRed,
+            11 => Self::SideBySideRightEyeFirst,
+            12 => Self::AnaglyphGreenMagenta,
+            13 => Self::BothEyesLacedInOneBlockLeftEyeIsFirst,
+            14 => Self::BothEyesLacedInOneBlockRightEyeIsFirst,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl StereoMode {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Mono => 0,
+            Self::SideBySideLeftEyeFirst => 1,
+            Self::TopBottomRightEyeIsFirst => 2,
+            Self::TopBottomLeftEyeIsFirst => 3,
+            Self::CheckboardRightEyeIsFirst => 4,
+            Self::CheckboardLeftEyeIsFirst => 5,
+            Self::RowInterleavedRightEyeIsFirst => 6,
+            Self::RowInterleavedLeftEyeIsFirst => 7,
+            Self::ColumnInterleavedRightEyeIsFirst => 8,
+            Self::ColumnInterleavedLeftEyeIsFirst => 9,
+            Self::AnaglyphCyanRed => 10,
+            Self::SideBySideRightEyeFirst => 11,
+            Self::AnaglyphGreenMagenta => 12,
+            Self::BothEyesLacedInOneBlockLeftEyeIsFirst => 13,
+            Self::BothEyesLacedInOneBlockRightEyeIsFirst => 14,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for StereoMode {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for StereoMode {}
+impl std::hash::Hash for StereoMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<StereoMode> for u64 {
+    fn from(val: StereoMode) -> Self { val.value() }
+}
+impl Default for StereoMode {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `AlphaMode` element
+#[derive(Debug, Clone, Copy)]
+pub enum AlphaMode {
+    /// none
+    None,
+    /// present
+    Present,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for AlphaMode {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::None,
+            1 => Self::Present,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl AlphaMode {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::None => 0,
+            Self::Present => 1,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for AlphaMode {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for AlphaMode {}
+impl std::hash::Hash for AlphaMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<AlphaMode> for u64 {
+    fn from(val: AlphaMode) -> Self { val.value() }
+}
+impl Default for AlphaMode {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `OldStereoMode` element
+#[derive(Debug, Clone, Copy)]
+pub enum OldStereoMode {
+    /// mono
+    Mono,
+    /// right eye
+    RightEye,
+    /// left eye
+    LeftEye,
+    /// both eyes
+    BothEyes,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for OldStereoMode {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Mono,
+            1 => Self::RightEye,
+            2 => Self::LeftEye,
+            3 => Self::BothEyes,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl OldStereoMode {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Mono => 0,
+            Self::RightEye => 1,
+            Self::LeftEye => 2,
+            Self::BothEyes => 3,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for OldStereoMode {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for OldStereoMode {}
+impl std::hash::Hash for OldStereoMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<OldStereoMode> for u64 {
+    fn from(val: OldStereoMode) -> Self { val.value() }
+}
+impl Default for OldStereoMode {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `DisplayUnit` element
+#[derive(Debug, Clone, Copy)]
+pub enum DisplayUnit {
+    /// pixels
+    Pixels,
+    /// centimeters
+    Centimeters,
+    /// inches
+    Inches,
+    /// display aspect ratio
+    DisplayAspectRatio,
+    /// unknown
+    Unknown,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for DisplayUnit {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Pixels,
+            1 => Self::Centimeters,
+            2 => Self::Inches,
+            3 => Self::DisplayAspectRatio,
+            4 => Self::Unknown,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl DisplayUnit {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Pixels => 0,
+            Self::Centimeters => 1,
+            Self::Inches => 2,
+            Self::DisplayAspectRatio => 3,
+            Self::Unknown => 4,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for DisplayUnit {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for DisplayUnit {}
+impl std::hash::Hash for DisplayUnit {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<DisplayUnit> for u64 {
+    fn from(val: DisplayUnit) -> Self { val.value() }
+}
+impl Default for DisplayUnit {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `AspectRatioType` element
+#[derive(Debug, Clone, Copy)]
+pub enum AspectRatioType {
+    /// free resizing
+    FreeResizing,
+    /// keep aspect ratio
+    KeepAspectRatio,
+    /// fixed
+    Fixed,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for AspectRatioType {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::FreeResizing,
+            1 => Self::KeepAspectRatio,
+            2 => Self::Fixed,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl AspectRatioType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::FreeResizing => 0,
+            Self::KeepAspectRatio => 1,
+            Self::Fixed => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for AspectRatioType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for AspectRatioType {}
+impl std::hash::Hash for AspectRatioType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<AspectRatioType> for u64 {
+    fn from(val: AspectRatioType) -> Self { val.value() }
+}
+impl Default for AspectRatioType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `MatrixCoefficients` element
+#[derive(Debug, Clone, Copy)]
+pub enum MatrixCoefficients {
+    /// Identity
+    Identity,
+    /// ITU-R BT.709
+    ItuRBt709,
+    /// unspecified
+    Unspecified,
+    /// reserved
+    Reserved,
+    /// US FCC 73.682
+    UsFcc73682,
+    /// ITU-R BT.470BG
+    ItuRBt470Bg,
+    /// SMPTE 170M
+    Smpte170M,
+    /// SMPTE 240M
+    Smpte240M,
+    /// YCoCg
+    YCoCg,
+    /// BT2020 Non-constant Luminance
+    Bt2020NonConstantLuminance,
+    /// BT2020 Constant Luminance
+    Bt2020ConstantLuminance,
+    /// SMPTE ST 2085
+    SmpteSt2085,
+    /// Chroma-derived Non-constant Luminance
+    ChromaDerivedNonConstantLuminance,
+    /// Chroma-derived Constant Luminance
+    ChromaDerivedConstantLuminance,
+    /// ITU-R BT.2100-0
+    ItuRBt21000,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for MatrixCoefficients {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Identity,
+            1 => Self::ItuRBt709,
+            2 => Self::Unspecified,
+            3 => Self::Reserved,
+            4 => Self::UsFcc73682,
+            5 => Self::ItuRBt470Bg,
+            6 => Self::Smpte170M,
+            7 => Self::Smpte240M,
+            8 => Self::YCoCg,
+            9 => Self::Bt2020NonConstantLuminance,
+            10 => Self::Bt2020ConstantLuminance,
+            11 => Self::SmpteSt2085,
+            12 => Self::ChromaDerivedNonConstantLuminance,
+            13 => Self::ChromaDerivedConstantLuminance,
+            14 => Self::ItuRBt21000,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl MatrixCoefficients {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Identity => 0,
+            Self::ItuRBt709 => 1,
+            Self::Unspecified => 2,
+            Self::Reserved => 3,
+            Self::UsFcc73682 => 4,
+            Self::ItuRBt470Bg => 5,
+            Self::Smpte170M => 6,
+            Self::Smpte240M => 7,
+            Self::YCoCg => 8,
+            Self::Bt2020NonConstantLuminance => 9,
+            Self::Bt2020ConstantLuminance => 10,
+            Self::SmpteSt2085 => 11,
+            Self::ChromaDerivedNonConstantLuminance => 12,
+            Self::ChromaDerivedConstantLuminance => 13,
+            Self::ItuRBt21000 => 14,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for MatrixCoefficients {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for MatrixCoefficients {}
+impl std::hash::Hash for MatrixCoefficients {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<MatrixCoefficients> for u64 {
+    fn from(val: MatrixCoefficients) -> Self { val.value() }
+}
+impl Default for MatrixCoefficients {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ChromaSitingHorz` element
+#[derive(Debug, Clone, Copy)]
+pub enum ChromaSitingHorz {
+    /// unspecified
+    Unspecified,
+    /// left collocated
+    LeftCollocated,
+    /// half
+    Half,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ChromaSitingHorz {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Unspecified,
+            1 => Self::LeftCollocated,
+            2 => Self::Half,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ChromaSitingHorz {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Unspecified => 0,
+            Self::LeftCollocated => 1,
+            Self::Half => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ChromaSitingHorz {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ChromaSitingHorz {}
+impl std::hash::Hash for ChromaSitingHorz {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ChromaSitingHorz> for u64 {
+    fn from(val: ChromaSitingHorz) -> Self { val.value() }
+}
+impl Default for ChromaSitingHorz {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ChromaSitingVert` element
+#[derive(Debug, Clone, Copy)]
+pub enum ChromaSitingVert {
+    /// unspecified
+    Unspecified,
+    /// top collocated
+    TopCollocated,
+    /// half
+    Half,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ChromaSitingVert {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Unspecified,
+            1 => Self::TopCollocated,
+            2 => Self::Half,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ChromaSitingVert {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Unspecified => 0,
+            Self::TopCollocated => 1,
+            Self::Half => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ChromaSitingVert {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ChromaSitingVert {}
+impl std::hash::Hash for ChromaSitingVert {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ChromaSitingVert> for u64 {
+    fn from(val: ChromaSitingVert) -> Self { val.value() }
+}
+impl Default for ChromaSitingVert {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `Range` element
+#[derive(Debug, Clone, Copy)]
+pub enum Range {
+    /// unspecified
+    Unspecified,
+    /// broadcast range
+    BroadcastRange,
+    /// full range (no clipping)
+    FullRangeNoClipping,
+    /// defined by MatrixCoefficients / TransferCharacteristics
+    DefinedByMatrixCoefficientsTransferCharacteristics,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for Range {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Unspecified,
+            1 => Self::BroadcastRange,
+            2 => Self::FullRangeNoClipping,
+            3 => Self::DefinedByMatrixCoefficientsTransferCharacteristics,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl Range {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Unspecified => 0,
+            Self::BroadcastRange => 1,
+            Self::FullRangeNoClipping => 2,
+            Self::DefinedByMatrixCoefficientsTransferCharacteristics => 3,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for Range {}
+impl std::hash::Hash for Range {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<Range> for u64 {
+    fn from(val: Range) -> Self { val.value() }
+}
+impl Default for Range {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TransferCharacteristics` element
+#[derive(Debug, Clone, Copy)]
+pub enum TransferCharacteristics {
+    /// reserved
+    Reserved,
+    /// ITU-R BT.709
+    ItuRBt709,
+    /// unspecified
+    Unspecified,
+    /// reserved2
+    Reserved2,
+    /// Gamma 2.2 curve - BT.470M
+    Gamma22CurveBt470M,
+    /// Gamma 2.8 curve - BT.470BG
+    Gamma28CurveBt470Bg,
+    /// SMPTE 170M
+    Smpte170M,
+    /// SMPTE 240M
+    Smpte240M,
+    /// Linear
+    Linear,
+    /// Log
+    Log,
+    /// Log Sqrt
+    LogSqrt,
+    /// IEC 61966-2-4
+    Iec6196624,
+    /// ITU-R BT.1361 Extended Colour Gamut
+    ItuRBt1361ExtendedColourGamut,
+    /// IEC 61966-2-1
+    Iec6196621,
+    /// ITU-R BT.2020 10 bit
+    ItuRBt202010Bit,
+    /// ITU-R BT.2020 12 bit
+    ItuRBt202012Bit,
+    /// ITU-R BT.2100 Perceptual Quantization
+    ItuRBt2100PerceptualQuantization,
+    /// SMPTE ST 428-1
+    SmpteSt4281,
+    /// ARIB STD-B67 (HLG)
+    AribStdB67Hlg,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for TransferCharacteristics {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Reserved,
+            1 => Self::ItuRBt709,
+            2 => Self::Unspecified,
+            3 => Self::Reserved2,
+            4 => Self::Gamma22CurveBt470M,
+            5 => Self::Gamma28CurveBt470Bg,
+            6 => Self::Smpte170M,
+            7 => Self::Smpte240M,
+            8 => Self::Linear,
+            9 => Self::Log,
+            10 => Self::LogSqrt,
+            11 => Self::Iec6196624,
+            12 => Self::ItuRBt1361ExtendedColourGamut,
+            13 => Self::Iec6196621,
+            14 => Self::ItuRBt202010Bit,
+            15 => Self::ItuRBt202012Bit,
+            16 => Self::ItuRBt2100PerceptualQuantization,
+            17 => Self::SmpteSt4281,
+            18 => Self::AribStdB67Hlg,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TransferCharacteristics {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Reserved => 0,
+            Self::ItuRBt709 => 1,
+            Self::Unspecified => 2,
+            Self::Reserved2 => 3,
+            Self::Gamma22CurveBt470M => 4,
+            Self::Gamma28CurveBt470Bg => 5,
+            Self::Smpte170M => 6,
+            Self::Smpte240M => 7,
+            Self::Linear => 8,
+            Self::Log => 9,
+            Self::LogSqrt => 10,
+            Self::Iec6196624 => 11,
+            Self::ItuRBt1361ExtendedColourGamut => 12,
+            Self::Iec6196621 => 13,
+            Self::ItuRBt202010Bit => 14,
+            Self::ItuRBt202012Bit => 15,
+            Self::ItuRBt2100PerceptualQuantization => 16,
+            Self::SmpteSt4281 => 17,
+            Self::AribStdB67Hlg => 18,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for TransferCharacteristics {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for TransferCharacteristics {}
+impl std::hash::Hash for TransferCharacteristics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<TransferCharacteristics> for u64 {
+    fn from(val: TransferCharacteristics) -> Self { val.value() }
+}
+impl Default for TransferCharacteristics {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `Primaries` element
+#[derive(Debug, Clone, Copy)]
+pub enum Primaries {
+    /// reserved
+    Reserved,
+    /// ITU-R BT.709
+    ItuRBt709,
+    /// unspecified
+    Unspecified,
+    /// reserved2
+    Reserved2,
+    /// ITU-R BT.470M
+    ItuRBt470M,
+    /// ITU-R BT.470BG - BT.601 625
+    ItuRBt470BgBt601625,
+    /// ITU-R BT.601 525 - SMPTE 170M
+    ItuRBt601525Smpte170M,
+    /// SMPTE 240M
+    Smpte240M,
+    /// FILM
+    Film,
+    /// ITU-R BT.2020
+    ItuRBt2020,
+    /// SMPTE ST 428-1
+    SmpteSt4281,
+    /// SMPTE RP 432-2
+    SmpteRp4322,
+    /// SMPTE EG 432-2
+    SmpteEg4322,
+    /// EBU Tech. 3213-E - JEDEC P22 phosphors
+    EbuTech3213EJedecP22Phosphors,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for Primaries {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Reserved,
+            1 => Self::ItuRBt709,
+            2 => Self::Unspecified,
+            3 => Self::Reserved2,
+            4 => Self::ItuRBt470M,
+            5 => Self::ItuRBt470BgBt601625,
+            6 => Self::ItuRBt601525Smpte170M,
+            7 => Self::Smpte240M,
+            8 => Self::Film,
+            9 => Self::ItuRBt2020,
+            10 => Self::SmpteSt4281,
+            11 => Self::SmpteRp4322,
+            12 => Self::SmpteEg4322,
+            22 => Self::EbuTech3213EJedecP22Phosphors,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl Primaries {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Reserved => 0,
+            Self::ItuRBt709 => 1,
+            Self::Unspecified => 2,
+            Self::Reserved2 => 3,
+            Self::ItuRBt470M => 4,
+            Self::ItuRBt470BgBt601625 => 5,
+            Self::ItuRBt601525Smpte170M => 6,
+            Self::Smpte240M => 7,
+            Self::Film => 8,
+            Self::ItuRBt2020 => 9,
+            Self::SmpteSt4281 => 10,
+            Self::SmpteRp4322 => 11,
+            Self::SmpteEg4322 => 12,
+            Self::EbuTech3213EJedecP22Phosphors => 22,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for Primaries {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for Primaries {}
+impl std::hash::Hash for Primaries {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<Primaries> for u64 {
+    fn from(val: Primaries) -> Self { val.value() }
+}
+impl Default for Primaries {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ProjectionType` element
+#[derive(Debug, Clone, Copy)]
+pub enum ProjectionType {
+    /// rectangular
+    Rectangular,
+    /// equirectangular
+    Equirectangular,
+    /// cubemap
+    Cubemap,
+    /// mesh
+    Mesh,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ProjectionType {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Rectangular,
+            1 => Self::Equirectangular,
+            2 => Self::Cubemap,
+            3 => Self::Mesh,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ProjectionType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Rectangular => 0,
+            Self::Equirectangular => 1,
+            Self::Cubemap => 2,
+            Self::Mesh => 3,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ProjectionType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ProjectionType {}
+impl std::hash::Hash for ProjectionType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ProjectionType> for u64 {
+    fn from(val: ProjectionType) -> Self { val.value() }
+}
+impl Default for ProjectionType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `Emphasis` element
+#[derive(Debug, Clone, Copy)]
+pub enum Emphasis {
+    /// No emphasis
+    NoEmphasis,
+    /// CD audio
+    CdAudio,
+    /// reserved
+    Reserved,
+    /// CCIT J.17
+    CcitJ17,
+    /// FM 50
+    Fm50,
+    /// FM 75
+    Fm75,
+    /// Phono RIAA
+    PhonoRiaa,
+    /// Phono IEC N78
+    PhonoIecN78,
+    /// Phono TELDEC
+    PhonoTeldec,
+    /// Phono EMI
+    PhonoEmi,
+    /// Phono Columbia LP
+    PhonoColumbiaLp,
+    /// Phono LONDON
+    PhonoLondon,
+    /// Phono NARTB
+    PhonoNartb,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for Emphasis {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::NoEmphasis,
+            1 => Self::CdAudio,
+            2 => Self::Reserved,
+            3 => Self::CcitJ17,
+            4 => Self::Fm50,
+            5 => Self::Fm75,
+            10 => Self::PhonoRiaa,
+            11 => Self::PhonoIecN78,
+            12 => Self::PhonoTeldec,
+            13 => Self::PhonoEmi,
+            14 => Self::PhonoColumbiaLp,
+            15 => Self::PhonoLondon,
+            16 => Self::PhonoNartb,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl Emphasis {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::NoEmphasis => 0,
+            Self::CdAudio => 1,
+            Self::Reserved => 2,
+            Self::CcitJ17 => 3,
+            Self::Fm50 => 4,
+            Self::Fm75 => 5,
+            Self::PhonoRiaa => 10,
+            Self::PhonoIecN78 => 11,
+            Self::PhonoTeldec => 12,
+            Self::PhonoEmi => 13,
+            Self::PhonoColumbiaLp => 14,
+            Self::PhonoLondon => 15,
+            Self::PhonoNartb => 16,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for Emphasis {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for Emphasis {}
+impl std::hash::Hash for Emphasis {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<Emphasis> for u64 {
+    fn from(val: Emphasis) -> Self { val.value() }
+}
+impl Default for Emphasis {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TrackPlaneType` element
+#[derive(Debug, Clone, Copy)]
+pub enum TrackPlaneType {
+    /// left eye
+    LeftEye,
+    /// right eye
+    RightEye,
+    /// background
+    Background,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for TrackPlaneType {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::LeftEye,
+            1 => Self::RightEye,
+            2 => Self::Background,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TrackPlaneType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::LeftEye => 0,
+            Self::RightEye => 1,
+            Self::Background => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for TrackPlaneType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for TrackPlaneType {}
+impl std::hash::Hash for TrackPlaneType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<TrackPlaneType> for u64 {
+    fn from(val: TrackPlaneType) -> Self { val.value() }
+}
+impl Default for TrackPlaneType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentEncodingScope` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentEncodingScope {
+    /// Block
+    Block,
+    /// Private
+    Private,
+    /// Next
+    Next,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentEncodingScope {
+    fn from(val: u64) -> Self {
+        match val {
+            1 => Self::Block,
+            2 => Self::Private,
+            4 => Self::Next,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentEncodingScope {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Block => 1,
+            Self::Private => 2,
+            Self::Next => 4,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentEncodingScope {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentEncodingScope {}
+impl std::hash::Hash for ContentEncodingScope {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentEncodingScope> for u64 {
+    fn from(val: ContentEncodingScope) -> Self { val.value() }
+}
+impl Default for ContentEncodingScope {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentEncodingType` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentEncodingType {
+    /// Compression
+    Compression,
+    /// Encryption
+    Encryption,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentEncodingType {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Compression,
+            1 => Self::Encryption,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentEncodingType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Compression => 0,
+            Self::Encryption => 1,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentEncodingType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentEncodingType {}
+impl std::hash::Hash for ContentEncodingType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentEncodingType> for u64 {
+    fn from(val: ContentEncodingType) -> Self { val.value() }
+}
+impl Default for ContentEncodingType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentCompAlgo` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentCompAlgo {
+    /// zlib
+    Zlib,
+    /// bzlib
+    Bzlib,
+    /// lzo1x
+    Lzo1X,
+    /// Header Stripping
+    HeaderStripping,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentCompAlgo {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::Zlib,
+            1 => Self::Bzlib,
+            2 => Self::Lzo1X,
+            3 => Self::HeaderStripping,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentCompAlgo {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Zlib => 0,
+            Self::Bzlib => 1,
+            Self::Lzo1X => 2,
+            Self::HeaderStripping => 3,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentCompAlgo {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentCompAlgo {}
+impl std::hash::Hash for ContentCompAlgo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentCompAlgo> for u64 {
+    fn from(val: ContentCompAlgo) -> Self { val.value() }
+}
+impl Default for ContentCompAlgo {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentEncAlgo` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentEncAlgo {
+    /// Not encrypted
+    NotEncrypted,
+    /// DES
+    Des,
+    /// 3DES
+    V3Des,
+    /// Twofish
+    Twofish,
+    /// Blowfish
+    Blowfish,
+    /// AES
+    Aes,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentEncAlgo {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::NotEncrypted,
+            1 => Self::Des,
+            2 => Self::V3Des,
+            3 => Self::Twofish,
+            4 => Self::Blowfish,
+            5 => Self::Aes,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentEncAlgo {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::NotEncrypted => 0,
+            Self::Des => 1,
+            Self::V3Des => 2,
+            Self::Twofish => 3,
+            Self::Blowfish => 4,
+            Self::Aes => 5,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentEncAlgo {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentEncAlgo {}
+impl std::hash::Hash for ContentEncAlgo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentEncAlgo> for u64 {
+    fn from(val: ContentEncAlgo) -> Self { val.value() }
+}
+impl Default for ContentEncAlgo {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `AesSettingsCipherMode` element
+#[derive(Debug, Clone, Copy)]
+pub enum AesSettingsCipherMode {
+    /// AES-CTR
+    AesCtr,
+    /// AES-CBC
+    AesCbc,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for AesSettingsCipherMode {
+    fn from(val: u64) -> Self {
+        match val {
+            1 => Self::AesCtr,
+            2 => Self::AesCbc,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl AesSettingsCipherMode {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::AesCtr => 1,
+            Self::AesCbc => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for AesSettingsCipherMode {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for AesSettingsCipherMode {}
+impl std::hash::Hash for AesSettingsCipherMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<AesSettingsCipherMode> for u64 {
+    fn from(val: AesSettingsCipherMode) -> Self { val.value() }
+}
+impl Default for AesSettingsCipherMode {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentSigAlgo` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentSigAlgo {
+    /// Not signed
+    NotSigned,
+    /// RSA
+    Rsa,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentSigAlgo {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::NotSigned,
+            1 => Self::Rsa,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentSigAlgo {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::NotSigned => 0,
+            Self::Rsa => 1,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentSigAlgo {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentSigAlgo {}
+impl std::hash::Hash for ContentSigAlgo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentSigAlgo> for u64 {
+    fn from(val: ContentSigAlgo) -> Self { val.value() }
+}
+impl Default for ContentSigAlgo {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ContentSigHashAlgo` element
+#[derive(Debug, Clone, Copy)]
+pub enum ContentSigHashAlgo {
+    /// Not signed
+    NotSigned,
+    /// SHA1-160
+    Sha1160,
+    /// MD5
+    Md5,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ContentSigHashAlgo {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::NotSigned,
+            1 => Self::Sha1160,
+            2 => Self::Md5,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ContentSigHashAlgo {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::NotSigned => 0,
+            Self::Sha1160 => 1,
+            Self::Md5 => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ContentSigHashAlgo {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ContentSigHashAlgo {}
+impl std::hash::Hash for ContentSigHashAlgo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ContentSigHashAlgo> for u64 {
+    fn from(val: ContentSigHashAlgo) -> Self { val.value() }
+}
+impl Default for ContentSigHashAlgo {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ChapterSkipType` element
+#[derive(Debug, Clone, Copy)]
+pub enum ChapterSkipType {
+    /// No Skipping
+    NoSkipping,
+    /// Opening Credits
+    OpeningCredits,
+    /// End Credits
+    EndCredits,
+    /// Recap
+    Recap,
+    /// Next Preview
+    NextPreview,
+    /// Preview
+    Preview,
+    /// Advertisement
+    Advertisement,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ChapterSkipType {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::NoSkipping,
+            1 => Self::OpeningCredits,
+            2 => Self::EndCredits,
+            3 => Self::Recap,
+            4 => Self::NextPreview,
+            5 => Self::Preview,
+            6 => Self::Advertisement,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ChapterSkipType {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::NoSkipping => 0,
+            Self::OpeningCredits => 1,
+            Self::EndCredits => 2,
+            Self::Recap => 3,
+            Self::NextPreview => 4,
+            Self::Preview => 5,
+            Self::Advertisement => 6,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ChapterSkipType {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ChapterSkipType {}
+impl std::hash::Hash for ChapterSkipType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ChapterSkipType> for u64 {
+    fn from(val: ChapterSkipType) -> Self { val.value() }
+}
+impl Default for ChapterSkipType {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `ChapProcessTime` element
+#[derive(Debug, Clone, Copy)]
+pub enum ChapProcessTime {
+    /// during the whole chapter
+    DuringTheWholeChapter,
+    /// before starting playback
+    BeforeStartingPlayback,
+    /// after playback of the chapter
+    AfterPlaybackOfTheChapter,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for ChapProcessTime {
+    fn from(val: u64) -> Self {
+        match val {
+            0 => Self::DuringTheWholeChapter,
+            1 => Self::BeforeStartingPlayback,
+            2 => Self::AfterPlaybackOfTheChapter,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl ChapProcessTime {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::DuringTheWholeChapter => 0,
+            Self::BeforeStartingPlayback => 1,
+            Self::AfterPlaybackOfTheChapter => 2,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for ChapProcessTime {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for ChapProcessTime {}
+impl std::hash::Hash for ChapProcessTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<ChapProcessTime> for u64 {
+    fn from(val: ChapProcessTime) -> Self { val.value() }
+}
+impl Default for ChapProcessTime {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TargetTypeValue` element
+#[derive(Debug, Clone, Copy)]
+pub enum TargetTypeValue {
+    /// COLLECTION
+    Collection,
+    /// EDITION / ISSUE / VOLUME / OPUS / SEASON / SEQUEL
+    EditionIssueVolumeOpusSeasonSequel,
+    /// ALBUM / OPERA / CONCERT / MOVIE / EPISODE
+    AlbumOperaConcertMovieEpisode,
+    /// PART / SESSION
+    PartSession,
+    /// TRACK / SONG / CHAPTER
+    TrackSongChapter,
+    /// SUBTRACK / MOVEMENT / SCENE
+    SubtrackMovementScene,
+    /// SHOT
+    Shot,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(u64),
+}
+impl From<u64> for TargetTypeValue {
+    fn from(val: u64) -> Self {
+        match val {
+            70 => Self::Collection,
+            60 => Self::EditionIssueVolumeOpusSeasonSequel,
+            50 => Self::AlbumOperaConcertMovieEpisode,
+            40 => Self::PartSession,
+            30 => Self::TrackSongChapter,
+            20 => Self::SubtrackMovementScene,
+            10 => Self::Shot,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TargetTypeValue {
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Collection => 70,
+            Self::EditionIssueVolumeOpusSeasonSequel => 60,
+            Self::AlbumOperaConcertMovieEpisode => 50,
+            Self::PartSession => 40,
+            Self::TrackSongChapter => 30,
+            Self::SubtrackMovementScene => 20,
+            Self::Shot => 10,
+            Self::Other(val) => *val,
+        }
+    }
+}
+impl PartialEq for TargetTypeValue {
+    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+}
+impl Eq for TargetTypeValue {}
+impl std::hash::Hash for TargetTypeValue {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.value().hash(state) }
+}
+impl From<TargetTypeValue> for u64 {
+    fn from(val: TargetTypeValue) -> Self { val.value() }
+}
+impl Default for TargetTypeValue {
+    fn default() -> Self { Self::from(u64::default()) }
+}
+
+/// Values of the `TargetType` element
+#[derive(Debug, Clone)]
+pub enum TargetType {
+    /// TargetTypeValue 70
+    Collection,
+    /// TargetTypeValue 60
+    Edition,
+    /// TargetTypeValue 60
+    Issue,
+    /// TargetTypeValue 60
+    Volume,
+    /// TargetTypeValue 60
+    Opus,
+    /// TargetTypeValue 60
+    Season,
+    /// TargetTypeValue 60
+    Sequel,
+    /// TargetTypeValue 50
+    Album,
+    /// TargetTypeValue 50
+    Opera,
+    /// TargetTypeValue 50
+    Concert,
+    /// TargetTypeValue 50
+    Movie,
+    /// TargetTypeValue 50
+    Episode,
+    /// TargetTypeValue 40
+    Part,
+    /// TargetTypeValue 40
+    Session,
+    /// TargetTypeValue 30
+    Track,
+    /// TargetTypeValue 30
+    Song,
+    /// TargetTypeValue 30
+    Chapter,
+    /// TargetTypeValue 20
+    Subtrack,
+    /// TargetTypeValue 20
+    Movement,
+    /// TargetTypeValue 20
+    Scene,
+    /// TargetTypeValue 10
+    Shot,
+    /// Value not listed in the specification, `From` never builds it with a listed one
+    Other(String),
+}
+impl From<String> for TargetType {
+    fn from(val: String) -> Self {
+        match val.as_str() {
+            "COLLECTION" => Self::Collection,
+            "EDITION" => Self::Edition,
+            "ISSUE" => Self::Issue,
+            "VOLUME" => Self::Volume,
+            "OPUS" => Self::Opus,
+            "SEASON" => Self::Season,
+            "SEQUEL" => Self::Sequel,
+            "ALBUM" => Self::Album,
+            "OPERA" => Self::Opera,
+            "CONCERT" => Self::Concert,
+            "MOVIE" => Self::Movie,
+            "EPISODE" => Self::Episode,
+            "PART" => Self::Part,
+            "SESSION" => Self::Session,
+            "TRACK" => Self::Track,
+            "SONG" => Self::Song,
+            "CHAPTER" => Self::Chapter,
+            "SUBTRACK" => Self::Subtrack,
+            "MOVEMENT" => Self::Movement,
+            "SCENE" => Self::Scene,
+            "SHOT" => Self::Shot,
+            _ => Self::Other(val),
+        }
+    }
+}
+impl TargetType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Collection => "COLLECTION",
+            Self::Edition => "EDITION",
+            Self::Issue => "ISSUE",
+            Self::Volume => "VOLUME",
+            Self::Opus => "OPUS",
+            Self::Season => "SEASON",
+            Self::Sequel => "SEQUEL",
+            Self::Album => "ALBUM",
+            Self::Opera => "OPERA",
+            Self::Concert => "CONCERT",
+            Self::Movie => "MOVIE",
+            Self::Episode => "EPISODE",
+            Self::Part => "PART",
+            Self::Session => "SESSION",
+            Self::Track => "TRACK",
+            Self::Song => "SONG",
+            Self::Chapter => "CHAPTER",
+            Self::Subtrack => "SUBTRACK",
+            Self::Movement => "MOVEMENT",
+            Self::Scene => "SCENE",
+            Self::Shot => "SHOT",
+            Self::Other(val) => val.as_str(),
+        }
+    }
+}
+impl PartialEq for TargetType {
+    fn eq(&self, other: &Self) -> bool { self.as_str() == other.as_str() }
+}
+impl Eq for TargetType {}
+impl std::hash::Hash for TargetType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.as_str().hash(state) }
+}
+impl From<TargetType> for String {
+    fn from(val: TargetType) -> Self { val.as_str().to_string() }
+}
+impl Default for TargetType {
+    fn default() -> Self { Self::from(String::default()) }
+}
+
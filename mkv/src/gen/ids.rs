@@ -1110,5 +1110,282 @@ impl EbmlId {
 
         }
     }
+    pub fn parent(&self) -> Option<EbmlId> {
+        match self {
+            Self::EbmlHeader => None,
+            Self::Version => Some(Self::EbmlHeader),
+            Self::ReadVersion => Some(Self::EbmlHeader),
+            Self::DocType => Some(Self::EbmlHeader),
+            Self::DocTypeVersion => Some(Self::EbmlHeader),
+            Self::DocTypeReadVersion => Some(Self::EbmlHeader),
+            Self::DocTypeExtension => Some(Self::EbmlHeader),
+            Self::DocTypeExtensionName => Some(Self::EbmlHeader),
+            Self::DocTypeExtensionVersion => Some(Self::EbmlHeader),
+            Self::Void => Some(Self::Segment),
+            Self::Crc32 => Some(Self::Segment),
+            Self::EbmlMaxIdLength => Some(Self::EbmlHeader),
+            Self::EbmlMaxSizeLength => Some(Self::EbmlHeader),
+            Self::Segment => None,
+            Self::SeekHead => Some(Self::Segment),
+            Self::Seek => Some(Self::SeekHead),
+            Self::SeekId => Some(Self::Seek),
+            Self::SeekPosition => Some(Self::Seek),
+            Self::Info => Some(Self::Segment),
+            Self::SegmentUuid => Some(Self::Info),
+            Self::SegmentFilename => Some(Self::Info),
+            Self::PrevUuid => Some(Self::Info),
+            Self::PrevFilename => Some(Self::Info),
+            Self::NextUuid => Some(Self::Info),
+            Self::NextFilename => Some(Self::Info),
+            Self::SegmentFamily => Some(Self::Info),
+            Self::ChapterTranslate => Some(Self::Info),
+            Self::ChapterTranslateId => Some(Self::ChapterTranslate),
+            Self::ChapterTranslateCodec => Some(Self::ChapterTranslate),
+            Self::ChapterTranslateEditionUid => Some(Self::ChapterTranslate),
+            Self::TimestampScale => Some(Self::Info),
+            Self::Duration => Some(Self::Info),
+            Self::DateUtc => Some(Self::Info),
+            Self::Title => Some(Self::Info),
+            Self::MuxingApp => Some(Self::Info),
+            Self::WritingApp => Some(Self::Info),
+            Self::Cluster => Some(Self::Segment),
+            Self::Timestamp => Some(Self::Cluster),
+            Self::SilentTracks => Some(Self::Cluster),
+            Self::SilentTrackNumber => Some(Self::SilentTracks),
+            Self::Position => Some(Self::Cluster),
+            Self::PrevSize => Some(Self::Cluster),
+            Self::SimpleBlock => Some(Self::Cluster),
+            Self::BlockGroup => Some(Self::Cluster),
+            Self::Block => Some(Self::BlockGroup),
+            Self::BlockVirtual => Some(Self::BlockGroup),
+            Self::BlockAdditions => Some(Self::BlockGroup),
+            Self::BlockMore => Some(Self::BlockAdditions),
+            Self::BlockAdditional => Some(Self::BlockMore),
+            Self::BlockAddId => Some(Self::BlockMore),
+            Self::BlockDuration => Some(Self::BlockGroup),
+            Self::ReferencePriority => Some(Self::BlockGroup),
+            Self::ReferenceBlock => Some(Self::BlockGroup),
+            Self::ReferenceVirtual => Some(Self::BlockGroup),
+            Self::CodecState => Some(Self::BlockGroup),
+            Self::DiscardPadding => Some(Self::BlockGroup),
+            Self::Slices => Some(Self::BlockGroup),
+            Self::TimeSlice => Some(Self::Slices),
+            Self::LaceNumber => Some(Self::TimeSlice),
+            Self::FrameNumber => Some(Self::TimeSlice),
+            Self::BlockAdditionId => Some(Self::TimeSlice),
+            Self::Delay => Some(Self::TimeSlice),
+            Self::SliceDuration => Some(Self::TimeSlice),
+            Self::ReferenceFrame => Some(Self::BlockGroup),
+            Self::ReferenceOffset => Some(Self::ReferenceFrame),
+            Self::ReferenceTimestamp => Some(Self::ReferenceFrame),
+            Self::EncryptedBlock => Some(Self::Cluster),
+            Self::Tracks => Some(Self::Segment),
+            Self::TrackEntry => Some(Self::Tracks),
+            Self::TrackNumber => Some(Self::TrackEntry),
+            Self::TrackUid => Some(Self::TrackEntry),
+            Self::TrackType => Some(Self::TrackEntry),
+            Self::FlagEnabled => Some(Self::TrackEntry),
+            Self::FlagDefault => Some(Self::TrackEntry),
+            Self::FlagForced => Some(Self::TrackEntry),
+            Self::FlagHearingImpaired => Some(Self::TrackEntry),
+            Self::FlagVisualImpaired => Some(Self::TrackEntry),
+            Self::FlagTextDescriptions => Some(Self::TrackEntry),
+            Self::FlagOriginal => Some(Self::TrackEntry),
+            Self::FlagCommentary => Some(Self::TrackEntry),
+            Self::FlagLacing => Some(Self::TrackEntry),
+            Self::MinCache => Some(Self::TrackEntry),
+            Self::MaxCache => Some(Self::TrackEntry),
+            Self::DefaultDuration => Some(Self::TrackEntry),
+            Self::DefaultDecodedFieldDuration => Some(Self::TrackEntry),
+            Self::TrackTimestampScale => Some(Self::TrackEntry),
+            Self::TrackOffset => Some(Self::TrackEntry),
+            Self::MaxBlockAdditionId => Some(Self::TrackEntry),
+            Self::BlockAdditionMapping => Some(Self::TrackEntry),
+            Self::BlockAddIdValue => Some(Self::BlockAdditionMapping),
+            Self::BlockAddIdName => Some(Self::BlockAdditionMapping),
+            Self::BlockAddIdType => Some(Self::BlockAdditionMapping),
+            Self::BlockAddIdExtraData => Some(Self::BlockAdditionMapping),
+            Self::Name => Some(Self::TrackEntry),
+            Self::Language => Some(Self::TrackEntry),
+            Self::LanguageBcp47 => Some(Self::TrackEntry),
+            Self::CodecId => Some(Self::TrackEntry),
+            Self::CodecPrivate => Some(Self::TrackEntry),
+            Self::CodecName => Some(Self::TrackEntry),
+            Self::AttachmentLink => Some(Self::TrackEntry),
+            Self::CodecSettings => Some(Self::TrackEntry),
+            Self::CodecInfoUrl => Some(Self::TrackEntry),
+            Self::CodecDownloadUrl => Some(Self::TrackEntry),
+            Self::CodecDecodeAll => Some(Self::TrackEntry),
+            Self::TrackOverlay => Some(Self::TrackEntry),
+            Self::CodecDelay => Some(Self::TrackEntry),
+            Self::SeekPreRoll => Some(Self::TrackEntry),
+            Self::TrackTranslate => Some(Self::TrackEntry),
+            Self::TrackTranslateTrackId => Some(Self::TrackTranslate),
+            Self::TrackTranslateCodec => Some(Self::TrackTranslate),
+            Self::TrackTranslateEditionUid => Some(Self::TrackTranslate),
+            Self::Video => Some(Self::TrackEntry),
+            Self::FlagInterlaced => Some(Self::Video),
+            Self::FieldOrder => Some(Self::Video),
+            Self::StereoMode => Some(Self::Video),
+            Self::AlphaMode => Some(Self::Video),
+            Self::OldStereoMode => Some(Self::Video),
+            Self::PixelWidth => Some(Self::Video),
+            Self::PixelHeight => Some(Self::Video),
+            Self::PixelCropBottom => Some(Self::Video),
+            Self::PixelCropTop => Some(Self::Video),
+            Self::PixelCropLeft => Some(Self::Video),
+            Self::PixelCropRight => Some(Self::Video),
+            Self::DisplayWidth => Some(Self::Video),
+            Self::DisplayHeight => Some(Self::Video),
+            Self::DisplayUnit => Some(Self::Video),
+            Self::AspectRatioType => Some(Self::Video),
+            Self::UncompressedFourCc => Some(Self::Video),
+            Self::GammaValue => Some(Self::Video),
+            Self::FrameRate => Some(Self::Video),
+            Self::Colour => Some(Self::Video),
+            Self::MatrixCoefficients => Some(Self::Colour),
+            Self::BitsPerChannel => Some(Self::Colour),
+            Self::ChromaSubsamplingHorz => Some(Self::Colour),
+            Self::ChromaSubsamplingVert => Some(Self::Colour),
+            Self::CbSubsamplingHorz => Some(Self::Colour),
+            Self::CbSubsamplingVert => Some(Self::Colour),
+            Self::ChromaSitingHorz => Some(Self::Colour),
+            Self::ChromaSitingVert => Some(Self::Colour),
+            Self::Range => Some(Self::Colour),
+            Self::TransferCharacteristics => Some(Self::Colour),
+            Self::Primaries => Some(Self::Colour),
+            Self::MaxCll => Some(Self::Colour),
+            Self::MaxFall => Some(Self::Colour),
+            Self::StructingMetadata => Some(Self::Colour),
+            Self::PrimaryRChromaticityX => Some(Self::StructingMetadata),
+            Self::PrimaryRChromaticityY => Some(Self::StructingMetadata),
+            Self::PrimaryGChromaticityX => Some(Self::StructingMetadata),
+            Self::PrimaryGChromaticityY => Some(Self::StructingMetadata),
+            Self::PrimaryBChromaticityX => Some(Self::StructingMetadata),
+            Self::PrimaryBChromaticityY => Some(Self::StructingMetadata),
+            Self::WhitePointChromaticityX => Some(Self::StructingMetadata),
+            Self::WhitePointChromaticityY => Some(Self::StructingMetadata),
+            Self::LuminanceMax => Some(Self::StructingMetadata),
+            Self::LuminanceMin => Some(Self::StructingMetadata),
+            Self::Projection => Some(Self::Video),
+            Self::ProjectionType => Some(Self::Projection),
+            Self::ProjectionPrivate => Some(Self::Projection),
+            Self::ProjectionPoseYaw => Some(Self::Projection),
+            Self::ProjectionPosePitch => Some(Self::Projection),
+            Self::ProjectionPoseRoll => Some(Self::Projection),
+            Self::Audio => Some(Self::TrackEntry),
+            Self::SamplingFrequency => Some(Self::Audio),
+            Self::OutputSamplingFrequency => Some(Self::Audio),
+            Self::Channels => Some(Self::Audio),
+            Self::ChannelPositions => Some(Self::Audio),
+            Self::BitDepth => Some(Self::Audio),
+            Self::Emphasis => Some(Self::Audio),
+            Self::TrackOperation => Some(Self::TrackEntry),
+            Self::TrackCombinePlanes => Some(Self::TrackOperation),
+            Self::TrackPlane => Some(Self::TrackCombinePlanes),
+            Self::TrackPlaneUid => Some(Self::TrackPlane),
+            Self::TrackPlaneType => Some(Self::TrackPlane),
+            Self::TrackJoinBlocks => Some(Self::TrackOperation),
+            Self::TrackJoinUid => Some(Self::TrackJoinBlocks),
+            Self::TrickTrackUid => Some(Self::TrackEntry),
+            Self::TrickTrackSegmentUid => Some(Self::TrackEntry),
+            Self::TrickTrackFlag => Some(Self::TrackEntry),
+            Self::TrickStructTrackUid => Some(Self::TrackEntry),
+            Self::TrickStructTrackSegmentUid => Some(Self::TrackEntry),
+            Self::ContentEncodings => Some(Self::TrackEntry),
+            Self::ContentEncoding => Some(Self::ContentEncodings),
+            Self::ContentEncodingOrder => Some(Self::ContentEncoding),
+            Self::ContentEncodingScope => Some(Self::ContentEncoding),
+            Self::ContentEncodingType => Some(Self::ContentEncoding),
+            Self::ContentCompression => Some(Self::ContentEncoding),
+            Self::ContentCompAlgo => Some(Self::ContentCompression),
+            Self::ContentCompSettings => Some(Self::ContentCompression),
+            Self::ContentEncryption => Some(Self::ContentEncoding),
+            Self::ContentEncAlgo => Some(Self::ContentEncryption),
+            Self::ContentEncKeyId => Some(Self::ContentEncryption),
+            Self::ContentEncAesSettings => Some(Self::ContentEncryption),
+            Self::AesSettingsCipherMode => Some(Self::ContentEncAesSettings),
+            Self::ContentSignature => Some(Self::ContentEncryption),
+            Self::ContentSigKeyId => Some(Self::ContentEncryption),
+            Self::ContentSigAlgo => Some(Self::ContentEncryption),
+            Self::ContentSigHashAlgo => Some(Self::ContentEncryption),
+            Self::Cues => Some(Self::Segment),
+            Self::CuePoint => Some(Self::Cues),
+            Self::CueTime => Some(Self::CuePoint),
+            Self::CueTrackPositions => Some(Self::CuePoint),
+            Self::CueTrack => Some(Self::CueTrackPositions),
+            Self::CueClusterPosition => Some(Self::CueTrackPositions),
+            Self::CueRelativePosition => Some(Self::CueTrackPositions),
+            Self::CueDuration => Some(Self::CueTrackPositions),
+            Self::CueBlockNumber => Some(Self::CueTrackPositions),
+            Self::CueCodecState => Some(Self::CueTrackPositions),
+            Self::CueReference => Some(Self::CueTrackPositions),
+            Self::CueRefTime => Some(Self::CueReference),
+            Self::CueRefCluster => Some(Self::CueReference),
+            Self::CueRefNumber => Some(Self::CueReference),
+            Self::CueRefCodecState => Some(Self::CueReference),
+            Self::Attachments => Some(Self::Segment),
+            Self::AttachedFile => Some(Self::Attachments),
+            Self::FileDescription => Some(Self::AttachedFile),
+            Self::FileName => Some(Self::AttachedFile),
+            Self::FileMediaType => Some(Self::AttachedFile),
+            Self::FileData => Some(Self::AttachedFile),
+            Self::FileUid => Some(Self::AttachedFile),
+            Self::FileReferral => Some(Self::AttachedFile),
+            Self::FileUsedStartTime => Some(Self::AttachedFile),
+            Self::FileUsedEndTime => Some(Self::AttachedFile),
+            Self::Chapters => Some(Self::Segment),
+            Self::EditionEntry => Some(Self::Chapters),
+            Self::EditionUid => Some(Self::EditionEntry),
+            Self::EditionFlagHidden => Some(Self::EditionEntry),
+            Self::EditionFlagDefault => Some(Self::EditionEntry),
+            Self::EditionFlagOrdered => Some(Self::EditionEntry),
+            Self::EditionDisplay => Some(Self::EditionEntry),
+            Self::EditionString => Some(Self::EditionDisplay),
+            Self::EditionLanguageIetf => Some(Self::EditionDisplay),
+            Self::ChapterAtom => Some(Self::EditionEntry),
+            Self::ChapterUid => Some(Self::ChapterAtom),
+            Self::ChapterStringUid => Some(Self::ChapterAtom),
+            Self::ChapterTimeStart => Some(Self::ChapterAtom),
+            Self::ChapterTimeEnd => Some(Self::ChapterAtom),
+            Self::ChapterFlagHidden => Some(Self::ChapterAtom),
+            Self::ChapterFlagEnabled => Some(Self::ChapterAtom),
+            Self::ChapterSegmentUuid => Some(Self::ChapterAtom),
+            Self::ChapterSkipType => Some(Self::ChapterAtom),
+            Self::ChapterSegmentEditionUid => Some(Self::ChapterAtom),
+            Self::ChapterPhysicalEquiv => Some(Self::ChapterAtom),
+            Self::ChapterTrack => Some(Self::ChapterAtom),
+            Self::ChapterTrackUid => Some(Self::ChapterTrack),
+            Self::ChapterDisplay => Some(Self::ChapterAtom),
+            Self::ChapString => Some(Self::ChapterDisplay),
+            Self::ChapLanguage => Some(Self::ChapterDisplay),
+            Self::ChapLanguageBcp47 => Some(Self::ChapterDisplay),
+            Self::ChapCountry => Some(Self::ChapterDisplay),
+            Self::ChapProcess => Some(Self::ChapterAtom),
+            Self::ChapProcessCodecId => Some(Self::ChapProcess),
+            Self::ChapProcessPrivate => Some(Self::ChapProcess),
+            Self::ChapProcessCommand => Some(Self::ChapProcess),
+            Self::ChapProcessTime => Some(Self::ChapProcessCommand),
+            Self::ChapProcessData => Some(Self::ChapProcessCommand),
+            Self::Tags => Some(Self::Segment),
+            Self::Tag => Some(Self::Tags),
+            Self::Targets => Some(Self::Tag),
+            Self::TargetTypeValue => Some(Self::Targets),
+            Self::TargetType => Some(Self::Targets),
+            Self::TagTrackUid => Some(Self::Targets),
+            Self::TagEditionUid => Some(Self::Targets),
+            Self::TagChapterUid => Some(Self::Targets),
+            Self::TagAttachmentUid => Some(Self::Targets),
+            Self::SimpleTag => Some(Self::Tag),
+            Self::TagName => Some(Self::SimpleTag),
+            Self::TagLanguage => Some(Self::SimpleTag),
+            Self::TagLanguageBcp47 => Some(Self::SimpleTag),
+            Self::TagDefault => Some(Self::SimpleTag),
+            Self::TagDefaultBogus => Some(Self::SimpleTag),
+            Self::TagString => Some(Self::SimpleTag),
+            Self::TagBinary => Some(Self::SimpleTag),
+
+        }
+    }
 }
 
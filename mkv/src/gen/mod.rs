@@ -2,5 +2,7 @@ pub mod ids;
 pub mod reader;
 pub mod writer;
 pub mod structs;
+pub mod enums;
+pub mod validator;
 
 use super::*;
@@ -1,6 +1,8 @@
-use super::Ebml;
+use super::{Ebml, ElementEncoding, RawElement};
 use super::ids::EbmlId;
+use super::enums::*;
 use super::ElementSize;
+use super::block::Block;
 
 #[derive(Debug, Clone, Default)]
 pub struct EbmlHeader {
@@ -14,61 +16,99 @@ pub struct EbmlHeader {
     pub doc_type_extension: Option<Ebml<DocTypeExtension>>,
     pub doc_type_extension_name: Option<Ebml<String>>,
     pub doc_type_extension_version: Option<Ebml<u64>>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub ebml_max_id_length: Ebml<u64>,
     pub ebml_max_size_length: Ebml<u64>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl EbmlHeader {
-    pub fn elements(&self) -> std::collections::BTreeSet<EbmlHeaderFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<EbmlHeaderFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(EbmlHeaderFields::Version(self.version.clone()));
-        elements.insert(EbmlHeaderFields::ReadVersion(self.read_version.clone()));
-        elements.insert(EbmlHeaderFields::DocType(self.doc_type.clone()));
-        elements.insert(EbmlHeaderFields::DocTypeVersion(self.doc_type_version.clone()));
-        elements.insert(EbmlHeaderFields::DocTypeReadVersion(self.doc_type_read_version.clone()));
-        if let Some(el) = &self.doc_type_extension { elements.insert(EbmlHeaderFields::DocTypeExtension(el.clone())); }
-        if let Some(el) = &self.doc_type_extension_name { elements.insert(EbmlHeaderFields::DocTypeExtensionName(el.clone())); }
-        if let Some(el) = &self.doc_type_extension_version { elements.insert(EbmlHeaderFields::DocTypeExtensionVersion(el.clone())); }
-        elements.insert(EbmlHeaderFields::EbmlMaxIdLength(self.ebml_max_id_length.clone()));
-        elements.insert(EbmlHeaderFields::EbmlMaxSizeLength(self.ebml_max_size_length.clone()));
+        elements.insert(EbmlHeaderFields::Version(&self.version));
+        elements.insert(EbmlHeaderFields::ReadVersion(&self.read_version));
+        elements.insert(EbmlHeaderFields::DocType(&self.doc_type));
+        elements.insert(EbmlHeaderFields::DocTypeVersion(&self.doc_type_version));
+        elements.insert(EbmlHeaderFields::DocTypeReadVersion(&self.doc_type_read_version));
+        if let Some(el) = &self.doc_type_extension { elements.insert(EbmlHeaderFields::DocTypeExtension(el)); }
+        if let Some(el) = &self.doc_type_extension_name { elements.insert(EbmlHeaderFields::DocTypeExtensionName(el)); }
+        if let Some(el) = &self.doc_type_extension_version { elements.insert(EbmlHeaderFields::DocTypeExtensionVersion(el)); }
+        if let Some(el) = &self.crc_32 { elements.insert(EbmlHeaderFields::Crc32(el)); }
+        elements.insert(EbmlHeaderFields::EbmlMaxIdLength(&self.ebml_max_id_length));
+        elements.insert(EbmlHeaderFields::EbmlMaxSizeLength(&self.ebml_max_size_length));
+        for el in &self.unknown { elements.insert(EbmlHeaderFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum EbmlHeaderFields {
-    Version(Ebml<u64>),
-    ReadVersion(Ebml<u64>),
-    DocType(Ebml<String>),
-    DocTypeVersion(Ebml<u64>),
-    DocTypeReadVersion(Ebml<u64>),
-    DocTypeExtension(Ebml<DocTypeExtension>),
-    DocTypeExtensionName(Ebml<String>),
-    DocTypeExtensionVersion(Ebml<u64>),
-    EbmlMaxIdLength(Ebml<u64>),
-    EbmlMaxSizeLength(Ebml<u64>),
-}
-impl EbmlHeaderFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum EbmlHeaderFields<'a> {
+    Version(&'a Ebml<u64>),
+    ReadVersion(&'a Ebml<u64>),
+    DocType(&'a Ebml<String>),
+    DocTypeVersion(&'a Ebml<u64>),
+    DocTypeReadVersion(&'a Ebml<u64>),
+    DocTypeExtension(&'a Ebml<DocTypeExtension>),
+    DocTypeExtensionName(&'a Ebml<String>),
+    DocTypeExtensionVersion(&'a Ebml<u64>),
+    Crc32(&'a Ebml<Vec<u8>>),
+    EbmlMaxIdLength(&'a Ebml<u64>),
+    EbmlMaxSizeLength(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl EbmlHeaderFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Version(val) => (EbmlId::Version, val.index, val.id),
-            Self::ReadVersion(val) => (EbmlId::ReadVersion, val.index, val.id),
-            Self::DocType(val) => (EbmlId::DocType, val.index, val.id),
-            Self::DocTypeVersion(val) => (EbmlId::DocTypeVersion, val.index, val.id),
-            Self::DocTypeReadVersion(val) => (EbmlId::DocTypeReadVersion, val.index, val.id),
-            Self::DocTypeExtension(val) => (EbmlId::DocTypeExtension, val.index, val.id),
-            Self::DocTypeExtensionName(val) => (EbmlId::DocTypeExtensionName, val.index, val.id),
-            Self::DocTypeExtensionVersion(val) => (EbmlId::DocTypeExtensionVersion, val.index, val.id),
-            Self::EbmlMaxIdLength(val) => (EbmlId::EbmlMaxIdLength, val.index, val.id),
-            Self::EbmlMaxSizeLength(val) => (EbmlId::EbmlMaxSizeLength, val.index, val.id),
+            Self::Version(val) => (EbmlId::Version as u64, val.index, val.id),
+            Self::ReadVersion(val) => (EbmlId::ReadVersion as u64, val.index, val.id),
+            Self::DocType(val) => (EbmlId::DocType as u64, val.index, val.id),
+            Self::DocTypeVersion(val) => (EbmlId::DocTypeVersion as u64, val.index, val.id),
+            Self::DocTypeReadVersion(val) => (EbmlId::DocTypeReadVersion as u64, val.index, val.id),
+            Self::DocTypeExtension(val) => (EbmlId::DocTypeExtension as u64, val.index, val.id),
+            Self::DocTypeExtensionName(val) => (EbmlId::DocTypeExtensionName as u64, val.index, val.id),
+            Self::DocTypeExtensionVersion(val) => (EbmlId::DocTypeExtensionVersion as u64, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::EbmlMaxIdLength(val) => (EbmlId::EbmlMaxIdLength as u64, val.index, val.id),
+            Self::EbmlMaxSizeLength(val) => (EbmlId::EbmlMaxSizeLength as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(EbmlHeaderFields);
+crate::impl_ord!(EbmlHeaderFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct DocTypeExtension {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
+}
+impl DocTypeExtension {
+    pub fn elements(&self) -> std::collections::BTreeSet<DocTypeExtensionFields<'_>> {
+        let mut elements = std::collections::BTreeSet::new();
+        if let Some(el) = &self.crc_32 { elements.insert(DocTypeExtensionFields::Crc32(el)); }
+        for el in &self.unknown { elements.insert(DocTypeExtensionFields::Unknown(el)); }
+        elements
+    }
+}
+#[derive(Debug)]
+pub enum DocTypeExtensionFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
 }
+impl DocTypeExtensionFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
+        match self {
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
+        }
+    }
+}
+crate::impl_ord!(DocTypeExtensionFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Segment {
@@ -84,114 +124,144 @@ pub struct Segment {
     pub attachments: Option<Ebml<Attachments>>,
     pub chapters: Option<Ebml<Chapters>>,
     pub tags: Vec<Ebml<Tags>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Segment {
-    pub fn elements(&self) -> std::collections::BTreeSet<SegmentFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SegmentFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.void { elements.insert(SegmentFields::Void(el.clone())); }
-        if let Some(el) = &self.crc_32 { elements.insert(SegmentFields::Crc32(el.clone())); }
-        for el in &self.seek_head { elements.insert(SegmentFields::SeekHead(el.clone())); }
-        elements.insert(SegmentFields::Info(self.info.clone()));
-        for el in &self.cluster { elements.insert(SegmentFields::Cluster(el.clone())); }
-        if let Some(el) = &self.tracks { elements.insert(SegmentFields::Tracks(el.clone())); }
-        if let Some(el) = &self.cues { elements.insert(SegmentFields::Cues(el.clone())); }
-        if let Some(el) = &self.attachments { elements.insert(SegmentFields::Attachments(el.clone())); }
-        if let Some(el) = &self.chapters { elements.insert(SegmentFields::Chapters(el.clone())); }
-        for el in &self.tags { elements.insert(SegmentFields::Tags(el.clone())); }
+        for el in &self.void { elements.insert(SegmentFields::Void(el)); }
+        if let Some(el) = &self.crc_32 { elements.insert(SegmentFields::Crc32(el)); }
+        for el in &self.seek_head { elements.insert(SegmentFields::SeekHead(el)); }
+        elements.insert(SegmentFields::Info(&self.info));
+        for el in &self.cluster { elements.insert(SegmentFields::Cluster(el)); }
+        if let Some(el) = &self.tracks { elements.insert(SegmentFields::Tracks(el)); }
+        if let Some(el) = &self.cues { elements.insert(SegmentFields::Cues(el)); }
+        if let Some(el) = &self.attachments { elements.insert(SegmentFields::Attachments(el)); }
+        if let Some(el) = &self.chapters { elements.insert(SegmentFields::Chapters(el)); }
+        for el in &self.tags { elements.insert(SegmentFields::Tags(el)); }
+        for el in &self.unknown { elements.insert(SegmentFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SegmentFields {
-    Void(Ebml<Vec<u8>>),
-    Crc32(Ebml<Vec<u8>>),
-    SeekHead(Ebml<SeekHead>),
-    Info(Ebml<Info>),
-    Cluster(Ebml<Cluster>),
-    Tracks(Ebml<Tracks>),
-    Cues(Ebml<Cues>),
-    Attachments(Ebml<Attachments>),
-    Chapters(Ebml<Chapters>),
-    Tags(Ebml<Tags>),
-}
-impl SegmentFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum SegmentFields<'a> {
+    Void(&'a Ebml<Vec<u8>>),
+    Crc32(&'a Ebml<Vec<u8>>),
+    SeekHead(&'a Ebml<SeekHead>),
+    Info(&'a Ebml<Info>),
+    Cluster(&'a Ebml<Cluster>),
+    Tracks(&'a Ebml<Tracks>),
+    Cues(&'a Ebml<Cues>),
+    Attachments(&'a Ebml<Attachments>),
+    Chapters(&'a Ebml<Chapters>),
+    Tags(&'a Ebml<Tags>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl SegmentFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Void(val) => (EbmlId::Void, val.index, val.id),
-            Self::Crc32(val) => (EbmlId::Crc32, val.index, val.id),
-            Self::SeekHead(val) => (EbmlId::SeekHead, val.index, val.id),
-            Self::Info(val) => (EbmlId::Info, val.index, val.id),
-            Self::Cluster(val) => (EbmlId::Cluster, val.index, val.id),
-            Self::Tracks(val) => (EbmlId::Tracks, val.index, val.id),
-            Self::Cues(val) => (EbmlId::Cues, val.index, val.id),
-            Self::Attachments(val) => (EbmlId::Attachments, val.index, val.id),
-            Self::Chapters(val) => (EbmlId::Chapters, val.index, val.id),
-            Self::Tags(val) => (EbmlId::Tags, val.index, val.id),
+            Self::Void(val) => (EbmlId::Void as u64, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::SeekHead(val) => (EbmlId::SeekHead as u64, val.index, val.id),
+            Self::Info(val) => (EbmlId::Info as u64, val.index, val.id),
+            Self::Cluster(val) => (EbmlId::Cluster as u64, val.index, val.id),
+            Self::Tracks(val) => (EbmlId::Tracks as u64, val.index, val.id),
+            Self::Cues(val) => (EbmlId::Cues as u64, val.index, val.id),
+            Self::Attachments(val) => (EbmlId::Attachments as u64, val.index, val.id),
+            Self::Chapters(val) => (EbmlId::Chapters as u64, val.index, val.id),
+            Self::Tags(val) => (EbmlId::Tags as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SegmentFields);
+crate::impl_ord!(SegmentFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct SeekHead {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub seek: Vec<Ebml<Seek>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl SeekHead {
-    pub fn elements(&self) -> std::collections::BTreeSet<SeekHeadFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SeekHeadFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.seek { elements.insert(SeekHeadFields::Seek(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(SeekHeadFields::Crc32(el)); }
+        for el in &self.seek { elements.insert(SeekHeadFields::Seek(el)); }
+        for el in &self.unknown { elements.insert(SeekHeadFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SeekHeadFields {
-    Seek(Ebml<Seek>),
+pub enum SeekHeadFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Seek(&'a Ebml<Seek>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl SeekHeadFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl SeekHeadFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Seek(val) => (EbmlId::Seek, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Seek(val) => (EbmlId::Seek as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SeekHeadFields);
+crate::impl_ord!(SeekHeadFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Seek {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub seek_id: Ebml<Vec<u8>>,
     pub seek_position: Ebml<u64>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Seek {
-    pub fn elements(&self) -> std::collections::BTreeSet<SeekFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SeekFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(SeekFields::SeekId(self.seek_id.clone()));
-        elements.insert(SeekFields::SeekPosition(self.seek_position.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(SeekFields::Crc32(el)); }
+        elements.insert(SeekFields::SeekId(&self.seek_id));
+        elements.insert(SeekFields::SeekPosition(&self.seek_position));
+        for el in &self.unknown { elements.insert(SeekFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SeekFields {
-    SeekId(Ebml<Vec<u8>>),
-    SeekPosition(Ebml<u64>),
-}
-impl SeekFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum SeekFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    SeekId(&'a Ebml<Vec<u8>>),
+    SeekPosition(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl SeekFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::SeekId(val) => (EbmlId::SeekId, val.index, val.id),
-            Self::SeekPosition(val) => (EbmlId::SeekPosition, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::SeekId(val) => (EbmlId::SeekId as u64, val.index, val.id),
+            Self::SeekPosition(val) => (EbmlId::SeekPosition as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SeekFields);
+crate::impl_ord!(SeekFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Info {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub segment_uuid: Option<Ebml<Vec<u8>>>,
     pub segment_filename: Option<Ebml<String>>,
     pub prev_uuid: Option<Ebml<Vec<u8>>>,
@@ -206,181 +276,225 @@ pub struct Info {
     pub title: Option<Ebml<String>>,
     pub muxing_app: Ebml<String>,
     pub writing_app: Ebml<String>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Info {
-    pub fn elements(&self) -> std::collections::BTreeSet<InfoFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<InfoFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.segment_uuid { elements.insert(InfoFields::SegmentUuid(el.clone())); }
-        if let Some(el) = &self.segment_filename { elements.insert(InfoFields::SegmentFilename(el.clone())); }
-        if let Some(el) = &self.prev_uuid { elements.insert(InfoFields::PrevUuid(el.clone())); }
-        if let Some(el) = &self.prev_filename { elements.insert(InfoFields::PrevFilename(el.clone())); }
-        if let Some(el) = &self.next_uuid { elements.insert(InfoFields::NextUuid(el.clone())); }
-        if let Some(el) = &self.next_filename { elements.insert(InfoFields::NextFilename(el.clone())); }
-        for el in &self.segment_family { elements.insert(InfoFields::SegmentFamily(el.clone())); }
-        for el in &self.chapter_translate { elements.insert(InfoFields::ChapterTranslate(el.clone())); }
-        elements.insert(InfoFields::TimestampScale(self.timestamp_scale.clone()));
-        if let Some(el) = &self.duration { elements.insert(InfoFields::Duration(el.clone())); }
-        if let Some(el) = &self.date_utc { elements.insert(InfoFields::DateUtc(el.clone())); }
-        if let Some(el) = &self.title { elements.insert(InfoFields::Title(el.clone())); }
-        elements.insert(InfoFields::MuxingApp(self.muxing_app.clone()));
-        elements.insert(InfoFields::WritingApp(self.writing_app.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(InfoFields::Crc32(el)); }
+        if let Some(el) = &self.segment_uuid { elements.insert(InfoFields::SegmentUuid(el)); }
+        if let Some(el) = &self.segment_filename { elements.insert(InfoFields::SegmentFilename(el)); }
+        if let Some(el) = &self.prev_uuid { elements.insert(InfoFields::PrevUuid(el)); }
+        if let Some(el) = &self.prev_filename { elements.insert(InfoFields::PrevFilename(el)); }
+        if let Some(el) = &self.next_uuid { elements.insert(InfoFields::NextUuid(el)); }
+        if let Some(el) = &self.next_filename { elements.insert(InfoFields::NextFilename(el)); }
+        for el in &self.segment_family { elements.insert(InfoFields::SegmentFamily(el)); }
+        for el in &self.chapter_translate { elements.insert(InfoFields::ChapterTranslate(el)); }
+        elements.insert(InfoFields::TimestampScale(&self.timestamp_scale));
+        if let Some(el) = &self.duration { elements.insert(InfoFields::Duration(el)); }
+        if let Some(el) = &self.date_utc { elements.insert(InfoFields::DateUtc(el)); }
+        if let Some(el) = &self.title { elements.insert(InfoFields::Title(el)); }
+        elements.insert(InfoFields::MuxingApp(&self.muxing_app));
+        elements.insert(InfoFields::WritingApp(&self.writing_app));
+        for el in &self.unknown { elements.insert(InfoFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum InfoFields {
-    SegmentUuid(Ebml<Vec<u8>>),
-    SegmentFilename(Ebml<String>),
-    PrevUuid(Ebml<Vec<u8>>),
-    PrevFilename(Ebml<String>),
-    NextUuid(Ebml<Vec<u8>>),
-    NextFilename(Ebml<String>),
-    SegmentFamily(Ebml<Vec<u8>>),
-    ChapterTranslate(Ebml<ChapterTranslate>),
-    TimestampScale(Ebml<u64>),
-    Duration(Ebml<f64>),
-    DateUtc(Ebml<time::OffsetDateTime>),
-    Title(Ebml<String>),
-    MuxingApp(Ebml<String>),
-    WritingApp(Ebml<String>),
-}
-impl InfoFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum InfoFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    SegmentUuid(&'a Ebml<Vec<u8>>),
+    SegmentFilename(&'a Ebml<String>),
+    PrevUuid(&'a Ebml<Vec<u8>>),
+    PrevFilename(&'a Ebml<String>),
+    NextUuid(&'a Ebml<Vec<u8>>),
+    NextFilename(&'a Ebml<String>),
+    SegmentFamily(&'a Ebml<Vec<u8>>),
+    ChapterTranslate(&'a Ebml<ChapterTranslate>),
+    TimestampScale(&'a Ebml<u64>),
+    Duration(&'a Ebml<f64>),
+    DateUtc(&'a Ebml<time::OffsetDateTime>),
+    Title(&'a Ebml<String>),
+    MuxingApp(&'a Ebml<String>),
+    WritingApp(&'a Ebml<String>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl InfoFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::SegmentUuid(val) => (EbmlId::SegmentUuid, val.index, val.id),
-            Self::SegmentFilename(val) => (EbmlId::SegmentFilename, val.index, val.id),
-            Self::PrevUuid(val) => (EbmlId::PrevUuid, val.index, val.id),
-            Self::PrevFilename(val) => (EbmlId::PrevFilename, val.index, val.id),
-            Self::NextUuid(val) => (EbmlId::NextUuid, val.index, val.id),
-            Self::NextFilename(val) => (EbmlId::NextFilename, val.index, val.id),
-            Self::SegmentFamily(val) => (EbmlId::SegmentFamily, val.index, val.id),
-            Self::ChapterTranslate(val) => (EbmlId::ChapterTranslate, val.index, val.id),
-            Self::TimestampScale(val) => (EbmlId::TimestampScale, val.index, val.id),
-            Self::Duration(val) => (EbmlId::Duration, val.index, val.id),
-            Self::DateUtc(val) => (EbmlId::DateUtc, val.index, val.id),
-            Self::Title(val) => (EbmlId::Title, val.index, val.id),
-            Self::MuxingApp(val) => (EbmlId::MuxingApp, val.index, val.id),
-            Self::WritingApp(val) => (EbmlId::WritingApp, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::SegmentUuid(val) => (EbmlId::SegmentUuid as u64, val.index, val.id),
+            Self::SegmentFilename(val) => (EbmlId::SegmentFilename as u64, val.index, val.id),
+            Self::PrevUuid(val) => (EbmlId::PrevUuid as u64, val.index, val.id),
+            Self::PrevFilename(val) => (EbmlId::PrevFilename as u64, val.index, val.id),
+            Self::NextUuid(val) => (EbmlId::NextUuid as u64, val.index, val.id),
+            Self::NextFilename(val) => (EbmlId::NextFilename as u64, val.index, val.id),
+            Self::SegmentFamily(val) => (EbmlId::SegmentFamily as u64, val.index, val.id),
+            Self::ChapterTranslate(val) => (EbmlId::ChapterTranslate as u64, val.index, val.id),
+            Self::TimestampScale(val) => (EbmlId::TimestampScale as u64, val.index, val.id),
+            Self::Duration(val) => (EbmlId::Duration as u64, val.index, val.id),
+            Self::DateUtc(val) => (EbmlId::DateUtc as u64, val.index, val.id),
+            Self::Title(val) => (EbmlId::Title as u64, val.index, val.id),
+            Self::MuxingApp(val) => (EbmlId::MuxingApp as u64, val.index, val.id),
+            Self::WritingApp(val) => (EbmlId::WritingApp as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(InfoFields);
+crate::impl_ord!(InfoFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapterTranslate {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub chapter_translate_id: Ebml<Vec<u8>>,
-    pub chapter_translate_codec: Ebml<u64>,
+    pub chapter_translate_codec: Ebml<ChapterTranslateCodec>,
     pub chapter_translate_edition_uid: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapterTranslate {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapterTranslateFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapterTranslateFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ChapterTranslateFields::ChapterTranslateId(self.chapter_translate_id.clone()));
-        elements.insert(ChapterTranslateFields::ChapterTranslateCodec(self.chapter_translate_codec.clone()));
-        for el in &self.chapter_translate_edition_uid { elements.insert(ChapterTranslateFields::ChapterTranslateEditionUid(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChapterTranslateFields::Crc32(el)); }
+        elements.insert(ChapterTranslateFields::ChapterTranslateId(&self.chapter_translate_id));
+        elements.insert(ChapterTranslateFields::ChapterTranslateCodec(&self.chapter_translate_codec));
+        for el in &self.chapter_translate_edition_uid { elements.insert(ChapterTranslateFields::ChapterTranslateEditionUid(el)); }
+        for el in &self.unknown { elements.insert(ChapterTranslateFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapterTranslateFields {
-    ChapterTranslateId(Ebml<Vec<u8>>),
-    ChapterTranslateCodec(Ebml<u64>),
-    ChapterTranslateEditionUid(Ebml<u64>),
-}
-impl ChapterTranslateFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ChapterTranslateFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapterTranslateId(&'a Ebml<Vec<u8>>),
+    ChapterTranslateCodec(&'a Ebml<ChapterTranslateCodec>),
+    ChapterTranslateEditionUid(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ChapterTranslateFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapterTranslateId(val) => (EbmlId::ChapterTranslateId, val.index, val.id),
-            Self::ChapterTranslateCodec(val) => (EbmlId::ChapterTranslateCodec, val.index, val.id),
-            Self::ChapterTranslateEditionUid(val) => (EbmlId::ChapterTranslateEditionUid, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapterTranslateId(val) => (EbmlId::ChapterTranslateId as u64, val.index, val.id),
+            Self::ChapterTranslateCodec(val) => (EbmlId::ChapterTranslateCodec as u64, val.index, val.id),
+            Self::ChapterTranslateEditionUid(val) => (EbmlId::ChapterTranslateEditionUid as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapterTranslateFields);
+crate::impl_ord!(ChapterTranslateFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Cluster {
     pub size: ElementSize,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub timestamp: Ebml<u64>,
     pub silent_tracks: Option<Ebml<SilentTracks>>,
     pub position: Option<Ebml<u64>>,
     pub prev_size: Option<Ebml<u64>>,
-    pub simple_block: Vec<Ebml<Vec<u8>>>,
+    pub simple_block: Vec<Ebml<Block>>,
     pub block_group: Vec<Ebml<BlockGroup>>,
     pub encrypted_block: Vec<Ebml<Vec<u8>>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Cluster {
-    pub fn elements(&self) -> std::collections::BTreeSet<ClusterFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ClusterFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ClusterFields::Timestamp(self.timestamp.clone()));
-        if let Some(el) = &self.silent_tracks { elements.insert(ClusterFields::SilentTracks(el.clone())); }
-        if let Some(el) = &self.position { elements.insert(ClusterFields::Position(el.clone())); }
-        if let Some(el) = &self.prev_size { elements.insert(ClusterFields::PrevSize(el.clone())); }
-        for el in &self.simple_block { elements.insert(ClusterFields::SimpleBlock(el.clone())); }
-        for el in &self.block_group { elements.insert(ClusterFields::BlockGroup(el.clone())); }
-        for el in &self.encrypted_block { elements.insert(ClusterFields::EncryptedBlock(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ClusterFields::Crc32(el)); }
+        elements.insert(ClusterFields::Timestamp(&self.timestamp));
+        if let Some(el) = &self.silent_tracks { elements.insert(ClusterFields::SilentTracks(el)); }
+        if let Some(el) = &self.position { elements.insert(ClusterFields::Position(el)); }
+        if let Some(el) = &self.prev_size { elements.insert(ClusterFields::PrevSize(el)); }
+        for el in &self.simple_block { elements.insert(ClusterFields::SimpleBlock(el)); }
+        for el in &self.block_group { elements.insert(ClusterFields::BlockGroup(el)); }
+        for el in &self.encrypted_block { elements.insert(ClusterFields::EncryptedBlock(el)); }
+        for el in &self.unknown { elements.insert(ClusterFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ClusterFields {
-    Timestamp(Ebml<u64>),
-    SilentTracks(Ebml<SilentTracks>),
-    Position(Ebml<u64>),
-    PrevSize(Ebml<u64>),
-    SimpleBlock(Ebml<Vec<u8>>),
-    BlockGroup(Ebml<BlockGroup>),
-    EncryptedBlock(Ebml<Vec<u8>>),
-}
-impl ClusterFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ClusterFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Timestamp(&'a Ebml<u64>),
+    SilentTracks(&'a Ebml<SilentTracks>),
+    Position(&'a Ebml<u64>),
+    PrevSize(&'a Ebml<u64>),
+    SimpleBlock(&'a Ebml<Block>),
+    BlockGroup(&'a Ebml<BlockGroup>),
+    EncryptedBlock(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ClusterFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Timestamp(val) => (EbmlId::Timestamp, val.index, val.id),
-            Self::SilentTracks(val) => (EbmlId::SilentTracks, val.index, val.id),
-            Self::Position(val) => (EbmlId::Position, val.index, val.id),
-            Self::PrevSize(val) => (EbmlId::PrevSize, val.index, val.id),
-            Self::SimpleBlock(val) => (EbmlId::SimpleBlock, val.index, val.id),
-            Self::BlockGroup(val) => (EbmlId::BlockGroup, val.index, val.id),
-            Self::EncryptedBlock(val) => (EbmlId::EncryptedBlock, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Timestamp(val) => (EbmlId::Timestamp as u64, val.index, val.id),
+            Self::SilentTracks(val) => (EbmlId::SilentTracks as u64, val.index, val.id),
+            Self::Position(val) => (EbmlId::Position as u64, val.index, val.id),
+            Self::PrevSize(val) => (EbmlId::PrevSize as u64, val.index, val.id),
+            Self::SimpleBlock(val) => (EbmlId::SimpleBlock as u64, val.index, val.id),
+            Self::BlockGroup(val) => (EbmlId::BlockGroup as u64, val.index, val.id),
+            Self::EncryptedBlock(val) => (EbmlId::EncryptedBlock as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ClusterFields);
+crate::impl_ord!(ClusterFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct SilentTracks {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub silent_track_number: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl SilentTracks {
-    pub fn elements(&self) -> std::collections::BTreeSet<SilentTracksFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SilentTracksFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.silent_track_number { elements.insert(SilentTracksFields::SilentTrackNumber(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(SilentTracksFields::Crc32(el)); }
+        for el in &self.silent_track_number { elements.insert(SilentTracksFields::SilentTrackNumber(el)); }
+        for el in &self.unknown { elements.insert(SilentTracksFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SilentTracksFields {
-    SilentTrackNumber(Ebml<u64>),
+pub enum SilentTracksFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    SilentTrackNumber(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl SilentTracksFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl SilentTracksFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::SilentTrackNumber(val) => (EbmlId::SilentTrackNumber, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::SilentTrackNumber(val) => (EbmlId::SilentTrackNumber as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SilentTracksFields);
+crate::impl_ord!(SilentTracksFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct BlockGroup {
     pub size: u64,
 
-    pub block: Ebml<Vec<u8>>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub block: Ebml<Block>,
     pub block_virtual: Option<Ebml<Vec<u8>>>,
     pub block_additions: Option<Ebml<BlockAdditions>>,
     pub block_duration: Option<Ebml<u64>>,
@@ -391,244 +505,321 @@ pub struct BlockGroup {
     pub discard_padding: Option<Ebml<i64>>,
     pub slices: Option<Ebml<Slices>>,
     pub reference_frame: Option<Ebml<ReferenceFrame>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl BlockGroup {
-    pub fn elements(&self) -> std::collections::BTreeSet<BlockGroupFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<BlockGroupFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(BlockGroupFields::Block(self.block.clone()));
-        if let Some(el) = &self.block_virtual { elements.insert(BlockGroupFields::BlockVirtual(el.clone())); }
-        if let Some(el) = &self.block_additions { elements.insert(BlockGroupFields::BlockAdditions(el.clone())); }
-        if let Some(el) = &self.block_duration { elements.insert(BlockGroupFields::BlockDuration(el.clone())); }
-        elements.insert(BlockGroupFields::ReferencePriority(self.reference_priority.clone()));
-        for el in &self.reference_block { elements.insert(BlockGroupFields::ReferenceBlock(el.clone())); }
-        if let Some(el) = &self.reference_virtual { elements.insert(BlockGroupFields::ReferenceVirtual(el.clone())); }
-        if let Some(el) = &self.codec_state { elements.insert(BlockGroupFields::CodecState(el.clone())); }
-        if let Some(el) = &self.discard_padding { elements.insert(BlockGroupFields::DiscardPadding(el.clone())); }
-        if let Some(el) = &self.slices { elements.insert(BlockGroupFields::Slices(el.clone())); }
-        if let Some(el) = &self.reference_frame { elements.insert(BlockGroupFields::ReferenceFrame(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(BlockGroupFields::Crc32(el)); }
+        elements.insert(BlockGroupFields::Block(&self.block));
+        if let Some(el) = &self.block_virtual { elements.insert(BlockGroupFields::BlockVirtual(el)); }
+        if let Some(el) = &self.block_additions { elements.insert(BlockGroupFields::BlockAdditions(el)); }
+        if let Some(el) = &self.block_duration { elements.insert(BlockGroupFields::BlockDuration(el)); }
+        elements.insert(BlockGroupFields::ReferencePriority(&self.reference_priority));
+        for el in &self.reference_block { elements.insert(BlockGroupFields::ReferenceBlock(el)); }
+        if let Some(el) = &self.reference_virtual { elements.insert(BlockGroupFields::ReferenceVirtual(el)); }
+        if let Some(el) = &self.codec_state { elements.insert(BlockGroupFields::CodecState(el)); }
+        if let Some(el) = &self.discard_padding { elements.insert(BlockGroupFields::DiscardPadding(el)); }
+        if let Some(el) = &self.slices { elements.insert(BlockGroupFields::Slices(el)); }
+        if let Some(el) = &self.reference_frame { elements.insert(BlockGroupFields::ReferenceFrame(el)); }
+        for el in &self.unknown { elements.insert(BlockGroupFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum BlockGroupFields {
-    Block(Ebml<Vec<u8>>),
-    BlockVirtual(Ebml<Vec<u8>>),
-    BlockAdditions(Ebml<BlockAdditions>),
-    BlockDuration(Ebml<u64>),
-    ReferencePriority(Ebml<u64>),
-    ReferenceBlock(Ebml<i64>),
-    ReferenceVirtual(Ebml<i64>),
-    CodecState(Ebml<Vec<u8>>),
-    DiscardPadding(Ebml<i64>),
-    Slices(Ebml<Slices>),
-    ReferenceFrame(Ebml<ReferenceFrame>),
-}
-impl BlockGroupFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum BlockGroupFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Block(&'a Ebml<Block>),
+    BlockVirtual(&'a Ebml<Vec<u8>>),
+    BlockAdditions(&'a Ebml<BlockAdditions>),
+    BlockDuration(&'a Ebml<u64>),
+    ReferencePriority(&'a Ebml<u64>),
+    ReferenceBlock(&'a Ebml<i64>),
+    ReferenceVirtual(&'a Ebml<i64>),
+    CodecState(&'a Ebml<Vec<u8>>),
+    DiscardPadding(&'a Ebml<i64>),
+    Slices(&'a Ebml<Slices>),
+    ReferenceFrame(&'a Ebml<ReferenceFrame>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl BlockGroupFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Block(val) => (EbmlId::Block, val.index, val.id),
-            Self::BlockVirtual(val) => (EbmlId::BlockVirtual, val.index, val.id),
-            Self::BlockAdditions(val) => (EbmlId::BlockAdditions, val.index, val.id),
-            Self::BlockDuration(val) => (EbmlId::BlockDuration, val.index, val.id),
-            Self::ReferencePriority(val) => (EbmlId::ReferencePriority, val.index, val.id),
-            Self::ReferenceBlock(val) => (EbmlId::ReferenceBlock, val.index, val.id),
-            Self::ReferenceVirtual(val) => (EbmlId::ReferenceVirtual, val.index, val.id),
-            Self::CodecState(val) => (EbmlId::CodecState, val.index, val.id),
-            Self::DiscardPadding(val) => (EbmlId::DiscardPadding, val.index, val.id),
-            Self::Slices(val) => (EbmlId::Slices, val.index, val.id),
-            Self::ReferenceFrame(val) => (EbmlId::ReferenceFrame, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Block(val) => (EbmlId::Block as u64, val.index, val.id),
+            Self::BlockVirtual(val) => (EbmlId::BlockVirtual as u64, val.index, val.id),
+            Self::BlockAdditions(val) => (EbmlId::BlockAdditions as u64, val.index, val.id),
+            Self::BlockDuration(val) => (EbmlId::BlockDuration as u64, val.index, val.id),
+            Self::ReferencePriority(val) => (EbmlId::ReferencePriority as u64, val.index, val.id),
+            Self::ReferenceBlock(val) => (EbmlId::ReferenceBlock as u64, val.index, val.id),
+            Self::ReferenceVirtual(val) => (EbmlId::ReferenceVirtual as u64, val.index, val.id),
+            Self::CodecState(val) => (EbmlId::CodecState as u64, val.index, val.id),
+            Self::DiscardPadding(val) => (EbmlId::DiscardPadding as u64, val.index, val.id),
+            Self::Slices(val) => (EbmlId::Slices as u64, val.index, val.id),
+            Self::ReferenceFrame(val) => (EbmlId::ReferenceFrame as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(BlockGroupFields);
+crate::impl_ord!(BlockGroupFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct BlockAdditions {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub block_more: Vec<Ebml<BlockMore>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl BlockAdditions {
-    pub fn elements(&self) -> std::collections::BTreeSet<BlockAdditionsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<BlockAdditionsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.block_more { elements.insert(BlockAdditionsFields::BlockMore(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(BlockAdditionsFields::Crc32(el)); }
+        for el in &self.block_more { elements.insert(BlockAdditionsFields::BlockMore(el)); }
+        for el in &self.unknown { elements.insert(BlockAdditionsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum BlockAdditionsFields {
-    BlockMore(Ebml<BlockMore>),
+pub enum BlockAdditionsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    BlockMore(&'a Ebml<BlockMore>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl BlockAdditionsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl BlockAdditionsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::BlockMore(val) => (EbmlId::BlockMore, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::BlockMore(val) => (EbmlId::BlockMore as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(BlockAdditionsFields);
+crate::impl_ord!(BlockAdditionsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct BlockMore {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub block_additional: Ebml<Vec<u8>>,
     pub block_add_id: Ebml<u64>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl BlockMore {
-    pub fn elements(&self) -> std::collections::BTreeSet<BlockMoreFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<BlockMoreFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(BlockMoreFields::BlockAdditional(self.block_additional.clone()));
-        elements.insert(BlockMoreFields::BlockAddId(self.block_add_id.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(BlockMoreFields::Crc32(el)); }
+        elements.insert(BlockMoreFields::BlockAdditional(&self.block_additional));
+        elements.insert(BlockMoreFields::BlockAddId(&self.block_add_id));
+        for el in &self.unknown { elements.insert(BlockMoreFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum BlockMoreFields {
-    BlockAdditional(Ebml<Vec<u8>>),
-    BlockAddId(Ebml<u64>),
-}
-impl BlockMoreFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum BlockMoreFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    BlockAdditional(&'a Ebml<Vec<u8>>),
+    BlockAddId(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl BlockMoreFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::BlockAdditional(val) => (EbmlId::BlockAdditional, val.index, val.id),
-            Self::BlockAddId(val) => (EbmlId::BlockAddId, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::BlockAdditional(val) => (EbmlId::BlockAdditional as u64, val.index, val.id),
+            Self::BlockAddId(val) => (EbmlId::BlockAddId as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(BlockMoreFields);
+crate::impl_ord!(BlockMoreFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Slices {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub time_slice: Vec<Ebml<TimeSlice>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Slices {
-    pub fn elements(&self) -> std::collections::BTreeSet<SlicesFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SlicesFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.time_slice { elements.insert(SlicesFields::TimeSlice(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(SlicesFields::Crc32(el)); }
+        for el in &self.time_slice { elements.insert(SlicesFields::TimeSlice(el)); }
+        for el in &self.unknown { elements.insert(SlicesFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SlicesFields {
-    TimeSlice(Ebml<TimeSlice>),
+pub enum SlicesFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TimeSlice(&'a Ebml<TimeSlice>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl SlicesFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl SlicesFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TimeSlice(val) => (EbmlId::TimeSlice, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TimeSlice(val) => (EbmlId::TimeSlice as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SlicesFields);
+crate::impl_ord!(SlicesFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TimeSlice {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub lace_number: Option<Ebml<u64>>,
     pub frame_number: Option<Ebml<u64>>,
     pub block_addition_id: Option<Ebml<u64>>,
     pub delay: Option<Ebml<u64>>,
     pub slice_duration: Option<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TimeSlice {
-    pub fn elements(&self) -> std::collections::BTreeSet<TimeSliceFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TimeSliceFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.lace_number { elements.insert(TimeSliceFields::LaceNumber(el.clone())); }
-        if let Some(el) = &self.frame_number { elements.insert(TimeSliceFields::FrameNumber(el.clone())); }
-        if let Some(el) = &self.block_addition_id { elements.insert(TimeSliceFields::BlockAdditionId(el.clone())); }
-        if let Some(el) = &self.delay { elements.insert(TimeSliceFields::Delay(el.clone())); }
-        if let Some(el) = &self.slice_duration { elements.insert(TimeSliceFields::SliceDuration(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TimeSliceFields::Crc32(el)); }
+        if let Some(el) = &self.lace_number { elements.insert(TimeSliceFields::LaceNumber(el)); }
+        if let Some(el) = &self.frame_number { elements.insert(TimeSliceFields::FrameNumber(el)); }
+        if let Some(el) = &self.block_addition_id { elements.insert(TimeSliceFields::BlockAdditionId(el)); }
+        if let Some(el) = &self.delay { elements.insert(TimeSliceFields::Delay(el)); }
+        if let Some(el) = &self.slice_duration { elements.insert(TimeSliceFields::SliceDuration(el)); }
+        for el in &self.unknown { elements.insert(TimeSliceFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TimeSliceFields {
-    LaceNumber(Ebml<u64>),
-    FrameNumber(Ebml<u64>),
-    BlockAdditionId(Ebml<u64>),
-    Delay(Ebml<u64>),
-    SliceDuration(Ebml<u64>),
-}
-impl TimeSliceFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TimeSliceFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    LaceNumber(&'a Ebml<u64>),
+    FrameNumber(&'a Ebml<u64>),
+    BlockAdditionId(&'a Ebml<u64>),
+    Delay(&'a Ebml<u64>),
+    SliceDuration(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TimeSliceFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::LaceNumber(val) => (EbmlId::LaceNumber, val.index, val.id),
-            Self::FrameNumber(val) => (EbmlId::FrameNumber, val.index, val.id),
-            Self::BlockAdditionId(val) => (EbmlId::BlockAdditionId, val.index, val.id),
-            Self::Delay(val) => (EbmlId::Delay, val.index, val.id),
-            Self::SliceDuration(val) => (EbmlId::SliceDuration, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::LaceNumber(val) => (EbmlId::LaceNumber as u64, val.index, val.id),
+            Self::FrameNumber(val) => (EbmlId::FrameNumber as u64, val.index, val.id),
+            Self::BlockAdditionId(val) => (EbmlId::BlockAdditionId as u64, val.index, val.id),
+            Self::Delay(val) => (EbmlId::Delay as u64, val.index, val.id),
+            Self::SliceDuration(val) => (EbmlId::SliceDuration as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TimeSliceFields);
+crate::impl_ord!(TimeSliceFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ReferenceFrame {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub reference_offset: Ebml<u64>,
     pub reference_timestamp: Ebml<u64>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ReferenceFrame {
-    pub fn elements(&self) -> std::collections::BTreeSet<ReferenceFrameFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ReferenceFrameFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ReferenceFrameFields::ReferenceOffset(self.reference_offset.clone()));
-        elements.insert(ReferenceFrameFields::ReferenceTimestamp(self.reference_timestamp.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(ReferenceFrameFields::Crc32(el)); }
+        elements.insert(ReferenceFrameFields::ReferenceOffset(&self.reference_offset));
+        elements.insert(ReferenceFrameFields::ReferenceTimestamp(&self.reference_timestamp));
+        for el in &self.unknown { elements.insert(ReferenceFrameFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ReferenceFrameFields {
-    ReferenceOffset(Ebml<u64>),
-    ReferenceTimestamp(Ebml<u64>),
-}
-impl ReferenceFrameFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ReferenceFrameFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ReferenceOffset(&'a Ebml<u64>),
+    ReferenceTimestamp(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ReferenceFrameFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ReferenceOffset(val) => (EbmlId::ReferenceOffset, val.index, val.id),
-            Self::ReferenceTimestamp(val) => (EbmlId::ReferenceTimestamp, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ReferenceOffset(val) => (EbmlId::ReferenceOffset as u64, val.index, val.id),
+            Self::ReferenceTimestamp(val) => (EbmlId::ReferenceTimestamp as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ReferenceFrameFields);
+crate::impl_ord!(ReferenceFrameFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Tracks {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_entry: Vec<Ebml<TrackEntry>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Tracks {
-    pub fn elements(&self) -> std::collections::BTreeSet<TracksFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TracksFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.track_entry { elements.insert(TracksFields::TrackEntry(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TracksFields::Crc32(el)); }
+        for el in &self.track_entry { elements.insert(TracksFields::TrackEntry(el)); }
+        for el in &self.unknown { elements.insert(TracksFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TracksFields {
-    TrackEntry(Ebml<TrackEntry>),
+pub enum TracksFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackEntry(&'a Ebml<TrackEntry>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl TracksFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl TracksFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackEntry(val) => (EbmlId::TrackEntry, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackEntry(val) => (EbmlId::TrackEntry as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TracksFields);
+crate::impl_ord!(TracksFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackEntry {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_number: Ebml<u64>,
     pub track_uid: Ebml<u64>,
-    pub track_type: Ebml<u64>,
+    pub track_type: Ebml<TrackType>,
     pub flag_enabled: Ebml<u64>,
     pub flag_default: Ebml<u64>,
     pub flag_forced: Ebml<u64>,
@@ -670,237 +861,270 @@ pub struct TrackEntry {
     pub trick_struct_track_uid: Option<Ebml<u64>>,
     pub trick_struct_track_segment_uid: Option<Ebml<Vec<u8>>>,
     pub content_encodings: Option<Ebml<ContentEncodings>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackEntry {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackEntryFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackEntryFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(TrackEntryFields::TrackNumber(self.track_number.clone()));
-        elements.insert(TrackEntryFields::TrackUid(self.track_uid.clone()));
-        elements.insert(TrackEntryFields::TrackType(self.track_type.clone()));
-        elements.insert(TrackEntryFields::FlagEnabled(self.flag_enabled.clone()));
-        elements.insert(TrackEntryFields::FlagDefault(self.flag_default.clone()));
-        elements.insert(TrackEntryFields::FlagForced(self.flag_forced.clone()));
-        if let Some(el) = &self.flag_hearing_impaired { elements.insert(TrackEntryFields::FlagHearingImpaired(el.clone())); }
-        if let Some(el) = &self.flag_visual_impaired { elements.insert(TrackEntryFields::FlagVisualImpaired(el.clone())); }
-        if let Some(el) = &self.flag_text_descriptions { elements.insert(TrackEntryFields::FlagTextDescriptions(el.clone())); }
-        if let Some(el) = &self.flag_original { elements.insert(TrackEntryFields::FlagOriginal(el.clone())); }
-        if let Some(el) = &self.flag_commentary { elements.insert(TrackEntryFields::FlagCommentary(el.clone())); }
-        elements.insert(TrackEntryFields::FlagLacing(self.flag_lacing.clone()));
-        elements.insert(TrackEntryFields::MinCache(self.min_cache.clone()));
-        if let Some(el) = &self.max_cache { elements.insert(TrackEntryFields::MaxCache(el.clone())); }
-        if let Some(el) = &self.default_duration { elements.insert(TrackEntryFields::DefaultDuration(el.clone())); }
-        if let Some(el) = &self.default_decoded_field_duration { elements.insert(TrackEntryFields::DefaultDecodedFieldDuration(el.clone())); }
-        elements.insert(TrackEntryFields::TrackTimestampScale(self.track_timestamp_scale.clone()));
-        if let Some(el) = &self.track_offset { elements.insert(TrackEntryFields::TrackOffset(el.clone())); }
-        elements.insert(TrackEntryFields::MaxBlockAdditionId(self.max_block_addition_id.clone()));
-        for el in &self.block_addition_mapping { elements.insert(TrackEntryFields::BlockAdditionMapping(el.clone())); }
-        if let Some(el) = &self.name { elements.insert(TrackEntryFields::Name(el.clone())); }
-        elements.insert(TrackEntryFields::Language(self.language.clone()));
-        if let Some(el) = &self.language_bcp_47 { elements.insert(TrackEntryFields::LanguageBcp47(el.clone())); }
-        elements.insert(TrackEntryFields::CodecId(self.codec_id.clone()));
-        if let Some(el) = &self.codec_private { elements.insert(TrackEntryFields::CodecPrivate(el.clone())); }
-        if let Some(el) = &self.codec_name { elements.insert(TrackEntryFields::CodecName(el.clone())); }
-        if let Some(el) = &self.attachment_link { elements.insert(TrackEntryFields::AttachmentLink(el.clone())); }
-        if let Some(el) = &self.codec_settings { elements.insert(TrackEntryFields::CodecSettings(el.clone())); }
-        for el in &self.codec_info_url { elements.insert(TrackEntryFields::CodecInfoUrl(el.clone())); }
-        for el in &self.codec_download_url { elements.insert(TrackEntryFields::CodecDownloadUrl(el.clone())); }
-        elements.insert(TrackEntryFields::CodecDecodeAll(self.codec_decode_all.clone()));
-        for el in &self.track_overlay { elements.insert(TrackEntryFields::TrackOverlay(el.clone())); }
-        elements.insert(TrackEntryFields::CodecDelay(self.codec_delay.clone()));
-        elements.insert(TrackEntryFields::SeekPreRoll(self.seek_pre_roll.clone()));
-        for el in &self.track_translate { elements.insert(TrackEntryFields::TrackTranslate(el.clone())); }
-        if let Some(el) = &self.video { elements.insert(TrackEntryFields::Video(el.clone())); }
-        if let Some(el) = &self.audio { elements.insert(TrackEntryFields::Audio(el.clone())); }
-        if let Some(el) = &self.track_operation { elements.insert(TrackEntryFields::TrackOperation(el.clone())); }
-        if let Some(el) = &self.trick_track_uid { elements.insert(TrackEntryFields::TrickTrackUid(el.clone())); }
-        if let Some(el) = &self.trick_track_segment_uid { elements.insert(TrackEntryFields::TrickTrackSegmentUid(el.clone())); }
-        if let Some(el) = &self.trick_track_flag { elements.insert(TrackEntryFields::TrickTrackFlag(el.clone())); }
-        if let Some(el) = &self.trick_struct_track_uid { elements.insert(TrackEntryFields::TrickStructTrackUid(el.clone())); }
-        if let Some(el) = &self.trick_struct_track_segment_uid { elements.insert(TrackEntryFields::TrickStructTrackSegmentUid(el.clone())); }
-        if let Some(el) = &self.content_encodings { elements.insert(TrackEntryFields::ContentEncodings(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TrackEntryFields::Crc32(el)); }
+        elements.insert(TrackEntryFields::TrackNumber(&self.track_number));
+        elements.insert(TrackEntryFields::TrackUid(&self.track_uid));
+        elements.insert(TrackEntryFields::TrackType(&self.track_type));
+        elements.insert(TrackEntryFields::FlagEnabled(&self.flag_enabled));
+        elements.insert(TrackEntryFields::FlagDefault(&self.flag_default));
+        elements.insert(TrackEntryFields::FlagForced(&self.flag_forced));
+        if let Some(el) = &self.flag_hearing_impaired { elements.insert(TrackEntryFields::FlagHearingImpaired(el)); }
+        if let Some(el) = &self.flag_visual_impaired { elements.insert(TrackEntryFields::FlagVisualImpaired(el)); }
+        if let Some(el) = &self.flag_text_descriptions { elements.insert(TrackEntryFields::FlagTextDescriptions(el)); }
+        if let Some(el) = &self.flag_original { elements.insert(TrackEntryFields::FlagOriginal(el)); }
+        if let Some(el) = &self.flag_commentary { elements.insert(TrackEntryFields::FlagCommentary(el)); }
+        elements.insert(TrackEntryFields::FlagLacing(&self.flag_lacing));
+        elements.insert(TrackEntryFields::MinCache(&self.min_cache));
+        if let Some(el) = &self.max_cache { elements.insert(TrackEntryFields::MaxCache(el)); }
+        if let Some(el) = &self.default_duration { elements.insert(TrackEntryFields::DefaultDuration(el)); }
+        if let Some(el) = &self.default_decoded_field_duration { elements.insert(TrackEntryFields::DefaultDecodedFieldDuration(el)); }
+        elements.insert(TrackEntryFields::TrackTimestampScale(&self.track_timestamp_scale));
+        if let Some(el) = &self.track_offset { elements.insert(TrackEntryFields::TrackOffset(el)); }
+        elements.insert(TrackEntryFields::MaxBlockAdditionId(&self.max_block_addition_id));
+        for el in &self.block_addition_mapping { elements.insert(TrackEntryFields::BlockAdditionMapping(el)); }
+        if let Some(el) = &self.name { elements.insert(TrackEntryFields::Name(el)); }
+        elements.insert(TrackEntryFields::Language(&self.language));
+        if let Some(el) = &self.language_bcp_47 { elements.insert(TrackEntryFields::LanguageBcp47(el)); }
+        elements.insert(TrackEntryFields::CodecId(&self.codec_id));
+        if let Some(el) = &self.codec_private { elements.insert(TrackEntryFields::CodecPrivate(el)); }
+        if let Some(el) = &self.codec_name { elements.insert(TrackEntryFields::CodecName(el)); }
+        if let Some(el) = &self.attachment_link { elements.insert(TrackEntryFields::AttachmentLink(el)); }
+        if let Some(el) = &self.codec_settings { elements.insert(TrackEntryFields::CodecSettings(el)); }
+        for el in &self.codec_info_url { elements.insert(TrackEntryFields::CodecInfoUrl(el)); }
+        for el in &self.codec_download_url { elements.insert(TrackEntryFields::CodecDownloadUrl(el)); }
+        elements.insert(TrackEntryFields::CodecDecodeAll(&self.codec_decode_all));
+        for el in &self.track_overlay { elements.insert(TrackEntryFields::TrackOverlay(el)); }
+        elements.insert(TrackEntryFields::CodecDelay(&self.codec_delay));
+        elements.insert(TrackEntryFields::SeekPreRoll(&self.seek_pre_roll));
+        for el in &self.track_translate { elements.insert(TrackEntryFields::TrackTranslate(el)); }
+        if let Some(el) = &self.video { elements.insert(TrackEntryFields::Video(el)); }
+        if let Some(el) = &self.audio { elements.insert(TrackEntryFields::Audio(el)); }
+        if let Some(el) = &self.track_operation { elements.insert(TrackEntryFields::TrackOperation(el)); }
+        if let Some(el) = &self.trick_track_uid { elements.insert(TrackEntryFields::TrickTrackUid(el)); }
+        if let Some(el) = &self.trick_track_segment_uid { elements.insert(TrackEntryFields::TrickTrackSegmentUid(el)); }
+        if let Some(el) = &self.trick_track_flag { elements.insert(TrackEntryFields::TrickTrackFlag(el)); }
+        if let Some(el) = &self.trick_struct_track_uid { elements.insert(TrackEntryFields::TrickStructTrackUid(el)); }
+        if let Some(el) = &self.trick_struct_track_segment_uid { elements.insert(TrackEntryFields::TrickStructTrackSegmentUid(el)); }
+        if let Some(el) = &self.content_encodings { elements.insert(TrackEntryFields::ContentEncodings(el)); }
+        for el in &self.unknown { elements.insert(TrackEntryFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackEntryFields {
-    TrackNumber(Ebml<u64>),
-    TrackUid(Ebml<u64>),
-    TrackType(Ebml<u64>),
-    FlagEnabled(Ebml<u64>),
-    FlagDefault(Ebml<u64>),
-    FlagForced(Ebml<u64>),
-    FlagHearingImpaired(Ebml<u64>),
-    FlagVisualImpaired(Ebml<u64>),
-    FlagTextDescriptions(Ebml<u64>),
-    FlagOriginal(Ebml<u64>),
-    FlagCommentary(Ebml<u64>),
-    FlagLacing(Ebml<u64>),
-    MinCache(Ebml<u64>),
-    MaxCache(Ebml<u64>),
-    DefaultDuration(Ebml<u64>),
-    DefaultDecodedFieldDuration(Ebml<u64>),
-    TrackTimestampScale(Ebml<f64>),
-    TrackOffset(Ebml<i64>),
-    MaxBlockAdditionId(Ebml<u64>),
-    BlockAdditionMapping(Ebml<BlockAdditionMapping>),
-    Name(Ebml<String>),
-    Language(Ebml<String>),
-    LanguageBcp47(Ebml<String>),
-    CodecId(Ebml<String>),
-    CodecPrivate(Ebml<Vec<u8>>),
-    CodecName(Ebml<String>),
-    AttachmentLink(Ebml<u64>),
-    CodecSettings(Ebml<String>),
-    CodecInfoUrl(Ebml<String>),
-    CodecDownloadUrl(Ebml<String>),
-    CodecDecodeAll(Ebml<u64>),
-    TrackOverlay(Ebml<u64>),
-    CodecDelay(Ebml<u64>),
-    SeekPreRoll(Ebml<u64>),
-    TrackTranslate(Ebml<TrackTranslate>),
-    Video(Ebml<Video>),
-    Audio(Ebml<Audio>),
-    TrackOperation(Ebml<TrackOperation>),
-    TrickTrackUid(Ebml<u64>),
-    TrickTrackSegmentUid(Ebml<Vec<u8>>),
-    TrickTrackFlag(Ebml<u64>),
-    TrickStructTrackUid(Ebml<u64>),
-    TrickStructTrackSegmentUid(Ebml<Vec<u8>>),
-    ContentEncodings(Ebml<ContentEncodings>),
-}
-impl TrackEntryFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TrackEntryFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackNumber(&'a Ebml<u64>),
+    TrackUid(&'a Ebml<u64>),
+    TrackType(&'a Ebml<TrackType>),
+    FlagEnabled(&'a Ebml<u64>),
+    FlagDefault(&'a Ebml<u64>),
+    FlagForced(&'a Ebml<u64>),
+    FlagHearingImpaired(&'a Ebml<u64>),
+    FlagVisualImpaired(&'a Ebml<u64>),
+    FlagTextDescriptions(&'a Ebml<u64>),
+    FlagOriginal(&'a Ebml<u64>),
+    FlagCommentary(&'a Ebml<u64>),
+    FlagLacing(&'a Ebml<u64>),
+    MinCache(&'a Ebml<u64>),
+    MaxCache(&'a Ebml<u64>),
+    DefaultDuration(&'a Ebml<u64>),
+    DefaultDecodedFieldDuration(&'a Ebml<u64>),
+    TrackTimestampScale(&'a Ebml<f64>),
+    TrackOffset(&'a Ebml<i64>),
+    MaxBlockAdditionId(&'a Ebml<u64>),
+    BlockAdditionMapping(&'a Ebml<BlockAdditionMapping>),
+    Name(&'a Ebml<String>),
+    Language(&'a Ebml<String>),
+    LanguageBcp47(&'a Ebml<String>),
+    CodecId(&'a Ebml<String>),
+    CodecPrivate(&'a Ebml<Vec<u8>>),
+    CodecName(&'a Ebml<String>),
+    AttachmentLink(&'a Ebml<u64>),
+    CodecSettings(&'a Ebml<String>),
+    CodecInfoUrl(&'a Ebml<String>),
+    CodecDownloadUrl(&'a Ebml<String>),
+    CodecDecodeAll(&'a Ebml<u64>),
+    TrackOverlay(&'a Ebml<u64>),
+    CodecDelay(&'a Ebml<u64>),
+    SeekPreRoll(&'a Ebml<u64>),
+    TrackTranslate(&'a Ebml<TrackTranslate>),
+    Video(&'a Ebml<Video>),
+    Audio(&'a Ebml<Audio>),
+    TrackOperation(&'a Ebml<TrackOperation>),
+    TrickTrackUid(&'a Ebml<u64>),
+    TrickTrackSegmentUid(&'a Ebml<Vec<u8>>),
+    TrickTrackFlag(&'a Ebml<u64>),
+    TrickStructTrackUid(&'a Ebml<u64>),
+    TrickStructTrackSegmentUid(&'a Ebml<Vec<u8>>),
+    ContentEncodings(&'a Ebml<ContentEncodings>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TrackEntryFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackNumber(val) => (EbmlId::TrackNumber, val.index, val.id),
-            Self::TrackUid(val) => (EbmlId::TrackUid, val.index, val.id),
-            Self::TrackType(val) => (EbmlId::TrackType, val.index, val.id),
-            Self::FlagEnabled(val) => (EbmlId::FlagEnabled, val.index, val.id),
-            Self::FlagDefault(val) => (EbmlId::FlagDefault, val.index, val.id),
-            Self::FlagForced(val) => (EbmlId::FlagForced, val.index, val.id),
-            Self::FlagHearingImpaired(val) => (EbmlId::FlagHearingImpaired, val.index, val.id),
-            Self::FlagVisualImpaired(val) => (EbmlId::FlagVisualImpaired, val.index, val.id),
-            Self::FlagTextDescriptions(val) => (EbmlId::FlagTextDescriptions, val.index, val.id),
-            Self::FlagOriginal(val) => (EbmlId::FlagOriginal, val.index, val.id),
-            Self::FlagCommentary(val) => (EbmlId::FlagCommentary, val.index, val.id),
-            Self::FlagLacing(val) => (EbmlId::FlagLacing, val.index, val.id),
-            Self::MinCache(val) => (EbmlId::MinCache, val.index, val.id),
-            Self::MaxCache(val) => (EbmlId::MaxCache, val.index, val.id),
-            Self::DefaultDuration(val) => (EbmlId::DefaultDuration, val.index, val.id),
-            Self::DefaultDecodedFieldDuration(val) => (EbmlId::DefaultDecodedFieldDuration, val.index, val.id),
-            Self::TrackTimestampScale(val) => (EbmlId::TrackTimestampScale, val.index, val.id),
-            Self::TrackOffset(val) => (EbmlId::TrackOffset, val.index, val.id),
-            Self::MaxBlockAdditionId(val) => (EbmlId::MaxBlockAdditionId, val.index, val.id),
-            Self::BlockAdditionMapping(val) => (EbmlId::BlockAdditionMapping, val.index, val.id),
-            Self::Name(val) => (EbmlId::Name, val.index, val.id),
-            Self::Language(val) => (EbmlId::Language, val.index, val.id),
-            Self::LanguageBcp47(val) => (EbmlId::LanguageBcp47, val.index, val.id),
-            Self::CodecId(val) => (EbmlId::CodecId, val.index, val.id),
-            Self::CodecPrivate(val) => (EbmlId::CodecPrivate, val.index, val.id),
-            Self::CodecName(val) => (EbmlId::CodecName, val.index, val.id),
-            Self::AttachmentLink(val) => (EbmlId::AttachmentLink, val.index, val.id),
-            Self::CodecSettings(val) => (EbmlId::CodecSettings, val.index, val.id),
-            Self::CodecInfoUrl(val) => (EbmlId::CodecInfoUrl, val.index, val.id),
-            Self::CodecDownloadUrl(val) => (EbmlId::CodecDownloadUrl, val.index, val.id),
-            Self::CodecDecodeAll(val) => (EbmlId::CodecDecodeAll, val.index, val.id),
-            Self::TrackOverlay(val) => (EbmlId::TrackOverlay, val.index, val.id),
-            Self::CodecDelay(val) => (EbmlId::CodecDelay, val.index, val.id),
-            Self::SeekPreRoll(val) => (EbmlId::SeekPreRoll, val.index, val.id),
-            Self::TrackTranslate(val) => (EbmlId::TrackTranslate, val.index, val.id),
-            Self::Video(val) => (EbmlId::Video, val.index, val.id),
-            Self::Audio(val) => (EbmlId::Audio, val.index, val.id),
-            Self::TrackOperation(val) => (EbmlId::TrackOperation, val.index, val.id),
-            Self::TrickTrackUid(val) => (EbmlId::TrickTrackUid, val.index, val.id),
-            Self::TrickTrackSegmentUid(val) => (EbmlId::TrickTrackSegmentUid, val.index, val.id),
-            Self::TrickTrackFlag(val) => (EbmlId::TrickTrackFlag, val.index, val.id),
-            Self::TrickStructTrackUid(val) => (EbmlId::TrickStructTrackUid, val.index, val.id),
-            Self::TrickStructTrackSegmentUid(val) => (EbmlId::TrickStructTrackSegmentUid, val.index, val.id),
-            Self::ContentEncodings(val) => (EbmlId::ContentEncodings, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackNumber(val) => (EbmlId::TrackNumber as u64, val.index, val.id),
+            Self::TrackUid(val) => (EbmlId::TrackUid as u64, val.index, val.id),
+            Self::TrackType(val) => (EbmlId::TrackType as u64, val.index, val.id),
+            Self::FlagEnabled(val) => (EbmlId::FlagEnabled as u64, val.index, val.id),
+            Self::FlagDefault(val) => (EbmlId::FlagDefault as u64, val.index, val.id),
+            Self::FlagForced(val) => (EbmlId::FlagForced as u64, val.index, val.id),
+            Self::FlagHearingImpaired(val) => (EbmlId::FlagHearingImpaired as u64, val.index, val.id),
+            Self::FlagVisualImpaired(val) => (EbmlId::FlagVisualImpaired as u64, val.index, val.id),
+            Self::FlagTextDescriptions(val) => (EbmlId::FlagTextDescriptions as u64, val.index, val.id),
+            Self::FlagOriginal(val) => (EbmlId::FlagOriginal as u64, val.index, val.id),
+            Self::FlagCommentary(val) => (EbmlId::FlagCommentary as u64, val.index, val.id),
+            Self::FlagLacing(val) => (EbmlId::FlagLacing as u64, val.index, val.id),
+            Self::MinCache(val) => (EbmlId::MinCache as u64, val.index, val.id),
+            Self::MaxCache(val) => (EbmlId::MaxCache as u64, val.index, val.id),
+            Self::DefaultDuration(val) => (EbmlId::DefaultDuration as u64, val.index, val.id),
+            Self::DefaultDecodedFieldDuration(val) => (EbmlId::DefaultDecodedFieldDuration as u64, val.index, val.id),
+            Self::TrackTimestampScale(val) => (EbmlId::TrackTimestampScale as u64, val.index, val.id),
+            Self::TrackOffset(val) => (EbmlId::TrackOffset as u64, val.index, val.id),
+            Self::MaxBlockAdditionId(val) => (EbmlId::MaxBlockAdditionId as u64, val.index, val.id),
+            Self::BlockAdditionMapping(val) => (EbmlId::BlockAdditionMapping as u64, val.index, val.id),
+            Self::Name(val) => (EbmlId::Name as u64, val.index, val.id),
+            Self::Language(val) => (EbmlId::Language as u64, val.index, val.id),
+            Self::LanguageBcp47(val) => (EbmlId::LanguageBcp47 as u64, val.index, val.id),
+            Self::CodecId(val) => (EbmlId::CodecId as u64, val.index, val.id),
+            Self::CodecPrivate(val) => (EbmlId::CodecPrivate as u64, val.index, val.id),
+            Self::CodecName(val) => (EbmlId::CodecName as u64, val.index, val.id),
+            Self::AttachmentLink(val) => (EbmlId::AttachmentLink as u64, val.index, val.id),
+            Self::CodecSettings(val) => (EbmlId::CodecSettings as u64, val.index, val.id),
+            Self::CodecInfoUrl(val) => (EbmlId::CodecInfoUrl as u64, val.index, val.id),
+            Self::CodecDownloadUrl(val) => (EbmlId::CodecDownloadUrl as u64, val.index, val.id),
+            Self::CodecDecodeAll(val) => (EbmlId::CodecDecodeAll as u64, val.index, val.id),
+            Self::TrackOverlay(val) => (EbmlId::TrackOverlay as u64, val.index, val.id),
+            Self::CodecDelay(val) => (EbmlId::CodecDelay as u64, val.index, val.id),
+            Self::SeekPreRoll(val) => (EbmlId::SeekPreRoll as u64, val.index, val.id),
+            Self::TrackTranslate(val) => (EbmlId::TrackTranslate as u64, val.index, val.id),
+            Self::Video(val) => (EbmlId::Video as u64, val.index, val.id),
+            Self::Audio(val) => (EbmlId::Audio as u64, val.index, val.id),
+            Self::TrackOperation(val) => (EbmlId::TrackOperation as u64, val.index, val.id),
+            Self::TrickTrackUid(val) => (EbmlId::TrickTrackUid as u64, val.index, val.id),
+            Self::TrickTrackSegmentUid(val) => (EbmlId::TrickTrackSegmentUid as u64, val.index, val.id),
+            Self::TrickTrackFlag(val) => (EbmlId::TrickTrackFlag as u64, val.index, val.id),
+            Self::TrickStructTrackUid(val) => (EbmlId::TrickStructTrackUid as u64, val.index, val.id),
+            Self::TrickStructTrackSegmentUid(val) => (EbmlId::TrickStructTrackSegmentUid as u64, val.index, val.id),
+            Self::ContentEncodings(val) => (EbmlId::ContentEncodings as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackEntryFields);
+crate::impl_ord!(TrackEntryFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct BlockAdditionMapping {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub block_add_id_value: Option<Ebml<u64>>,
     pub block_add_id_name: Option<Ebml<String>>,
     pub block_add_id_type: Ebml<u64>,
     pub block_add_id_extra_data: Option<Ebml<Vec<u8>>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl BlockAdditionMapping {
-    pub fn elements(&self) -> std::collections::BTreeSet<BlockAdditionMappingFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<BlockAdditionMappingFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.block_add_id_value { elements.insert(BlockAdditionMappingFields::BlockAddIdValue(el.clone())); }
-        if let Some(el) = &self.block_add_id_name { elements.insert(BlockAdditionMappingFields::BlockAddIdName(el.clone())); }
-        elements.insert(BlockAdditionMappingFields::BlockAddIdType(self.block_add_id_type.clone()));
-        if let Some(el) = &self.block_add_id_extra_data { elements.insert(BlockAdditionMappingFields::BlockAddIdExtraData(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(BlockAdditionMappingFields::Crc32(el)); }
+        if let Some(el) = &self.block_add_id_value { elements.insert(BlockAdditionMappingFields::BlockAddIdValue(el)); }
+        if let Some(el) = &self.block_add_id_name { elements.insert(BlockAdditionMappingFields::BlockAddIdName(el)); }
+        elements.insert(BlockAdditionMappingFields::BlockAddIdType(&self.block_add_id_type));
+        if let Some(el) = &self.block_add_id_extra_data { elements.insert(BlockAdditionMappingFields::BlockAddIdExtraData(el)); }
+        for el in &self.unknown { elements.insert(BlockAdditionMappingFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum BlockAdditionMappingFields {
-    BlockAddIdValue(Ebml<u64>),
-    BlockAddIdName(Ebml<String>),
-    BlockAddIdType(Ebml<u64>),
-    BlockAddIdExtraData(Ebml<Vec<u8>>),
-}
-impl BlockAdditionMappingFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum BlockAdditionMappingFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    BlockAddIdValue(&'a Ebml<u64>),
+    BlockAddIdName(&'a Ebml<String>),
+    BlockAddIdType(&'a Ebml<u64>),
+    BlockAddIdExtraData(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl BlockAdditionMappingFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::BlockAddIdValue(val) => (EbmlId::BlockAddIdValue, val.index, val.id),
-            Self::BlockAddIdName(val) => (EbmlId::BlockAddIdName, val.index, val.id),
-            Self::BlockAddIdType(val) => (EbmlId::BlockAddIdType, val.index, val.id),
-            Self::BlockAddIdExtraData(val) => (EbmlId::BlockAddIdExtraData, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::BlockAddIdValue(val) => (EbmlId::BlockAddIdValue as u64, val.index, val.id),
+            Self::BlockAddIdName(val) => (EbmlId::BlockAddIdName as u64, val.index, val.id),
+            Self::BlockAddIdType(val) => (EbmlId::BlockAddIdType as u64, val.index, val.id),
+            Self::BlockAddIdExtraData(val) => (EbmlId::BlockAddIdExtraData as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(BlockAdditionMappingFields);
+crate::impl_ord!(BlockAdditionMappingFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackTranslate {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_translate_track_id: Ebml<Vec<u8>>,
-    pub track_translate_codec: Ebml<u64>,
+    pub track_translate_codec: Ebml<TrackTranslateCodec>,
     pub track_translate_edition_uid: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackTranslate {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackTranslateFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackTranslateFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(TrackTranslateFields::TrackTranslateTrackId(self.track_translate_track_id.clone()));
-        elements.insert(TrackTranslateFields::TrackTranslateCodec(self.track_translate_codec.clone()));
-        for el in &self.track_translate_edition_uid { elements.insert(TrackTranslateFields::TrackTranslateEditionUid(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TrackTranslateFields::Crc32(el)); }
+        elements.insert(TrackTranslateFields::TrackTranslateTrackId(&self.track_translate_track_id));
+        elements.insert(TrackTranslateFields::TrackTranslateCodec(&self.track_translate_codec));
+        for el in &self.track_translate_edition_uid { elements.insert(TrackTranslateFields::TrackTranslateEditionUid(el)); }
+        for el in &self.unknown { elements.insert(TrackTranslateFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackTranslateFields {
-    TrackTranslateTrackId(Ebml<Vec<u8>>),
-    TrackTranslateCodec(Ebml<u64>),
-    TrackTranslateEditionUid(Ebml<u64>),
-}
-impl TrackTranslateFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TrackTranslateFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackTranslateTrackId(&'a Ebml<Vec<u8>>),
+    TrackTranslateCodec(&'a Ebml<TrackTranslateCodec>),
+    TrackTranslateEditionUid(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TrackTranslateFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackTranslateTrackId(val) => (EbmlId::TrackTranslateTrackId, val.index, val.id),
-            Self::TrackTranslateCodec(val) => (EbmlId::TrackTranslateCodec, val.index, val.id),
-            Self::TrackTranslateEditionUid(val) => (EbmlId::TrackTranslateEditionUid, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackTranslateTrackId(val) => (EbmlId::TrackTranslateTrackId as u64, val.index, val.id),
+            Self::TrackTranslateCodec(val) => (EbmlId::TrackTranslateCodec as u64, val.index, val.id),
+            Self::TrackTranslateEditionUid(val) => (EbmlId::TrackTranslateEditionUid as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackTranslateFields);
+crate::impl_ord!(TrackTranslateFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Video {
     pub size: u64,
 
-    pub flag_interlaced: Ebml<u64>,
-    pub field_order: Ebml<u64>,
-    pub stereo_mode: Ebml<u64>,
-    pub alpha_mode: Ebml<u64>,
-    pub old_stereo_mode: Option<Ebml<u64>>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub flag_interlaced: Ebml<FlagInterlaced>,
+    pub field_order: Ebml<FieldOrder>,
+    pub stereo_mode: Ebml<StereoMode>,
+    pub alpha_mode: Ebml<AlphaMode>,
+    pub old_stereo_mode: Option<Ebml<OldStereoMode>>,
     pub pixel_width: Ebml<u64>,
     pub pixel_height: Ebml<u64>,
     pub pixel_crop_bottom: Ebml<u64>,
@@ -909,173 +1133,195 @@ pub struct Video {
     pub pixel_crop_right: Ebml<u64>,
     pub display_width: Option<Ebml<u64>>,
     pub display_height: Option<Ebml<u64>>,
-    pub display_unit: Ebml<u64>,
-    pub aspect_ratio_type: Option<Ebml<u64>>,
+    pub display_unit: Ebml<DisplayUnit>,
+    pub aspect_ratio_type: Option<Ebml<AspectRatioType>>,
     pub uncompressed_four_cc: Option<Ebml<Vec<u8>>>,
     pub gamma_value: Option<Ebml<f64>>,
     pub frame_rate: Option<Ebml<f64>>,
     pub colour: Option<Ebml<Colour>>,
     pub projection: Option<Ebml<Projection>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Video {
-    pub fn elements(&self) -> std::collections::BTreeSet<VideoFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<VideoFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(VideoFields::FlagInterlaced(self.flag_interlaced.clone()));
-        elements.insert(VideoFields::FieldOrder(self.field_order.clone()));
-        elements.insert(VideoFields::StereoMode(self.stereo_mode.clone()));
-        elements.insert(VideoFields::AlphaMode(self.alpha_mode.clone()));
-        if let Some(el) = &self.old_stereo_mode { elements.insert(VideoFields::OldStereoMode(el.clone())); }
-        elements.insert(VideoFields::PixelWidth(self.pixel_width.clone()));
-        elements.insert(VideoFields::PixelHeight(self.pixel_height.clone()));
-        elements.insert(VideoFields::PixelCropBottom(self.pixel_crop_bottom.clone()));
-        elements.insert(VideoFields::PixelCropTop(self.pixel_crop_top.clone()));
-        elements.insert(VideoFields::PixelCropLeft(self.pixel_crop_left.clone()));
-        elements.insert(VideoFields::PixelCropRight(self.pixel_crop_right.clone()));
-        if let Some(el) = &self.display_width { elements.insert(VideoFields::DisplayWidth(el.clone())); }
-        if let Some(el) = &self.display_height { elements.insert(VideoFields::DisplayHeight(el.clone())); }
-        elements.insert(VideoFields::DisplayUnit(self.display_unit.clone()));
-        if let Some(el) = &self.aspect_ratio_type { elements.insert(VideoFields::AspectRatioType(el.clone())); }
-        if let Some(el) = &self.uncompressed_four_cc { elements.insert(VideoFields::UncompressedFourCc(el.clone())); }
-        if let Some(el) = &self.gamma_value { elements.insert(VideoFields::GammaValue(el.clone())); }
-        if let Some(el) = &self.frame_rate { elements.insert(VideoFields::FrameRate(el.clone())); }
-        if let Some(el) = &self.colour { elements.insert(VideoFields::Colour(el.clone())); }
-        if let Some(el) = &self.projection { elements.insert(VideoFields::Projection(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(VideoFields::Crc32(el)); }
+        elements.insert(VideoFields::FlagInterlaced(&self.flag_interlaced));
+        elements.insert(VideoFields::FieldOrder(&self.field_order));
+        elements.insert(VideoFields::StereoMode(&self.stereo_mode));
+        elements.insert(VideoFields::AlphaMode(&self.alpha_mode));
+        if let Some(el) = &self.old_stereo_mode { elements.insert(VideoFields::OldStereoMode(el)); }
+        elements.insert(VideoFields::PixelWidth(&self.pixel_width));
+        elements.insert(VideoFields::PixelHeight(&self.pixel_height));
+        elements.insert(VideoFields::PixelCropBottom(&self.pixel_crop_bottom));
+        elements.insert(VideoFields::PixelCropTop(&self.pixel_crop_top));
+        elements.insert(VideoFields::PixelCropLeft(&self.pixel_crop_left));
+        elements.insert(VideoFields::PixelCropRight(&self.pixel_crop_right));
+        if let Some(el) = &self.display_width { elements.insert(VideoFields::DisplayWidth(el)); }
+        if let Some(el) = &self.display_height { elements.insert(VideoFields::DisplayHeight(el)); }
+        elements.insert(VideoFields::DisplayUnit(&self.display_unit));
+        if let Some(el) = &self.aspect_ratio_type { elements.insert(VideoFields::AspectRatioType(el)); }
+        if let Some(el) = &self.uncompressed_four_cc { elements.insert(VideoFields::UncompressedFourCc(el)); }
+        if let Some(el) = &self.gamma_value { elements.insert(VideoFields::GammaValue(el)); }
+        if let Some(el) = &self.frame_rate { elements.insert(VideoFields::FrameRate(el)); }
+        if let Some(el) = &self.colour { elements.insert(VideoFields::Colour(el)); }
+        if let Some(el) = &self.projection { elements.insert(VideoFields::Projection(el)); }
+        for el in &self.unknown { elements.insert(VideoFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum VideoFields {
-    FlagInterlaced(Ebml<u64>),
-    FieldOrder(Ebml<u64>),
-    StereoMode(Ebml<u64>),
-    AlphaMode(Ebml<u64>),
-    OldStereoMode(Ebml<u64>),
-    PixelWidth(Ebml<u64>),
-    PixelHeight(Ebml<u64>),
-    PixelCropBottom(Ebml<u64>),
-    PixelCropTop(Ebml<u64>),
-    PixelCropLeft(Ebml<u64>),
-    PixelCropRight(Ebml<u64>),
-    DisplayWidth(Ebml<u64>),
-    DisplayHeight(Ebml<u64>),
-    DisplayUnit(Ebml<u64>),
-    AspectRatioType(Ebml<u64>),
-    UncompressedFourCc(Ebml<Vec<u8>>),
-    GammaValue(Ebml<f64>),
-    FrameRate(Ebml<f64>),
-    Colour(Ebml<Colour>),
-    Projection(Ebml<Projection>),
-}
-impl VideoFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum VideoFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    FlagInterlaced(&'a Ebml<FlagInterlaced>),
+    FieldOrder(&'a Ebml<FieldOrder>),
+    StereoMode(&'a Ebml<StereoMode>),
+    AlphaMode(&'a Ebml<AlphaMode>),
+    OldStereoMode(&'a Ebml<OldStereoMode>),
+    PixelWidth(&'a Ebml<u64>),
+    PixelHeight(&'a Ebml<u64>),
+    PixelCropBottom(&'a Ebml<u64>),
+    PixelCropTop(&'a Ebml<u64>),
+    PixelCropLeft(&'a Ebml<u64>),
+    PixelCropRight(&'a Ebml<u64>),
+    DisplayWidth(&'a Ebml<u64>),
+    DisplayHeight(&'a Ebml<u64>),
+    DisplayUnit(&'a Ebml<DisplayUnit>),
+    AspectRatioType(&'a Ebml<AspectRatioType>),
+    UncompressedFourCc(&'a Ebml<Vec<u8>>),
+    GammaValue(&'a Ebml<f64>),
+    FrameRate(&'a Ebml<f64>),
+    Colour(&'a Ebml<Colour>),
+    Projection(&'a Ebml<Projection>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl VideoFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::FlagInterlaced(val) => (EbmlId::FlagInterlaced, val.index, val.id),
-            Self::FieldOrder(val) => (EbmlId::FieldOrder, val.index, val.id),
-            Self::StereoMode(val) => (EbmlId::StereoMode, val.index, val.id),
-            Self::AlphaMode(val) => (EbmlId::AlphaMode, val.index, val.id),
-            Self::OldStereoMode(val) => (EbmlId::OldStereoMode, val.index, val.id),
-            Self::PixelWidth(val) => (EbmlId::PixelWidth, val.index, val.id),
-            Self::PixelHeight(val) => (EbmlId::PixelHeight, val.index, val.id),
-            Self::PixelCropBottom(val) => (EbmlId::PixelCropBottom, val.index, val.id),
-            Self::PixelCropTop(val) => (EbmlId::PixelCropTop, val.index, val.id),
-            Self::PixelCropLeft(val) => (EbmlId::PixelCropLeft, val.index, val.id),
-            Self::PixelCropRight(val) => (EbmlId::PixelCropRight, val.index, val.id),
-            Self::DisplayWidth(val) => (EbmlId::DisplayWidth, val.index, val.id),
-            Self::DisplayHeight(val) => (EbmlId::DisplayHeight, val.index, val.id),
-            Self::DisplayUnit(val) => (EbmlId::DisplayUnit, val.index, val.id),
-            Self::AspectRatioType(val) => (EbmlId::AspectRatioType, val.index, val.id),
-            Self::UncompressedFourCc(val) => (EbmlId::UncompressedFourCc, val.index, val.id),
-            Self::GammaValue(val) => (EbmlId::GammaValue, val.index, val.id),
-            Self::FrameRate(val) => (EbmlId::FrameRate, val.index, val.id),
-            Self::Colour(val) => (EbmlId::Colour, val.index, val.id),
-            Self::Projection(val) => (EbmlId::Projection, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::FlagInterlaced(val) => (EbmlId::FlagInterlaced as u64, val.index, val.id),
+            Self::FieldOrder(val) => (EbmlId::FieldOrder as u64, val.index, val.id),
+            Self::StereoMode(val) => (EbmlId::StereoMode as u64, val.index, val.id),
+            Self::AlphaMode(val) => (EbmlId::AlphaMode as u64, val.index, val.id),
+            Self::OldStereoMode(val) => (EbmlId::OldStereoMode as u64, val.index, val.id),
+            Self::PixelWidth(val) => (EbmlId::PixelWidth as u64, val.index, val.id),
+            Self::PixelHeight(val) => (EbmlId::PixelHeight as u64, val.index, val.id),
+            Self::PixelCropBottom(val) => (EbmlId::PixelCropBottom as u64, val.index, val.id),
+            Self::PixelCropTop(val) => (EbmlId::PixelCropTop as u64, val.index, val.id),
+            Self::PixelCropLeft(val) => (EbmlId::PixelCropLeft as u64, val.index, val.id),
+            Self::PixelCropRight(val) => (EbmlId::PixelCropRight as u64, val.index, val.id),
+            Self::DisplayWidth(val) => (EbmlId::DisplayWidth as u64, val.index, val.id),
+            Self::DisplayHeight(val) => (EbmlId::DisplayHeight as u64, val.index, val.id),
+            Self::DisplayUnit(val) => (EbmlId::DisplayUnit as u64, val.index, val.id),
+            Self::AspectRatioType(val) => (EbmlId::AspectRatioType as u64, val.index, val.id),
+            Self::UncompressedFourCc(val) => (EbmlId::UncompressedFourCc as u64, val.index, val.id),
+            Self::GammaValue(val) => (EbmlId::GammaValue as u64, val.index, val.id),
+            Self::FrameRate(val) => (EbmlId::FrameRate as u64, val.index, val.id),
+            Self::Colour(val) => (EbmlId::Colour as u64, val.index, val.id),
+            Self::Projection(val) => (EbmlId::Projection as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(VideoFields);
+crate::impl_ord!(VideoFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Colour {
     pub size: u64,
 
-    pub matrix_coefficients: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub matrix_coefficients: Ebml<MatrixCoefficients>,
     pub bits_per_channel: Ebml<u64>,
     pub chroma_subsampling_horz: Option<Ebml<u64>>,
     pub chroma_subsampling_vert: Option<Ebml<u64>>,
     pub cb_subsampling_horz: Option<Ebml<u64>>,
     pub cb_subsampling_vert: Option<Ebml<u64>>,
-    pub chroma_siting_horz: Ebml<u64>,
-    pub chroma_siting_vert: Ebml<u64>,
-    pub range: Ebml<u64>,
-    pub transfer_characteristics: Ebml<u64>,
-    pub primaries: Ebml<u64>,
+    pub chroma_siting_horz: Ebml<ChromaSitingHorz>,
+    pub chroma_siting_vert: Ebml<ChromaSitingVert>,
+    pub range: Ebml<Range>,
+    pub transfer_characteristics: Ebml<TransferCharacteristics>,
+    pub primaries: Ebml<Primaries>,
     pub max_cll: Option<Ebml<u64>>,
     pub max_fall: Option<Ebml<u64>>,
     pub structing_metadata: Option<Ebml<StructingMetadata>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Colour {
-    pub fn elements(&self) -> std::collections::BTreeSet<ColourFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ColourFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ColourFields::MatrixCoefficients(self.matrix_coefficients.clone()));
-        elements.insert(ColourFields::BitsPerChannel(self.bits_per_channel.clone()));
-        if let Some(el) = &self.chroma_subsampling_horz { elements.insert(ColourFields::ChromaSubsamplingHorz(el.clone())); }
-        if let Some(el) = &self.chroma_subsampling_vert { elements.insert(ColourFields::ChromaSubsamplingVert(el.clone())); }
-        if let Some(el) = &self.cb_subsampling_horz { elements.insert(ColourFields::CbSubsamplingHorz(el.clone())); }
-        if let Some(el) = &self.cb_subsampling_vert { elements.insert(ColourFields::CbSubsamplingVert(el.clone())); }
-        elements.insert(ColourFields::ChromaSitingHorz(self.chroma_siting_horz.clone()));
-        elements.insert(ColourFields::ChromaSitingVert(self.chroma_siting_vert.clone()));
-        elements.insert(ColourFields::Range(self.range.clone()));
-        elements.insert(ColourFields::TransferCharacteristics(self.transfer_characteristics.clone()));
-        elements.insert(ColourFields::Primaries(self.primaries.clone()));
-        if let Some(el) = &self.max_cll { elements.insert(ColourFields::MaxCll(el.clone())); }
-        if let Some(el) = &self.max_fall { elements.insert(ColourFields::MaxFall(el.clone())); }
-        if let Some(el) = &self.structing_metadata { elements.insert(ColourFields::StructingMetadata(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ColourFields::Crc32(el)); }
+        elements.insert(ColourFields::MatrixCoefficients(&self.matrix_coefficients));
+        elements.insert(ColourFields::BitsPerChannel(&self.bits_per_channel));
+        if let Some(el) = &self.chroma_subsampling_horz { elements.insert(ColourFields::ChromaSubsamplingHorz(el)); }
+        if let Some(el) = &self.chroma_subsampling_vert { elements.insert(ColourFields::ChromaSubsamplingVert(el)); }
+        if let Some(el) = &self.cb_subsampling_horz { elements.insert(ColourFields::CbSubsamplingHorz(el)); }
+        if let Some(el) = &self.cb_subsampling_vert { elements.insert(ColourFields::CbSubsamplingVert(el)); }
+        elements.insert(ColourFields::ChromaSitingHorz(&self.chroma_siting_horz));
+        elements.insert(ColourFields::ChromaSitingVert(&self.chroma_siting_vert));
+        elements.insert(ColourFields::Range(&self.range));
+        elements.insert(ColourFields::TransferCharacteristics(&self.transfer_characteristics));
+        elements.insert(ColourFields::Primaries(&self.primaries));
+        if let Some(el) = &self.max_cll { elements.insert(ColourFields::MaxCll(el)); }
+        if let Some(el) = &self.max_fall { elements.insert(ColourFields::MaxFall(el)); }
+        if let Some(el) = &self.structing_metadata { elements.insert(ColourFields::StructingMetadata(el)); }
+        for el in &self.unknown { elements.insert(ColourFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ColourFields {
-    MatrixCoefficients(Ebml<u64>),
-    BitsPerChannel(Ebml<u64>),
-    ChromaSubsamplingHorz(Ebml<u64>),
-    ChromaSubsamplingVert(Ebml<u64>),
-    CbSubsamplingHorz(Ebml<u64>),
-    CbSubsamplingVert(Ebml<u64>),
-    ChromaSitingHorz(Ebml<u64>),
-    ChromaSitingVert(Ebml<u64>),
-    Range(Ebml<u64>),
-    TransferCharacteristics(Ebml<u64>),
-    Primaries(Ebml<u64>),
-    MaxCll(Ebml<u64>),
-    MaxFall(Ebml<u64>),
-    StructingMetadata(Ebml<StructingMetadata>),
-}
-impl ColourFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ColourFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    MatrixCoefficients(&'a Ebml<MatrixCoefficients>),
+    BitsPerChannel(&'a Ebml<u64>),
+    ChromaSubsamplingHorz(&'a Ebml<u64>),
+    ChromaSubsamplingVert(&'a Ebml<u64>),
+    CbSubsamplingHorz(&'a Ebml<u64>),
+    CbSubsamplingVert(&'a Ebml<u64>),
+    ChromaSitingHorz(&'a Ebml<ChromaSitingHorz>),
+    ChromaSitingVert(&'a Ebml<ChromaSitingVert>),
+    Range(&'a Ebml<Range>),
+    TransferCharacteristics(&'a Ebml<TransferCharacteristics>),
+    Primaries(&'a Ebml<Primaries>),
+    MaxCll(&'a Ebml<u64>),
+    MaxFall(&'a Ebml<u64>),
+    StructingMetadata(&'a Ebml<StructingMetadata>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ColourFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::MatrixCoefficients(val) => (EbmlId::MatrixCoefficients, val.index, val.id),
-            Self::BitsPerChannel(val) => (EbmlId::BitsPerChannel, val.index, val.id),
-            Self::ChromaSubsamplingHorz(val) => (EbmlId::ChromaSubsamplingHorz, val.index, val.id),
-            Self::ChromaSubsamplingVert(val) => (EbmlId::ChromaSubsamplingVert, val.index, val.id),
-            Self::CbSubsamplingHorz(val) => (EbmlId::CbSubsamplingHorz, val.index, val.id),
-            Self::CbSubsamplingVert(val) => (EbmlId::CbSubsamplingVert, val.index, val.id),
-            Self::ChromaSitingHorz(val) => (EbmlId::ChromaSitingHorz, val.index, val.id),
-            Self::ChromaSitingVert(val) => (EbmlId::ChromaSitingVert, val.index, val.id),
-            Self::Range(val) => (EbmlId::Range, val.index, val.id),
-            Self::TransferCharacteristics(val) => (EbmlId::TransferCharacteristics, val.index, val.id),
-            Self::Primaries(val) => (EbmlId::Primaries, val.index, val.id),
-            Self::MaxCll(val) => (EbmlId::MaxCll, val.index, val.id),
-            Self::MaxFall(val) => (EbmlId::MaxFall, val.index, val.id),
-            Self::StructingMetadata(val) => (EbmlId::StructingMetadata, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::MatrixCoefficients(val) => (EbmlId::MatrixCoefficients as u64, val.index, val.id),
+            Self::BitsPerChannel(val) => (EbmlId::BitsPerChannel as u64, val.index, val.id),
+            Self::ChromaSubsamplingHorz(val) => (EbmlId::ChromaSubsamplingHorz as u64, val.index, val.id),
+            Self::ChromaSubsamplingVert(val) => (EbmlId::ChromaSubsamplingVert as u64, val.index, val.id),
+            Self::CbSubsamplingHorz(val) => (EbmlId::CbSubsamplingHorz as u64, val.index, val.id),
+            Self::CbSubsamplingVert(val) => (EbmlId::CbSubsamplingVert as u64, val.index, val.id),
+            Self::ChromaSitingHorz(val) => (EbmlId::ChromaSitingHorz as u64, val.index, val.id),
+            Self::ChromaSitingVert(val) => (EbmlId::ChromaSitingVert as u64, val.index, val.id),
+            Self::Range(val) => (EbmlId::Range as u64, val.index, val.id),
+            Self::TransferCharacteristics(val) => (EbmlId::TransferCharacteristics as u64, val.index, val.id),
+            Self::Primaries(val) => (EbmlId::Primaries as u64, val.index, val.id),
+            Self::MaxCll(val) => (EbmlId::MaxCll as u64, val.index, val.id),
+            Self::MaxFall(val) => (EbmlId::MaxFall as u64, val.index, val.id),
+            Self::StructingMetadata(val) => (EbmlId::StructingMetadata as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ColourFields);
+crate::impl_ord!(ColourFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct StructingMetadata {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub primary_r_chromaticity_x: Option<Ebml<f64>>,
     pub primary_r_chromaticity_y: Option<Ebml<f64>>,
     pub primary_g_chromaticity_x: Option<Ebml<f64>>,
@@ -1086,488 +1332,642 @@ pub struct StructingMetadata {
     pub white_point_chromaticity_y: Option<Ebml<f64>>,
     pub luminance_max: Option<Ebml<f64>>,
     pub luminance_min: Option<Ebml<f64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl StructingMetadata {
-    pub fn elements(&self) -> std::collections::BTreeSet<StructingMetadataFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<StructingMetadataFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.primary_r_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryRChromaticityX(el.clone())); }
-        if let Some(el) = &self.primary_r_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryRChromaticityY(el.clone())); }
-        if let Some(el) = &self.primary_g_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryGChromaticityX(el.clone())); }
-        if let Some(el) = &self.primary_g_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryGChromaticityY(el.clone())); }
-        if let Some(el) = &self.primary_b_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryBChromaticityX(el.clone())); }
-        if let Some(el) = &self.primary_b_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryBChromaticityY(el.clone())); }
-        if let Some(el) = &self.white_point_chromaticity_x { elements.insert(StructingMetadataFields::WhitePointChromaticityX(el.clone())); }
-        if let Some(el) = &self.white_point_chromaticity_y { elements.insert(StructingMetadataFields::WhitePointChromaticityY(el.clone())); }
-        if let Some(el) = &self.luminance_max { elements.insert(StructingMetadataFields::LuminanceMax(el.clone())); }
-        if let Some(el) = &self.luminance_min { elements.insert(StructingMetadataFields::LuminanceMin(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(StructingMetadataFields::Crc32(el)); }
+        if let Some(el) = &self.primary_r_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryRChromaticityX(el)); }
+        if let Some(el) = &self.primary_r_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryRChromaticityY(el)); }
+        if let Some(el) = &self.primary_g_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryGChromaticityX(el)); }
+        if let Some(el) = &self.primary_g_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryGChromaticityY(el)); }
+        if let Some(el) = &self.primary_b_chromaticity_x { elements.insert(StructingMetadataFields::PrimaryBChromaticityX(el)); }
+        if let Some(el) = &self.primary_b_chromaticity_y { elements.insert(StructingMetadataFields::PrimaryBChromaticityY(el)); }
+        if let Some(el) = &self.white_point_chromaticity_x { elements.insert(StructingMetadataFields::WhitePointChromaticityX(el)); }
+        if let Some(el) = &self.white_point_chromaticity_y { elements.insert(StructingMetadataFields::WhitePointChromaticityY(el)); }
+        if let Some(el) = &self.luminance_max { elements.insert(StructingMetadataFields::LuminanceMax(el)); }
+        if let Some(el) = &self.luminance_min { elements.insert(StructingMetadataFields::LuminanceMin(el)); }
+        for el in &self.unknown { elements.insert(StructingMetadataFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum StructingMetadataFields {
-    PrimaryRChromaticityX(Ebml<f64>),
-    PrimaryRChromaticityY(Ebml<f64>),
-    PrimaryGChromaticityX(Ebml<f64>),
-    PrimaryGChromaticityY(Ebml<f64>),
-    PrimaryBChromaticityX(Ebml<f64>),
-    PrimaryBChromaticityY(Ebml<f64>),
-    WhitePointChromaticityX(Ebml<f64>),
-    WhitePointChromaticityY(Ebml<f64>),
-    LuminanceMax(Ebml<f64>),
-    LuminanceMin(Ebml<f64>),
-}
-impl StructingMetadataFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum StructingMetadataFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    PrimaryRChromaticityX(&'a Ebml<f64>),
+    PrimaryRChromaticityY(&'a Ebml<f64>),
+    PrimaryGChromaticityX(&'a Ebml<f64>),
+    PrimaryGChromaticityY(&'a Ebml<f64>),
+    PrimaryBChromaticityX(&'a Ebml<f64>),
+    PrimaryBChromaticityY(&'a Ebml<f64>),
+    WhitePointChromaticityX(&'a Ebml<f64>),
+    WhitePointChromaticityY(&'a Ebml<f64>),
+    LuminanceMax(&'a Ebml<f64>),
+    LuminanceMin(&'a Ebml<f64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl StructingMetadataFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::PrimaryRChromaticityX(val) => (EbmlId::PrimaryRChromaticityX, val.index, val.id),
-            Self::PrimaryRChromaticityY(val) => (EbmlId::PrimaryRChromaticityY, val.index, val.id),
-            Self::PrimaryGChromaticityX(val) => (EbmlId::PrimaryGChromaticityX, val.index, val.id),
-            Self::PrimaryGChromaticityY(val) => (EbmlId::PrimaryGChromaticityY, val.index, val.id),
-            Self::PrimaryBChromaticityX(val) => (EbmlId::PrimaryBChromaticityX, val.index, val.id),
-            Self::PrimaryBChromaticityY(val) => (EbmlId::PrimaryBChromaticityY, val.index, val.id),
-            Self::WhitePointChromaticityX(val) => (EbmlId::WhitePointChromaticityX, val.index, val.id),
-            Self::WhitePointChromaticityY(val) => (EbmlId::WhitePointChromaticityY, val.index, val.id),
-            Self::LuminanceMax(val) => (EbmlId::LuminanceMax, val.index, val.id),
-            Self::LuminanceMin(val) => (EbmlId::LuminanceMin, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::PrimaryRChromaticityX(val) => (EbmlId::PrimaryRChromaticityX as u64, val.index, val.id),
+            Self::PrimaryRChromaticityY(val) => (EbmlId::PrimaryRChromaticityY as u64, val.index, val.id),
+            Self::PrimaryGChromaticityX(val) => (EbmlId::PrimaryGChromaticityX as u64, val.index, val.id),
+            Self::PrimaryGChromaticityY(val) => (EbmlId::PrimaryGChromaticityY as u64, val.index, val.id),
+            Self::PrimaryBChromaticityX(val) => (EbmlId::PrimaryBChromaticityX as u64, val.index, val.id),
+            Self::PrimaryBChromaticityY(val) => (EbmlId::PrimaryBChromaticityY as u64, val.index, val.id),
+            Self::WhitePointChromaticityX(val) => (EbmlId::WhitePointChromaticityX as u64, val.index, val.id),
+            Self::WhitePointChromaticityY(val) => (EbmlId::WhitePointChromaticityY as u64, val.index, val.id),
+            Self::LuminanceMax(val) => (EbmlId::LuminanceMax as u64, val.index, val.id),
+            Self::LuminanceMin(val) => (EbmlId::LuminanceMin as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(StructingMetadataFields);
+crate::impl_ord!(StructingMetadataFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Projection {
     pub size: u64,
 
-    pub projection_type: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub projection_type: Ebml<ProjectionType>,
     pub projection_private: Option<Ebml<Vec<u8>>>,
     pub projection_pose_yaw: Ebml<f64>,
     pub projection_pose_pitch: Ebml<f64>,
     pub projection_pose_roll: Ebml<f64>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Projection {
-    pub fn elements(&self) -> std::collections::BTreeSet<ProjectionFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ProjectionFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ProjectionFields::ProjectionType(self.projection_type.clone()));
-        if let Some(el) = &self.projection_private { elements.insert(ProjectionFields::ProjectionPrivate(el.clone())); }
-        elements.insert(ProjectionFields::ProjectionPoseYaw(self.projection_pose_yaw.clone()));
-        elements.insert(ProjectionFields::ProjectionPosePitch(self.projection_pose_pitch.clone()));
-        elements.insert(ProjectionFields::ProjectionPoseRoll(self.projection_pose_roll.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(ProjectionFields::Crc32(el)); }
+        elements.insert(ProjectionFields::ProjectionType(&self.projection_type));
+        if let Some(el) = &self.projection_private { elements.insert(ProjectionFields::ProjectionPrivate(el)); }
+        elements.insert(ProjectionFields::ProjectionPoseYaw(&self.projection_pose_yaw));
+        elements.insert(ProjectionFields::ProjectionPosePitch(&self.projection_pose_pitch));
+        elements.insert(ProjectionFields::ProjectionPoseRoll(&self.projection_pose_roll));
+        for el in &self.unknown { elements.insert(ProjectionFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ProjectionFields {
-    ProjectionType(Ebml<u64>),
-    ProjectionPrivate(Ebml<Vec<u8>>),
-    ProjectionPoseYaw(Ebml<f64>),
-    ProjectionPosePitch(Ebml<f64>),
-    ProjectionPoseRoll(Ebml<f64>),
-}
-impl ProjectionFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ProjectionFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ProjectionType(&'a Ebml<ProjectionType>),
+    ProjectionPrivate(&'a Ebml<Vec<u8>>),
+    ProjectionPoseYaw(&'a Ebml<f64>),
+    ProjectionPosePitch(&'a Ebml<f64>),
+    ProjectionPoseRoll(&'a Ebml<f64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ProjectionFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ProjectionType(val) => (EbmlId::ProjectionType, val.index, val.id),
-            Self::ProjectionPrivate(val) => (EbmlId::ProjectionPrivate, val.index, val.id),
-            Self::ProjectionPoseYaw(val) => (EbmlId::ProjectionPoseYaw, val.index, val.id),
-            Self::ProjectionPosePitch(val) => (EbmlId::ProjectionPosePitch, val.index, val.id),
-            Self::ProjectionPoseRoll(val) => (EbmlId::ProjectionPoseRoll, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ProjectionType(val) => (EbmlId::ProjectionType as u64, val.index, val.id),
+            Self::ProjectionPrivate(val) => (EbmlId::ProjectionPrivate as u64, val.index, val.id),
+            Self::ProjectionPoseYaw(val) => (EbmlId::ProjectionPoseYaw as u64, val.index, val.id),
+            Self::ProjectionPosePitch(val) => (EbmlId::ProjectionPosePitch as u64, val.index, val.id),
+            Self::ProjectionPoseRoll(val) => (EbmlId::ProjectionPoseRoll as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ProjectionFields);
+crate::impl_ord!(ProjectionFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Audio {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub sampling_frequency: Ebml<f64>,
     pub output_sampling_frequency: Option<Ebml<f64>>,
     pub channels: Ebml<u64>,
     pub channel_positions: Option<Ebml<Vec<u8>>>,
     pub bit_depth: Option<Ebml<u64>>,
-    pub emphasis: Ebml<u64>,
+    pub emphasis: Ebml<Emphasis>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Audio {
-    pub fn elements(&self) -> std::collections::BTreeSet<AudioFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<AudioFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(AudioFields::SamplingFrequency(self.sampling_frequency.clone()));
-        if let Some(el) = &self.output_sampling_frequency { elements.insert(AudioFields::OutputSamplingFrequency(el.clone())); }
-        elements.insert(AudioFields::Channels(self.channels.clone()));
-        if let Some(el) = &self.channel_positions { elements.insert(AudioFields::ChannelPositions(el.clone())); }
-        if let Some(el) = &self.bit_depth { elements.insert(AudioFields::BitDepth(el.clone())); }
-        elements.insert(AudioFields::Emphasis(self.emphasis.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(AudioFields::Crc32(el)); }
+        elements.insert(AudioFields::SamplingFrequency(&self.sampling_frequency));
+        if let Some(el) = &self.output_sampling_frequency { elements.insert(AudioFields::OutputSamplingFrequency(el)); }
+        elements.insert(AudioFields::Channels(&self.channels));
+        if let Some(el) = &self.channel_positions { elements.insert(AudioFields::ChannelPositions(el)); }
+        if let Some(el) = &self.bit_depth { elements.insert(AudioFields::BitDepth(el)); }
+        elements.insert(AudioFields::Emphasis(&self.emphasis));
+        for el in &self.unknown { elements.insert(AudioFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum AudioFields {
-    SamplingFrequency(Ebml<f64>),
-    OutputSamplingFrequency(Ebml<f64>),
-    Channels(Ebml<u64>),
-    ChannelPositions(Ebml<Vec<u8>>),
-    BitDepth(Ebml<u64>),
-    Emphasis(Ebml<u64>),
-}
-impl AudioFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum AudioFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    SamplingFrequency(&'a Ebml<f64>),
+    OutputSamplingFrequency(&'a Ebml<f64>),
+    Channels(&'a Ebml<u64>),
+    ChannelPositions(&'a Ebml<Vec<u8>>),
+    BitDepth(&'a Ebml<u64>),
+    Emphasis(&'a Ebml<Emphasis>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl AudioFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::SamplingFrequency(val) => (EbmlId::SamplingFrequency, val.index, val.id),
-            Self::OutputSamplingFrequency(val) => (EbmlId::OutputSamplingFrequency, val.index, val.id),
-            Self::Channels(val) => (EbmlId::Channels, val.index, val.id),
-            Self::ChannelPositions(val) => (EbmlId::ChannelPositions, val.index, val.id),
-            Self::BitDepth(val) => (EbmlId::BitDepth, val.index, val.id),
-            Self::Emphasis(val) => (EbmlId::Emphasis, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::SamplingFrequency(val) => (EbmlId::SamplingFrequency as u64, val.index, val.id),
+            Self::OutputSamplingFrequency(val) => (EbmlId::OutputSamplingFrequency as u64, val.index, val.id),
+            Self::Channels(val) => (EbmlId::Channels as u64, val.index, val.id),
+            Self::ChannelPositions(val) => (EbmlId::ChannelPositions as u64, val.index, val.id),
+            Self::BitDepth(val) => (EbmlId::BitDepth as u64, val.index, val.id),
+            Self::Emphasis(val) => (EbmlId::Emphasis as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(AudioFields);
+crate::impl_ord!(AudioFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackOperation {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_combine_planes: Option<Ebml<TrackCombinePlanes>>,
     pub track_join_blocks: Option<Ebml<TrackJoinBlocks>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackOperation {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackOperationFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackOperationFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.track_combine_planes { elements.insert(TrackOperationFields::TrackCombinePlanes(el.clone())); }
-        if let Some(el) = &self.track_join_blocks { elements.insert(TrackOperationFields::TrackJoinBlocks(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TrackOperationFields::Crc32(el)); }
+        if let Some(el) = &self.track_combine_planes { elements.insert(TrackOperationFields::TrackCombinePlanes(el)); }
+        if let Some(el) = &self.track_join_blocks { elements.insert(TrackOperationFields::TrackJoinBlocks(el)); }
+        for el in &self.unknown { elements.insert(TrackOperationFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackOperationFields {
-    TrackCombinePlanes(Ebml<TrackCombinePlanes>),
-    TrackJoinBlocks(Ebml<TrackJoinBlocks>),
-}
-impl TrackOperationFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TrackOperationFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackCombinePlanes(&'a Ebml<TrackCombinePlanes>),
+    TrackJoinBlocks(&'a Ebml<TrackJoinBlocks>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TrackOperationFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackCombinePlanes(val) => (EbmlId::TrackCombinePlanes, val.index, val.id),
-            Self::TrackJoinBlocks(val) => (EbmlId::TrackJoinBlocks, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackCombinePlanes(val) => (EbmlId::TrackCombinePlanes as u64, val.index, val.id),
+            Self::TrackJoinBlocks(val) => (EbmlId::TrackJoinBlocks as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackOperationFields);
+crate::impl_ord!(TrackOperationFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackCombinePlanes {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_plane: Vec<Ebml<TrackPlane>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackCombinePlanes {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackCombinePlanesFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackCombinePlanesFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.track_plane { elements.insert(TrackCombinePlanesFields::TrackPlane(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TrackCombinePlanesFields::Crc32(el)); }
+        for el in &self.track_plane { elements.insert(TrackCombinePlanesFields::TrackPlane(el)); }
+        for el in &self.unknown { elements.insert(TrackCombinePlanesFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackCombinePlanesFields {
-    TrackPlane(Ebml<TrackPlane>),
+pub enum TrackCombinePlanesFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackPlane(&'a Ebml<TrackPlane>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl TrackCombinePlanesFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl TrackCombinePlanesFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackPlane(val) => (EbmlId::TrackPlane, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackPlane(val) => (EbmlId::TrackPlane as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackCombinePlanesFields);
+crate::impl_ord!(TrackCombinePlanesFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackPlane {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_plane_uid: Ebml<u64>,
-    pub track_plane_type: Ebml<u64>,
+    pub track_plane_type: Ebml<TrackPlaneType>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackPlane {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackPlaneFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackPlaneFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(TrackPlaneFields::TrackPlaneUid(self.track_plane_uid.clone()));
-        elements.insert(TrackPlaneFields::TrackPlaneType(self.track_plane_type.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(TrackPlaneFields::Crc32(el)); }
+        elements.insert(TrackPlaneFields::TrackPlaneUid(&self.track_plane_uid));
+        elements.insert(TrackPlaneFields::TrackPlaneType(&self.track_plane_type));
+        for el in &self.unknown { elements.insert(TrackPlaneFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackPlaneFields {
-    TrackPlaneUid(Ebml<u64>),
-    TrackPlaneType(Ebml<u64>),
-}
-impl TrackPlaneFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TrackPlaneFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackPlaneUid(&'a Ebml<u64>),
+    TrackPlaneType(&'a Ebml<TrackPlaneType>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TrackPlaneFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackPlaneUid(val) => (EbmlId::TrackPlaneUid, val.index, val.id),
-            Self::TrackPlaneType(val) => (EbmlId::TrackPlaneType, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackPlaneUid(val) => (EbmlId::TrackPlaneUid as u64, val.index, val.id),
+            Self::TrackPlaneType(val) => (EbmlId::TrackPlaneType as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackPlaneFields);
+crate::impl_ord!(TrackPlaneFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct TrackJoinBlocks {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub track_join_uid: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl TrackJoinBlocks {
-    pub fn elements(&self) -> std::collections::BTreeSet<TrackJoinBlocksFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TrackJoinBlocksFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.track_join_uid { elements.insert(TrackJoinBlocksFields::TrackJoinUid(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TrackJoinBlocksFields::Crc32(el)); }
+        for el in &self.track_join_uid { elements.insert(TrackJoinBlocksFields::TrackJoinUid(el)); }
+        for el in &self.unknown { elements.insert(TrackJoinBlocksFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TrackJoinBlocksFields {
-    TrackJoinUid(Ebml<u64>),
+pub enum TrackJoinBlocksFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TrackJoinUid(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl TrackJoinBlocksFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl TrackJoinBlocksFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TrackJoinUid(val) => (EbmlId::TrackJoinUid, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TrackJoinUid(val) => (EbmlId::TrackJoinUid as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TrackJoinBlocksFields);
+crate::impl_ord!(TrackJoinBlocksFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ContentEncodings {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub content_encoding: Vec<Ebml<ContentEncoding>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ContentEncodings {
-    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncodingsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncodingsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.content_encoding { elements.insert(ContentEncodingsFields::ContentEncoding(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ContentEncodingsFields::Crc32(el)); }
+        for el in &self.content_encoding { elements.insert(ContentEncodingsFields::ContentEncoding(el)); }
+        for el in &self.unknown { elements.insert(ContentEncodingsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ContentEncodingsFields {
-    ContentEncoding(Ebml<ContentEncoding>),
+pub enum ContentEncodingsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ContentEncoding(&'a Ebml<ContentEncoding>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl ContentEncodingsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl ContentEncodingsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ContentEncoding(val) => (EbmlId::ContentEncoding, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ContentEncoding(val) => (EbmlId::ContentEncoding as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ContentEncodingsFields);
+crate::impl_ord!(ContentEncodingsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ContentEncoding {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub content_encoding_order: Ebml<u64>,
-    pub content_encoding_scope: Ebml<u64>,
-    pub content_encoding_type: Ebml<u64>,
+    pub content_encoding_scope: Ebml<ContentEncodingScope>,
+    pub content_encoding_type: Ebml<ContentEncodingType>,
     pub content_compression: Option<Ebml<ContentCompression>>,
     pub content_encryption: Option<Ebml<ContentEncryption>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ContentEncoding {
-    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncodingFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncodingFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ContentEncodingFields::ContentEncodingOrder(self.content_encoding_order.clone()));
-        elements.insert(ContentEncodingFields::ContentEncodingScope(self.content_encoding_scope.clone()));
-        elements.insert(ContentEncodingFields::ContentEncodingType(self.content_encoding_type.clone()));
-        if let Some(el) = &self.content_compression { elements.insert(ContentEncodingFields::ContentCompression(el.clone())); }
-        if let Some(el) = &self.content_encryption { elements.insert(ContentEncodingFields::ContentEncryption(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ContentEncodingFields::Crc32(el)); }
+        elements.insert(ContentEncodingFields::ContentEncodingOrder(&self.content_encoding_order));
+        elements.insert(ContentEncodingFields::ContentEncodingScope(&self.content_encoding_scope));
+        elements.insert(ContentEncodingFields::ContentEncodingType(&self.content_encoding_type));
+        if let Some(el) = &self.content_compression { elements.insert(ContentEncodingFields::ContentCompression(el)); }
+        if let Some(el) = &self.content_encryption { elements.insert(ContentEncodingFields::ContentEncryption(el)); }
+        for el in &self.unknown { elements.insert(ContentEncodingFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ContentEncodingFields {
-    ContentEncodingOrder(Ebml<u64>),
-    ContentEncodingScope(Ebml<u64>),
-    ContentEncodingType(Ebml<u64>),
-    ContentCompression(Ebml<ContentCompression>),
-    ContentEncryption(Ebml<ContentEncryption>),
-}
-impl ContentEncodingFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ContentEncodingFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ContentEncodingOrder(&'a Ebml<u64>),
+    ContentEncodingScope(&'a Ebml<ContentEncodingScope>),
+    ContentEncodingType(&'a Ebml<ContentEncodingType>),
+    ContentCompression(&'a Ebml<ContentCompression>),
+    ContentEncryption(&'a Ebml<ContentEncryption>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ContentEncodingFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ContentEncodingOrder(val) => (EbmlId::ContentEncodingOrder, val.index, val.id),
-            Self::ContentEncodingScope(val) => (EbmlId::ContentEncodingScope, val.index, val.id),
-            Self::ContentEncodingType(val) => (EbmlId::ContentEncodingType, val.index, val.id),
-            Self::ContentCompression(val) => (EbmlId::ContentCompression, val.index, val.id),
-            Self::ContentEncryption(val) => (EbmlId::ContentEncryption, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ContentEncodingOrder(val) => (EbmlId::ContentEncodingOrder as u64, val.index, val.id),
+            Self::ContentEncodingScope(val) => (EbmlId::ContentEncodingScope as u64, val.index, val.id),
+            Self::ContentEncodingType(val) => (EbmlId::ContentEncodingType as u64, val.index, val.id),
+            Self::ContentCompression(val) => (EbmlId::ContentCompression as u64, val.index, val.id),
+            Self::ContentEncryption(val) => (EbmlId::ContentEncryption as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ContentEncodingFields);
+crate::impl_ord!(ContentEncodingFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ContentCompression {
     pub size: u64,
 
-    pub content_comp_algo: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub content_comp_algo: Ebml<ContentCompAlgo>,
     pub content_comp_settings: Option<Ebml<Vec<u8>>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ContentCompression {
-    pub fn elements(&self) -> std::collections::BTreeSet<ContentCompressionFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ContentCompressionFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ContentCompressionFields::ContentCompAlgo(self.content_comp_algo.clone()));
-        if let Some(el) = &self.content_comp_settings { elements.insert(ContentCompressionFields::ContentCompSettings(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ContentCompressionFields::Crc32(el)); }
+        elements.insert(ContentCompressionFields::ContentCompAlgo(&self.content_comp_algo));
+        if let Some(el) = &self.content_comp_settings { elements.insert(ContentCompressionFields::ContentCompSettings(el)); }
+        for el in &self.unknown { elements.insert(ContentCompressionFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ContentCompressionFields {
-    ContentCompAlgo(Ebml<u64>),
-    ContentCompSettings(Ebml<Vec<u8>>),
-}
-impl ContentCompressionFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ContentCompressionFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ContentCompAlgo(&'a Ebml<ContentCompAlgo>),
+    ContentCompSettings(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ContentCompressionFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ContentCompAlgo(val) => (EbmlId::ContentCompAlgo, val.index, val.id),
-            Self::ContentCompSettings(val) => (EbmlId::ContentCompSettings, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ContentCompAlgo(val) => (EbmlId::ContentCompAlgo as u64, val.index, val.id),
+            Self::ContentCompSettings(val) => (EbmlId::ContentCompSettings as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ContentCompressionFields);
+crate::impl_ord!(ContentCompressionFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ContentEncryption {
     pub size: u64,
 
-    pub content_enc_algo: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub content_enc_algo: Ebml<ContentEncAlgo>,
     pub content_enc_key_id: Option<Ebml<Vec<u8>>>,
     pub content_enc_aes_settings: Option<Ebml<ContentEncAesSettings>>,
     pub content_signature: Option<Ebml<Vec<u8>>>,
     pub content_sig_key_id: Option<Ebml<Vec<u8>>>,
-    pub content_sig_algo: Option<Ebml<u64>>,
-    pub content_sig_hash_algo: Option<Ebml<u64>>,
+    pub content_sig_algo: Option<Ebml<ContentSigAlgo>>,
+    pub content_sig_hash_algo: Option<Ebml<ContentSigHashAlgo>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ContentEncryption {
-    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncryptionFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncryptionFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ContentEncryptionFields::ContentEncAlgo(self.content_enc_algo.clone()));
-        if let Some(el) = &self.content_enc_key_id { elements.insert(ContentEncryptionFields::ContentEncKeyId(el.clone())); }
-        if let Some(el) = &self.content_enc_aes_settings { elements.insert(ContentEncryptionFields::ContentEncAesSettings(el.clone())); }
-        if let Some(el) = &self.content_signature { elements.insert(ContentEncryptionFields::ContentSignature(el.clone())); }
-        if let Some(el) = &self.content_sig_key_id { elements.insert(ContentEncryptionFields::ContentSigKeyId(el.clone())); }
-        if let Some(el) = &self.content_sig_algo { elements.insert(ContentEncryptionFields::ContentSigAlgo(el.clone())); }
-        if let Some(el) = &self.content_sig_hash_algo { elements.insert(ContentEncryptionFields::ContentSigHashAlgo(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ContentEncryptionFields::Crc32(el)); }
+        elements.insert(ContentEncryptionFields::ContentEncAlgo(&self.content_enc_algo));
+        if let Some(el) = &self.content_enc_key_id { elements.insert(ContentEncryptionFields::ContentEncKeyId(el)); }
+        if let Some(el) = &self.content_enc_aes_settings { elements.insert(ContentEncryptionFields::ContentEncAesSettings(el)); }
+        if let Some(el) = &self.content_signature { elements.insert(ContentEncryptionFields::ContentSignature(el)); }
+        if let Some(el) = &self.content_sig_key_id { elements.insert(ContentEncryptionFields::ContentSigKeyId(el)); }
+        if let Some(el) = &self.content_sig_algo { elements.insert(ContentEncryptionFields::ContentSigAlgo(el)); }
+        if let Some(el) = &self.content_sig_hash_algo { elements.insert(ContentEncryptionFields::ContentSigHashAlgo(el)); }
+        for el in &self.unknown { elements.insert(ContentEncryptionFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ContentEncryptionFields {
-    ContentEncAlgo(Ebml<u64>),
-    ContentEncKeyId(Ebml<Vec<u8>>),
-    ContentEncAesSettings(Ebml<ContentEncAesSettings>),
-    ContentSignature(Ebml<Vec<u8>>),
-    ContentSigKeyId(Ebml<Vec<u8>>),
-    ContentSigAlgo(Ebml<u64>),
-    ContentSigHashAlgo(Ebml<u64>),
-}
-impl ContentEncryptionFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ContentEncryptionFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ContentEncAlgo(&'a Ebml<ContentEncAlgo>),
+    ContentEncKeyId(&'a Ebml<Vec<u8>>),
+    ContentEncAesSettings(&'a Ebml<ContentEncAesSettings>),
+    ContentSignature(&'a Ebml<Vec<u8>>),
+    ContentSigKeyId(&'a Ebml<Vec<u8>>),
+    ContentSigAlgo(&'a Ebml<ContentSigAlgo>),
+    ContentSigHashAlgo(&'a Ebml<ContentSigHashAlgo>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ContentEncryptionFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ContentEncAlgo(val) => (EbmlId::ContentEncAlgo, val.index, val.id),
-            Self::ContentEncKeyId(val) => (EbmlId::ContentEncKeyId, val.index, val.id),
-            Self::ContentEncAesSettings(val) => (EbmlId::ContentEncAesSettings, val.index, val.id),
-            Self::ContentSignature(val) => (EbmlId::ContentSignature, val.index, val.id),
-            Self::ContentSigKeyId(val) => (EbmlId::ContentSigKeyId, val.index, val.id),
-            Self::ContentSigAlgo(val) => (EbmlId::ContentSigAlgo, val.index, val.id),
-            Self::ContentSigHashAlgo(val) => (EbmlId::ContentSigHashAlgo, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ContentEncAlgo(val) => (EbmlId::ContentEncAlgo as u64, val.index, val.id),
+            Self::ContentEncKeyId(val) => (EbmlId::ContentEncKeyId as u64, val.index, val.id),
+            Self::ContentEncAesSettings(val) => (EbmlId::ContentEncAesSettings as u64, val.index, val.id),
+            Self::ContentSignature(val) => (EbmlId::ContentSignature as u64, val.index, val.id),
+            Self::ContentSigKeyId(val) => (EbmlId::ContentSigKeyId as u64, val.index, val.id),
+            Self::ContentSigAlgo(val) => (EbmlId::ContentSigAlgo as u64, val.index, val.id),
+            Self::ContentSigHashAlgo(val) => (EbmlId::ContentSigHashAlgo as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ContentEncryptionFields);
+crate::impl_ord!(ContentEncryptionFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ContentEncAesSettings {
     pub size: u64,
 
-    pub aes_settings_cipher_mode: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub aes_settings_cipher_mode: Ebml<AesSettingsCipherMode>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ContentEncAesSettings {
-    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncAesSettingsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ContentEncAesSettingsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ContentEncAesSettingsFields::AesSettingsCipherMode(self.aes_settings_cipher_mode.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(ContentEncAesSettingsFields::Crc32(el)); }
+        elements.insert(ContentEncAesSettingsFields::AesSettingsCipherMode(&self.aes_settings_cipher_mode));
+        for el in &self.unknown { elements.insert(ContentEncAesSettingsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ContentEncAesSettingsFields {
-    AesSettingsCipherMode(Ebml<u64>),
+pub enum ContentEncAesSettingsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    AesSettingsCipherMode(&'a Ebml<AesSettingsCipherMode>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl ContentEncAesSettingsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl ContentEncAesSettingsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::AesSettingsCipherMode(val) => (EbmlId::AesSettingsCipherMode, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::AesSettingsCipherMode(val) => (EbmlId::AesSettingsCipherMode as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ContentEncAesSettingsFields);
+crate::impl_ord!(ContentEncAesSettingsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Cues {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub cue_point: Vec<Ebml<CuePoint>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Cues {
-    pub fn elements(&self) -> std::collections::BTreeSet<CuesFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<CuesFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.cue_point { elements.insert(CuesFields::CuePoint(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(CuesFields::Crc32(el)); }
+        for el in &self.cue_point { elements.insert(CuesFields::CuePoint(el)); }
+        for el in &self.unknown { elements.insert(CuesFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum CuesFields {
-    CuePoint(Ebml<CuePoint>),
+pub enum CuesFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    CuePoint(&'a Ebml<CuePoint>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl CuesFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl CuesFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::CuePoint(val) => (EbmlId::CuePoint, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::CuePoint(val) => (EbmlId::CuePoint as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(CuesFields);
+crate::impl_ord!(CuesFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct CuePoint {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub cue_time: Ebml<u64>,
     pub cue_track_positions: Vec<Ebml<CueTrackPositions>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl CuePoint {
-    pub fn elements(&self) -> std::collections::BTreeSet<CuePointFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<CuePointFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(CuePointFields::CueTime(self.cue_time.clone()));
-        for el in &self.cue_track_positions { elements.insert(CuePointFields::CueTrackPositions(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(CuePointFields::Crc32(el)); }
+        elements.insert(CuePointFields::CueTime(&self.cue_time));
+        for el in &self.cue_track_positions { elements.insert(CuePointFields::CueTrackPositions(el)); }
+        for el in &self.unknown { elements.insert(CuePointFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum CuePointFields {
-    CueTime(Ebml<u64>),
-    CueTrackPositions(Ebml<CueTrackPositions>),
-}
-impl CuePointFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum CuePointFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    CueTime(&'a Ebml<u64>),
+    CueTrackPositions(&'a Ebml<CueTrackPositions>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl CuePointFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::CueTime(val) => (EbmlId::CueTime, val.index, val.id),
-            Self::CueTrackPositions(val) => (EbmlId::CueTrackPositions, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::CueTime(val) => (EbmlId::CueTime as u64, val.index, val.id),
+            Self::CueTrackPositions(val) => (EbmlId::CueTrackPositions as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(CuePointFields);
+crate::impl_ord!(CuePointFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct CueTrackPositions {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub cue_track: Ebml<u64>,
     pub cue_cluster_position: Ebml<u64>,
     pub cue_relative_position: Option<Ebml<u64>>,
@@ -1575,113 +1975,146 @@ pub struct CueTrackPositions {
     pub cue_block_number: Option<Ebml<u64>>,
     pub cue_codec_state: Ebml<u64>,
     pub cue_reference: Vec<Ebml<CueReference>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl CueTrackPositions {
-    pub fn elements(&self) -> std::collections::BTreeSet<CueTrackPositionsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<CueTrackPositionsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(CueTrackPositionsFields::CueTrack(self.cue_track.clone()));
-        elements.insert(CueTrackPositionsFields::CueClusterPosition(self.cue_cluster_position.clone()));
-        if let Some(el) = &self.cue_relative_position { elements.insert(CueTrackPositionsFields::CueRelativePosition(el.clone())); }
-        if let Some(el) = &self.cue_duration { elements.insert(CueTrackPositionsFields::CueDuration(el.clone())); }
-        if let Some(el) = &self.cue_block_number { elements.insert(CueTrackPositionsFields::CueBlockNumber(el.clone())); }
-        elements.insert(CueTrackPositionsFields::CueCodecState(self.cue_codec_state.clone()));
-        for el in &self.cue_reference { elements.insert(CueTrackPositionsFields::CueReference(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(CueTrackPositionsFields::Crc32(el)); }
+        elements.insert(CueTrackPositionsFields::CueTrack(&self.cue_track));
+        elements.insert(CueTrackPositionsFields::CueClusterPosition(&self.cue_cluster_position));
+        if let Some(el) = &self.cue_relative_position { elements.insert(CueTrackPositionsFields::CueRelativePosition(el)); }
+        if let Some(el) = &self.cue_duration { elements.insert(CueTrackPositionsFields::CueDuration(el)); }
+        if let Some(el) = &self.cue_block_number { elements.insert(CueTrackPositionsFields::CueBlockNumber(el)); }
+        elements.insert(CueTrackPositionsFields::CueCodecState(&self.cue_codec_state));
+        for el in &self.cue_reference { elements.insert(CueTrackPositionsFields::CueReference(el)); }
+        for el in &self.unknown { elements.insert(CueTrackPositionsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum CueTrackPositionsFields {
-    CueTrack(Ebml<u64>),
-    CueClusterPosition(Ebml<u64>),
-    CueRelativePosition(Ebml<u64>),
-    CueDuration(Ebml<u64>),
-    CueBlockNumber(Ebml<u64>),
-    CueCodecState(Ebml<u64>),
-    CueReference(Ebml<CueReference>),
-}
-impl CueTrackPositionsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum CueTrackPositionsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    CueTrack(&'a Ebml<u64>),
+    CueClusterPosition(&'a Ebml<u64>),
+    CueRelativePosition(&'a Ebml<u64>),
+    CueDuration(&'a Ebml<u64>),
+    CueBlockNumber(&'a Ebml<u64>),
+    CueCodecState(&'a Ebml<u64>),
+    CueReference(&'a Ebml<CueReference>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl CueTrackPositionsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::CueTrack(val) => (EbmlId::CueTrack, val.index, val.id),
-            Self::CueClusterPosition(val) => (EbmlId::CueClusterPosition, val.index, val.id),
-            Self::CueRelativePosition(val) => (EbmlId::CueRelativePosition, val.index, val.id),
-            Self::CueDuration(val) => (EbmlId::CueDuration, val.index, val.id),
-            Self::CueBlockNumber(val) => (EbmlId::CueBlockNumber, val.index, val.id),
-            Self::CueCodecState(val) => (EbmlId::CueCodecState, val.index, val.id),
-            Self::CueReference(val) => (EbmlId::CueReference, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::CueTrack(val) => (EbmlId::CueTrack as u64, val.index, val.id),
+            Self::CueClusterPosition(val) => (EbmlId::CueClusterPosition as u64, val.index, val.id),
+            Self::CueRelativePosition(val) => (EbmlId::CueRelativePosition as u64, val.index, val.id),
+            Self::CueDuration(val) => (EbmlId::CueDuration as u64, val.index, val.id),
+            Self::CueBlockNumber(val) => (EbmlId::CueBlockNumber as u64, val.index, val.id),
+            Self::CueCodecState(val) => (EbmlId::CueCodecState as u64, val.index, val.id),
+            Self::CueReference(val) => (EbmlId::CueReference as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(CueTrackPositionsFields);
+crate::impl_ord!(CueTrackPositionsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct CueReference {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub cue_ref_time: Ebml<u64>,
     pub cue_ref_cluster: Ebml<u64>,
     pub cue_ref_number: Option<Ebml<u64>>,
     pub cue_ref_codec_state: Option<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl CueReference {
-    pub fn elements(&self) -> std::collections::BTreeSet<CueReferenceFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<CueReferenceFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(CueReferenceFields::CueRefTime(self.cue_ref_time.clone()));
-        elements.insert(CueReferenceFields::CueRefCluster(self.cue_ref_cluster.clone()));
-        if let Some(el) = &self.cue_ref_number { elements.insert(CueReferenceFields::CueRefNumber(el.clone())); }
-        if let Some(el) = &self.cue_ref_codec_state { elements.insert(CueReferenceFields::CueRefCodecState(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(CueReferenceFields::Crc32(el)); }
+        elements.insert(CueReferenceFields::CueRefTime(&self.cue_ref_time));
+        elements.insert(CueReferenceFields::CueRefCluster(&self.cue_ref_cluster));
+        if let Some(el) = &self.cue_ref_number { elements.insert(CueReferenceFields::CueRefNumber(el)); }
+        if let Some(el) = &self.cue_ref_codec_state { elements.insert(CueReferenceFields::CueRefCodecState(el)); }
+        for el in &self.unknown { elements.insert(CueReferenceFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum CueReferenceFields {
-    CueRefTime(Ebml<u64>),
-    CueRefCluster(Ebml<u64>),
-    CueRefNumber(Ebml<u64>),
-    CueRefCodecState(Ebml<u64>),
-}
-impl CueReferenceFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum CueReferenceFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    CueRefTime(&'a Ebml<u64>),
+    CueRefCluster(&'a Ebml<u64>),
+    CueRefNumber(&'a Ebml<u64>),
+    CueRefCodecState(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl CueReferenceFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::CueRefTime(val) => (EbmlId::CueRefTime, val.index, val.id),
-            Self::CueRefCluster(val) => (EbmlId::CueRefCluster, val.index, val.id),
-            Self::CueRefNumber(val) => (EbmlId::CueRefNumber, val.index, val.id),
-            Self::CueRefCodecState(val) => (EbmlId::CueRefCodecState, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::CueRefTime(val) => (EbmlId::CueRefTime as u64, val.index, val.id),
+            Self::CueRefCluster(val) => (EbmlId::CueRefCluster as u64, val.index, val.id),
+            Self::CueRefNumber(val) => (EbmlId::CueRefNumber as u64, val.index, val.id),
+            Self::CueRefCodecState(val) => (EbmlId::CueRefCodecState as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(CueReferenceFields);
+crate::impl_ord!(CueReferenceFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Attachments {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub attached_file: Vec<Ebml<AttachedFile>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Attachments {
-    pub fn elements(&self) -> std::collections::BTreeSet<AttachmentsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<AttachmentsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.attached_file { elements.insert(AttachmentsFields::AttachedFile(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(AttachmentsFields::Crc32(el)); }
+        for el in &self.attached_file { elements.insert(AttachmentsFields::AttachedFile(el)); }
+        for el in &self.unknown { elements.insert(AttachmentsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum AttachmentsFields {
-    AttachedFile(Ebml<AttachedFile>),
+pub enum AttachmentsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    AttachedFile(&'a Ebml<AttachedFile>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl AttachmentsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl AttachmentsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::AttachedFile(val) => (EbmlId::AttachedFile, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::AttachedFile(val) => (EbmlId::AttachedFile as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(AttachmentsFields);
+crate::impl_ord!(AttachmentsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct AttachedFile {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub file_description: Option<Ebml<String>>,
     pub file_name: Ebml<String>,
     pub file_media_type: Ebml<String>,
@@ -1690,154 +2123,198 @@ pub struct AttachedFile {
     pub file_referral: Option<Ebml<Vec<u8>>>,
     pub file_used_start_time: Option<Ebml<u64>>,
     pub file_used_end_time: Option<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl AttachedFile {
-    pub fn elements(&self) -> std::collections::BTreeSet<AttachedFileFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<AttachedFileFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.file_description { elements.insert(AttachedFileFields::FileDescription(el.clone())); }
-        elements.insert(AttachedFileFields::FileName(self.file_name.clone()));
-        elements.insert(AttachedFileFields::FileMediaType(self.file_media_type.clone()));
-        elements.insert(AttachedFileFields::FileData(self.file_data.clone()));
-        elements.insert(AttachedFileFields::FileUid(self.file_uid.clone()));
-        if let Some(el) = &self.file_referral { elements.insert(AttachedFileFields::FileReferral(el.clone())); }
-        if let Some(el) = &self.file_used_start_time { elements.insert(AttachedFileFields::FileUsedStartTime(el.clone())); }
-        if let Some(el) = &self.file_used_end_time { elements.insert(AttachedFileFields::FileUsedEndTime(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(AttachedFileFields::Crc32(el)); }
+        if let Some(el) = &self.file_description { elements.insert(AttachedFileFields::FileDescription(el)); }
+        elements.insert(AttachedFileFields::FileName(&self.file_name));
+        elements.insert(AttachedFileFields::FileMediaType(&self.file_media_type));
+        elements.insert(AttachedFileFields::FileData(&self.file_data));
+        elements.insert(AttachedFileFields::FileUid(&self.file_uid));
+        if let Some(el) = &self.file_referral { elements.insert(AttachedFileFields::FileReferral(el)); }
+        if let Some(el) = &self.file_used_start_time { elements.insert(AttachedFileFields::FileUsedStartTime(el)); }
+        if let Some(el) = &self.file_used_end_time { elements.insert(AttachedFileFields::FileUsedEndTime(el)); }
+        for el in &self.unknown { elements.insert(AttachedFileFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum AttachedFileFields {
-    FileDescription(Ebml<String>),
-    FileName(Ebml<String>),
-    FileMediaType(Ebml<String>),
-    FileData(Ebml<Vec<u8>>),
-    FileUid(Ebml<u64>),
-    FileReferral(Ebml<Vec<u8>>),
-    FileUsedStartTime(Ebml<u64>),
-    FileUsedEndTime(Ebml<u64>),
-}
-impl AttachedFileFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum AttachedFileFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    FileDescription(&'a Ebml<String>),
+    FileName(&'a Ebml<String>),
+    FileMediaType(&'a Ebml<String>),
+    FileData(&'a Ebml<Vec<u8>>),
+    FileUid(&'a Ebml<u64>),
+    FileReferral(&'a Ebml<Vec<u8>>),
+    FileUsedStartTime(&'a Ebml<u64>),
+    FileUsedEndTime(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl AttachedFileFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::FileDescription(val) => (EbmlId::FileDescription, val.index, val.id),
-            Self::FileName(val) => (EbmlId::FileName, val.index, val.id),
-            Self::FileMediaType(val) => (EbmlId::FileMediaType, val.index, val.id),
-            Self::FileData(val) => (EbmlId::FileData, val.index, val.id),
-            Self::FileUid(val) => (EbmlId::FileUid, val.index, val.id),
-            Self::FileReferral(val) => (EbmlId::FileReferral, val.index, val.id),
-            Self::FileUsedStartTime(val) => (EbmlId::FileUsedStartTime, val.index, val.id),
-            Self::FileUsedEndTime(val) => (EbmlId::FileUsedEndTime, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::FileDescription(val) => (EbmlId::FileDescription as u64, val.index, val.id),
+            Self::FileName(val) => (EbmlId::FileName as u64, val.index, val.id),
+            Self::FileMediaType(val) => (EbmlId::FileMediaType as u64, val.index, val.id),
+            Self::FileData(val) => (EbmlId::FileData as u64, val.index, val.id),
+            Self::FileUid(val) => (EbmlId::FileUid as u64, val.index, val.id),
+            Self::FileReferral(val) => (EbmlId::FileReferral as u64, val.index, val.id),
+            Self::FileUsedStartTime(val) => (EbmlId::FileUsedStartTime as u64, val.index, val.id),
+            Self::FileUsedEndTime(val) => (EbmlId::FileUsedEndTime as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(AttachedFileFields);
+crate::impl_ord!(AttachedFileFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Chapters {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub edition_entry: Vec<Ebml<EditionEntry>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Chapters {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChaptersFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChaptersFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.edition_entry { elements.insert(ChaptersFields::EditionEntry(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChaptersFields::Crc32(el)); }
+        for el in &self.edition_entry { elements.insert(ChaptersFields::EditionEntry(el)); }
+        for el in &self.unknown { elements.insert(ChaptersFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChaptersFields {
-    EditionEntry(Ebml<EditionEntry>),
+pub enum ChaptersFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    EditionEntry(&'a Ebml<EditionEntry>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl ChaptersFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl ChaptersFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::EditionEntry(val) => (EbmlId::EditionEntry, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::EditionEntry(val) => (EbmlId::EditionEntry as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChaptersFields);
+crate::impl_ord!(ChaptersFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct EditionEntry {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub edition_uid: Option<Ebml<u64>>,
     pub edition_flag_hidden: Ebml<u64>,
     pub edition_flag_default: Ebml<u64>,
     pub edition_flag_ordered: Ebml<u64>,
     pub edition_display: Vec<Ebml<EditionDisplay>>,
     pub chapter_atom: Vec<Ebml<ChapterAtom>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl EditionEntry {
-    pub fn elements(&self) -> std::collections::BTreeSet<EditionEntryFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<EditionEntryFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        if let Some(el) = &self.edition_uid { elements.insert(EditionEntryFields::EditionUid(el.clone())); }
-        elements.insert(EditionEntryFields::EditionFlagHidden(self.edition_flag_hidden.clone()));
-        elements.insert(EditionEntryFields::EditionFlagDefault(self.edition_flag_default.clone()));
-        elements.insert(EditionEntryFields::EditionFlagOrdered(self.edition_flag_ordered.clone()));
-        for el in &self.edition_display { elements.insert(EditionEntryFields::EditionDisplay(el.clone())); }
-        for el in &self.chapter_atom { elements.insert(EditionEntryFields::ChapterAtom(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(EditionEntryFields::Crc32(el)); }
+        if let Some(el) = &self.edition_uid { elements.insert(EditionEntryFields::EditionUid(el)); }
+        elements.insert(EditionEntryFields::EditionFlagHidden(&self.edition_flag_hidden));
+        elements.insert(EditionEntryFields::EditionFlagDefault(&self.edition_flag_default));
+        elements.insert(EditionEntryFields::EditionFlagOrdered(&self.edition_flag_ordered));
+        for el in &self.edition_display { elements.insert(EditionEntryFields::EditionDisplay(el)); }
+        for el in &self.chapter_atom { elements.insert(EditionEntryFields::ChapterAtom(el)); }
+        for el in &self.unknown { elements.insert(EditionEntryFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum EditionEntryFields {
-    EditionUid(Ebml<u64>),
-    EditionFlagHidden(Ebml<u64>),
-    EditionFlagDefault(Ebml<u64>),
-    EditionFlagOrdered(Ebml<u64>),
-    EditionDisplay(Ebml<EditionDisplay>),
-    ChapterAtom(Ebml<ChapterAtom>),
-}
-impl EditionEntryFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum EditionEntryFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    EditionUid(&'a Ebml<u64>),
+    EditionFlagHidden(&'a Ebml<u64>),
+    EditionFlagDefault(&'a Ebml<u64>),
+    EditionFlagOrdered(&'a Ebml<u64>),
+    EditionDisplay(&'a Ebml<EditionDisplay>),
+    ChapterAtom(&'a Ebml<ChapterAtom>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl EditionEntryFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::EditionUid(val) => (EbmlId::EditionUid, val.index, val.id),
-            Self::EditionFlagHidden(val) => (EbmlId::EditionFlagHidden, val.index, val.id),
-            Self::EditionFlagDefault(val) => (EbmlId::EditionFlagDefault, val.index, val.id),
-            Self::EditionFlagOrdered(val) => (EbmlId::EditionFlagOrdered, val.index, val.id),
-            Self::EditionDisplay(val) => (EbmlId::EditionDisplay, val.index, val.id),
-            Self::ChapterAtom(val) => (EbmlId::ChapterAtom, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::EditionUid(val) => (EbmlId::EditionUid as u64, val.index, val.id),
+            Self::EditionFlagHidden(val) => (EbmlId::EditionFlagHidden as u64, val.index, val.id),
+            Self::EditionFlagDefault(val) => (EbmlId::EditionFlagDefault as u64, val.index, val.id),
+            Self::EditionFlagOrdered(val) => (EbmlId::EditionFlagOrdered as u64, val.index, val.id),
+            Self::EditionDisplay(val) => (EbmlId::EditionDisplay as u64, val.index, val.id),
+            Self::ChapterAtom(val) => (EbmlId::ChapterAtom as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(EditionEntryFields);
+crate::impl_ord!(EditionEntryFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct EditionDisplay {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub edition_string: Ebml<String>,
     pub edition_language_ietf: Vec<Ebml<String>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl EditionDisplay {
-    pub fn elements(&self) -> std::collections::BTreeSet<EditionDisplayFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<EditionDisplayFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(EditionDisplayFields::EditionString(self.edition_string.clone()));
-        for el in &self.edition_language_ietf { elements.insert(EditionDisplayFields::EditionLanguageIetf(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(EditionDisplayFields::Crc32(el)); }
+        elements.insert(EditionDisplayFields::EditionString(&self.edition_string));
+        for el in &self.edition_language_ietf { elements.insert(EditionDisplayFields::EditionLanguageIetf(el)); }
+        for el in &self.unknown { elements.insert(EditionDisplayFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum EditionDisplayFields {
-    EditionString(Ebml<String>),
-    EditionLanguageIetf(Ebml<String>),
-}
-impl EditionDisplayFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum EditionDisplayFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    EditionString(&'a Ebml<String>),
+    EditionLanguageIetf(&'a Ebml<String>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl EditionDisplayFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::EditionString(val) => (EbmlId::EditionString, val.index, val.id),
-            Self::EditionLanguageIetf(val) => (EbmlId::EditionLanguageIetf, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::EditionString(val) => (EbmlId::EditionString as u64, val.index, val.id),
+            Self::EditionLanguageIetf(val) => (EbmlId::EditionLanguageIetf as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(EditionDisplayFields);
+crate::impl_ord!(EditionDisplayFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapterAtom {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub chapter_uid: Ebml<u64>,
     pub chapter_string_uid: Option<Ebml<String>>,
     pub chapter_time_start: Ebml<u64>,
@@ -1845,303 +2322,391 @@ pub struct ChapterAtom {
     pub chapter_flag_hidden: Ebml<u64>,
     pub chapter_flag_enabled: Ebml<u64>,
     pub chapter_segment_uuid: Option<Ebml<Vec<u8>>>,
-    pub chapter_skip_type: Option<Ebml<u64>>,
+    pub chapter_skip_type: Option<Ebml<ChapterSkipType>>,
     pub chapter_segment_edition_uid: Option<Ebml<u64>>,
     pub chapter_physical_equiv: Option<Ebml<u64>>,
     pub chapter_track: Option<Ebml<ChapterTrack>>,
     pub chapter_display: Vec<Ebml<ChapterDisplay>>,
     pub chap_process: Vec<Ebml<ChapProcess>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapterAtom {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapterAtomFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapterAtomFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ChapterAtomFields::ChapterUid(self.chapter_uid.clone()));
-        if let Some(el) = &self.chapter_string_uid { elements.insert(ChapterAtomFields::ChapterStringUid(el.clone())); }
-        elements.insert(ChapterAtomFields::ChapterTimeStart(self.chapter_time_start.clone()));
-        if let Some(el) = &self.chapter_time_end { elements.insert(ChapterAtomFields::ChapterTimeEnd(el.clone())); }
-        elements.insert(ChapterAtomFields::ChapterFlagHidden(self.chapter_flag_hidden.clone()));
-        elements.insert(ChapterAtomFields::ChapterFlagEnabled(self.chapter_flag_enabled.clone()));
-        if let Some(el) = &self.chapter_segment_uuid { elements.insert(ChapterAtomFields::ChapterSegmentUuid(el.clone())); }
-        if let Some(el) = &self.chapter_skip_type { elements.insert(ChapterAtomFields::ChapterSkipType(el.clone())); }
-        if let Some(el) = &self.chapter_segment_edition_uid { elements.insert(ChapterAtomFields::ChapterSegmentEditionUid(el.clone())); }
-        if let Some(el) = &self.chapter_physical_equiv { elements.insert(ChapterAtomFields::ChapterPhysicalEquiv(el.clone())); }
-        if let Some(el) = &self.chapter_track { elements.insert(ChapterAtomFields::ChapterTrack(el.clone())); }
-        for el in &self.chapter_display { elements.insert(ChapterAtomFields::ChapterDisplay(el.clone())); }
-        for el in &self.chap_process { elements.insert(ChapterAtomFields::ChapProcess(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChapterAtomFields::Crc32(el)); }
+        elements.insert(ChapterAtomFields::ChapterUid(&self.chapter_uid));
+        if let Some(el) = &self.chapter_string_uid { elements.insert(ChapterAtomFields::ChapterStringUid(el)); }
+        elements.insert(ChapterAtomFields::ChapterTimeStart(&self.chapter_time_start));
+        if let Some(el) = &self.chapter_time_end { elements.insert(ChapterAtomFields::ChapterTimeEnd(el)); }
+        elements.insert(ChapterAtomFields::ChapterFlagHidden(&self.chapter_flag_hidden));
+        elements.insert(ChapterAtomFields::ChapterFlagEnabled(&self.chapter_flag_enabled));
+        if let Some(el) = &self.chapter_segment_uuid { elements.insert(ChapterAtomFields::ChapterSegmentUuid(el)); }
+        if let Some(el) = &self.chapter_skip_type { elements.insert(ChapterAtomFields::ChapterSkipType(el)); }
+        if let Some(el) = &self.chapter_segment_edition_uid { elements.insert(ChapterAtomFields::ChapterSegmentEditionUid(el)); }
+        if let Some(el) = &self.chapter_physical_equiv { elements.insert(ChapterAtomFields::ChapterPhysicalEquiv(el)); }
+        if let Some(el) = &self.chapter_track { elements.insert(ChapterAtomFields::ChapterTrack(el)); }
+        for el in &self.chapter_display { elements.insert(ChapterAtomFields::ChapterDisplay(el)); }
+        for el in &self.chap_process { elements.insert(ChapterAtomFields::ChapProcess(el)); }
+        for el in &self.unknown { elements.insert(ChapterAtomFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapterAtomFields {
-    ChapterUid(Ebml<u64>),
-    ChapterStringUid(Ebml<String>),
-    ChapterTimeStart(Ebml<u64>),
-    ChapterTimeEnd(Ebml<u64>),
-    ChapterFlagHidden(Ebml<u64>),
-    ChapterFlagEnabled(Ebml<u64>),
-    ChapterSegmentUuid(Ebml<Vec<u8>>),
-    ChapterSkipType(Ebml<u64>),
-    ChapterSegmentEditionUid(Ebml<u64>),
-    ChapterPhysicalEquiv(Ebml<u64>),
-    ChapterTrack(Ebml<ChapterTrack>),
-    ChapterDisplay(Ebml<ChapterDisplay>),
-    ChapProcess(Ebml<ChapProcess>),
-}
-impl ChapterAtomFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ChapterAtomFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapterUid(&'a Ebml<u64>),
+    ChapterStringUid(&'a Ebml<String>),
+    ChapterTimeStart(&'a Ebml<u64>),
+    ChapterTimeEnd(&'a Ebml<u64>),
+    ChapterFlagHidden(&'a Ebml<u64>),
+    ChapterFlagEnabled(&'a Ebml<u64>),
+    ChapterSegmentUuid(&'a Ebml<Vec<u8>>),
+    ChapterSkipType(&'a Ebml<ChapterSkipType>),
+    ChapterSegmentEditionUid(&'a Ebml<u64>),
+    ChapterPhysicalEquiv(&'a Ebml<u64>),
+    ChapterTrack(&'a Ebml<ChapterTrack>),
+    ChapterDisplay(&'a Ebml<ChapterDisplay>),
+    ChapProcess(&'a Ebml<ChapProcess>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ChapterAtomFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapterUid(val) => (EbmlId::ChapterUid, val.index, val.id),
-            Self::ChapterStringUid(val) => (EbmlId::ChapterStringUid, val.index, val.id),
-            Self::ChapterTimeStart(val) => (EbmlId::ChapterTimeStart, val.index, val.id),
-            Self::ChapterTimeEnd(val) => (EbmlId::ChapterTimeEnd, val.index, val.id),
-            Self::ChapterFlagHidden(val) => (EbmlId::ChapterFlagHidden, val.index, val.id),
-            Self::ChapterFlagEnabled(val) => (EbmlId::ChapterFlagEnabled, val.index, val.id),
-            Self::ChapterSegmentUuid(val) => (EbmlId::ChapterSegmentUuid, val.index, val.id),
-            Self::ChapterSkipType(val) => (EbmlId::ChapterSkipType, val.index, val.id),
-            Self::ChapterSegmentEditionUid(val) => (EbmlId::ChapterSegmentEditionUid, val.index, val.id),
-            Self::ChapterPhysicalEquiv(val) => (EbmlId::ChapterPhysicalEquiv, val.index, val.id),
-            Self::ChapterTrack(val) => (EbmlId::ChapterTrack, val.index, val.id),
-            Self::ChapterDisplay(val) => (EbmlId::ChapterDisplay, val.index, val.id),
-            Self::ChapProcess(val) => (EbmlId::ChapProcess, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapterUid(val) => (EbmlId::ChapterUid as u64, val.index, val.id),
+            Self::ChapterStringUid(val) => (EbmlId::ChapterStringUid as u64, val.index, val.id),
+            Self::ChapterTimeStart(val) => (EbmlId::ChapterTimeStart as u64, val.index, val.id),
+            Self::ChapterTimeEnd(val) => (EbmlId::ChapterTimeEnd as u64, val.index, val.id),
+            Self::ChapterFlagHidden(val) => (EbmlId::ChapterFlagHidden as u64, val.index, val.id),
+            Self::ChapterFlagEnabled(val) => (EbmlId::ChapterFlagEnabled as u64, val.index, val.id),
+            Self::ChapterSegmentUuid(val) => (EbmlId::ChapterSegmentUuid as u64, val.index, val.id),
+            Self::ChapterSkipType(val) => (EbmlId::ChapterSkipType as u64, val.index, val.id),
+            Self::ChapterSegmentEditionUid(val) => (EbmlId::ChapterSegmentEditionUid as u64, val.index, val.id),
+            Self::ChapterPhysicalEquiv(val) => (EbmlId::ChapterPhysicalEquiv as u64, val.index, val.id),
+            Self::ChapterTrack(val) => (EbmlId::ChapterTrack as u64, val.index, val.id),
+            Self::ChapterDisplay(val) => (EbmlId::ChapterDisplay as u64, val.index, val.id),
+            Self::ChapProcess(val) => (EbmlId::ChapProcess as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapterAtomFields);
+crate::impl_ord!(ChapterAtomFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapterTrack {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub chapter_track_uid: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapterTrack {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapterTrackFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapterTrackFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.chapter_track_uid { elements.insert(ChapterTrackFields::ChapterTrackUid(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChapterTrackFields::Crc32(el)); }
+        for el in &self.chapter_track_uid { elements.insert(ChapterTrackFields::ChapterTrackUid(el)); }
+        for el in &self.unknown { elements.insert(ChapterTrackFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapterTrackFields {
-    ChapterTrackUid(Ebml<u64>),
+pub enum ChapterTrackFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapterTrackUid(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl ChapterTrackFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl ChapterTrackFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapterTrackUid(val) => (EbmlId::ChapterTrackUid, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapterTrackUid(val) => (EbmlId::ChapterTrackUid as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapterTrackFields);
+crate::impl_ord!(ChapterTrackFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapterDisplay {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub chap_string: Ebml<String>,
     pub chap_language: Vec<Ebml<String>>,
     pub chap_language_bcp_47: Vec<Ebml<String>>,
     pub chap_country: Vec<Ebml<String>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapterDisplay {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapterDisplayFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapterDisplayFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ChapterDisplayFields::ChapString(self.chap_string.clone()));
-        for el in &self.chap_language { elements.insert(ChapterDisplayFields::ChapLanguage(el.clone())); }
-        for el in &self.chap_language_bcp_47 { elements.insert(ChapterDisplayFields::ChapLanguageBcp47(el.clone())); }
-        for el in &self.chap_country { elements.insert(ChapterDisplayFields::ChapCountry(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChapterDisplayFields::Crc32(el)); }
+        elements.insert(ChapterDisplayFields::ChapString(&self.chap_string));
+        for el in &self.chap_language { elements.insert(ChapterDisplayFields::ChapLanguage(el)); }
+        for el in &self.chap_language_bcp_47 { elements.insert(ChapterDisplayFields::ChapLanguageBcp47(el)); }
+        for el in &self.chap_country { elements.insert(ChapterDisplayFields::ChapCountry(el)); }
+        for el in &self.unknown { elements.insert(ChapterDisplayFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapterDisplayFields {
-    ChapString(Ebml<String>),
-    ChapLanguage(Ebml<String>),
-    ChapLanguageBcp47(Ebml<String>),
-    ChapCountry(Ebml<String>),
-}
-impl ChapterDisplayFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ChapterDisplayFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapString(&'a Ebml<String>),
+    ChapLanguage(&'a Ebml<String>),
+    ChapLanguageBcp47(&'a Ebml<String>),
+    ChapCountry(&'a Ebml<String>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ChapterDisplayFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapString(val) => (EbmlId::ChapString, val.index, val.id),
-            Self::ChapLanguage(val) => (EbmlId::ChapLanguage, val.index, val.id),
-            Self::ChapLanguageBcp47(val) => (EbmlId::ChapLanguageBcp47, val.index, val.id),
-            Self::ChapCountry(val) => (EbmlId::ChapCountry, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapString(val) => (EbmlId::ChapString as u64, val.index, val.id),
+            Self::ChapLanguage(val) => (EbmlId::ChapLanguage as u64, val.index, val.id),
+            Self::ChapLanguageBcp47(val) => (EbmlId::ChapLanguageBcp47 as u64, val.index, val.id),
+            Self::ChapCountry(val) => (EbmlId::ChapCountry as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapterDisplayFields);
+crate::impl_ord!(ChapterDisplayFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapProcess {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub chap_process_codec_id: Ebml<u64>,
     pub chap_process_private: Option<Ebml<Vec<u8>>>,
     pub chap_process_command: Vec<Ebml<ChapProcessCommand>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapProcess {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapProcessFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapProcessFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ChapProcessFields::ChapProcessCodecId(self.chap_process_codec_id.clone()));
-        if let Some(el) = &self.chap_process_private { elements.insert(ChapProcessFields::ChapProcessPrivate(el.clone())); }
-        for el in &self.chap_process_command { elements.insert(ChapProcessFields::ChapProcessCommand(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(ChapProcessFields::Crc32(el)); }
+        elements.insert(ChapProcessFields::ChapProcessCodecId(&self.chap_process_codec_id));
+        if let Some(el) = &self.chap_process_private { elements.insert(ChapProcessFields::ChapProcessPrivate(el)); }
+        for el in &self.chap_process_command { elements.insert(ChapProcessFields::ChapProcessCommand(el)); }
+        for el in &self.unknown { elements.insert(ChapProcessFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapProcessFields {
-    ChapProcessCodecId(Ebml<u64>),
-    ChapProcessPrivate(Ebml<Vec<u8>>),
-    ChapProcessCommand(Ebml<ChapProcessCommand>),
-}
-impl ChapProcessFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ChapProcessFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapProcessCodecId(&'a Ebml<u64>),
+    ChapProcessPrivate(&'a Ebml<Vec<u8>>),
+    ChapProcessCommand(&'a Ebml<ChapProcessCommand>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ChapProcessFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapProcessCodecId(val) => (EbmlId::ChapProcessCodecId, val.index, val.id),
-            Self::ChapProcessPrivate(val) => (EbmlId::ChapProcessPrivate, val.index, val.id),
-            Self::ChapProcessCommand(val) => (EbmlId::ChapProcessCommand, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapProcessCodecId(val) => (EbmlId::ChapProcessCodecId as u64, val.index, val.id),
+            Self::ChapProcessPrivate(val) => (EbmlId::ChapProcessPrivate as u64, val.index, val.id),
+            Self::ChapProcessCommand(val) => (EbmlId::ChapProcessCommand as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapProcessFields);
+crate::impl_ord!(ChapProcessFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct ChapProcessCommand {
     pub size: u64,
 
-    pub chap_process_time: Ebml<u64>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub chap_process_time: Ebml<ChapProcessTime>,
     pub chap_process_data: Ebml<Vec<u8>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl ChapProcessCommand {
-    pub fn elements(&self) -> std::collections::BTreeSet<ChapProcessCommandFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<ChapProcessCommandFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(ChapProcessCommandFields::ChapProcessTime(self.chap_process_time.clone()));
-        elements.insert(ChapProcessCommandFields::ChapProcessData(self.chap_process_data.clone()));
+        if let Some(el) = &self.crc_32 { elements.insert(ChapProcessCommandFields::Crc32(el)); }
+        elements.insert(ChapProcessCommandFields::ChapProcessTime(&self.chap_process_time));
+        elements.insert(ChapProcessCommandFields::ChapProcessData(&self.chap_process_data));
+        for el in &self.unknown { elements.insert(ChapProcessCommandFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum ChapProcessCommandFields {
-    ChapProcessTime(Ebml<u64>),
-    ChapProcessData(Ebml<Vec<u8>>),
-}
-impl ChapProcessCommandFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum ChapProcessCommandFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    ChapProcessTime(&'a Ebml<ChapProcessTime>),
+    ChapProcessData(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl ChapProcessCommandFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::ChapProcessTime(val) => (EbmlId::ChapProcessTime, val.index, val.id),
-            Self::ChapProcessData(val) => (EbmlId::ChapProcessData, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::ChapProcessTime(val) => (EbmlId::ChapProcessTime as u64, val.index, val.id),
+            Self::ChapProcessData(val) => (EbmlId::ChapProcessData as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(ChapProcessCommandFields);
+crate::impl_ord!(ChapProcessCommandFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Tags {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub tag: Vec<Ebml<Tag>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Tags {
-    pub fn elements(&self) -> std::collections::BTreeSet<TagsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TagsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        for el in &self.tag { elements.insert(TagsFields::Tag(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TagsFields::Crc32(el)); }
+        for el in &self.tag { elements.insert(TagsFields::Tag(el)); }
+        for el in &self.unknown { elements.insert(TagsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TagsFields {
-    Tag(Ebml<Tag>),
+pub enum TagsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Tag(&'a Ebml<Tag>),
+    Unknown(&'a Ebml<RawElement>),
 }
-impl TagsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+impl TagsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Tag(val) => (EbmlId::Tag, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Tag(val) => (EbmlId::Tag as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TagsFields);
+crate::impl_ord!(TagsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Tag {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub targets: Ebml<Targets>,
     pub simple_tag: Vec<Ebml<SimpleTag>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Tag {
-    pub fn elements(&self) -> std::collections::BTreeSet<TagFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TagFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(TagFields::Targets(self.targets.clone()));
-        for el in &self.simple_tag { elements.insert(TagFields::SimpleTag(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TagFields::Crc32(el)); }
+        elements.insert(TagFields::Targets(&self.targets));
+        for el in &self.simple_tag { elements.insert(TagFields::SimpleTag(el)); }
+        for el in &self.unknown { elements.insert(TagFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TagFields {
-    Targets(Ebml<Targets>),
-    SimpleTag(Ebml<SimpleTag>),
-}
-impl TagFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TagFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    Targets(&'a Ebml<Targets>),
+    SimpleTag(&'a Ebml<SimpleTag>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TagFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::Targets(val) => (EbmlId::Targets, val.index, val.id),
-            Self::SimpleTag(val) => (EbmlId::SimpleTag, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::Targets(val) => (EbmlId::Targets as u64, val.index, val.id),
+            Self::SimpleTag(val) => (EbmlId::SimpleTag as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TagFields);
+crate::impl_ord!(TagFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct Targets {
     pub size: u64,
 
-    pub target_type_value: Ebml<u64>,
-    pub target_type: Option<Ebml<String>>,
+    pub crc_32: Option<Ebml<Vec<u8>>>,
+    pub target_type_value: Ebml<TargetTypeValue>,
+    pub target_type: Option<Ebml<TargetType>>,
     pub tag_track_uid: Vec<Ebml<u64>>,
     pub tag_edition_uid: Vec<Ebml<u64>>,
     pub tag_chapter_uid: Vec<Ebml<u64>>,
     pub tag_attachment_uid: Vec<Ebml<u64>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl Targets {
-    pub fn elements(&self) -> std::collections::BTreeSet<TargetsFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<TargetsFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(TargetsFields::TargetTypeValue(self.target_type_value.clone()));
-        if let Some(el) = &self.target_type { elements.insert(TargetsFields::TargetType(el.clone())); }
-        for el in &self.tag_track_uid { elements.insert(TargetsFields::TagTrackUid(el.clone())); }
-        for el in &self.tag_edition_uid { elements.insert(TargetsFields::TagEditionUid(el.clone())); }
-        for el in &self.tag_chapter_uid { elements.insert(TargetsFields::TagChapterUid(el.clone())); }
-        for el in &self.tag_attachment_uid { elements.insert(TargetsFields::TagAttachmentUid(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(TargetsFields::Crc32(el)); }
+        elements.insert(TargetsFields::TargetTypeValue(&self.target_type_value));
+        if let Some(el) = &self.target_type { elements.insert(TargetsFields::TargetType(el)); }
+        for el in &self.tag_track_uid { elements.insert(TargetsFields::TagTrackUid(el)); }
+        for el in &self.tag_edition_uid { elements.insert(TargetsFields::TagEditionUid(el)); }
+        for el in &self.tag_chapter_uid { elements.insert(TargetsFields::TagChapterUid(el)); }
+        for el in &self.tag_attachment_uid { elements.insert(TargetsFields::TagAttachmentUid(el)); }
+        for el in &self.unknown { elements.insert(TargetsFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum TargetsFields {
-    TargetTypeValue(Ebml<u64>),
-    TargetType(Ebml<String>),
-    TagTrackUid(Ebml<u64>),
-    TagEditionUid(Ebml<u64>),
-    TagChapterUid(Ebml<u64>),
-    TagAttachmentUid(Ebml<u64>),
-}
-impl TargetsFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum TargetsFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TargetTypeValue(&'a Ebml<TargetTypeValue>),
+    TargetType(&'a Ebml<TargetType>),
+    TagTrackUid(&'a Ebml<u64>),
+    TagEditionUid(&'a Ebml<u64>),
+    TagChapterUid(&'a Ebml<u64>),
+    TagAttachmentUid(&'a Ebml<u64>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl TargetsFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TargetTypeValue(val) => (EbmlId::TargetTypeValue, val.index, val.id),
-            Self::TargetType(val) => (EbmlId::TargetType, val.index, val.id),
-            Self::TagTrackUid(val) => (EbmlId::TagTrackUid, val.index, val.id),
-            Self::TagEditionUid(val) => (EbmlId::TagEditionUid, val.index, val.id),
-            Self::TagChapterUid(val) => (EbmlId::TagChapterUid, val.index, val.id),
-            Self::TagAttachmentUid(val) => (EbmlId::TagAttachmentUid, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TargetTypeValue(val) => (EbmlId::TargetTypeValue as u64, val.index, val.id),
+            Self::TargetType(val) => (EbmlId::TargetType as u64, val.index, val.id),
+            Self::TagTrackUid(val) => (EbmlId::TagTrackUid as u64, val.index, val.id),
+            Self::TagEditionUid(val) => (EbmlId::TagEditionUid as u64, val.index, val.id),
+            Self::TagChapterUid(val) => (EbmlId::TagChapterUid as u64, val.index, val.id),
+            Self::TagAttachmentUid(val) => (EbmlId::TagAttachmentUid as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(TargetsFields);
+crate::impl_ord!(TargetsFields<'_>);
 
 #[derive(Debug, Clone, Default)]
 pub struct SimpleTag {
     pub size: u64,
 
+    pub crc_32: Option<Ebml<Vec<u8>>>,
     pub tag_name: Ebml<String>,
     pub tag_language: Ebml<String>,
     pub tag_language_bcp_47: Option<Ebml<String>>,
@@ -2149,42 +2714,52 @@ pub struct SimpleTag {
     pub tag_default_bogus: Ebml<u64>,
     pub tag_string: Option<Ebml<String>>,
     pub tag_binary: Option<Ebml<Vec<u8>>>,
+    /// Header encoding of the element read with `ReadOptions::lossless`
+    pub encoding: Option<ElementEncoding>,
+    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`
+    pub unknown: Vec<Ebml<RawElement>>,
 }
 impl SimpleTag {
-    pub fn elements(&self) -> std::collections::BTreeSet<SimpleTagFields> {
+    pub fn elements(&self) -> std::collections::BTreeSet<SimpleTagFields<'_>> {
         let mut elements = std::collections::BTreeSet::new();
-        elements.insert(SimpleTagFields::TagName(self.tag_name.clone()));
-        elements.insert(SimpleTagFields::TagLanguage(self.tag_language.clone()));
-        if let Some(el) = &self.tag_language_bcp_47 { elements.insert(SimpleTagFields::TagLanguageBcp47(el.clone())); }
-        elements.insert(SimpleTagFields::TagDefault(self.tag_default.clone()));
-        elements.insert(SimpleTagFields::TagDefaultBogus(self.tag_default_bogus.clone()));
-        if let Some(el) = &self.tag_string { elements.insert(SimpleTagFields::TagString(el.clone())); }
-        if let Some(el) = &self.tag_binary { elements.insert(SimpleTagFields::TagBinary(el.clone())); }
+        if let Some(el) = &self.crc_32 { elements.insert(SimpleTagFields::Crc32(el)); }
+        elements.insert(SimpleTagFields::TagName(&self.tag_name));
+        elements.insert(SimpleTagFields::TagLanguage(&self.tag_language));
+        if let Some(el) = &self.tag_language_bcp_47 { elements.insert(SimpleTagFields::TagLanguageBcp47(el)); }
+        elements.insert(SimpleTagFields::TagDefault(&self.tag_default));
+        elements.insert(SimpleTagFields::TagDefaultBogus(&self.tag_default_bogus));
+        if let Some(el) = &self.tag_string { elements.insert(SimpleTagFields::TagString(el)); }
+        if let Some(el) = &self.tag_binary { elements.insert(SimpleTagFields::TagBinary(el)); }
+        for el in &self.unknown { elements.insert(SimpleTagFields::Unknown(el)); }
         elements
     }
 }
 #[derive(Debug)]
-pub enum SimpleTagFields {
-    TagName(Ebml<String>),
-    TagLanguage(Ebml<String>),
-    TagLanguageBcp47(Ebml<String>),
-    TagDefault(Ebml<u64>),
-    TagDefaultBogus(Ebml<u64>),
-    TagString(Ebml<String>),
-    TagBinary(Ebml<Vec<u8>>),
-}
-impl SimpleTagFields {
-    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {
+pub enum SimpleTagFields<'a> {
+    Crc32(&'a Ebml<Vec<u8>>),
+    TagName(&'a Ebml<String>),
+    TagLanguage(&'a Ebml<String>),
+    TagLanguageBcp47(&'a Ebml<String>),
+    TagDefault(&'a Ebml<u64>),
+    TagDefaultBogus(&'a Ebml<u64>),
+    TagString(&'a Ebml<String>),
+    TagBinary(&'a Ebml<Vec<u8>>),
+    Unknown(&'a Ebml<RawElement>),
+}
+impl SimpleTagFields<'_> {
+    pub fn index(&self) -> (u64, Option<u64>, u64) {
         match self {
-            Self::TagName(val) => (EbmlId::TagName, val.index, val.id),
-            Self::TagLanguage(val) => (EbmlId::TagLanguage, val.index, val.id),
-            Self::TagLanguageBcp47(val) => (EbmlId::TagLanguageBcp47, val.index, val.id),
-            Self::TagDefault(val) => (EbmlId::TagDefault, val.index, val.id),
-            Self::TagDefaultBogus(val) => (EbmlId::TagDefaultBogus, val.index, val.id),
-            Self::TagString(val) => (EbmlId::TagString, val.index, val.id),
-            Self::TagBinary(val) => (EbmlId::TagBinary, val.index, val.id),
+            Self::Crc32(val) => (EbmlId::Crc32 as u64, val.index, val.id),
+            Self::TagName(val) => (EbmlId::TagName as u64, val.index, val.id),
+            Self::TagLanguage(val) => (EbmlId::TagLanguage as u64, val.index, val.id),
+            Self::TagLanguageBcp47(val) => (EbmlId::TagLanguageBcp47 as u64, val.index, val.id),
+            Self::TagDefault(val) => (EbmlId::TagDefault as u64, val.index, val.id),
+            Self::TagDefaultBogus(val) => (EbmlId::TagDefaultBogus as u64, val.index, val.id),
+            Self::TagString(val) => (EbmlId::TagString as u64, val.index, val.id),
+            Self::TagBinary(val) => (EbmlId::TagBinary as u64, val.index, val.id),
+            Self::Unknown(val) => (val.v.id, val.index, val.id),
         }
     }
 }
-crate::impl_ord!(SimpleTagFields);
+crate::impl_ord!(SimpleTagFields<'_>);
 
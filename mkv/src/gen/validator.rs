@@ -0,0 +1,1537 @@
+// The code was generated by codegen!
+
+use super::structs::*;
+use super::enums::*;
+use super::validate::{Diagnostic, Validation};
+
+impl EbmlHeader {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\EBML");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.doc_type_extension.iter() {
+            let path = format!("{path}\\DocTypeExtension");
+            el.v.validate_with(v, &path);
+        }
+        for el in std::iter::once(&self.ebml_max_id_length) {
+            let path = format!("{path}\\EBMLMaxIDLength");
+            let val = *el.v;
+            let valid = val == 4;
+            if !valid { v.error(&path, format!("value {val} is out of the range '4'")); }
+        }
+        for el in std::iter::once(&self.ebml_max_size_length) {
+            let path = format!("{path}\\EBMLMaxSizeLength");
+            let val = *el.v;
+            let valid = (1..=8).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '1-8'")); }
+        }
+    }
+}
+
+impl DocTypeExtension {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\EBML\\DocTypeExtension");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, _v: &mut Validation, _path: &str) {
+    }
+}
+
+impl Segment {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.seek_head.iter().enumerate() {
+            let path = format!("{path}\\SeekHead[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for el in std::iter::once(&self.info) {
+            let path = format!("{path}\\Info");
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.cluster.iter().enumerate() {
+            let path = format!("{path}\\Cluster[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.tracks.iter() {
+            let path = format!("{path}\\Tracks");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.cues.iter() {
+            let path = format!("{path}\\Cues");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.attachments.iter() {
+            let path = format!("{path}\\Attachments");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.chapters.iter() {
+            let path = format!("{path}\\Chapters");
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.tags.iter().enumerate() {
+            let path = format!("{path}\\Tags[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl SeekHead {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\SeekHead");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.seek.iter().enumerate() {
+            let path = format!("{path}\\Seek[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl Seek {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\SeekHead\\Seek");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.seek_id) {
+            let path = format!("{path}\\SeekID");
+            if el.v.len() != 4 { v.error(&path, format!("length {} must be 4", el.v.len())); }
+        }
+    }
+}
+
+impl Info {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Info");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.segment_uuid.iter() {
+            let path = format!("{path}\\SegmentUUID");
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for el in self.prev_uuid.iter() {
+            let path = format!("{path}\\PrevUUID");
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for el in self.next_uuid.iter() {
+            let path = format!("{path}\\NextUUID");
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for (i, el) in self.segment_family.iter().enumerate() {
+            let path = format!("{path}\\SegmentFamily[{i}]");
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for (i, el) in self.chapter_translate.iter().enumerate() {
+            let path = format!("{path}\\ChapterTranslate[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for el in std::iter::once(&self.timestamp_scale) {
+            let path = format!("{path}\\TimestampScale");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in self.duration.iter() {
+            let path = format!("{path}\\Duration");
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+    }
+}
+
+impl ChapterTranslate {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Info\\ChapterTranslate");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.chapter_translate_codec) {
+            let path = format!("{path}\\ChapterTranslateCodec");
+            let val = el.v.value();
+            if matches!(ChapterTranslateCodec::from(val), ChapterTranslateCodec::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl Cluster {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.silent_tracks.iter() {
+            let path = format!("{path}\\SilentTracks");
+            v.version(&path, 0, Some(0));
+            el.v.validate_with(v, &path);
+        }
+        for _el in self.position.iter() {
+            let path = format!("{path}\\Position");
+            v.version(&path, 1, Some(4));
+        }
+        for (i, _el) in self.simple_block.iter().enumerate() {
+            let path = format!("{path}\\SimpleBlock[{i}]");
+            v.version(&path, 2, None);
+        }
+        for (i, el) in self.block_group.iter().enumerate() {
+            let path = format!("{path}\\BlockGroup[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for (i, _el) in self.encrypted_block.iter().enumerate() {
+            let path = format!("{path}\\EncryptedBlock[{i}]");
+            v.version(&path, 0, Some(0));
+        }
+    }
+}
+
+impl SilentTracks {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\SilentTracks");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, _el) in self.silent_track_number.iter().enumerate() {
+            let path = format!("{path}\\SilentTrackNumber[{i}]");
+            v.version(&path, 0, Some(0));
+        }
+    }
+}
+
+impl BlockGroup {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in self.block_virtual.iter() {
+            let path = format!("{path}\\BlockVirtual");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.block_additions.iter() {
+            let path = format!("{path}\\BlockAdditions");
+            el.v.validate_with(v, &path);
+        }
+        for _el in self.reference_virtual.iter() {
+            let path = format!("{path}\\ReferenceVirtual");
+            v.version(&path, 0, Some(0));
+        }
+        for _el in self.codec_state.iter() {
+            let path = format!("{path}\\CodecState");
+            v.version(&path, 2, None);
+        }
+        for _el in self.discard_padding.iter() {
+            let path = format!("{path}\\DiscardPadding");
+            v.version(&path, 4, None);
+        }
+        for el in self.slices.iter() {
+            let path = format!("{path}\\Slices");
+            v.version(&path, 0, Some(0));
+            el.v.validate_with(v, &path);
+        }
+        for el in self.reference_frame.iter() {
+            let path = format!("{path}\\ReferenceFrame");
+            v.version(&path, 0, Some(0));
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl BlockAdditions {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup\\BlockAdditions");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.block_more.iter().enumerate() {
+            let path = format!("{path}\\BlockMore[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl BlockMore {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.block_add_id) {
+            let path = format!("{path}\\BlockAddID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+    }
+}
+
+impl Slices {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup\\Slices");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.time_slice.iter().enumerate() {
+            let path = format!("{path}\\TimeSlice[{i}]");
+            v.version(&path, 0, Some(0));
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl TimeSlice {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in self.lace_number.iter() {
+            let path = format!("{path}\\LaceNumber");
+            v.version(&path, 0, Some(0));
+        }
+    }
+}
+
+impl ReferenceFrame {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cluster\\BlockGroup\\ReferenceFrame");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in std::iter::once(&self.reference_offset) {
+            let path = format!("{path}\\ReferenceOffset");
+            v.version(&path, 0, Some(0));
+        }
+        for _el in std::iter::once(&self.reference_timestamp) {
+            let path = format!("{path}\\ReferenceTimestamp");
+            v.version(&path, 0, Some(0));
+        }
+    }
+}
+
+impl Tracks {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.track_entry.iter().enumerate() {
+            let path = format!("{path}\\TrackEntry[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl TrackEntry {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.track_number) {
+            let path = format!("{path}\\TrackNumber");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.track_uid) {
+            let path = format!("{path}\\TrackUID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.track_type) {
+            let path = format!("{path}\\TrackType");
+            let val = el.v.value();
+            if matches!(TrackType::from(val), TrackType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.flag_enabled) {
+            let path = format!("{path}\\FlagEnabled");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.flag_default) {
+            let path = format!("{path}\\FlagDefault");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.flag_forced) {
+            let path = format!("{path}\\FlagForced");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.flag_hearing_impaired.iter() {
+            let path = format!("{path}\\FlagHearingImpaired");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.flag_visual_impaired.iter() {
+            let path = format!("{path}\\FlagVisualImpaired");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.flag_text_descriptions.iter() {
+            let path = format!("{path}\\FlagTextDescriptions");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.flag_original.iter() {
+            let path = format!("{path}\\FlagOriginal");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.flag_commentary.iter() {
+            let path = format!("{path}\\FlagCommentary");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.flag_lacing) {
+            let path = format!("{path}\\FlagLacing");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for _el in self.max_cache.iter() {
+            let path = format!("{path}\\MaxCache");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.default_duration.iter() {
+            let path = format!("{path}\\DefaultDuration");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in self.default_decoded_field_duration.iter() {
+            let path = format!("{path}\\DefaultDecodedFieldDuration");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.track_timestamp_scale) {
+            let path = format!("{path}\\TrackTimestampScale");
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+        for (i, el) in self.block_addition_mapping.iter().enumerate() {
+            let path = format!("{path}\\BlockAdditionMapping[{i}]");
+            v.version(&path, 4, None);
+            el.v.validate_with(v, &path);
+        }
+        for _el in self.language_bcp_47.iter() {
+            let path = format!("{path}\\LanguageBCP47");
+            v.version(&path, 4, None);
+        }
+        for el in self.attachment_link.iter() {
+            let path = format!("{path}\\AttachmentLink");
+            v.version(&path, 1, Some(3));
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for _el in self.codec_settings.iter() {
+            let path = format!("{path}\\CodecSettings");
+            v.version(&path, 0, Some(0));
+        }
+        for (i, _el) in self.codec_info_url.iter().enumerate() {
+            let path = format!("{path}\\CodecInfoURL[{i}]");
+            v.version(&path, 0, Some(0));
+        }
+        for (i, _el) in self.codec_download_url.iter().enumerate() {
+            let path = format!("{path}\\CodecDownloadURL[{i}]");
+            v.version(&path, 0, Some(0));
+        }
+        for el in std::iter::once(&self.codec_decode_all) {
+            let path = format!("{path}\\CodecDecodeAll");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for (i, _el) in self.track_overlay.iter().enumerate() {
+            let path = format!("{path}\\TrackOverlay[{i}]");
+            v.version(&path, 1, Some(0));
+        }
+        for (i, el) in self.track_translate.iter().enumerate() {
+            let path = format!("{path}\\TrackTranslate[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.video.iter() {
+            let path = format!("{path}\\Video");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.audio.iter() {
+            let path = format!("{path}\\Audio");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.track_operation.iter() {
+            let path = format!("{path}\\TrackOperation");
+            v.version(&path, 3, None);
+            el.v.validate_with(v, &path);
+        }
+        for _el in self.trick_track_uid.iter() {
+            let path = format!("{path}\\TrickTrackUID");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.trick_track_segment_uid.iter() {
+            let path = format!("{path}\\TrickTrackSegmentUID");
+            v.version(&path, 0, Some(0));
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for _el in self.trick_struct_track_uid.iter() {
+            let path = format!("{path}\\TrickStructTrackUID");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.trick_struct_track_segment_uid.iter() {
+            let path = format!("{path}\\TrickStructTrackSegmentUID");
+            v.version(&path, 0, Some(0));
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for el in self.content_encodings.iter() {
+            let path = format!("{path}\\ContentEncodings");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl BlockAdditionMapping {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.block_add_id_value.iter() {
+            let path = format!("{path}\\BlockAddIDValue");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = val >= 2;
+            if !valid { v.error(&path, format!("value {val} is out of the range '>=2'")); }
+        }
+        for _el in self.block_add_id_name.iter() {
+            let path = format!("{path}\\BlockAddIDName");
+            v.version(&path, 4, None);
+        }
+        for _el in self.block_add_id_extra_data.iter() {
+            let path = format!("{path}\\BlockAddIDExtraData");
+            v.version(&path, 4, None);
+        }
+    }
+}
+
+impl TrackTranslate {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\TrackTranslate");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.track_translate_codec) {
+            let path = format!("{path}\\TrackTranslateCodec");
+            let val = el.v.value();
+            if matches!(TrackTranslateCodec::from(val), TrackTranslateCodec::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl Video {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\Video");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.flag_interlaced) {
+            let path = format!("{path}\\FlagInterlaced");
+            let val = el.v.value();
+            if matches!(FlagInterlaced::from(val), FlagInterlaced::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.field_order) {
+            let path = format!("{path}\\FieldOrder");
+            let val = el.v.value();
+            if matches!(FieldOrder::from(val), FieldOrder::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.stereo_mode) {
+            let path = format!("{path}\\StereoMode");
+            let val = el.v.value();
+            if matches!(StereoMode::from(val), StereoMode::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.alpha_mode) {
+            let path = format!("{path}\\AlphaMode");
+            let val = el.v.value();
+            if matches!(AlphaMode::from(val), AlphaMode::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.old_stereo_mode.iter() {
+            let path = format!("{path}\\OldStereoMode");
+            v.version(&path, 1, Some(2));
+            let val = el.v.value();
+            if matches!(OldStereoMode::from(val), OldStereoMode::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.pixel_width) {
+            let path = format!("{path}\\PixelWidth");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.pixel_height) {
+            let path = format!("{path}\\PixelHeight");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in self.display_width.iter() {
+            let path = format!("{path}\\DisplayWidth");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in self.display_height.iter() {
+            let path = format!("{path}\\DisplayHeight");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.display_unit) {
+            let path = format!("{path}\\DisplayUnit");
+            let val = el.v.value();
+            if matches!(DisplayUnit::from(val), DisplayUnit::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.aspect_ratio_type.iter() {
+            let path = format!("{path}\\AspectRatioType");
+            let val = el.v.value();
+            if matches!(AspectRatioType::from(val), AspectRatioType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.uncompressed_four_cc.iter() {
+            let path = format!("{path}\\UncompressedFourCC");
+            if el.v.len() != 4 { v.error(&path, format!("length {} must be 4", el.v.len())); }
+        }
+        for el in self.gamma_value.iter() {
+            let path = format!("{path}\\GammaValue");
+            v.version(&path, 0, Some(0));
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+        for el in self.frame_rate.iter() {
+            let path = format!("{path}\\FrameRate");
+            v.version(&path, 0, Some(0));
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+        for el in self.colour.iter() {
+            let path = format!("{path}\\Colour");
+            v.version(&path, 4, None);
+            el.v.validate_with(v, &path);
+        }
+        for el in self.projection.iter() {
+            let path = format!("{path}\\Projection");
+            v.version(&path, 4, None);
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl Colour {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\Video\\Colour");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.matrix_coefficients) {
+            let path = format!("{path}\\MatrixCoefficients");
+            let val = el.v.value();
+            if matches!(MatrixCoefficients::from(val), MatrixCoefficients::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for _el in self.chroma_subsampling_horz.iter() {
+            let path = format!("{path}\\ChromaSubsamplingHorz");
+            v.version(&path, 4, None);
+        }
+        for _el in self.chroma_subsampling_vert.iter() {
+            let path = format!("{path}\\ChromaSubsamplingVert");
+            v.version(&path, 4, None);
+        }
+        for _el in self.cb_subsampling_horz.iter() {
+            let path = format!("{path}\\CbSubsamplingHorz");
+            v.version(&path, 4, None);
+        }
+        for _el in self.cb_subsampling_vert.iter() {
+            let path = format!("{path}\\CbSubsamplingVert");
+            v.version(&path, 4, None);
+        }
+        for el in std::iter::once(&self.chroma_siting_horz) {
+            let path = format!("{path}\\ChromaSitingHorz");
+            let val = el.v.value();
+            if matches!(ChromaSitingHorz::from(val), ChromaSitingHorz::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.chroma_siting_vert) {
+            let path = format!("{path}\\ChromaSitingVert");
+            let val = el.v.value();
+            if matches!(ChromaSitingVert::from(val), ChromaSitingVert::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.range) {
+            let path = format!("{path}\\Range");
+            let val = el.v.value();
+            if matches!(Range::from(val), Range::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.transfer_characteristics) {
+            let path = format!("{path}\\TransferCharacteristics");
+            let val = el.v.value();
+            if matches!(TransferCharacteristics::from(val), TransferCharacteristics::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.primaries) {
+            let path = format!("{path}\\Primaries");
+            let val = el.v.value();
+            if matches!(Primaries::from(val), Primaries::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for _el in self.max_cll.iter() {
+            let path = format!("{path}\\MaxCLL");
+            v.version(&path, 4, None);
+        }
+        for _el in self.max_fall.iter() {
+            let path = format!("{path}\\MaxFALL");
+            v.version(&path, 4, None);
+        }
+        for el in self.structing_metadata.iter() {
+            let path = format!("{path}\\StructingMetadata");
+            v.version(&path, 4, None);
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl StructingMetadata {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\StructingMetadata");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.primary_r_chromaticity_x.iter() {
+            let path = format!("{path}\\PrimaryRChromaticityX");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.primary_r_chromaticity_y.iter() {
+            let path = format!("{path}\\PrimaryRChromaticityY");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.primary_g_chromaticity_x.iter() {
+            let path = format!("{path}\\PrimaryGChromaticityX");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.primary_g_chromaticity_y.iter() {
+            let path = format!("{path}\\PrimaryGChromaticityY");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.primary_b_chromaticity_x.iter() {
+            let path = format!("{path}\\PrimaryBChromaticityX");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.primary_b_chromaticity_y.iter() {
+            let path = format!("{path}\\PrimaryBChromaticityY");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.white_point_chromaticity_x.iter() {
+            let path = format!("{path}\\WhitePointChromaticityX");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.white_point_chromaticity_y.iter() {
+            let path = format!("{path}\\WhitePointChromaticityY");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = (hexf::hexf64!("0x0p+0")..=hexf::hexf64!("0x1p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0x0p+0-0x1p+0'")); }
+        }
+        for el in self.luminance_max.iter() {
+            let path = format!("{path}\\LuminanceMax");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = val >= hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '>= 0x0p+0'")); }
+        }
+        for el in self.luminance_min.iter() {
+            let path = format!("{path}\\LuminanceMin");
+            v.version(&path, 4, None);
+            let val = *el.v;
+            let valid = val >= hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '>= 0x0p+0'")); }
+        }
+    }
+}
+
+impl Projection {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\Video\\Projection");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.projection_type) {
+            let path = format!("{path}\\ProjectionType");
+            let val = el.v.value();
+            if matches!(ProjectionType::from(val), ProjectionType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for _el in self.projection_private.iter() {
+            let path = format!("{path}\\ProjectionPrivate");
+            v.version(&path, 4, None);
+        }
+        for el in std::iter::once(&self.projection_pose_yaw) {
+            let path = format!("{path}\\ProjectionPoseYaw");
+            let val = *el.v;
+            let valid = (hexf::hexf64!("-0xB4p+0")..=hexf::hexf64!("0xB4p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '>= -0xB4p+0, <= 0xB4p+0'")); }
+        }
+        for el in std::iter::once(&self.projection_pose_pitch) {
+            let path = format!("{path}\\ProjectionPosePitch");
+            let val = *el.v;
+            let valid = (hexf::hexf64!("-0x5Ap+0")..=hexf::hexf64!("0x5Ap+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '>= -0x5Ap+0, <= 0x5Ap+0'")); }
+        }
+        for el in std::iter::once(&self.projection_pose_roll) {
+            let path = format!("{path}\\ProjectionPoseRoll");
+            let val = *el.v;
+            let valid = (hexf::hexf64!("-0xB4p+0")..=hexf::hexf64!("0xB4p+0")).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '>= -0xB4p+0, <= 0xB4p+0'")); }
+        }
+    }
+}
+
+impl Audio {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\Audio");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.sampling_frequency) {
+            let path = format!("{path}\\SamplingFrequency");
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+        for el in self.output_sampling_frequency.iter() {
+            let path = format!("{path}\\OutputSamplingFrequency");
+            let val = *el.v;
+            let valid = val > hexf::hexf64!("0x0p+0");
+            if !valid { v.error(&path, format!("value {val} is out of the range '> 0x0p+0'")); }
+        }
+        for el in std::iter::once(&self.channels) {
+            let path = format!("{path}\\Channels");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for _el in self.channel_positions.iter() {
+            let path = format!("{path}\\ChannelPositions");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.bit_depth.iter() {
+            let path = format!("{path}\\BitDepth");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.emphasis) {
+            let path = format!("{path}\\Emphasis");
+            let val = el.v.value();
+            if matches!(Emphasis::from(val), Emphasis::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl TrackOperation {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\TrackOperation");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.track_combine_planes.iter() {
+            let path = format!("{path}\\TrackCombinePlanes");
+            v.version(&path, 3, None);
+            el.v.validate_with(v, &path);
+        }
+        for el in self.track_join_blocks.iter() {
+            let path = format!("{path}\\TrackJoinBlocks");
+            v.version(&path, 3, None);
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl TrackCombinePlanes {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.track_plane.iter().enumerate() {
+            let path = format!("{path}\\TrackPlane[{i}]");
+            v.version(&path, 3, None);
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl TrackPlane {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.track_plane_uid) {
+            let path = format!("{path}\\TrackPlaneUID");
+            v.version(&path, 3, None);
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.track_plane_type) {
+            let path = format!("{path}\\TrackPlaneType");
+            v.version(&path, 3, None);
+            let val = el.v.value();
+            if matches!(TrackPlaneType::from(val), TrackPlaneType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl TrackJoinBlocks {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackJoinBlocks");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.track_join_uid.iter().enumerate() {
+            let path = format!("{path}\\TrackJoinUID[{i}]");
+            v.version(&path, 3, None);
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+    }
+}
+
+impl ContentEncodings {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\ContentEncodings");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.content_encoding.iter().enumerate() {
+            let path = format!("{path}\\ContentEncoding[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl ContentEncoding {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.content_encoding_scope) {
+            let path = format!("{path}\\ContentEncodingScope");
+            let val = el.v.value();
+            if matches!(ContentEncodingScope::from(val), ContentEncodingScope::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in std::iter::once(&self.content_encoding_type) {
+            let path = format!("{path}\\ContentEncodingType");
+            let val = el.v.value();
+            if matches!(ContentEncodingType::from(val), ContentEncodingType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.content_compression.iter() {
+            let path = format!("{path}\\ContentCompression");
+            el.v.validate_with(v, &path);
+        }
+        for el in self.content_encryption.iter() {
+            let path = format!("{path}\\ContentEncryption");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl ContentCompression {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.content_comp_algo) {
+            let path = format!("{path}\\ContentCompAlgo");
+            let val = el.v.value();
+            if matches!(ContentCompAlgo::from(val), ContentCompAlgo::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl ContentEncryption {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.content_enc_algo) {
+            let path = format!("{path}\\ContentEncAlgo");
+            let val = el.v.value();
+            if matches!(ContentEncAlgo::from(val), ContentEncAlgo::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.content_enc_aes_settings.iter() {
+            let path = format!("{path}\\ContentEncAESSettings");
+            v.version(&path, 4, None);
+            el.v.validate_with(v, &path);
+        }
+        for _el in self.content_signature.iter() {
+            let path = format!("{path}\\ContentSignature");
+            v.version(&path, 1, Some(0));
+        }
+        for _el in self.content_sig_key_id.iter() {
+            let path = format!("{path}\\ContentSigKeyID");
+            v.version(&path, 1, Some(0));
+        }
+        for el in self.content_sig_algo.iter() {
+            let path = format!("{path}\\ContentSigAlgo");
+            let val = el.v.value();
+            if matches!(ContentSigAlgo::from(val), ContentSigAlgo::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.content_sig_hash_algo.iter() {
+            let path = format!("{path}\\ContentSigHashAlgo");
+            let val = el.v.value();
+            if matches!(ContentSigHashAlgo::from(val), ContentSigHashAlgo::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl ContentEncAesSettings {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAESSettings");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.aes_settings_cipher_mode) {
+            let path = format!("{path}\\AESSettingsCipherMode");
+            v.version(&path, 4, None);
+            let val = el.v.value();
+            if matches!(AesSettingsCipherMode::from(val), AesSettingsCipherMode::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl Cues {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cues");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.cue_point.iter().enumerate() {
+            let path = format!("{path}\\CuePoint[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl CuePoint {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cues\\CuePoint");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.cue_track_positions.iter().enumerate() {
+            let path = format!("{path}\\CueTrackPositions[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl CueTrackPositions {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cues\\CuePoint\\CueTrackPositions");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.cue_track) {
+            let path = format!("{path}\\CueTrack");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for _el in self.cue_relative_position.iter() {
+            let path = format!("{path}\\CueRelativePosition");
+            v.version(&path, 4, None);
+        }
+        for _el in self.cue_duration.iter() {
+            let path = format!("{path}\\CueDuration");
+            v.version(&path, 4, None);
+        }
+        for el in self.cue_block_number.iter() {
+            let path = format!("{path}\\CueBlockNumber");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for (i, el) in self.cue_reference.iter().enumerate() {
+            let path = format!("{path}\\CueReference[{i}]");
+            v.version(&path, 2, None);
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl CueReference {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in std::iter::once(&self.cue_ref_time) {
+            let path = format!("{path}\\CueRefTime");
+            v.version(&path, 2, None);
+        }
+        for _el in std::iter::once(&self.cue_ref_cluster) {
+            let path = format!("{path}\\CueRefCluster");
+            v.version(&path, 0, Some(0));
+        }
+        for el in self.cue_ref_number.iter() {
+            let path = format!("{path}\\CueRefNumber");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+    }
+}
+
+impl Attachments {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Attachments");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.attached_file.iter().enumerate() {
+            let path = format!("{path}\\AttachedFile[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl AttachedFile {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Attachments\\AttachedFile");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.file_uid) {
+            let path = format!("{path}\\FileUID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for _el in self.file_referral.iter() {
+            let path = format!("{path}\\FileReferral");
+            v.version(&path, 0, Some(0));
+        }
+        for _el in self.file_used_start_time.iter() {
+            let path = format!("{path}\\FileUsedStartTime");
+            v.version(&path, 0, Some(0));
+        }
+        for _el in self.file_used_end_time.iter() {
+            let path = format!("{path}\\FileUsedEndTime");
+            v.version(&path, 0, Some(0));
+        }
+    }
+}
+
+impl Chapters {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.edition_entry.iter().enumerate() {
+            let path = format!("{path}\\EditionEntry[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl EditionEntry {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in self.edition_uid.iter() {
+            let path = format!("{path}\\EditionUID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in std::iter::once(&self.edition_flag_hidden) {
+            let path = format!("{path}\\EditionFlagHidden");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.edition_flag_default) {
+            let path = format!("{path}\\EditionFlagDefault");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.edition_flag_ordered) {
+            let path = format!("{path}\\EditionFlagOrdered");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for (i, el) in self.edition_display.iter().enumerate() {
+            let path = format!("{path}\\EditionDisplay[{i}]");
+            v.version(&path, 5, None);
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.chapter_atom.iter().enumerate() {
+            let path = format!("{path}\\ChapterAtom[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl EditionDisplay {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\EditionDisplay");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in std::iter::once(&self.edition_string) {
+            let path = format!("{path}\\EditionString");
+            v.version(&path, 5, None);
+        }
+        for (i, _el) in self.edition_language_ietf.iter().enumerate() {
+            let path = format!("{path}\\EditionLanguageIETF[{i}]");
+            v.version(&path, 5, None);
+        }
+    }
+}
+
+impl ChapterAtom {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\ChapterAtom");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.chapter_uid) {
+            let path = format!("{path}\\ChapterUID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for _el in self.chapter_string_uid.iter() {
+            let path = format!("{path}\\ChapterStringUID");
+            v.version(&path, 3, None);
+        }
+        for el in std::iter::once(&self.chapter_flag_hidden) {
+            let path = format!("{path}\\ChapterFlagHidden");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.chapter_flag_enabled) {
+            let path = format!("{path}\\ChapterFlagEnabled");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in self.chapter_segment_uuid.iter() {
+            let path = format!("{path}\\ChapterSegmentUUID");
+            if el.v.len() != 16 { v.error(&path, format!("length {} must be 16", el.v.len())); }
+        }
+        for el in self.chapter_skip_type.iter() {
+            let path = format!("{path}\\ChapterSkipType");
+            v.version(&path, 5, None);
+            v.not_webm(&path);
+            let val = el.v.value();
+            if matches!(ChapterSkipType::from(val), ChapterSkipType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.chapter_segment_edition_uid.iter() {
+            let path = format!("{path}\\ChapterSegmentEditionUID");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+        for el in self.chapter_track.iter() {
+            let path = format!("{path}\\ChapterTrack");
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.chapter_display.iter().enumerate() {
+            let path = format!("{path}\\ChapterDisplay[{i}]");
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.chap_process.iter().enumerate() {
+            let path = format!("{path}\\ChapProcess[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl ChapterTrack {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\ChapterAtom\\ChapterTrack");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.chapter_track_uid.iter().enumerate() {
+            let path = format!("{path}\\ChapterTrackUID[{i}]");
+            let val = *el.v;
+            let valid = val != 0;
+            if !valid { v.error(&path, format!("value {val} is out of the range 'not 0'")); }
+        }
+    }
+}
+
+impl ChapterDisplay {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\ChapterAtom\\ChapterDisplay");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, _el) in self.chap_language_bcp_47.iter().enumerate() {
+            let path = format!("{path}\\ChapLanguageBCP47[{i}]");
+            v.version(&path, 4, None);
+        }
+    }
+}
+
+impl ChapProcess {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\ChapterAtom\\ChapProcess");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.chap_process_command.iter().enumerate() {
+            let path = format!("{path}\\ChapProcessCommand[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl ChapProcessCommand {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Chapters\\EditionEntry\\ChapterAtom\\ChapProcess\\ChapProcessCommand");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.chap_process_time) {
+            let path = format!("{path}\\ChapProcessTime");
+            let val = el.v.value();
+            if matches!(ChapProcessTime::from(val), ChapProcessTime::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl Tags {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tags");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for (i, el) in self.tag.iter().enumerate() {
+            let path = format!("{path}\\Tag[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl Tag {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tags\\Tag");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.targets) {
+            let path = format!("{path}\\Targets");
+            el.v.validate_with(v, &path);
+        }
+        for (i, el) in self.simple_tag.iter().enumerate() {
+            let path = format!("{path}\\SimpleTag[{i}]");
+            el.v.validate_with(v, &path);
+        }
+    }
+}
+
+impl Targets {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tags\\Tag\\Targets");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for el in std::iter::once(&self.target_type_value) {
+            let path = format!("{path}\\TargetTypeValue");
+            let val = el.v.value();
+            if matches!(TargetTypeValue::from(val), TargetTypeValue::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+        for el in self.target_type.iter() {
+            let path = format!("{path}\\TargetType");
+            let val = el.v.as_str();
+            if matches!(TargetType::from(val.to_string()), TargetType::Other(_)) { v.warning(&path, format!("value {val:?} is not defined by the specification")); }
+        }
+    }
+}
+
+impl SimpleTag {
+    /// Check values against the specification, the element is expected at its path in the file
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validation = Validation::default();
+        self.validate_with(&mut validation, "\\Segment\\Tags\\Tag\\SimpleTag");
+        validation.into_diagnostics()
+    }
+    pub fn validate_with(&self, v: &mut Validation, path: &str) {
+        for _el in self.tag_language_bcp_47.iter() {
+            let path = format!("{path}\\TagLanguageBCP47");
+            v.version(&path, 4, None);
+        }
+        for el in std::iter::once(&self.tag_default) {
+            let path = format!("{path}\\TagDefault");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+        for el in std::iter::once(&self.tag_default_bogus) {
+            let path = format!("{path}\\TagDefaultBogus");
+            let val = *el.v;
+            let valid = (0..=1).contains(&val);
+            if !valid { v.error(&path, format!("value {val} is out of the range '0-1'")); }
+        }
+    }
+}
+
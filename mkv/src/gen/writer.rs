@@ -3,62 +3,139 @@ use std::collections::VecDeque;
 
 use super::io::*;
 use super::structs::*;
+use super::enums::*;
 use super::ids::EbmlId;
+use super::{MatroskaError, WriteOptions};
+use super::crc32;
 use tokio::io::AsyncWriteExt;
 
 impl EbmlHeader {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::EbmlHeader as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        size += size_el_encoded(EbmlId::Version as u64, gen_uint_encoded(&self.version.v, self.version.encoding.as_ref()).len() as u64, self.version.encoding.as_ref());
+        size += size_el_encoded(EbmlId::ReadVersion as u64, gen_uint_encoded(&self.read_version.v, self.read_version.encoding.as_ref()).len() as u64, self.read_version.encoding.as_ref());
+        size += size_el_encoded(EbmlId::DocType as u64, self.doc_type.v.len() as u64, self.doc_type.encoding.as_ref());
+        size += size_el_encoded(EbmlId::DocTypeVersion as u64, gen_uint_encoded(&self.doc_type_version.v, self.doc_type_version.encoding.as_ref()).len() as u64, self.doc_type_version.encoding.as_ref());
+        size += size_el_encoded(EbmlId::DocTypeReadVersion as u64, gen_uint_encoded(&self.doc_type_read_version.v, self.doc_type_read_version.encoding.as_ref()).len() as u64, self.doc_type_read_version.encoding.as_ref());
+        if let Some(val) = &self.doc_type_extension { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.doc_type_extension_name { size += size_el_encoded(EbmlId::DocTypeExtensionName as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.doc_type_extension_version { size += size_el_encoded(EbmlId::DocTypeExtensionVersion as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.ebml_max_id_length.v != 4 || (!options.omit_defaults && (self.encoding.is_none() || !self.ebml_max_id_length.defaulted)) { size += size_el_encoded(EbmlId::EbmlMaxIdLength as u64, gen_uint_encoded(&self.ebml_max_id_length.v, self.ebml_max_id_length.encoding.as_ref()).len() as u64, self.ebml_max_id_length.encoding.as_ref()); }
+        if *self.ebml_max_size_length.v != 8 || (!options.omit_defaults && (self.encoding.is_none() || !self.ebml_max_size_length.defaulted)) { size += size_el_encoded(EbmlId::EbmlMaxSizeLength as u64, gen_uint_encoded(&self.ebml_max_size_length.v, self.ebml_max_size_length.encoding.as_ref()).len() as u64, self.ebml_max_size_length.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl EbmlHeader {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::EbmlHeader as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::EbmlHeader as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EbmlHeaderFields::Version(val) => blocking::write_el_uint(w, EbmlId::Version as u64, &*val.v)?,
-                EbmlHeaderFields::ReadVersion(val) => blocking::write_el_uint(w, EbmlId::ReadVersion as u64, &*val.v)?,
-                EbmlHeaderFields::DocType(val) => blocking::write_el_utf8(w, EbmlId::DocType as u64, &val.v)?,
-                EbmlHeaderFields::DocTypeVersion(val) => blocking::write_el_uint(w, EbmlId::DocTypeVersion as u64, &*val.v)?,
-                EbmlHeaderFields::DocTypeReadVersion(val) => blocking::write_el_uint(w, EbmlId::DocTypeReadVersion as u64, &*val.v)?,
-                EbmlHeaderFields::DocTypeExtension(val) => val.v.write_blocking(w)?,
-                EbmlHeaderFields::DocTypeExtensionName(val) => blocking::write_el_utf8(w, EbmlId::DocTypeExtensionName as u64, &val.v)?,
-                EbmlHeaderFields::DocTypeExtensionVersion(val) => blocking::write_el_uint(w, EbmlId::DocTypeExtensionVersion as u64, &*val.v)?,
-                EbmlHeaderFields::EbmlMaxIdLength(val) => blocking::write_el_uint(w, EbmlId::EbmlMaxIdLength as u64, &*val.v)?,
-                EbmlHeaderFields::EbmlMaxSizeLength(val) => blocking::write_el_uint(w, EbmlId::EbmlMaxSizeLength as u64, &*val.v)?,
+                EbmlHeaderFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EbmlHeaderFields::Version(val) => blocking::write_el_encoded(w, EbmlId::Version as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::ReadVersion(val) => blocking::write_el_encoded(w, EbmlId::ReadVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::DocType(val) => blocking::write_el_encoded(w, EbmlId::DocType as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                EbmlHeaderFields::DocTypeVersion(val) => blocking::write_el_encoded(w, EbmlId::DocTypeVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::DocTypeReadVersion(val) => blocking::write_el_encoded(w, EbmlId::DocTypeReadVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::DocTypeExtension(val) => val.v.write_with_blocking(w, options)?,
+                EbmlHeaderFields::DocTypeExtensionName(val) => blocking::write_el_encoded(w, EbmlId::DocTypeExtensionName as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                EbmlHeaderFields::DocTypeExtensionVersion(val) => blocking::write_el_encoded(w, EbmlId::DocTypeExtensionVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                EbmlHeaderFields::EbmlMaxIdLength(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 4 => 0,
+                EbmlHeaderFields::EbmlMaxIdLength(val) => blocking::write_el_encoded(w, EbmlId::EbmlMaxIdLength as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::EbmlMaxSizeLength(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 8 => 0,
+                EbmlHeaderFields::EbmlMaxSizeLength(val) => blocking::write_el_encoded(w, EbmlId::EbmlMaxSizeLength as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EbmlHeaderFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl EbmlHeader {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::EbmlHeader as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::EbmlHeader as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EbmlHeaderFields::Version(val) => async_::write_el_uint(w, EbmlId::Version as u64, &*val.v).await?,
-                EbmlHeaderFields::ReadVersion(val) => async_::write_el_uint(w, EbmlId::ReadVersion as u64, &*val.v).await?,
-                EbmlHeaderFields::DocType(val) => async_::write_el_utf8(w, EbmlId::DocType as u64, &val.v).await?,
-                EbmlHeaderFields::DocTypeVersion(val) => async_::write_el_uint(w, EbmlId::DocTypeVersion as u64, &*val.v).await?,
-                EbmlHeaderFields::DocTypeReadVersion(val) => async_::write_el_uint(w, EbmlId::DocTypeReadVersion as u64, &*val.v).await?,
-                EbmlHeaderFields::DocTypeExtension(val) => val.v.write(w).await?,
-                EbmlHeaderFields::DocTypeExtensionName(val) => async_::write_el_utf8(w, EbmlId::DocTypeExtensionName as u64, &val.v).await?,
-                EbmlHeaderFields::DocTypeExtensionVersion(val) => async_::write_el_uint(w, EbmlId::DocTypeExtensionVersion as u64, &*val.v).await?,
-                EbmlHeaderFields::EbmlMaxIdLength(val) => async_::write_el_uint(w, EbmlId::EbmlMaxIdLength as u64, &*val.v).await?,
-                EbmlHeaderFields::EbmlMaxSizeLength(val) => async_::write_el_uint(w, EbmlId::EbmlMaxSizeLength as u64, &*val.v).await?,
+                EbmlHeaderFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EbmlHeaderFields::Version(val) => async_::write_el_encoded(w, EbmlId::Version as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::ReadVersion(val) => async_::write_el_encoded(w, EbmlId::ReadVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::DocType(val) => async_::write_el_encoded(w, EbmlId::DocType as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::DocTypeVersion(val) => async_::write_el_encoded(w, EbmlId::DocTypeVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::DocTypeReadVersion(val) => async_::write_el_encoded(w, EbmlId::DocTypeReadVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::DocTypeExtension(val) => val.v.write_with(w, options).await?,
+                EbmlHeaderFields::DocTypeExtensionName(val) => async_::write_el_encoded(w, EbmlId::DocTypeExtensionName as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::DocTypeExtensionVersion(val) => async_::write_el_encoded(w, EbmlId::DocTypeExtensionVersion as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                EbmlHeaderFields::EbmlMaxIdLength(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 4 => 0,
+                EbmlHeaderFields::EbmlMaxIdLength(val) => async_::write_el_encoded(w, EbmlId::EbmlMaxIdLength as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::EbmlMaxSizeLength(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 8 => 0,
+                EbmlHeaderFields::EbmlMaxSizeLength(val) => async_::write_el_encoded(w, EbmlId::EbmlMaxSizeLength as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EbmlHeaderFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -66,130 +143,342 @@ impl EbmlHeader {
 }
 
 impl DocTypeExtension {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::DocTypeExtension as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl DocTypeExtension {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::DocTypeExtension as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::DocTypeExtension as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
+        for el in self.elements() {
+            size += match el {
+                DocTypeExtensionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                DocTypeExtensionFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                DocTypeExtensionFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
+            }
+        }
         Ok(size)
     }
 }
 impl DocTypeExtension {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::DocTypeExtension as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::DocTypeExtension as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
+        for el in self.elements() {
+            size += match el {
+                DocTypeExtensionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                DocTypeExtensionFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                DocTypeExtensionFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
+            }
+        }
         Ok(size)
     }
 }
 
 impl Segment {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Segment as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        for val in &self.void { size += size_el_encoded(EbmlId::Void as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.seek_head { size += val.v.encoded_size_with(options)?; }
+        size += self.info.v.encoded_size_with(options)?;
+        for val in &self.cluster { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.tracks { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.cues { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.attachments { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.chapters { size += val.v.encoded_size_with(options)?; }
+        for val in &self.tags { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Segment {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Segment as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Segment as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SegmentFields::Void(val) => blocking::write_el_bin(w, EbmlId::Void as u64, &val.v)?,
-                SegmentFields::Crc32(val) => blocking::write_el_bin(w, EbmlId::Crc32 as u64, &val.v)?,
-                SegmentFields::SeekHead(val) => val.v.write_blocking(w)?,
-                SegmentFields::Info(val) => val.v.write_blocking(w)?,
-                SegmentFields::Cluster(val) => val.v.write_blocking(w)?,
-                SegmentFields::Tracks(val) => val.v.write_blocking(w)?,
-                SegmentFields::Cues(val) => val.v.write_blocking(w)?,
-                SegmentFields::Attachments(val) => val.v.write_blocking(w)?,
-                SegmentFields::Chapters(val) => val.v.write_blocking(w)?,
-                SegmentFields::Tags(val) => val.v.write_blocking(w)?,
+                SegmentFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SegmentFields::Void(val) => blocking::write_el_encoded(w, EbmlId::Void as u64, &val.v, val.encoding.as_ref())?,
+                SegmentFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SegmentFields::SeekHead(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Info(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Cluster(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Tracks(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Cues(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Attachments(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Chapters(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Tags(val) => val.v.write_with_blocking(w, options)?,
+                SegmentFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
+    pub fn write_unknown_size_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_unknown_size_blocking(w)?;
+        size += self.write_body_blocking(w)?;
+        Ok(size)
+    }
+    pub fn write_header_unknown_size_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_id_unknown_size(w, EbmlId::Segment as u64)?)
+    }
 }
 impl Segment {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Segment as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Segment as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SegmentFields::Void(val) => async_::write_el_bin(w, EbmlId::Void as u64, &val.v).await?,
-                SegmentFields::Crc32(val) => async_::write_el_bin(w, EbmlId::Crc32 as u64, &val.v).await?,
-                SegmentFields::SeekHead(val) => val.v.write(w).await?,
-                SegmentFields::Info(val) => val.v.write(w).await?,
-                SegmentFields::Cluster(val) => val.v.write(w).await?,
-                SegmentFields::Tracks(val) => val.v.write(w).await?,
-                SegmentFields::Cues(val) => val.v.write(w).await?,
-                SegmentFields::Attachments(val) => val.v.write(w).await?,
-                SegmentFields::Chapters(val) => val.v.write(w).await?,
-                SegmentFields::Tags(val) => val.v.write(w).await?,
+                SegmentFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SegmentFields::Void(val) => async_::write_el_encoded(w, EbmlId::Void as u64, &val.v, val.encoding.as_ref()).await?,
+                SegmentFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SegmentFields::SeekHead(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Info(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Cluster(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Tracks(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Cues(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Attachments(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Chapters(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Tags(val) => val.v.write_with(w, options).await?,
+                SegmentFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
     }
+    pub async fn write_unknown_size<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_unknown_size(w).await?;
+        size += self.write_body(w).await?;
+        Ok(size)
+    }
+    pub async fn write_header_unknown_size<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_id_unknown_size(w, EbmlId::Segment as u64).await?)
+    }
 }
 
 impl SeekHead {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::SeekHead as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.seek { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl SeekHead {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::SeekHead as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::SeekHead as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SeekHeadFields::Seek(val) => val.v.write_blocking(w)?,
+                SeekHeadFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SeekHeadFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SeekHeadFields::Seek(val) => val.v.write_with_blocking(w, options)?,
+                SeekHeadFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl SeekHead {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::SeekHead as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::SeekHead as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SeekHeadFields::Seek(val) => val.v.write(w).await?,
+                SeekHeadFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SeekHeadFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SeekHeadFields::Seek(val) => val.v.write_with(w, options).await?,
+                SeekHeadFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -197,42 +486,104 @@ impl SeekHead {
 }
 
 impl Seek {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Seek as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::SeekId as u64, self.seek_id.v.len() as u64, self.seek_id.encoding.as_ref());
+        size += size_el_encoded(EbmlId::SeekPosition as u64, gen_uint_encoded(&self.seek_position.v, self.seek_position.encoding.as_ref()).len() as u64, self.seek_position.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Seek {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Seek as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Seek as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SeekFields::SeekId(val) => blocking::write_el_bin(w, EbmlId::SeekId as u64, &val.v)?,
-                SeekFields::SeekPosition(val) => blocking::write_el_uint(w, EbmlId::SeekPosition as u64, &*val.v)?,
+                SeekFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SeekFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SeekFields::SeekId(val) => blocking::write_el_encoded(w, EbmlId::SeekId as u64, &val.v, val.encoding.as_ref())?,
+                SeekFields::SeekPosition(val) => blocking::write_el_encoded(w, EbmlId::SeekPosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                SeekFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Seek {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Seek as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Seek as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SeekFields::SeekId(val) => async_::write_el_bin(w, EbmlId::SeekId as u64, &val.v).await?,
-                SeekFields::SeekPosition(val) => async_::write_el_uint(w, EbmlId::SeekPosition as u64, &*val.v).await?,
+                SeekFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SeekFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SeekFields::SeekId(val) => async_::write_el_encoded(w, EbmlId::SeekId as u64, &val.v, val.encoding.as_ref()).await?,
+                SeekFields::SeekPosition(val) => async_::write_el_encoded(w, EbmlId::SeekPosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                SeekFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -240,66 +591,142 @@ impl Seek {
 }
 
 impl Info {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Info as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.segment_uuid { size += size_el_encoded(EbmlId::SegmentUuid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.segment_filename { size += size_el_encoded(EbmlId::SegmentFilename as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.prev_uuid { size += size_el_encoded(EbmlId::PrevUuid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.prev_filename { size += size_el_encoded(EbmlId::PrevFilename as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.next_uuid { size += size_el_encoded(EbmlId::NextUuid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.next_filename { size += size_el_encoded(EbmlId::NextFilename as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.segment_family { size += size_el_encoded(EbmlId::SegmentFamily as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.chapter_translate { size += val.v.encoded_size_with(options)?; }
+        if *self.timestamp_scale.v != 1000000 || (!options.omit_defaults && (self.encoding.is_none() || !self.timestamp_scale.defaulted)) { size += size_el_encoded(EbmlId::TimestampScale as u64, gen_uint_encoded(&self.timestamp_scale.v, self.timestamp_scale.encoding.as_ref()).len() as u64, self.timestamp_scale.encoding.as_ref()); }
+        if let Some(val) = &self.duration { size += size_el_encoded(EbmlId::Duration as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.date_utc { size += size_el_encoded(EbmlId::DateUtc as u64, 8, val.encoding.as_ref()); }
+        if let Some(val) = &self.title { size += size_el_encoded(EbmlId::Title as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::MuxingApp as u64, self.muxing_app.v.len() as u64, self.muxing_app.encoding.as_ref());
+        size += size_el_encoded(EbmlId::WritingApp as u64, self.writing_app.v.len() as u64, self.writing_app.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Info {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Info as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Info as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                InfoFields::SegmentUuid(val) => blocking::write_el_bin(w, EbmlId::SegmentUuid as u64, &val.v)?,
-                InfoFields::SegmentFilename(val) => blocking::write_el_utf8(w, EbmlId::SegmentFilename as u64, &val.v)?,
-                InfoFields::PrevUuid(val) => blocking::write_el_bin(w, EbmlId::PrevUuid as u64, &val.v)?,
-                InfoFields::PrevFilename(val) => blocking::write_el_utf8(w, EbmlId::PrevFilename as u64, &val.v)?,
-                InfoFields::NextUuid(val) => blocking::write_el_bin(w, EbmlId::NextUuid as u64, &val.v)?,
-                InfoFields::NextFilename(val) => blocking::write_el_utf8(w, EbmlId::NextFilename as u64, &val.v)?,
-                InfoFields::SegmentFamily(val) => blocking::write_el_bin(w, EbmlId::SegmentFamily as u64, &val.v)?,
-                InfoFields::ChapterTranslate(val) => val.v.write_blocking(w)?,
-                InfoFields::TimestampScale(val) => blocking::write_el_uint(w, EbmlId::TimestampScale as u64, &*val.v)?,
-                InfoFields::Duration(val) => blocking::write_el_float64(w, EbmlId::Duration as u64, &*val.v)?,
-                InfoFields::DateUtc(val) => blocking::write_el_date(w, EbmlId::DateUtc as u64, &val.v)?,
-                InfoFields::Title(val) => blocking::write_el_utf8(w, EbmlId::Title as u64, &val.v)?,
-                InfoFields::MuxingApp(val) => blocking::write_el_utf8(w, EbmlId::MuxingApp as u64, &val.v)?,
-                InfoFields::WritingApp(val) => blocking::write_el_utf8(w, EbmlId::WritingApp as u64, &val.v)?,
+                InfoFields::Crc32(_) if self.computes_crc32(options) => 0,
+                InfoFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                InfoFields::SegmentUuid(val) => blocking::write_el_encoded(w, EbmlId::SegmentUuid as u64, &val.v, val.encoding.as_ref())?,
+                InfoFields::SegmentFilename(val) => blocking::write_el_encoded(w, EbmlId::SegmentFilename as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::PrevUuid(val) => blocking::write_el_encoded(w, EbmlId::PrevUuid as u64, &val.v, val.encoding.as_ref())?,
+                InfoFields::PrevFilename(val) => blocking::write_el_encoded(w, EbmlId::PrevFilename as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::NextUuid(val) => blocking::write_el_encoded(w, EbmlId::NextUuid as u64, &val.v, val.encoding.as_ref())?,
+                InfoFields::NextFilename(val) => blocking::write_el_encoded(w, EbmlId::NextFilename as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::SegmentFamily(val) => blocking::write_el_encoded(w, EbmlId::SegmentFamily as u64, &val.v, val.encoding.as_ref())?,
+                InfoFields::ChapterTranslate(val) => val.v.write_with_blocking(w, options)?,
+                InfoFields::TimestampScale(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1000000 => 0,
+                InfoFields::TimestampScale(val) => blocking::write_el_encoded(w, EbmlId::TimestampScale as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                InfoFields::Duration(val) => blocking::write_el_encoded(w, EbmlId::Duration as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                InfoFields::DateUtc(val) => blocking::write_el_encoded(w, EbmlId::DateUtc as u64, &gen_date(&val.v), val.encoding.as_ref())?,
+                InfoFields::Title(val) => blocking::write_el_encoded(w, EbmlId::Title as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::MuxingApp(val) => blocking::write_el_encoded(w, EbmlId::MuxingApp as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::WritingApp(val) => blocking::write_el_encoded(w, EbmlId::WritingApp as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                InfoFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Info {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Info as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Info as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                InfoFields::SegmentUuid(val) => async_::write_el_bin(w, EbmlId::SegmentUuid as u64, &val.v).await?,
-                InfoFields::SegmentFilename(val) => async_::write_el_utf8(w, EbmlId::SegmentFilename as u64, &val.v).await?,
-                InfoFields::PrevUuid(val) => async_::write_el_bin(w, EbmlId::PrevUuid as u64, &val.v).await?,
-                InfoFields::PrevFilename(val) => async_::write_el_utf8(w, EbmlId::PrevFilename as u64, &val.v).await?,
-                InfoFields::NextUuid(val) => async_::write_el_bin(w, EbmlId::NextUuid as u64, &val.v).await?,
-                InfoFields::NextFilename(val) => async_::write_el_utf8(w, EbmlId::NextFilename as u64, &val.v).await?,
-                InfoFields::SegmentFamily(val) => async_::write_el_bin(w, EbmlId::SegmentFamily as u64, &val.v).await?,
-                InfoFields::ChapterTranslate(val) => val.v.write(w).await?,
-                InfoFields::TimestampScale(val) => async_::write_el_uint(w, EbmlId::TimestampScale as u64, &*val.v).await?,
-                InfoFields::Duration(val) => async_::write_el_float64(w, EbmlId::Duration as u64, &*val.v).await?,
-                InfoFields::DateUtc(val) => async_::write_el_date(w, EbmlId::DateUtc as u64, &val.v).await?,
-                InfoFields::Title(val) => async_::write_el_utf8(w, EbmlId::Title as u64, &val.v).await?,
-                InfoFields::MuxingApp(val) => async_::write_el_utf8(w, EbmlId::MuxingApp as u64, &val.v).await?,
-                InfoFields::WritingApp(val) => async_::write_el_utf8(w, EbmlId::WritingApp as u64, &val.v).await?,
+                InfoFields::Crc32(_) if self.computes_crc32(options) => 0,
+                InfoFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                InfoFields::SegmentUuid(val) => async_::write_el_encoded(w, EbmlId::SegmentUuid as u64, &val.v, val.encoding.as_ref()).await?,
+                InfoFields::SegmentFilename(val) => async_::write_el_encoded(w, EbmlId::SegmentFilename as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::PrevUuid(val) => async_::write_el_encoded(w, EbmlId::PrevUuid as u64, &val.v, val.encoding.as_ref()).await?,
+                InfoFields::PrevFilename(val) => async_::write_el_encoded(w, EbmlId::PrevFilename as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::NextUuid(val) => async_::write_el_encoded(w, EbmlId::NextUuid as u64, &val.v, val.encoding.as_ref()).await?,
+                InfoFields::NextFilename(val) => async_::write_el_encoded(w, EbmlId::NextFilename as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::SegmentFamily(val) => async_::write_el_encoded(w, EbmlId::SegmentFamily as u64, &val.v, val.encoding.as_ref()).await?,
+                InfoFields::ChapterTranslate(val) => val.v.write_with(w, options).await?,
+                InfoFields::TimestampScale(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1000000 => 0,
+                InfoFields::TimestampScale(val) => async_::write_el_encoded(w, EbmlId::TimestampScale as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                InfoFields::Duration(val) => async_::write_el_encoded(w, EbmlId::Duration as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                InfoFields::DateUtc(val) => async_::write_el_encoded(w, EbmlId::DateUtc as u64, &gen_date(&val.v), val.encoding.as_ref()).await?,
+                InfoFields::Title(val) => async_::write_el_encoded(w, EbmlId::Title as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::MuxingApp(val) => async_::write_el_encoded(w, EbmlId::MuxingApp as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::WritingApp(val) => async_::write_el_encoded(w, EbmlId::WritingApp as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                InfoFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -307,44 +734,107 @@ impl Info {
 }
 
 impl ChapterTranslate {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapterTranslate as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ChapterTranslateId as u64, self.chapter_translate_id.v.len() as u64, self.chapter_translate_id.encoding.as_ref());
+        size += size_el_encoded(EbmlId::ChapterTranslateCodec as u64, gen_uint_encoded(&self.chapter_translate_codec.v.value(), self.chapter_translate_codec.encoding.as_ref()).len() as u64, self.chapter_translate_codec.encoding.as_ref());
+        for val in &self.chapter_translate_edition_uid { size += size_el_encoded(EbmlId::ChapterTranslateEditionUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapterTranslate {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapterTranslate as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapterTranslate as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterTranslateFields::ChapterTranslateId(val) => blocking::write_el_bin(w, EbmlId::ChapterTranslateId as u64, &val.v)?,
-                ChapterTranslateFields::ChapterTranslateCodec(val) => blocking::write_el_uint(w, EbmlId::ChapterTranslateCodec as u64, &*val.v)?,
-                ChapterTranslateFields::ChapterTranslateEditionUid(val) => blocking::write_el_uint(w, EbmlId::ChapterTranslateEditionUid as u64, &*val.v)?,
+                ChapterTranslateFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterTranslateFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapterTranslateFields::ChapterTranslateId(val) => blocking::write_el_encoded(w, EbmlId::ChapterTranslateId as u64, &val.v, val.encoding.as_ref())?,
+                ChapterTranslateFields::ChapterTranslateCodec(val) => blocking::write_el_encoded(w, EbmlId::ChapterTranslateCodec as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterTranslateFields::ChapterTranslateEditionUid(val) => blocking::write_el_encoded(w, EbmlId::ChapterTranslateEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterTranslateFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapterTranslate {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapterTranslate as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapterTranslate as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterTranslateFields::ChapterTranslateId(val) => async_::write_el_bin(w, EbmlId::ChapterTranslateId as u64, &val.v).await?,
-                ChapterTranslateFields::ChapterTranslateCodec(val) => async_::write_el_uint(w, EbmlId::ChapterTranslateCodec as u64, &*val.v).await?,
-                ChapterTranslateFields::ChapterTranslateEditionUid(val) => async_::write_el_uint(w, EbmlId::ChapterTranslateEditionUid as u64, &*val.v).await?,
+                ChapterTranslateFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterTranslateFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterTranslateFields::ChapterTranslateId(val) => async_::write_el_encoded(w, EbmlId::ChapterTranslateId as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterTranslateFields::ChapterTranslateCodec(val) => async_::write_el_encoded(w, EbmlId::ChapterTranslateCodec as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterTranslateFields::ChapterTranslateEditionUid(val) => async_::write_el_encoded(w, EbmlId::ChapterTranslateEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterTranslateFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -352,93 +842,237 @@ impl ChapterTranslate {
 }
 
 impl Cluster {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Cluster as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::Timestamp as u64, gen_uint_encoded(&self.timestamp.v, self.timestamp.encoding.as_ref()).len() as u64, self.timestamp.encoding.as_ref());
+        if let Some(val) = &self.silent_tracks { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.position { size += size_el_encoded(EbmlId::Position as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.prev_size { size += size_el_encoded(EbmlId::PrevSize as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.simple_block { size += size_el_block_encoded(EbmlId::SimpleBlock as u64, &val.v, val.encoding.as_ref())?; }
+        for val in &self.block_group { size += val.v.encoded_size_with(options)?; }
+        for val in &self.encrypted_block { size += size_el_encoded(EbmlId::EncryptedBlock as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Cluster as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl Cluster {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Cluster as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ClusterFields::Timestamp(val) => blocking::write_el_uint(w, EbmlId::Timestamp as u64, &*val.v)?,
-                ClusterFields::SilentTracks(val) => val.v.write_blocking(w)?,
-                ClusterFields::Position(val) => blocking::write_el_uint(w, EbmlId::Position as u64, &*val.v)?,
-                ClusterFields::PrevSize(val) => blocking::write_el_uint(w, EbmlId::PrevSize as u64, &*val.v)?,
-                ClusterFields::SimpleBlock(val) => blocking::write_el_bin(w, EbmlId::SimpleBlock as u64, &val.v)?,
-                ClusterFields::BlockGroup(val) => val.v.write_blocking(w)?,
-                ClusterFields::EncryptedBlock(val) => blocking::write_el_bin(w, EbmlId::EncryptedBlock as u64, &val.v)?,
+                ClusterFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ClusterFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ClusterFields::Timestamp(val) => blocking::write_el_encoded(w, EbmlId::Timestamp as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ClusterFields::SilentTracks(val) => val.v.write_with_blocking(w, options)?,
+                ClusterFields::Position(val) => blocking::write_el_encoded(w, EbmlId::Position as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ClusterFields::PrevSize(val) => blocking::write_el_encoded(w, EbmlId::PrevSize as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ClusterFields::SimpleBlock(val) => blocking::write_el_block_encoded(w, EbmlId::SimpleBlock as u64, &val.v, val.encoding.as_ref())?,
+                ClusterFields::BlockGroup(val) => val.v.write_with_blocking(w, options)?,
+                ClusterFields::EncryptedBlock(val) => blocking::write_el_encoded(w, EbmlId::EncryptedBlock as u64, &val.v, val.encoding.as_ref())?,
+                ClusterFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
+    pub fn write_unknown_size_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_unknown_size_blocking(w)?;
+        size += self.write_body_blocking(w)?;
+        Ok(size)
+    }
+    pub fn write_header_unknown_size_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_id_unknown_size(w, EbmlId::Cluster as u64)?)
+    }
 }
 impl Cluster {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Cluster as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Cluster as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ClusterFields::Timestamp(val) => async_::write_el_uint(w, EbmlId::Timestamp as u64, &*val.v).await?,
-                ClusterFields::SilentTracks(val) => val.v.write(w).await?,
-                ClusterFields::Position(val) => async_::write_el_uint(w, EbmlId::Position as u64, &*val.v).await?,
-                ClusterFields::PrevSize(val) => async_::write_el_uint(w, EbmlId::PrevSize as u64, &*val.v).await?,
-                ClusterFields::SimpleBlock(val) => async_::write_el_bin(w, EbmlId::SimpleBlock as u64, &val.v).await?,
-                ClusterFields::BlockGroup(val) => val.v.write(w).await?,
-                ClusterFields::EncryptedBlock(val) => async_::write_el_bin(w, EbmlId::EncryptedBlock as u64, &val.v).await?,
+                ClusterFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ClusterFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ClusterFields::Timestamp(val) => async_::write_el_encoded(w, EbmlId::Timestamp as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ClusterFields::SilentTracks(val) => val.v.write_with(w, options).await?,
+                ClusterFields::Position(val) => async_::write_el_encoded(w, EbmlId::Position as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ClusterFields::PrevSize(val) => async_::write_el_encoded(w, EbmlId::PrevSize as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ClusterFields::SimpleBlock(val) => async_::write_el_block_encoded(w, EbmlId::SimpleBlock as u64, &val.v, val.encoding.as_ref()).await?,
+                ClusterFields::BlockGroup(val) => val.v.write_with(w, options).await?,
+                ClusterFields::EncryptedBlock(val) => async_::write_el_encoded(w, EbmlId::EncryptedBlock as u64, &val.v, val.encoding.as_ref()).await?,
+                ClusterFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
     }
+    pub async fn write_unknown_size<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_unknown_size(w).await?;
+        size += self.write_body(w).await?;
+        Ok(size)
+    }
+    pub async fn write_header_unknown_size<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_id_unknown_size(w, EbmlId::Cluster as u64).await?)
+    }
 }
 
 impl SilentTracks {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::SilentTracks as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.silent_track_number { size += size_el_encoded(EbmlId::SilentTrackNumber as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl SilentTracks {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::SilentTracks as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::SilentTracks as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SilentTracksFields::SilentTrackNumber(val) => blocking::write_el_uint(w, EbmlId::SilentTrackNumber as u64, &*val.v)?,
+                SilentTracksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SilentTracksFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SilentTracksFields::SilentTrackNumber(val) => blocking::write_el_encoded(w, EbmlId::SilentTrackNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                SilentTracksFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl SilentTracks {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::SilentTracks as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::SilentTracks as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SilentTracksFields::SilentTrackNumber(val) => async_::write_el_uint(w, EbmlId::SilentTrackNumber as u64, &*val.v).await?,
+                SilentTracksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SilentTracksFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SilentTracksFields::SilentTrackNumber(val) => async_::write_el_encoded(w, EbmlId::SilentTrackNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                SilentTracksFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -446,60 +1080,133 @@ impl SilentTracks {
 }
 
 impl BlockGroup {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::BlockGroup as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_block_encoded(EbmlId::Block as u64, &self.block.v, self.block.encoding.as_ref())?;
+        if let Some(val) = &self.block_virtual { size += size_el_encoded(EbmlId::BlockVirtual as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.block_additions { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.block_duration { size += size_el_encoded(EbmlId::BlockDuration as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.reference_priority.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.reference_priority.defaulted)) { size += size_el_encoded(EbmlId::ReferencePriority as u64, gen_uint_encoded(&self.reference_priority.v, self.reference_priority.encoding.as_ref()).len() as u64, self.reference_priority.encoding.as_ref()); }
+        for val in &self.reference_block { size += size_el_encoded(EbmlId::ReferenceBlock as u64, gen_int_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.reference_virtual { size += size_el_encoded(EbmlId::ReferenceVirtual as u64, gen_int_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.codec_state { size += size_el_encoded(EbmlId::CodecState as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.discard_padding { size += size_el_encoded(EbmlId::DiscardPadding as u64, gen_int_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.slices { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.reference_frame { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl BlockGroup {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::BlockGroup as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::BlockGroup as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockGroupFields::Block(val) => blocking::write_el_bin(w, EbmlId::Block as u64, &val.v)?,
-                BlockGroupFields::BlockVirtual(val) => blocking::write_el_bin(w, EbmlId::BlockVirtual as u64, &val.v)?,
-                BlockGroupFields::BlockAdditions(val) => val.v.write_blocking(w)?,
-                BlockGroupFields::BlockDuration(val) => blocking::write_el_uint(w, EbmlId::BlockDuration as u64, &*val.v)?,
-                BlockGroupFields::ReferencePriority(val) => blocking::write_el_uint(w, EbmlId::ReferencePriority as u64, &*val.v)?,
-                BlockGroupFields::ReferenceBlock(val) => blocking::write_el_int(w, EbmlId::ReferenceBlock as u64, &*val.v)?,
-                BlockGroupFields::ReferenceVirtual(val) => blocking::write_el_int(w, EbmlId::ReferenceVirtual as u64, &*val.v)?,
-                BlockGroupFields::CodecState(val) => blocking::write_el_bin(w, EbmlId::CodecState as u64, &val.v)?,
-                BlockGroupFields::DiscardPadding(val) => blocking::write_el_int(w, EbmlId::DiscardPadding as u64, &*val.v)?,
-                BlockGroupFields::Slices(val) => val.v.write_blocking(w)?,
-                BlockGroupFields::ReferenceFrame(val) => val.v.write_blocking(w)?,
+                BlockGroupFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockGroupFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                BlockGroupFields::Block(val) => blocking::write_el_block_encoded(w, EbmlId::Block as u64, &val.v, val.encoding.as_ref())?,
+                BlockGroupFields::BlockVirtual(val) => blocking::write_el_encoded(w, EbmlId::BlockVirtual as u64, &val.v, val.encoding.as_ref())?,
+                BlockGroupFields::BlockAdditions(val) => val.v.write_with_blocking(w, options)?,
+                BlockGroupFields::BlockDuration(val) => blocking::write_el_encoded(w, EbmlId::BlockDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockGroupFields::ReferencePriority(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                BlockGroupFields::ReferencePriority(val) => blocking::write_el_encoded(w, EbmlId::ReferencePriority as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockGroupFields::ReferenceBlock(val) => blocking::write_el_encoded(w, EbmlId::ReferenceBlock as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockGroupFields::ReferenceVirtual(val) => blocking::write_el_encoded(w, EbmlId::ReferenceVirtual as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockGroupFields::CodecState(val) => blocking::write_el_encoded(w, EbmlId::CodecState as u64, &val.v, val.encoding.as_ref())?,
+                BlockGroupFields::DiscardPadding(val) => blocking::write_el_encoded(w, EbmlId::DiscardPadding as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockGroupFields::Slices(val) => val.v.write_with_blocking(w, options)?,
+                BlockGroupFields::ReferenceFrame(val) => val.v.write_with_blocking(w, options)?,
+                BlockGroupFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl BlockGroup {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::BlockGroup as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::BlockGroup as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockGroupFields::Block(val) => async_::write_el_bin(w, EbmlId::Block as u64, &val.v).await?,
-                BlockGroupFields::BlockVirtual(val) => async_::write_el_bin(w, EbmlId::BlockVirtual as u64, &val.v).await?,
-                BlockGroupFields::BlockAdditions(val) => val.v.write(w).await?,
-                BlockGroupFields::BlockDuration(val) => async_::write_el_uint(w, EbmlId::BlockDuration as u64, &*val.v).await?,
-                BlockGroupFields::ReferencePriority(val) => async_::write_el_uint(w, EbmlId::ReferencePriority as u64, &*val.v).await?,
-                BlockGroupFields::ReferenceBlock(val) => async_::write_el_int(w, EbmlId::ReferenceBlock as u64, &*val.v).await?,
-                BlockGroupFields::ReferenceVirtual(val) => async_::write_el_int(w, EbmlId::ReferenceVirtual as u64, &*val.v).await?,
-                BlockGroupFields::CodecState(val) => async_::write_el_bin(w, EbmlId::CodecState as u64, &val.v).await?,
-                BlockGroupFields::DiscardPadding(val) => async_::write_el_int(w, EbmlId::DiscardPadding as u64, &*val.v).await?,
-                BlockGroupFields::Slices(val) => val.v.write(w).await?,
-                BlockGroupFields::ReferenceFrame(val) => val.v.write(w).await?,
+                BlockGroupFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockGroupFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockGroupFields::Block(val) => async_::write_el_block_encoded(w, EbmlId::Block as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockGroupFields::BlockVirtual(val) => async_::write_el_encoded(w, EbmlId::BlockVirtual as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockGroupFields::BlockAdditions(val) => val.v.write_with(w, options).await?,
+                BlockGroupFields::BlockDuration(val) => async_::write_el_encoded(w, EbmlId::BlockDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockGroupFields::ReferencePriority(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                BlockGroupFields::ReferencePriority(val) => async_::write_el_encoded(w, EbmlId::ReferencePriority as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockGroupFields::ReferenceBlock(val) => async_::write_el_encoded(w, EbmlId::ReferenceBlock as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockGroupFields::ReferenceVirtual(val) => async_::write_el_encoded(w, EbmlId::ReferenceVirtual as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockGroupFields::CodecState(val) => async_::write_el_encoded(w, EbmlId::CodecState as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockGroupFields::DiscardPadding(val) => async_::write_el_encoded(w, EbmlId::DiscardPadding as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockGroupFields::Slices(val) => val.v.write_with(w, options).await?,
+                BlockGroupFields::ReferenceFrame(val) => val.v.write_with(w, options).await?,
+                BlockGroupFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -507,40 +1214,101 @@ impl BlockGroup {
 }
 
 impl BlockAdditions {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::BlockAdditions as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.block_more { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl BlockAdditions {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::BlockAdditions as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::BlockAdditions as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockAdditionsFields::BlockMore(val) => val.v.write_blocking(w)?,
+                BlockAdditionsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockAdditionsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                BlockAdditionsFields::BlockMore(val) => val.v.write_with_blocking(w, options)?,
+                BlockAdditionsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl BlockAdditions {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::BlockAdditions as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::BlockAdditions as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockAdditionsFields::BlockMore(val) => val.v.write(w).await?,
+                BlockAdditionsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockAdditionsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockAdditionsFields::BlockMore(val) => val.v.write_with(w, options).await?,
+                BlockAdditionsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -548,42 +1316,106 @@ impl BlockAdditions {
 }
 
 impl BlockMore {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::BlockMore as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::BlockAdditional as u64, self.block_additional.v.len() as u64, self.block_additional.encoding.as_ref());
+        if *self.block_add_id.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.block_add_id.defaulted)) { size += size_el_encoded(EbmlId::BlockAddId as u64, gen_uint_encoded(&self.block_add_id.v, self.block_add_id.encoding.as_ref()).len() as u64, self.block_add_id.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::BlockMore as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl BlockMore {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::BlockMore as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockMoreFields::BlockAdditional(val) => blocking::write_el_bin(w, EbmlId::BlockAdditional as u64, &val.v)?,
-                BlockMoreFields::BlockAddId(val) => blocking::write_el_uint(w, EbmlId::BlockAddId as u64, &*val.v)?,
+                BlockMoreFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockMoreFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                BlockMoreFields::BlockAdditional(val) => blocking::write_el_encoded(w, EbmlId::BlockAdditional as u64, &val.v, val.encoding.as_ref())?,
+                BlockMoreFields::BlockAddId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                BlockMoreFields::BlockAddId(val) => blocking::write_el_encoded(w, EbmlId::BlockAddId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockMoreFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl BlockMore {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::BlockMore as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::BlockMore as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockMoreFields::BlockAdditional(val) => async_::write_el_bin(w, EbmlId::BlockAdditional as u64, &val.v).await?,
-                BlockMoreFields::BlockAddId(val) => async_::write_el_uint(w, EbmlId::BlockAddId as u64, &*val.v).await?,
+                BlockMoreFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockMoreFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockMoreFields::BlockAdditional(val) => async_::write_el_encoded(w, EbmlId::BlockAdditional as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockMoreFields::BlockAddId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                BlockMoreFields::BlockAddId(val) => async_::write_el_encoded(w, EbmlId::BlockAddId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockMoreFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -591,40 +1423,101 @@ impl BlockMore {
 }
 
 impl Slices {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Slices as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.time_slice { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Slices {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Slices as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Slices as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SlicesFields::TimeSlice(val) => val.v.write_blocking(w)?,
+                SlicesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SlicesFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SlicesFields::TimeSlice(val) => val.v.write_with_blocking(w, options)?,
+                SlicesFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Slices {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Slices as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Slices as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SlicesFields::TimeSlice(val) => val.v.write(w).await?,
+                SlicesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SlicesFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SlicesFields::TimeSlice(val) => val.v.write_with(w, options).await?,
+                SlicesFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -632,48 +1525,113 @@ impl Slices {
 }
 
 impl TimeSlice {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TimeSlice as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.lace_number { size += size_el_encoded(EbmlId::LaceNumber as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.frame_number { size += size_el_encoded(EbmlId::FrameNumber as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.block_addition_id { size += size_el_encoded(EbmlId::BlockAdditionId as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.delay { size += size_el_encoded(EbmlId::Delay as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.slice_duration { size += size_el_encoded(EbmlId::SliceDuration as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TimeSlice {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TimeSlice as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TimeSlice as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TimeSliceFields::LaceNumber(val) => blocking::write_el_uint(w, EbmlId::LaceNumber as u64, &*val.v)?,
-                TimeSliceFields::FrameNumber(val) => blocking::write_el_uint(w, EbmlId::FrameNumber as u64, &*val.v)?,
-                TimeSliceFields::BlockAdditionId(val) => blocking::write_el_uint(w, EbmlId::BlockAdditionId as u64, &*val.v)?,
-                TimeSliceFields::Delay(val) => blocking::write_el_uint(w, EbmlId::Delay as u64, &*val.v)?,
-                TimeSliceFields::SliceDuration(val) => blocking::write_el_uint(w, EbmlId::SliceDuration as u64, &*val.v)?,
+                TimeSliceFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TimeSliceFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TimeSliceFields::LaceNumber(val) => blocking::write_el_encoded(w, EbmlId::LaceNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TimeSliceFields::FrameNumber(val) => blocking::write_el_encoded(w, EbmlId::FrameNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TimeSliceFields::BlockAdditionId(val) => blocking::write_el_encoded(w, EbmlId::BlockAdditionId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TimeSliceFields::Delay(val) => blocking::write_el_encoded(w, EbmlId::Delay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TimeSliceFields::SliceDuration(val) => blocking::write_el_encoded(w, EbmlId::SliceDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TimeSliceFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TimeSlice {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TimeSlice as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TimeSlice as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TimeSliceFields::LaceNumber(val) => async_::write_el_uint(w, EbmlId::LaceNumber as u64, &*val.v).await?,
-                TimeSliceFields::FrameNumber(val) => async_::write_el_uint(w, EbmlId::FrameNumber as u64, &*val.v).await?,
-                TimeSliceFields::BlockAdditionId(val) => async_::write_el_uint(w, EbmlId::BlockAdditionId as u64, &*val.v).await?,
-                TimeSliceFields::Delay(val) => async_::write_el_uint(w, EbmlId::Delay as u64, &*val.v).await?,
-                TimeSliceFields::SliceDuration(val) => async_::write_el_uint(w, EbmlId::SliceDuration as u64, &*val.v).await?,
+                TimeSliceFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TimeSliceFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TimeSliceFields::LaceNumber(val) => async_::write_el_encoded(w, EbmlId::LaceNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TimeSliceFields::FrameNumber(val) => async_::write_el_encoded(w, EbmlId::FrameNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TimeSliceFields::BlockAdditionId(val) => async_::write_el_encoded(w, EbmlId::BlockAdditionId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TimeSliceFields::Delay(val) => async_::write_el_encoded(w, EbmlId::Delay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TimeSliceFields::SliceDuration(val) => async_::write_el_encoded(w, EbmlId::SliceDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TimeSliceFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -681,42 +1639,104 @@ impl TimeSlice {
 }
 
 impl ReferenceFrame {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ReferenceFrame as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ReferenceOffset as u64, gen_uint_encoded(&self.reference_offset.v, self.reference_offset.encoding.as_ref()).len() as u64, self.reference_offset.encoding.as_ref());
+        size += size_el_encoded(EbmlId::ReferenceTimestamp as u64, gen_uint_encoded(&self.reference_timestamp.v, self.reference_timestamp.encoding.as_ref()).len() as u64, self.reference_timestamp.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ReferenceFrame {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ReferenceFrame as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ReferenceFrame as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ReferenceFrameFields::ReferenceOffset(val) => blocking::write_el_uint(w, EbmlId::ReferenceOffset as u64, &*val.v)?,
-                ReferenceFrameFields::ReferenceTimestamp(val) => blocking::write_el_uint(w, EbmlId::ReferenceTimestamp as u64, &*val.v)?,
+                ReferenceFrameFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ReferenceFrameFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ReferenceFrameFields::ReferenceOffset(val) => blocking::write_el_encoded(w, EbmlId::ReferenceOffset as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ReferenceFrameFields::ReferenceTimestamp(val) => blocking::write_el_encoded(w, EbmlId::ReferenceTimestamp as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ReferenceFrameFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ReferenceFrame {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ReferenceFrame as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ReferenceFrame as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ReferenceFrameFields::ReferenceOffset(val) => async_::write_el_uint(w, EbmlId::ReferenceOffset as u64, &*val.v).await?,
-                ReferenceFrameFields::ReferenceTimestamp(val) => async_::write_el_uint(w, EbmlId::ReferenceTimestamp as u64, &*val.v).await?,
+                ReferenceFrameFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ReferenceFrameFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ReferenceFrameFields::ReferenceOffset(val) => async_::write_el_encoded(w, EbmlId::ReferenceOffset as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ReferenceFrameFields::ReferenceTimestamp(val) => async_::write_el_encoded(w, EbmlId::ReferenceTimestamp as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ReferenceFrameFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -724,40 +1744,101 @@ impl ReferenceFrame {
 }
 
 impl Tracks {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Tracks as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.track_entry { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Tracks as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Tracks {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Tracks as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TracksFields::TrackEntry(val) => val.v.write_blocking(w)?,
+                TracksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TracksFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TracksFields::TrackEntry(val) => val.v.write_with_blocking(w, options)?,
+                TracksFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Tracks {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Tracks as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Tracks as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TracksFields::TrackEntry(val) => val.v.write(w).await?,
+                TracksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TracksFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TracksFields::TrackEntry(val) => val.v.write_with(w, options).await?,
+                TracksFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -765,126 +1846,252 @@ impl Tracks {
 }
 
 impl TrackEntry {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
-        Ok(size)
-    }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackEntry as u64, size)?)
-    }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut size = 0usize;
-        for el in self.elements() {
-            size += match el {
-                TrackEntryFields::TrackNumber(val) => blocking::write_el_uint(w, EbmlId::TrackNumber as u64, &*val.v)?,
-                TrackEntryFields::TrackUid(val) => blocking::write_el_uint(w, EbmlId::TrackUid as u64, &*val.v)?,
-                TrackEntryFields::TrackType(val) => blocking::write_el_uint(w, EbmlId::TrackType as u64, &*val.v)?,
-                TrackEntryFields::FlagEnabled(val) => blocking::write_el_uint(w, EbmlId::FlagEnabled as u64, &*val.v)?,
-                TrackEntryFields::FlagDefault(val) => blocking::write_el_uint(w, EbmlId::FlagDefault as u64, &*val.v)?,
-                TrackEntryFields::FlagForced(val) => blocking::write_el_uint(w, EbmlId::FlagForced as u64, &*val.v)?,
-                TrackEntryFields::FlagHearingImpaired(val) => blocking::write_el_uint(w, EbmlId::FlagHearingImpaired as u64, &*val.v)?,
-                TrackEntryFields::FlagVisualImpaired(val) => blocking::write_el_uint(w, EbmlId::FlagVisualImpaired as u64, &*val.v)?,
-                TrackEntryFields::FlagTextDescriptions(val) => blocking::write_el_uint(w, EbmlId::FlagTextDescriptions as u64, &*val.v)?,
-                TrackEntryFields::FlagOriginal(val) => blocking::write_el_uint(w, EbmlId::FlagOriginal as u64, &*val.v)?,
-                TrackEntryFields::FlagCommentary(val) => blocking::write_el_uint(w, EbmlId::FlagCommentary as u64, &*val.v)?,
-                TrackEntryFields::FlagLacing(val) => blocking::write_el_uint(w, EbmlId::FlagLacing as u64, &*val.v)?,
-                TrackEntryFields::MinCache(val) => blocking::write_el_uint(w, EbmlId::MinCache as u64, &*val.v)?,
-                TrackEntryFields::MaxCache(val) => blocking::write_el_uint(w, EbmlId::MaxCache as u64, &*val.v)?,
-                TrackEntryFields::DefaultDuration(val) => blocking::write_el_uint(w, EbmlId::DefaultDuration as u64, &*val.v)?,
-                TrackEntryFields::DefaultDecodedFieldDuration(val) => blocking::write_el_uint(w, EbmlId::DefaultDecodedFieldDuration as u64, &*val.v)?,
-                TrackEntryFields::TrackTimestampScale(val) => blocking::write_el_float64(w, EbmlId::TrackTimestampScale as u64, &*val.v)?,
-                TrackEntryFields::TrackOffset(val) => blocking::write_el_int(w, EbmlId::TrackOffset as u64, &*val.v)?,
-                TrackEntryFields::MaxBlockAdditionId(val) => blocking::write_el_uint(w, EbmlId::MaxBlockAdditionId as u64, &*val.v)?,
-                TrackEntryFields::BlockAdditionMapping(val) => val.v.write_blocking(w)?,
-                TrackEntryFields::Name(val) => blocking::write_el_utf8(w, EbmlId::Name as u64, &val.v)?,
-                TrackEntryFields::Language(val) => blocking::write_el_string(w, EbmlId::Language as u64, &val.v)?,
-                TrackEntryFields::LanguageBcp47(val) => blocking::write_el_string(w, EbmlId::LanguageBcp47 as u64, &val.v)?,
-                TrackEntryFields::CodecId(val) => blocking::write_el_string(w, EbmlId::CodecId as u64, &val.v)?,
-                TrackEntryFields::CodecPrivate(val) => blocking::write_el_bin(w, EbmlId::CodecPrivate as u64, &val.v)?,
-                TrackEntryFields::CodecName(val) => blocking::write_el_utf8(w, EbmlId::CodecName as u64, &val.v)?,
-                TrackEntryFields::AttachmentLink(val) => blocking::write_el_uint(w, EbmlId::AttachmentLink as u64, &*val.v)?,
-                TrackEntryFields::CodecSettings(val) => blocking::write_el_utf8(w, EbmlId::CodecSettings as u64, &val.v)?,
-                TrackEntryFields::CodecInfoUrl(val) => blocking::write_el_string(w, EbmlId::CodecInfoUrl as u64, &val.v)?,
-                TrackEntryFields::CodecDownloadUrl(val) => blocking::write_el_string(w, EbmlId::CodecDownloadUrl as u64, &val.v)?,
-                TrackEntryFields::CodecDecodeAll(val) => blocking::write_el_uint(w, EbmlId::CodecDecodeAll as u64, &*val.v)?,
-                TrackEntryFields::TrackOverlay(val) => blocking::write_el_uint(w, EbmlId::TrackOverlay as u64, &*val.v)?,
-                TrackEntryFields::CodecDelay(val) => blocking::write_el_uint(w, EbmlId::CodecDelay as u64, &*val.v)?,
-                TrackEntryFields::SeekPreRoll(val) => blocking::write_el_uint(w, EbmlId::SeekPreRoll as u64, &*val.v)?,
-                TrackEntryFields::TrackTranslate(val) => val.v.write_blocking(w)?,
-                TrackEntryFields::Video(val) => val.v.write_blocking(w)?,
-                TrackEntryFields::Audio(val) => val.v.write_blocking(w)?,
-                TrackEntryFields::TrackOperation(val) => val.v.write_blocking(w)?,
-                TrackEntryFields::TrickTrackUid(val) => blocking::write_el_uint(w, EbmlId::TrickTrackUid as u64, &*val.v)?,
-                TrackEntryFields::TrickTrackSegmentUid(val) => blocking::write_el_bin(w, EbmlId::TrickTrackSegmentUid as u64, &val.v)?,
-                TrackEntryFields::TrickTrackFlag(val) => blocking::write_el_uint(w, EbmlId::TrickTrackFlag as u64, &*val.v)?,
-                TrackEntryFields::TrickStructTrackUid(val) => blocking::write_el_uint(w, EbmlId::TrickStructTrackUid as u64, &*val.v)?,
-                TrackEntryFields::TrickStructTrackSegmentUid(val) => blocking::write_el_bin(w, EbmlId::TrickStructTrackSegmentUid as u64, &val.v)?,
-                TrackEntryFields::ContentEncodings(val) => val.v.write_blocking(w)?,
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackEntry as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::TrackNumber as u64, gen_uint_encoded(&self.track_number.v, self.track_number.encoding.as_ref()).len() as u64, self.track_number.encoding.as_ref());
+        size += size_el_encoded(EbmlId::TrackUid as u64, gen_uint_encoded(&self.track_uid.v, self.track_uid.encoding.as_ref()).len() as u64, self.track_uid.encoding.as_ref());
+        size += size_el_encoded(EbmlId::TrackType as u64, gen_uint_encoded(&self.track_type.v.value(), self.track_type.encoding.as_ref()).len() as u64, self.track_type.encoding.as_ref());
+        if *self.flag_enabled.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.flag_enabled.defaulted)) { size += size_el_encoded(EbmlId::FlagEnabled as u64, gen_uint_encoded(&self.flag_enabled.v, self.flag_enabled.encoding.as_ref()).len() as u64, self.flag_enabled.encoding.as_ref()); }
+        if *self.flag_default.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.flag_default.defaulted)) { size += size_el_encoded(EbmlId::FlagDefault as u64, gen_uint_encoded(&self.flag_default.v, self.flag_default.encoding.as_ref()).len() as u64, self.flag_default.encoding.as_ref()); }
+        if *self.flag_forced.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.flag_forced.defaulted)) { size += size_el_encoded(EbmlId::FlagForced as u64, gen_uint_encoded(&self.flag_forced.v, self.flag_forced.encoding.as_ref()).len() as u64, self.flag_forced.encoding.as_ref()); }
+        if let Some(val) = &self.flag_hearing_impaired { size += size_el_encoded(EbmlId::FlagHearingImpaired as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.flag_visual_impaired { size += size_el_encoded(EbmlId::FlagVisualImpaired as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.flag_text_descriptions { size += size_el_encoded(EbmlId::FlagTextDescriptions as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.flag_original { size += size_el_encoded(EbmlId::FlagOriginal as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.flag_commentary { size += size_el_encoded(EbmlId::FlagCommentary as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.flag_lacing.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.flag_lacing.defaulted)) { size += size_el_encoded(EbmlId::FlagLacing as u64, gen_uint_encoded(&self.flag_lacing.v, self.flag_lacing.encoding.as_ref()).len() as u64, self.flag_lacing.encoding.as_ref()); }
+        if *self.min_cache.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.min_cache.defaulted)) { size += size_el_encoded(EbmlId::MinCache as u64, gen_uint_encoded(&self.min_cache.v, self.min_cache.encoding.as_ref()).len() as u64, self.min_cache.encoding.as_ref()); }
+        if let Some(val) = &self.max_cache { size += size_el_encoded(EbmlId::MaxCache as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.default_duration { size += size_el_encoded(EbmlId::DefaultDuration as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.default_decoded_field_duration { size += size_el_encoded(EbmlId::DefaultDecodedFieldDuration as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.track_timestamp_scale.v != hexf::hexf64!("0x1p+0") || (!options.omit_defaults && (self.encoding.is_none() || !self.track_timestamp_scale.defaulted)) { size += size_el_encoded(EbmlId::TrackTimestampScale as u64, gen_float_encoded(&self.track_timestamp_scale.v, self.track_timestamp_scale.encoding.as_ref()).len() as u64, self.track_timestamp_scale.encoding.as_ref()); }
+        if let Some(val) = &self.track_offset { size += size_el_encoded(EbmlId::TrackOffset as u64, gen_int_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.max_block_addition_id.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.max_block_addition_id.defaulted)) { size += size_el_encoded(EbmlId::MaxBlockAdditionId as u64, gen_uint_encoded(&self.max_block_addition_id.v, self.max_block_addition_id.encoding.as_ref()).len() as u64, self.max_block_addition_id.encoding.as_ref()); }
+        for val in &self.block_addition_mapping { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.name { size += size_el_encoded(EbmlId::Name as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.language.v.as_str() != "eng" || (!options.omit_defaults && (self.encoding.is_none() || !self.language.defaulted)) { size += size_el_encoded(EbmlId::Language as u64, self.language.v.len() as u64, self.language.encoding.as_ref()); }
+        if let Some(val) = &self.language_bcp_47 { size += size_el_encoded(EbmlId::LanguageBcp47 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::CodecId as u64, self.codec_id.v.len() as u64, self.codec_id.encoding.as_ref());
+        if let Some(val) = &self.codec_private { size += size_el_encoded(EbmlId::CodecPrivate as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.codec_name { size += size_el_encoded(EbmlId::CodecName as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.attachment_link { size += size_el_encoded(EbmlId::AttachmentLink as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.codec_settings { size += size_el_encoded(EbmlId::CodecSettings as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.codec_info_url { size += size_el_encoded(EbmlId::CodecInfoUrl as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.codec_download_url { size += size_el_encoded(EbmlId::CodecDownloadUrl as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.codec_decode_all.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.codec_decode_all.defaulted)) { size += size_el_encoded(EbmlId::CodecDecodeAll as u64, gen_uint_encoded(&self.codec_decode_all.v, self.codec_decode_all.encoding.as_ref()).len() as u64, self.codec_decode_all.encoding.as_ref()); }
+        for val in &self.track_overlay { size += size_el_encoded(EbmlId::TrackOverlay as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.codec_delay.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.codec_delay.defaulted)) { size += size_el_encoded(EbmlId::CodecDelay as u64, gen_uint_encoded(&self.codec_delay.v, self.codec_delay.encoding.as_ref()).len() as u64, self.codec_delay.encoding.as_ref()); }
+        if *self.seek_pre_roll.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.seek_pre_roll.defaulted)) { size += size_el_encoded(EbmlId::SeekPreRoll as u64, gen_uint_encoded(&self.seek_pre_roll.v, self.seek_pre_roll.encoding.as_ref()).len() as u64, self.seek_pre_roll.encoding.as_ref()); }
+        for val in &self.track_translate { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.video { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.audio { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.track_operation { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.trick_track_uid { size += size_el_encoded(EbmlId::TrickTrackUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.trick_track_segment_uid { size += size_el_encoded(EbmlId::TrickTrackSegmentUid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.trick_track_flag { size += size_el_encoded(EbmlId::TrickTrackFlag as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.trick_struct_track_uid { size += size_el_encoded(EbmlId::TrickStructTrackUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.trick_struct_track_segment_uid { size += size_el_encoded(EbmlId::TrickStructTrackSegmentUid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.content_encodings { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TrackEntry {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackEntry as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = 0usize;
+        for el in self.elements() {
+            size += match el {
+                TrackEntryFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackEntryFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackEntryFields::TrackNumber(val) => blocking::write_el_encoded(w, EbmlId::TrackNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackUid(val) => blocking::write_el_encoded(w, EbmlId::TrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackType(val) => blocking::write_el_encoded(w, EbmlId::TrackType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagEnabled(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagEnabled(val) => blocking::write_el_encoded(w, EbmlId::FlagEnabled as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagDefault(val) => blocking::write_el_encoded(w, EbmlId::FlagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagForced(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::FlagForced(val) => blocking::write_el_encoded(w, EbmlId::FlagForced as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagHearingImpaired(val) => blocking::write_el_encoded(w, EbmlId::FlagHearingImpaired as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagVisualImpaired(val) => blocking::write_el_encoded(w, EbmlId::FlagVisualImpaired as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagTextDescriptions(val) => blocking::write_el_encoded(w, EbmlId::FlagTextDescriptions as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagOriginal(val) => blocking::write_el_encoded(w, EbmlId::FlagOriginal as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagCommentary(val) => blocking::write_el_encoded(w, EbmlId::FlagCommentary as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::FlagLacing(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagLacing(val) => blocking::write_el_encoded(w, EbmlId::FlagLacing as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::MinCache(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::MinCache(val) => blocking::write_el_encoded(w, EbmlId::MinCache as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::MaxCache(val) => blocking::write_el_encoded(w, EbmlId::MaxCache as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::DefaultDuration(val) => blocking::write_el_encoded(w, EbmlId::DefaultDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::DefaultDecodedFieldDuration(val) => blocking::write_el_encoded(w, EbmlId::DefaultDecodedFieldDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackTimestampScale(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x1p+0") => 0,
+                TrackEntryFields::TrackTimestampScale(val) => blocking::write_el_encoded(w, EbmlId::TrackTimestampScale as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackOffset(val) => blocking::write_el_encoded(w, EbmlId::TrackOffset as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::MaxBlockAdditionId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::MaxBlockAdditionId(val) => blocking::write_el_encoded(w, EbmlId::MaxBlockAdditionId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::BlockAdditionMapping(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::Name(val) => blocking::write_el_encoded(w, EbmlId::Name as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::Language(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.as_str() == "eng" => 0,
+                TrackEntryFields::Language(val) => blocking::write_el_encoded(w, EbmlId::Language as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::LanguageBcp47(val) => blocking::write_el_encoded(w, EbmlId::LanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::CodecId(val) => blocking::write_el_encoded(w, EbmlId::CodecId as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::CodecPrivate(val) => blocking::write_el_encoded(w, EbmlId::CodecPrivate as u64, &val.v, val.encoding.as_ref())?,
+                TrackEntryFields::CodecName(val) => blocking::write_el_encoded(w, EbmlId::CodecName as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::AttachmentLink(val) => blocking::write_el_encoded(w, EbmlId::AttachmentLink as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::CodecSettings(val) => blocking::write_el_encoded(w, EbmlId::CodecSettings as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::CodecInfoUrl(val) => blocking::write_el_encoded(w, EbmlId::CodecInfoUrl as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::CodecDownloadUrl(val) => blocking::write_el_encoded(w, EbmlId::CodecDownloadUrl as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                TrackEntryFields::CodecDecodeAll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::CodecDecodeAll(val) => blocking::write_el_encoded(w, EbmlId::CodecDecodeAll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackOverlay(val) => blocking::write_el_encoded(w, EbmlId::TrackOverlay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::CodecDelay(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::CodecDelay(val) => blocking::write_el_encoded(w, EbmlId::CodecDelay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::SeekPreRoll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::SeekPreRoll(val) => blocking::write_el_encoded(w, EbmlId::SeekPreRoll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrackTranslate(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::Video(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::Audio(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::TrackOperation(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::TrickTrackUid(val) => blocking::write_el_encoded(w, EbmlId::TrickTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrickTrackSegmentUid(val) => blocking::write_el_encoded(w, EbmlId::TrickTrackSegmentUid as u64, &val.v, val.encoding.as_ref())?,
+                TrackEntryFields::TrickTrackFlag(val) => blocking::write_el_encoded(w, EbmlId::TrickTrackFlag as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrickStructTrackUid(val) => blocking::write_el_encoded(w, EbmlId::TrickStructTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackEntryFields::TrickStructTrackSegmentUid(val) => blocking::write_el_encoded(w, EbmlId::TrickStructTrackSegmentUid as u64, &val.v, val.encoding.as_ref())?,
+                TrackEntryFields::ContentEncodings(val) => val.v.write_with_blocking(w, options)?,
+                TrackEntryFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackEntry {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
-        Ok(size)
-    }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackEntry as u64, size).await?)
-    }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut size = 0usize;
-        for el in self.elements() {
-            size += match el {
-                TrackEntryFields::TrackNumber(val) => async_::write_el_uint(w, EbmlId::TrackNumber as u64, &*val.v).await?,
-                TrackEntryFields::TrackUid(val) => async_::write_el_uint(w, EbmlId::TrackUid as u64, &*val.v).await?,
-                TrackEntryFields::TrackType(val) => async_::write_el_uint(w, EbmlId::TrackType as u64, &*val.v).await?,
-                TrackEntryFields::FlagEnabled(val) => async_::write_el_uint(w, EbmlId::FlagEnabled as u64, &*val.v).await?,
-                TrackEntryFields::FlagDefault(val) => async_::write_el_uint(w, EbmlId::FlagDefault as u64, &*val.v).await?,
-                TrackEntryFields::FlagForced(val) => async_::write_el_uint(w, EbmlId::FlagForced as u64, &*val.v).await?,
-                TrackEntryFields::FlagHearingImpaired(val) => async_::write_el_uint(w, EbmlId::FlagHearingImpaired as u64, &*val.v).await?,
-                TrackEntryFields::FlagVisualImpaired(val) => async_::write_el_uint(w, EbmlId::FlagVisualImpaired as u64, &*val.v).await?,
-                TrackEntryFields::FlagTextDescriptions(val) => async_::write_el_uint(w, EbmlId::FlagTextDescriptions as u64, &*val.v).await?,
-                TrackEntryFields::FlagOriginal(val) => async_::write_el_uint(w, EbmlId::FlagOriginal as u64, &*val.v).await?,
-                TrackEntryFields::FlagCommentary(val) => async_::write_el_uint(w, EbmlId::FlagCommentary as u64, &*val.v).await?,
-                TrackEntryFields::FlagLacing(val) => async_::write_el_uint(w, EbmlId::FlagLacing as u64, &*val.v).await?,
-                TrackEntryFields::MinCache(val) => async_::write_el_uint(w, EbmlId::MinCache as u64, &*val.v).await?,
-                TrackEntryFields::MaxCache(val) => async_::write_el_uint(w, EbmlId::MaxCache as u64, &*val.v).await?,
-                TrackEntryFields::DefaultDuration(val) => async_::write_el_uint(w, EbmlId::DefaultDuration as u64, &*val.v).await?,
-                TrackEntryFields::DefaultDecodedFieldDuration(val) => async_::write_el_uint(w, EbmlId::DefaultDecodedFieldDuration as u64, &*val.v).await?,
-                TrackEntryFields::TrackTimestampScale(val) => async_::write_el_float64(w, EbmlId::TrackTimestampScale as u64, &*val.v).await?,
-                TrackEntryFields::TrackOffset(val) => async_::write_el_int(w, EbmlId::TrackOffset as u64, &*val.v).await?,
-                TrackEntryFields::MaxBlockAdditionId(val) => async_::write_el_uint(w, EbmlId::MaxBlockAdditionId as u64, &*val.v).await?,
-                TrackEntryFields::BlockAdditionMapping(val) => val.v.write(w).await?,
-                TrackEntryFields::Name(val) => async_::write_el_utf8(w, EbmlId::Name as u64, &val.v).await?,
-                TrackEntryFields::Language(val) => async_::write_el_string(w, EbmlId::Language as u64, &val.v).await?,
-                TrackEntryFields::LanguageBcp47(val) => async_::write_el_string(w, EbmlId::LanguageBcp47 as u64, &val.v).await?,
-                TrackEntryFields::CodecId(val) => async_::write_el_string(w, EbmlId::CodecId as u64, &val.v).await?,
-                TrackEntryFields::CodecPrivate(val) => async_::write_el_bin(w, EbmlId::CodecPrivate as u64, &val.v).await?,
-                TrackEntryFields::CodecName(val) => async_::write_el_utf8(w, EbmlId::CodecName as u64, &val.v).await?,
-                TrackEntryFields::AttachmentLink(val) => async_::write_el_uint(w, EbmlId::AttachmentLink as u64, &*val.v).await?,
-                TrackEntryFields::CodecSettings(val) => async_::write_el_utf8(w, EbmlId::CodecSettings as u64, &val.v).await?,
-                TrackEntryFields::CodecInfoUrl(val) => async_::write_el_string(w, EbmlId::CodecInfoUrl as u64, &val.v).await?,
-                TrackEntryFields::CodecDownloadUrl(val) => async_::write_el_string(w, EbmlId::CodecDownloadUrl as u64, &val.v).await?,
-                TrackEntryFields::CodecDecodeAll(val) => async_::write_el_uint(w, EbmlId::CodecDecodeAll as u64, &*val.v).await?,
-                TrackEntryFields::TrackOverlay(val) => async_::write_el_uint(w, EbmlId::TrackOverlay as u64, &*val.v).await?,
-                TrackEntryFields::CodecDelay(val) => async_::write_el_uint(w, EbmlId::CodecDelay as u64, &*val.v).await?,
-                TrackEntryFields::SeekPreRoll(val) => async_::write_el_uint(w, EbmlId::SeekPreRoll as u64, &*val.v).await?,
-                TrackEntryFields::TrackTranslate(val) => val.v.write(w).await?,
-                TrackEntryFields::Video(val) => val.v.write(w).await?,
-                TrackEntryFields::Audio(val) => val.v.write(w).await?,
-                TrackEntryFields::TrackOperation(val) => val.v.write(w).await?,
-                TrackEntryFields::TrickTrackUid(val) => async_::write_el_uint(w, EbmlId::TrickTrackUid as u64, &*val.v).await?,
-                TrackEntryFields::TrickTrackSegmentUid(val) => async_::write_el_bin(w, EbmlId::TrickTrackSegmentUid as u64, &val.v).await?,
-                TrackEntryFields::TrickTrackFlag(val) => async_::write_el_uint(w, EbmlId::TrickTrackFlag as u64, &*val.v).await?,
-                TrackEntryFields::TrickStructTrackUid(val) => async_::write_el_uint(w, EbmlId::TrickStructTrackUid as u64, &*val.v).await?,
-                TrackEntryFields::TrickStructTrackSegmentUid(val) => async_::write_el_bin(w, EbmlId::TrickStructTrackSegmentUid as u64, &val.v).await?,
-                TrackEntryFields::ContentEncodings(val) => val.v.write(w).await?,
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
+        Ok(size)
+    }
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackEntry as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = 0usize;
+        for el in self.elements() {
+            size += match el {
+                TrackEntryFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackEntryFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackNumber(val) => async_::write_el_encoded(w, EbmlId::TrackNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackUid(val) => async_::write_el_encoded(w, EbmlId::TrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackType(val) => async_::write_el_encoded(w, EbmlId::TrackType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagEnabled(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagEnabled(val) => async_::write_el_encoded(w, EbmlId::FlagEnabled as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagDefault(val) => async_::write_el_encoded(w, EbmlId::FlagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagForced(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::FlagForced(val) => async_::write_el_encoded(w, EbmlId::FlagForced as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagHearingImpaired(val) => async_::write_el_encoded(w, EbmlId::FlagHearingImpaired as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagVisualImpaired(val) => async_::write_el_encoded(w, EbmlId::FlagVisualImpaired as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagTextDescriptions(val) => async_::write_el_encoded(w, EbmlId::FlagTextDescriptions as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagOriginal(val) => async_::write_el_encoded(w, EbmlId::FlagOriginal as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagCommentary(val) => async_::write_el_encoded(w, EbmlId::FlagCommentary as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::FlagLacing(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::FlagLacing(val) => async_::write_el_encoded(w, EbmlId::FlagLacing as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::MinCache(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::MinCache(val) => async_::write_el_encoded(w, EbmlId::MinCache as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::MaxCache(val) => async_::write_el_encoded(w, EbmlId::MaxCache as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::DefaultDuration(val) => async_::write_el_encoded(w, EbmlId::DefaultDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::DefaultDecodedFieldDuration(val) => async_::write_el_encoded(w, EbmlId::DefaultDecodedFieldDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackTimestampScale(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x1p+0") => 0,
+                TrackEntryFields::TrackTimestampScale(val) => async_::write_el_encoded(w, EbmlId::TrackTimestampScale as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackOffset(val) => async_::write_el_encoded(w, EbmlId::TrackOffset as u64, &gen_int_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::MaxBlockAdditionId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::MaxBlockAdditionId(val) => async_::write_el_encoded(w, EbmlId::MaxBlockAdditionId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::BlockAdditionMapping(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::Name(val) => async_::write_el_encoded(w, EbmlId::Name as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::Language(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.as_str() == "eng" => 0,
+                TrackEntryFields::Language(val) => async_::write_el_encoded(w, EbmlId::Language as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::LanguageBcp47(val) => async_::write_el_encoded(w, EbmlId::LanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecId(val) => async_::write_el_encoded(w, EbmlId::CodecId as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecPrivate(val) => async_::write_el_encoded(w, EbmlId::CodecPrivate as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecName(val) => async_::write_el_encoded(w, EbmlId::CodecName as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::AttachmentLink(val) => async_::write_el_encoded(w, EbmlId::AttachmentLink as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecSettings(val) => async_::write_el_encoded(w, EbmlId::CodecSettings as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecInfoUrl(val) => async_::write_el_encoded(w, EbmlId::CodecInfoUrl as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecDownloadUrl(val) => async_::write_el_encoded(w, EbmlId::CodecDownloadUrl as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecDecodeAll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                TrackEntryFields::CodecDecodeAll(val) => async_::write_el_encoded(w, EbmlId::CodecDecodeAll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackOverlay(val) => async_::write_el_encoded(w, EbmlId::TrackOverlay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::CodecDelay(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::CodecDelay(val) => async_::write_el_encoded(w, EbmlId::CodecDelay as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::SeekPreRoll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                TrackEntryFields::SeekPreRoll(val) => async_::write_el_encoded(w, EbmlId::SeekPreRoll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrackTranslate(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::Video(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::Audio(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::TrackOperation(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::TrickTrackUid(val) => async_::write_el_encoded(w, EbmlId::TrickTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrickTrackSegmentUid(val) => async_::write_el_encoded(w, EbmlId::TrickTrackSegmentUid as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackEntryFields::TrickTrackFlag(val) => async_::write_el_encoded(w, EbmlId::TrickTrackFlag as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrickStructTrackUid(val) => async_::write_el_encoded(w, EbmlId::TrickStructTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackEntryFields::TrickStructTrackSegmentUid(val) => async_::write_el_encoded(w, EbmlId::TrickStructTrackSegmentUid as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackEntryFields::ContentEncodings(val) => val.v.write_with(w, options).await?,
+                TrackEntryFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -892,46 +2099,112 @@ impl TrackEntry {
 }
 
 impl BlockAdditionMapping {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::BlockAdditionMapping as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.block_add_id_value { size += size_el_encoded(EbmlId::BlockAddIdValue as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.block_add_id_name { size += size_el_encoded(EbmlId::BlockAddIdName as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.block_add_id_type.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.block_add_id_type.defaulted)) { size += size_el_encoded(EbmlId::BlockAddIdType as u64, gen_uint_encoded(&self.block_add_id_type.v, self.block_add_id_type.encoding.as_ref()).len() as u64, self.block_add_id_type.encoding.as_ref()); }
+        if let Some(val) = &self.block_add_id_extra_data { size += size_el_encoded(EbmlId::BlockAddIdExtraData as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::BlockAdditionMapping as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl BlockAdditionMapping {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::BlockAdditionMapping as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockAdditionMappingFields::BlockAddIdValue(val) => blocking::write_el_uint(w, EbmlId::BlockAddIdValue as u64, &*val.v)?,
-                BlockAdditionMappingFields::BlockAddIdName(val) => blocking::write_el_string(w, EbmlId::BlockAddIdName as u64, &val.v)?,
-                BlockAdditionMappingFields::BlockAddIdType(val) => blocking::write_el_uint(w, EbmlId::BlockAddIdType as u64, &*val.v)?,
-                BlockAdditionMappingFields::BlockAddIdExtraData(val) => blocking::write_el_bin(w, EbmlId::BlockAddIdExtraData as u64, &val.v)?,
+                BlockAdditionMappingFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockAdditionMappingFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                BlockAdditionMappingFields::BlockAddIdValue(val) => blocking::write_el_encoded(w, EbmlId::BlockAddIdValue as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockAdditionMappingFields::BlockAddIdName(val) => blocking::write_el_encoded(w, EbmlId::BlockAddIdName as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                BlockAdditionMappingFields::BlockAddIdType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                BlockAdditionMappingFields::BlockAddIdType(val) => blocking::write_el_encoded(w, EbmlId::BlockAddIdType as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                BlockAdditionMappingFields::BlockAddIdExtraData(val) => blocking::write_el_encoded(w, EbmlId::BlockAddIdExtraData as u64, &val.v, val.encoding.as_ref())?,
+                BlockAdditionMappingFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl BlockAdditionMapping {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::BlockAdditionMapping as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::BlockAdditionMapping as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                BlockAdditionMappingFields::BlockAddIdValue(val) => async_::write_el_uint(w, EbmlId::BlockAddIdValue as u64, &*val.v).await?,
-                BlockAdditionMappingFields::BlockAddIdName(val) => async_::write_el_string(w, EbmlId::BlockAddIdName as u64, &val.v).await?,
-                BlockAdditionMappingFields::BlockAddIdType(val) => async_::write_el_uint(w, EbmlId::BlockAddIdType as u64, &*val.v).await?,
-                BlockAdditionMappingFields::BlockAddIdExtraData(val) => async_::write_el_bin(w, EbmlId::BlockAddIdExtraData as u64, &val.v).await?,
+                BlockAdditionMappingFields::Crc32(_) if self.computes_crc32(options) => 0,
+                BlockAdditionMappingFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockAdditionMappingFields::BlockAddIdValue(val) => async_::write_el_encoded(w, EbmlId::BlockAddIdValue as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockAdditionMappingFields::BlockAddIdName(val) => async_::write_el_encoded(w, EbmlId::BlockAddIdName as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                BlockAdditionMappingFields::BlockAddIdType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                BlockAdditionMappingFields::BlockAddIdType(val) => async_::write_el_encoded(w, EbmlId::BlockAddIdType as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                BlockAdditionMappingFields::BlockAddIdExtraData(val) => async_::write_el_encoded(w, EbmlId::BlockAddIdExtraData as u64, &val.v, val.encoding.as_ref()).await?,
+                BlockAdditionMappingFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -939,44 +2212,107 @@ impl BlockAdditionMapping {
 }
 
 impl TrackTranslate {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackTranslate as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::TrackTranslateTrackId as u64, self.track_translate_track_id.v.len() as u64, self.track_translate_track_id.encoding.as_ref());
+        size += size_el_encoded(EbmlId::TrackTranslateCodec as u64, gen_uint_encoded(&self.track_translate_codec.v.value(), self.track_translate_codec.encoding.as_ref()).len() as u64, self.track_translate_codec.encoding.as_ref());
+        for val in &self.track_translate_edition_uid { size += size_el_encoded(EbmlId::TrackTranslateEditionUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackTranslate as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl TrackTranslate {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackTranslate as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackTranslateFields::TrackTranslateTrackId(val) => blocking::write_el_bin(w, EbmlId::TrackTranslateTrackId as u64, &val.v)?,
-                TrackTranslateFields::TrackTranslateCodec(val) => blocking::write_el_uint(w, EbmlId::TrackTranslateCodec as u64, &*val.v)?,
-                TrackTranslateFields::TrackTranslateEditionUid(val) => blocking::write_el_uint(w, EbmlId::TrackTranslateEditionUid as u64, &*val.v)?,
+                TrackTranslateFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackTranslateFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackTranslateFields::TrackTranslateTrackId(val) => blocking::write_el_encoded(w, EbmlId::TrackTranslateTrackId as u64, &val.v, val.encoding.as_ref())?,
+                TrackTranslateFields::TrackTranslateCodec(val) => blocking::write_el_encoded(w, EbmlId::TrackTranslateCodec as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackTranslateFields::TrackTranslateEditionUid(val) => blocking::write_el_encoded(w, EbmlId::TrackTranslateEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackTranslateFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackTranslate {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackTranslate as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackTranslate as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackTranslateFields::TrackTranslateTrackId(val) => async_::write_el_bin(w, EbmlId::TrackTranslateTrackId as u64, &val.v).await?,
-                TrackTranslateFields::TrackTranslateCodec(val) => async_::write_el_uint(w, EbmlId::TrackTranslateCodec as u64, &*val.v).await?,
-                TrackTranslateFields::TrackTranslateEditionUid(val) => async_::write_el_uint(w, EbmlId::TrackTranslateEditionUid as u64, &*val.v).await?,
+                TrackTranslateFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackTranslateFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackTranslateFields::TrackTranslateTrackId(val) => async_::write_el_encoded(w, EbmlId::TrackTranslateTrackId as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackTranslateFields::TrackTranslateCodec(val) => async_::write_el_encoded(w, EbmlId::TrackTranslateCodec as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackTranslateFields::TrackTranslateEditionUid(val) => async_::write_el_encoded(w, EbmlId::TrackTranslateEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackTranslateFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -984,78 +2320,176 @@ impl TrackTranslate {
 }
 
 impl Video {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Video as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.flag_interlaced.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.flag_interlaced.defaulted)) { size += size_el_encoded(EbmlId::FlagInterlaced as u64, gen_uint_encoded(&self.flag_interlaced.v.value(), self.flag_interlaced.encoding.as_ref()).len() as u64, self.flag_interlaced.encoding.as_ref()); }
+        if self.field_order.v.value() != 2 || (!options.omit_defaults && (self.encoding.is_none() || !self.field_order.defaulted)) { size += size_el_encoded(EbmlId::FieldOrder as u64, gen_uint_encoded(&self.field_order.v.value(), self.field_order.encoding.as_ref()).len() as u64, self.field_order.encoding.as_ref()); }
+        if self.stereo_mode.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.stereo_mode.defaulted)) { size += size_el_encoded(EbmlId::StereoMode as u64, gen_uint_encoded(&self.stereo_mode.v.value(), self.stereo_mode.encoding.as_ref()).len() as u64, self.stereo_mode.encoding.as_ref()); }
+        if self.alpha_mode.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.alpha_mode.defaulted)) { size += size_el_encoded(EbmlId::AlphaMode as u64, gen_uint_encoded(&self.alpha_mode.v.value(), self.alpha_mode.encoding.as_ref()).len() as u64, self.alpha_mode.encoding.as_ref()); }
+        if let Some(val) = &self.old_stereo_mode { size += size_el_encoded(EbmlId::OldStereoMode as u64, gen_uint_encoded(&val.v.value(), val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::PixelWidth as u64, gen_uint_encoded(&self.pixel_width.v, self.pixel_width.encoding.as_ref()).len() as u64, self.pixel_width.encoding.as_ref());
+        size += size_el_encoded(EbmlId::PixelHeight as u64, gen_uint_encoded(&self.pixel_height.v, self.pixel_height.encoding.as_ref()).len() as u64, self.pixel_height.encoding.as_ref());
+        if *self.pixel_crop_bottom.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.pixel_crop_bottom.defaulted)) { size += size_el_encoded(EbmlId::PixelCropBottom as u64, gen_uint_encoded(&self.pixel_crop_bottom.v, self.pixel_crop_bottom.encoding.as_ref()).len() as u64, self.pixel_crop_bottom.encoding.as_ref()); }
+        if *self.pixel_crop_top.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.pixel_crop_top.defaulted)) { size += size_el_encoded(EbmlId::PixelCropTop as u64, gen_uint_encoded(&self.pixel_crop_top.v, self.pixel_crop_top.encoding.as_ref()).len() as u64, self.pixel_crop_top.encoding.as_ref()); }
+        if *self.pixel_crop_left.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.pixel_crop_left.defaulted)) { size += size_el_encoded(EbmlId::PixelCropLeft as u64, gen_uint_encoded(&self.pixel_crop_left.v, self.pixel_crop_left.encoding.as_ref()).len() as u64, self.pixel_crop_left.encoding.as_ref()); }
+        if *self.pixel_crop_right.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.pixel_crop_right.defaulted)) { size += size_el_encoded(EbmlId::PixelCropRight as u64, gen_uint_encoded(&self.pixel_crop_right.v, self.pixel_crop_right.encoding.as_ref()).len() as u64, self.pixel_crop_right.encoding.as_ref()); }
+        if let Some(val) = &self.display_width { size += size_el_encoded(EbmlId::DisplayWidth as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.display_height { size += size_el_encoded(EbmlId::DisplayHeight as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if self.display_unit.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.display_unit.defaulted)) { size += size_el_encoded(EbmlId::DisplayUnit as u64, gen_uint_encoded(&self.display_unit.v.value(), self.display_unit.encoding.as_ref()).len() as u64, self.display_unit.encoding.as_ref()); }
+        if let Some(val) = &self.aspect_ratio_type { size += size_el_encoded(EbmlId::AspectRatioType as u64, gen_uint_encoded(&val.v.value(), val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.uncompressed_four_cc { size += size_el_encoded(EbmlId::UncompressedFourCc as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.gamma_value { size += size_el_encoded(EbmlId::GammaValue as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.frame_rate { size += size_el_encoded(EbmlId::FrameRate as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.colour { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.projection { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Video {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Video as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Video as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                VideoFields::FlagInterlaced(val) => blocking::write_el_uint(w, EbmlId::FlagInterlaced as u64, &*val.v)?,
-                VideoFields::FieldOrder(val) => blocking::write_el_uint(w, EbmlId::FieldOrder as u64, &*val.v)?,
-                VideoFields::StereoMode(val) => blocking::write_el_uint(w, EbmlId::StereoMode as u64, &*val.v)?,
-                VideoFields::AlphaMode(val) => blocking::write_el_uint(w, EbmlId::AlphaMode as u64, &*val.v)?,
-                VideoFields::OldStereoMode(val) => blocking::write_el_uint(w, EbmlId::OldStereoMode as u64, &*val.v)?,
-                VideoFields::PixelWidth(val) => blocking::write_el_uint(w, EbmlId::PixelWidth as u64, &*val.v)?,
-                VideoFields::PixelHeight(val) => blocking::write_el_uint(w, EbmlId::PixelHeight as u64, &*val.v)?,
-                VideoFields::PixelCropBottom(val) => blocking::write_el_uint(w, EbmlId::PixelCropBottom as u64, &*val.v)?,
-                VideoFields::PixelCropTop(val) => blocking::write_el_uint(w, EbmlId::PixelCropTop as u64, &*val.v)?,
-                VideoFields::PixelCropLeft(val) => blocking::write_el_uint(w, EbmlId::PixelCropLeft as u64, &*val.v)?,
-                VideoFields::PixelCropRight(val) => blocking::write_el_uint(w, EbmlId::PixelCropRight as u64, &*val.v)?,
-                VideoFields::DisplayWidth(val) => blocking::write_el_uint(w, EbmlId::DisplayWidth as u64, &*val.v)?,
-                VideoFields::DisplayHeight(val) => blocking::write_el_uint(w, EbmlId::DisplayHeight as u64, &*val.v)?,
-                VideoFields::DisplayUnit(val) => blocking::write_el_uint(w, EbmlId::DisplayUnit as u64, &*val.v)?,
-                VideoFields::AspectRatioType(val) => blocking::write_el_uint(w, EbmlId::AspectRatioType as u64, &*val.v)?,
-                VideoFields::UncompressedFourCc(val) => blocking::write_el_bin(w, EbmlId::UncompressedFourCc as u64, &val.v)?,
-                VideoFields::GammaValue(val) => blocking::write_el_float64(w, EbmlId::GammaValue as u64, &*val.v)?,
-                VideoFields::FrameRate(val) => blocking::write_el_float64(w, EbmlId::FrameRate as u64, &*val.v)?,
-                VideoFields::Colour(val) => val.v.write_blocking(w)?,
-                VideoFields::Projection(val) => val.v.write_blocking(w)?,
+                VideoFields::Crc32(_) if self.computes_crc32(options) => 0,
+                VideoFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                VideoFields::FlagInterlaced(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::FlagInterlaced(val) => blocking::write_el_encoded(w, EbmlId::FlagInterlaced as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::FieldOrder(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                VideoFields::FieldOrder(val) => blocking::write_el_encoded(w, EbmlId::FieldOrder as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::StereoMode(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::StereoMode(val) => blocking::write_el_encoded(w, EbmlId::StereoMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::AlphaMode(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::AlphaMode(val) => blocking::write_el_encoded(w, EbmlId::AlphaMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::OldStereoMode(val) => blocking::write_el_encoded(w, EbmlId::OldStereoMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelWidth(val) => blocking::write_el_encoded(w, EbmlId::PixelWidth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelHeight(val) => blocking::write_el_encoded(w, EbmlId::PixelHeight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelCropBottom(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropBottom(val) => blocking::write_el_encoded(w, EbmlId::PixelCropBottom as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelCropTop(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropTop(val) => blocking::write_el_encoded(w, EbmlId::PixelCropTop as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelCropLeft(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropLeft(val) => blocking::write_el_encoded(w, EbmlId::PixelCropLeft as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::PixelCropRight(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropRight(val) => blocking::write_el_encoded(w, EbmlId::PixelCropRight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::DisplayWidth(val) => blocking::write_el_encoded(w, EbmlId::DisplayWidth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::DisplayHeight(val) => blocking::write_el_encoded(w, EbmlId::DisplayHeight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::DisplayUnit(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::DisplayUnit(val) => blocking::write_el_encoded(w, EbmlId::DisplayUnit as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::AspectRatioType(val) => blocking::write_el_encoded(w, EbmlId::AspectRatioType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::UncompressedFourCc(val) => blocking::write_el_encoded(w, EbmlId::UncompressedFourCc as u64, &val.v, val.encoding.as_ref())?,
+                VideoFields::GammaValue(val) => blocking::write_el_encoded(w, EbmlId::GammaValue as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::FrameRate(val) => blocking::write_el_encoded(w, EbmlId::FrameRate as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                VideoFields::Colour(val) => val.v.write_with_blocking(w, options)?,
+                VideoFields::Projection(val) => val.v.write_with_blocking(w, options)?,
+                VideoFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Video {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Video as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Video as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                VideoFields::FlagInterlaced(val) => async_::write_el_uint(w, EbmlId::FlagInterlaced as u64, &*val.v).await?,
-                VideoFields::FieldOrder(val) => async_::write_el_uint(w, EbmlId::FieldOrder as u64, &*val.v).await?,
-                VideoFields::StereoMode(val) => async_::write_el_uint(w, EbmlId::StereoMode as u64, &*val.v).await?,
-                VideoFields::AlphaMode(val) => async_::write_el_uint(w, EbmlId::AlphaMode as u64, &*val.v).await?,
-                VideoFields::OldStereoMode(val) => async_::write_el_uint(w, EbmlId::OldStereoMode as u64, &*val.v).await?,
-                VideoFields::PixelWidth(val) => async_::write_el_uint(w, EbmlId::PixelWidth as u64, &*val.v).await?,
-                VideoFields::PixelHeight(val) => async_::write_el_uint(w, EbmlId::PixelHeight as u64, &*val.v).await?,
-                VideoFields::PixelCropBottom(val) => async_::write_el_uint(w, EbmlId::PixelCropBottom as u64, &*val.v).await?,
-                VideoFields::PixelCropTop(val) => async_::write_el_uint(w, EbmlId::PixelCropTop as u64, &*val.v).await?,
-                VideoFields::PixelCropLeft(val) => async_::write_el_uint(w, EbmlId::PixelCropLeft as u64, &*val.v).await?,
-                VideoFields::PixelCropRight(val) => async_::write_el_uint(w, EbmlId::PixelCropRight as u64, &*val.v).await?,
-                VideoFields::DisplayWidth(val) => async_::write_el_uint(w, EbmlId::DisplayWidth as u64, &*val.v).await?,
-                VideoFields::DisplayHeight(val) => async_::write_el_uint(w, EbmlId::DisplayHeight as u64, &*val.v).await?,
-                VideoFields::DisplayUnit(val) => async_::write_el_uint(w, EbmlId::DisplayUnit as u64, &*val.v).await?,
-                VideoFields::AspectRatioType(val) => async_::write_el_uint(w, EbmlId::AspectRatioType as u64, &*val.v).await?,
-                VideoFields::UncompressedFourCc(val) => async_::write_el_bin(w, EbmlId::UncompressedFourCc as u64, &val.v).await?,
-                VideoFields::GammaValue(val) => async_::write_el_float64(w, EbmlId::GammaValue as u64, &*val.v).await?,
-                VideoFields::FrameRate(val) => async_::write_el_float64(w, EbmlId::FrameRate as u64, &*val.v).await?,
-                VideoFields::Colour(val) => val.v.write(w).await?,
-                VideoFields::Projection(val) => val.v.write(w).await?,
+                VideoFields::Crc32(_) if self.computes_crc32(options) => 0,
+                VideoFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                VideoFields::FlagInterlaced(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::FlagInterlaced(val) => async_::write_el_encoded(w, EbmlId::FlagInterlaced as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::FieldOrder(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                VideoFields::FieldOrder(val) => async_::write_el_encoded(w, EbmlId::FieldOrder as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::StereoMode(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::StereoMode(val) => async_::write_el_encoded(w, EbmlId::StereoMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::AlphaMode(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::AlphaMode(val) => async_::write_el_encoded(w, EbmlId::AlphaMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::OldStereoMode(val) => async_::write_el_encoded(w, EbmlId::OldStereoMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelWidth(val) => async_::write_el_encoded(w, EbmlId::PixelWidth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelHeight(val) => async_::write_el_encoded(w, EbmlId::PixelHeight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelCropBottom(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropBottom(val) => async_::write_el_encoded(w, EbmlId::PixelCropBottom as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelCropTop(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropTop(val) => async_::write_el_encoded(w, EbmlId::PixelCropTop as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelCropLeft(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropLeft(val) => async_::write_el_encoded(w, EbmlId::PixelCropLeft as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::PixelCropRight(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                VideoFields::PixelCropRight(val) => async_::write_el_encoded(w, EbmlId::PixelCropRight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::DisplayWidth(val) => async_::write_el_encoded(w, EbmlId::DisplayWidth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::DisplayHeight(val) => async_::write_el_encoded(w, EbmlId::DisplayHeight as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::DisplayUnit(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                VideoFields::DisplayUnit(val) => async_::write_el_encoded(w, EbmlId::DisplayUnit as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::AspectRatioType(val) => async_::write_el_encoded(w, EbmlId::AspectRatioType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::UncompressedFourCc(val) => async_::write_el_encoded(w, EbmlId::UncompressedFourCc as u64, &val.v, val.encoding.as_ref()).await?,
+                VideoFields::GammaValue(val) => async_::write_el_encoded(w, EbmlId::GammaValue as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::FrameRate(val) => async_::write_el_encoded(w, EbmlId::FrameRate as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                VideoFields::Colour(val) => val.v.write_with(w, options).await?,
+                VideoFields::Projection(val) => val.v.write_with(w, options).await?,
+                VideoFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1063,66 +2497,154 @@ impl Video {
 }
 
 impl Colour {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Colour as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.matrix_coefficients.v.value() != 2 || (!options.omit_defaults && (self.encoding.is_none() || !self.matrix_coefficients.defaulted)) { size += size_el_encoded(EbmlId::MatrixCoefficients as u64, gen_uint_encoded(&self.matrix_coefficients.v.value(), self.matrix_coefficients.encoding.as_ref()).len() as u64, self.matrix_coefficients.encoding.as_ref()); }
+        if *self.bits_per_channel.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.bits_per_channel.defaulted)) { size += size_el_encoded(EbmlId::BitsPerChannel as u64, gen_uint_encoded(&self.bits_per_channel.v, self.bits_per_channel.encoding.as_ref()).len() as u64, self.bits_per_channel.encoding.as_ref()); }
+        if let Some(val) = &self.chroma_subsampling_horz { size += size_el_encoded(EbmlId::ChromaSubsamplingHorz as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.chroma_subsampling_vert { size += size_el_encoded(EbmlId::ChromaSubsamplingVert as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.cb_subsampling_horz { size += size_el_encoded(EbmlId::CbSubsamplingHorz as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.cb_subsampling_vert { size += size_el_encoded(EbmlId::CbSubsamplingVert as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if self.chroma_siting_horz.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.chroma_siting_horz.defaulted)) { size += size_el_encoded(EbmlId::ChromaSitingHorz as u64, gen_uint_encoded(&self.chroma_siting_horz.v.value(), self.chroma_siting_horz.encoding.as_ref()).len() as u64, self.chroma_siting_horz.encoding.as_ref()); }
+        if self.chroma_siting_vert.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.chroma_siting_vert.defaulted)) { size += size_el_encoded(EbmlId::ChromaSitingVert as u64, gen_uint_encoded(&self.chroma_siting_vert.v.value(), self.chroma_siting_vert.encoding.as_ref()).len() as u64, self.chroma_siting_vert.encoding.as_ref()); }
+        if self.range.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.range.defaulted)) { size += size_el_encoded(EbmlId::Range as u64, gen_uint_encoded(&self.range.v.value(), self.range.encoding.as_ref()).len() as u64, self.range.encoding.as_ref()); }
+        if self.transfer_characteristics.v.value() != 2 || (!options.omit_defaults && (self.encoding.is_none() || !self.transfer_characteristics.defaulted)) { size += size_el_encoded(EbmlId::TransferCharacteristics as u64, gen_uint_encoded(&self.transfer_characteristics.v.value(), self.transfer_characteristics.encoding.as_ref()).len() as u64, self.transfer_characteristics.encoding.as_ref()); }
+        if self.primaries.v.value() != 2 || (!options.omit_defaults && (self.encoding.is_none() || !self.primaries.defaulted)) { size += size_el_encoded(EbmlId::Primaries as u64, gen_uint_encoded(&self.primaries.v.value(), self.primaries.encoding.as_ref()).len() as u64, self.primaries.encoding.as_ref()); }
+        if let Some(val) = &self.max_cll { size += size_el_encoded(EbmlId::MaxCll as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.max_fall { size += size_el_encoded(EbmlId::MaxFall as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.structing_metadata { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Colour {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Colour as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Colour as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ColourFields::MatrixCoefficients(val) => blocking::write_el_uint(w, EbmlId::MatrixCoefficients as u64, &*val.v)?,
-                ColourFields::BitsPerChannel(val) => blocking::write_el_uint(w, EbmlId::BitsPerChannel as u64, &*val.v)?,
-                ColourFields::ChromaSubsamplingHorz(val) => blocking::write_el_uint(w, EbmlId::ChromaSubsamplingHorz as u64, &*val.v)?,
-                ColourFields::ChromaSubsamplingVert(val) => blocking::write_el_uint(w, EbmlId::ChromaSubsamplingVert as u64, &*val.v)?,
-                ColourFields::CbSubsamplingHorz(val) => blocking::write_el_uint(w, EbmlId::CbSubsamplingHorz as u64, &*val.v)?,
-                ColourFields::CbSubsamplingVert(val) => blocking::write_el_uint(w, EbmlId::CbSubsamplingVert as u64, &*val.v)?,
-                ColourFields::ChromaSitingHorz(val) => blocking::write_el_uint(w, EbmlId::ChromaSitingHorz as u64, &*val.v)?,
-                ColourFields::ChromaSitingVert(val) => blocking::write_el_uint(w, EbmlId::ChromaSitingVert as u64, &*val.v)?,
-                ColourFields::Range(val) => blocking::write_el_uint(w, EbmlId::Range as u64, &*val.v)?,
-                ColourFields::TransferCharacteristics(val) => blocking::write_el_uint(w, EbmlId::TransferCharacteristics as u64, &*val.v)?,
-                ColourFields::Primaries(val) => blocking::write_el_uint(w, EbmlId::Primaries as u64, &*val.v)?,
-                ColourFields::MaxCll(val) => blocking::write_el_uint(w, EbmlId::MaxCll as u64, &*val.v)?,
-                ColourFields::MaxFall(val) => blocking::write_el_uint(w, EbmlId::MaxFall as u64, &*val.v)?,
-                ColourFields::StructingMetadata(val) => val.v.write_blocking(w)?,
+                ColourFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ColourFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ColourFields::MatrixCoefficients(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::MatrixCoefficients(val) => blocking::write_el_encoded(w, EbmlId::MatrixCoefficients as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::BitsPerChannel(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ColourFields::BitsPerChannel(val) => blocking::write_el_encoded(w, EbmlId::BitsPerChannel as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::ChromaSubsamplingHorz(val) => blocking::write_el_encoded(w, EbmlId::ChromaSubsamplingHorz as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::ChromaSubsamplingVert(val) => blocking::write_el_encoded(w, EbmlId::ChromaSubsamplingVert as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::CbSubsamplingHorz(val) => blocking::write_el_encoded(w, EbmlId::CbSubsamplingHorz as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::CbSubsamplingVert(val) => blocking::write_el_encoded(w, EbmlId::CbSubsamplingVert as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::ChromaSitingHorz(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::ChromaSitingHorz(val) => blocking::write_el_encoded(w, EbmlId::ChromaSitingHorz as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::ChromaSitingVert(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::ChromaSitingVert(val) => blocking::write_el_encoded(w, EbmlId::ChromaSitingVert as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::Range(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::Range(val) => blocking::write_el_encoded(w, EbmlId::Range as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::TransferCharacteristics(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::TransferCharacteristics(val) => blocking::write_el_encoded(w, EbmlId::TransferCharacteristics as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::Primaries(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::Primaries(val) => blocking::write_el_encoded(w, EbmlId::Primaries as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::MaxCll(val) => blocking::write_el_encoded(w, EbmlId::MaxCll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::MaxFall(val) => blocking::write_el_encoded(w, EbmlId::MaxFall as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ColourFields::StructingMetadata(val) => val.v.write_with_blocking(w, options)?,
+                ColourFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Colour {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Colour as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Colour as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ColourFields::MatrixCoefficients(val) => async_::write_el_uint(w, EbmlId::MatrixCoefficients as u64, &*val.v).await?,
-                ColourFields::BitsPerChannel(val) => async_::write_el_uint(w, EbmlId::BitsPerChannel as u64, &*val.v).await?,
-                ColourFields::ChromaSubsamplingHorz(val) => async_::write_el_uint(w, EbmlId::ChromaSubsamplingHorz as u64, &*val.v).await?,
-                ColourFields::ChromaSubsamplingVert(val) => async_::write_el_uint(w, EbmlId::ChromaSubsamplingVert as u64, &*val.v).await?,
-                ColourFields::CbSubsamplingHorz(val) => async_::write_el_uint(w, EbmlId::CbSubsamplingHorz as u64, &*val.v).await?,
-                ColourFields::CbSubsamplingVert(val) => async_::write_el_uint(w, EbmlId::CbSubsamplingVert as u64, &*val.v).await?,
-                ColourFields::ChromaSitingHorz(val) => async_::write_el_uint(w, EbmlId::ChromaSitingHorz as u64, &*val.v).await?,
-                ColourFields::ChromaSitingVert(val) => async_::write_el_uint(w, EbmlId::ChromaSitingVert as u64, &*val.v).await?,
-                ColourFields::Range(val) => async_::write_el_uint(w, EbmlId::Range as u64, &*val.v).await?,
-                ColourFields::TransferCharacteristics(val) => async_::write_el_uint(w, EbmlId::TransferCharacteristics as u64, &*val.v).await?,
-                ColourFields::Primaries(val) => async_::write_el_uint(w, EbmlId::Primaries as u64, &*val.v).await?,
-                ColourFields::MaxCll(val) => async_::write_el_uint(w, EbmlId::MaxCll as u64, &*val.v).await?,
-                ColourFields::MaxFall(val) => async_::write_el_uint(w, EbmlId::MaxFall as u64, &*val.v).await?,
-                ColourFields::StructingMetadata(val) => val.v.write(w).await?,
+                ColourFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ColourFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ColourFields::MatrixCoefficients(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::MatrixCoefficients(val) => async_::write_el_encoded(w, EbmlId::MatrixCoefficients as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::BitsPerChannel(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ColourFields::BitsPerChannel(val) => async_::write_el_encoded(w, EbmlId::BitsPerChannel as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::ChromaSubsamplingHorz(val) => async_::write_el_encoded(w, EbmlId::ChromaSubsamplingHorz as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::ChromaSubsamplingVert(val) => async_::write_el_encoded(w, EbmlId::ChromaSubsamplingVert as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::CbSubsamplingHorz(val) => async_::write_el_encoded(w, EbmlId::CbSubsamplingHorz as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::CbSubsamplingVert(val) => async_::write_el_encoded(w, EbmlId::CbSubsamplingVert as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::ChromaSitingHorz(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::ChromaSitingHorz(val) => async_::write_el_encoded(w, EbmlId::ChromaSitingHorz as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::ChromaSitingVert(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::ChromaSitingVert(val) => async_::write_el_encoded(w, EbmlId::ChromaSitingVert as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::Range(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ColourFields::Range(val) => async_::write_el_encoded(w, EbmlId::Range as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::TransferCharacteristics(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::TransferCharacteristics(val) => async_::write_el_encoded(w, EbmlId::TransferCharacteristics as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::Primaries(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 2 => 0,
+                ColourFields::Primaries(val) => async_::write_el_encoded(w, EbmlId::Primaries as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::MaxCll(val) => async_::write_el_encoded(w, EbmlId::MaxCll as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::MaxFall(val) => async_::write_el_encoded(w, EbmlId::MaxFall as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ColourFields::StructingMetadata(val) => val.v.write_with(w, options).await?,
+                ColourFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1130,58 +2652,128 @@ impl Colour {
 }
 
 impl StructingMetadata {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::StructingMetadata as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_r_chromaticity_x { size += size_el_encoded(EbmlId::PrimaryRChromaticityX as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_r_chromaticity_y { size += size_el_encoded(EbmlId::PrimaryRChromaticityY as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_g_chromaticity_x { size += size_el_encoded(EbmlId::PrimaryGChromaticityX as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_g_chromaticity_y { size += size_el_encoded(EbmlId::PrimaryGChromaticityY as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_b_chromaticity_x { size += size_el_encoded(EbmlId::PrimaryBChromaticityX as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.primary_b_chromaticity_y { size += size_el_encoded(EbmlId::PrimaryBChromaticityY as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.white_point_chromaticity_x { size += size_el_encoded(EbmlId::WhitePointChromaticityX as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.white_point_chromaticity_y { size += size_el_encoded(EbmlId::WhitePointChromaticityY as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.luminance_max { size += size_el_encoded(EbmlId::LuminanceMax as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.luminance_min { size += size_el_encoded(EbmlId::LuminanceMin as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl StructingMetadata {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::StructingMetadata as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::StructingMetadata as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                StructingMetadataFields::PrimaryRChromaticityX(val) => blocking::write_el_float64(w, EbmlId::PrimaryRChromaticityX as u64, &*val.v)?,
-                StructingMetadataFields::PrimaryRChromaticityY(val) => blocking::write_el_float64(w, EbmlId::PrimaryRChromaticityY as u64, &*val.v)?,
-                StructingMetadataFields::PrimaryGChromaticityX(val) => blocking::write_el_float64(w, EbmlId::PrimaryGChromaticityX as u64, &*val.v)?,
-                StructingMetadataFields::PrimaryGChromaticityY(val) => blocking::write_el_float64(w, EbmlId::PrimaryGChromaticityY as u64, &*val.v)?,
-                StructingMetadataFields::PrimaryBChromaticityX(val) => blocking::write_el_float64(w, EbmlId::PrimaryBChromaticityX as u64, &*val.v)?,
-                StructingMetadataFields::PrimaryBChromaticityY(val) => blocking::write_el_float64(w, EbmlId::PrimaryBChromaticityY as u64, &*val.v)?,
-                StructingMetadataFields::WhitePointChromaticityX(val) => blocking::write_el_float64(w, EbmlId::WhitePointChromaticityX as u64, &*val.v)?,
-                StructingMetadataFields::WhitePointChromaticityY(val) => blocking::write_el_float64(w, EbmlId::WhitePointChromaticityY as u64, &*val.v)?,
-                StructingMetadataFields::LuminanceMax(val) => blocking::write_el_float64(w, EbmlId::LuminanceMax as u64, &*val.v)?,
-                StructingMetadataFields::LuminanceMin(val) => blocking::write_el_float64(w, EbmlId::LuminanceMin as u64, &*val.v)?,
+                StructingMetadataFields::Crc32(_) if self.computes_crc32(options) => 0,
+                StructingMetadataFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryRChromaticityX(val) => blocking::write_el_encoded(w, EbmlId::PrimaryRChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryRChromaticityY(val) => blocking::write_el_encoded(w, EbmlId::PrimaryRChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryGChromaticityX(val) => blocking::write_el_encoded(w, EbmlId::PrimaryGChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryGChromaticityY(val) => blocking::write_el_encoded(w, EbmlId::PrimaryGChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryBChromaticityX(val) => blocking::write_el_encoded(w, EbmlId::PrimaryBChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::PrimaryBChromaticityY(val) => blocking::write_el_encoded(w, EbmlId::PrimaryBChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::WhitePointChromaticityX(val) => blocking::write_el_encoded(w, EbmlId::WhitePointChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::WhitePointChromaticityY(val) => blocking::write_el_encoded(w, EbmlId::WhitePointChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::LuminanceMax(val) => blocking::write_el_encoded(w, EbmlId::LuminanceMax as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::LuminanceMin(val) => blocking::write_el_encoded(w, EbmlId::LuminanceMin as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                StructingMetadataFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl StructingMetadata {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::StructingMetadata as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::StructingMetadata as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                StructingMetadataFields::PrimaryRChromaticityX(val) => async_::write_el_float64(w, EbmlId::PrimaryRChromaticityX as u64, &*val.v).await?,
-                StructingMetadataFields::PrimaryRChromaticityY(val) => async_::write_el_float64(w, EbmlId::PrimaryRChromaticityY as u64, &*val.v).await?,
-                StructingMetadataFields::PrimaryGChromaticityX(val) => async_::write_el_float64(w, EbmlId::PrimaryGChromaticityX as u64, &*val.v).await?,
-                StructingMetadataFields::PrimaryGChromaticityY(val) => async_::write_el_float64(w, EbmlId::PrimaryGChromaticityY as u64, &*val.v).await?,
-                StructingMetadataFields::PrimaryBChromaticityX(val) => async_::write_el_float64(w, EbmlId::PrimaryBChromaticityX as u64, &*val.v).await?,
-                StructingMetadataFields::PrimaryBChromaticityY(val) => async_::write_el_float64(w, EbmlId::PrimaryBChromaticityY as u64, &*val.v).await?,
-                StructingMetadataFields::WhitePointChromaticityX(val) => async_::write_el_float64(w, EbmlId::WhitePointChromaticityX as u64, &*val.v).await?,
-                StructingMetadataFields::WhitePointChromaticityY(val) => async_::write_el_float64(w, EbmlId::WhitePointChromaticityY as u64, &*val.v).await?,
-                StructingMetadataFields::LuminanceMax(val) => async_::write_el_float64(w, EbmlId::LuminanceMax as u64, &*val.v).await?,
-                StructingMetadataFields::LuminanceMin(val) => async_::write_el_float64(w, EbmlId::LuminanceMin as u64, &*val.v).await?,
+                StructingMetadataFields::Crc32(_) if self.computes_crc32(options) => 0,
+                StructingMetadataFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryRChromaticityX(val) => async_::write_el_encoded(w, EbmlId::PrimaryRChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryRChromaticityY(val) => async_::write_el_encoded(w, EbmlId::PrimaryRChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryGChromaticityX(val) => async_::write_el_encoded(w, EbmlId::PrimaryGChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryGChromaticityY(val) => async_::write_el_encoded(w, EbmlId::PrimaryGChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryBChromaticityX(val) => async_::write_el_encoded(w, EbmlId::PrimaryBChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::PrimaryBChromaticityY(val) => async_::write_el_encoded(w, EbmlId::PrimaryBChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::WhitePointChromaticityX(val) => async_::write_el_encoded(w, EbmlId::WhitePointChromaticityX as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::WhitePointChromaticityY(val) => async_::write_el_encoded(w, EbmlId::WhitePointChromaticityY as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::LuminanceMax(val) => async_::write_el_encoded(w, EbmlId::LuminanceMax as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::LuminanceMin(val) => async_::write_el_encoded(w, EbmlId::LuminanceMin as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                StructingMetadataFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1189,48 +2781,121 @@ impl StructingMetadata {
 }
 
 impl Projection {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Projection as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.projection_type.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.projection_type.defaulted)) { size += size_el_encoded(EbmlId::ProjectionType as u64, gen_uint_encoded(&self.projection_type.v.value(), self.projection_type.encoding.as_ref()).len() as u64, self.projection_type.encoding.as_ref()); }
+        if let Some(val) = &self.projection_private { size += size_el_encoded(EbmlId::ProjectionPrivate as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.projection_pose_yaw.v != hexf::hexf64!("0x0p+0") || (!options.omit_defaults && (self.encoding.is_none() || !self.projection_pose_yaw.defaulted)) { size += size_el_encoded(EbmlId::ProjectionPoseYaw as u64, gen_float_encoded(&self.projection_pose_yaw.v, self.projection_pose_yaw.encoding.as_ref()).len() as u64, self.projection_pose_yaw.encoding.as_ref()); }
+        if *self.projection_pose_pitch.v != hexf::hexf64!("0x0p+0") || (!options.omit_defaults && (self.encoding.is_none() || !self.projection_pose_pitch.defaulted)) { size += size_el_encoded(EbmlId::ProjectionPosePitch as u64, gen_float_encoded(&self.projection_pose_pitch.v, self.projection_pose_pitch.encoding.as_ref()).len() as u64, self.projection_pose_pitch.encoding.as_ref()); }
+        if *self.projection_pose_roll.v != hexf::hexf64!("0x0p+0") || (!options.omit_defaults && (self.encoding.is_none() || !self.projection_pose_roll.defaulted)) { size += size_el_encoded(EbmlId::ProjectionPoseRoll as u64, gen_float_encoded(&self.projection_pose_roll.v, self.projection_pose_roll.encoding.as_ref()).len() as u64, self.projection_pose_roll.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Projection as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl Projection {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Projection as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ProjectionFields::ProjectionType(val) => blocking::write_el_uint(w, EbmlId::ProjectionType as u64, &*val.v)?,
-                ProjectionFields::ProjectionPrivate(val) => blocking::write_el_bin(w, EbmlId::ProjectionPrivate as u64, &val.v)?,
-                ProjectionFields::ProjectionPoseYaw(val) => blocking::write_el_float64(w, EbmlId::ProjectionPoseYaw as u64, &*val.v)?,
-                ProjectionFields::ProjectionPosePitch(val) => blocking::write_el_float64(w, EbmlId::ProjectionPosePitch as u64, &*val.v)?,
-                ProjectionFields::ProjectionPoseRoll(val) => blocking::write_el_float64(w, EbmlId::ProjectionPoseRoll as u64, &*val.v)?,
+                ProjectionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ProjectionFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ProjectionFields::ProjectionType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ProjectionFields::ProjectionType(val) => blocking::write_el_encoded(w, EbmlId::ProjectionType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ProjectionFields::ProjectionPrivate(val) => blocking::write_el_encoded(w, EbmlId::ProjectionPrivate as u64, &val.v, val.encoding.as_ref())?,
+                ProjectionFields::ProjectionPoseYaw(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPoseYaw(val) => blocking::write_el_encoded(w, EbmlId::ProjectionPoseYaw as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ProjectionFields::ProjectionPosePitch(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPosePitch(val) => blocking::write_el_encoded(w, EbmlId::ProjectionPosePitch as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ProjectionFields::ProjectionPoseRoll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPoseRoll(val) => blocking::write_el_encoded(w, EbmlId::ProjectionPoseRoll as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ProjectionFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Projection {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Projection as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Projection as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ProjectionFields::ProjectionType(val) => async_::write_el_uint(w, EbmlId::ProjectionType as u64, &*val.v).await?,
-                ProjectionFields::ProjectionPrivate(val) => async_::write_el_bin(w, EbmlId::ProjectionPrivate as u64, &val.v).await?,
-                ProjectionFields::ProjectionPoseYaw(val) => async_::write_el_float64(w, EbmlId::ProjectionPoseYaw as u64, &*val.v).await?,
-                ProjectionFields::ProjectionPosePitch(val) => async_::write_el_float64(w, EbmlId::ProjectionPosePitch as u64, &*val.v).await?,
-                ProjectionFields::ProjectionPoseRoll(val) => async_::write_el_float64(w, EbmlId::ProjectionPoseRoll as u64, &*val.v).await?,
+                ProjectionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ProjectionFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ProjectionFields::ProjectionType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ProjectionFields::ProjectionType(val) => async_::write_el_encoded(w, EbmlId::ProjectionType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ProjectionFields::ProjectionPrivate(val) => async_::write_el_encoded(w, EbmlId::ProjectionPrivate as u64, &val.v, val.encoding.as_ref()).await?,
+                ProjectionFields::ProjectionPoseYaw(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPoseYaw(val) => async_::write_el_encoded(w, EbmlId::ProjectionPoseYaw as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ProjectionFields::ProjectionPosePitch(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPosePitch(val) => async_::write_el_encoded(w, EbmlId::ProjectionPosePitch as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ProjectionFields::ProjectionPoseRoll(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x0p+0") => 0,
+                ProjectionFields::ProjectionPoseRoll(val) => async_::write_el_encoded(w, EbmlId::ProjectionPoseRoll as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ProjectionFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1238,50 +2903,122 @@ impl Projection {
 }
 
 impl Audio {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Audio as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.sampling_frequency.v != hexf::hexf64!("0x1.f4p+12") || (!options.omit_defaults && (self.encoding.is_none() || !self.sampling_frequency.defaulted)) { size += size_el_encoded(EbmlId::SamplingFrequency as u64, gen_float_encoded(&self.sampling_frequency.v, self.sampling_frequency.encoding.as_ref()).len() as u64, self.sampling_frequency.encoding.as_ref()); }
+        if let Some(val) = &self.output_sampling_frequency { size += size_el_encoded(EbmlId::OutputSamplingFrequency as u64, gen_float_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.channels.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.channels.defaulted)) { size += size_el_encoded(EbmlId::Channels as u64, gen_uint_encoded(&self.channels.v, self.channels.encoding.as_ref()).len() as u64, self.channels.encoding.as_ref()); }
+        if let Some(val) = &self.channel_positions { size += size_el_encoded(EbmlId::ChannelPositions as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.bit_depth { size += size_el_encoded(EbmlId::BitDepth as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if self.emphasis.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.emphasis.defaulted)) { size += size_el_encoded(EbmlId::Emphasis as u64, gen_uint_encoded(&self.emphasis.v.value(), self.emphasis.encoding.as_ref()).len() as u64, self.emphasis.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Audio as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+}
+impl Audio {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
+    }
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Audio as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AudioFields::SamplingFrequency(val) => blocking::write_el_float64(w, EbmlId::SamplingFrequency as u64, &*val.v)?,
-                AudioFields::OutputSamplingFrequency(val) => blocking::write_el_float64(w, EbmlId::OutputSamplingFrequency as u64, &*val.v)?,
-                AudioFields::Channels(val) => blocking::write_el_uint(w, EbmlId::Channels as u64, &*val.v)?,
-                AudioFields::ChannelPositions(val) => blocking::write_el_bin(w, EbmlId::ChannelPositions as u64, &val.v)?,
-                AudioFields::BitDepth(val) => blocking::write_el_uint(w, EbmlId::BitDepth as u64, &*val.v)?,
-                AudioFields::Emphasis(val) => blocking::write_el_uint(w, EbmlId::Emphasis as u64, &*val.v)?,
+                AudioFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AudioFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                AudioFields::SamplingFrequency(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x1.f4p+12") => 0,
+                AudioFields::SamplingFrequency(val) => blocking::write_el_encoded(w, EbmlId::SamplingFrequency as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AudioFields::OutputSamplingFrequency(val) => blocking::write_el_encoded(w, EbmlId::OutputSamplingFrequency as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AudioFields::Channels(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                AudioFields::Channels(val) => blocking::write_el_encoded(w, EbmlId::Channels as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AudioFields::ChannelPositions(val) => blocking::write_el_encoded(w, EbmlId::ChannelPositions as u64, &val.v, val.encoding.as_ref())?,
+                AudioFields::BitDepth(val) => blocking::write_el_encoded(w, EbmlId::BitDepth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AudioFields::Emphasis(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                AudioFields::Emphasis(val) => blocking::write_el_encoded(w, EbmlId::Emphasis as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                AudioFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Audio {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Audio as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Audio as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AudioFields::SamplingFrequency(val) => async_::write_el_float64(w, EbmlId::SamplingFrequency as u64, &*val.v).await?,
-                AudioFields::OutputSamplingFrequency(val) => async_::write_el_float64(w, EbmlId::OutputSamplingFrequency as u64, &*val.v).await?,
-                AudioFields::Channels(val) => async_::write_el_uint(w, EbmlId::Channels as u64, &*val.v).await?,
-                AudioFields::ChannelPositions(val) => async_::write_el_bin(w, EbmlId::ChannelPositions as u64, &val.v).await?,
-                AudioFields::BitDepth(val) => async_::write_el_uint(w, EbmlId::BitDepth as u64, &*val.v).await?,
-                AudioFields::Emphasis(val) => async_::write_el_uint(w, EbmlId::Emphasis as u64, &*val.v).await?,
+                AudioFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AudioFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                AudioFields::SamplingFrequency(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == hexf::hexf64!("0x1.f4p+12") => 0,
+                AudioFields::SamplingFrequency(val) => async_::write_el_encoded(w, EbmlId::SamplingFrequency as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AudioFields::OutputSamplingFrequency(val) => async_::write_el_encoded(w, EbmlId::OutputSamplingFrequency as u64, &gen_float_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AudioFields::Channels(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                AudioFields::Channels(val) => async_::write_el_encoded(w, EbmlId::Channels as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AudioFields::ChannelPositions(val) => async_::write_el_encoded(w, EbmlId::ChannelPositions as u64, &val.v, val.encoding.as_ref()).await?,
+                AudioFields::BitDepth(val) => async_::write_el_encoded(w, EbmlId::BitDepth as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AudioFields::Emphasis(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                AudioFields::Emphasis(val) => async_::write_el_encoded(w, EbmlId::Emphasis as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AudioFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1289,42 +3026,104 @@ impl Audio {
 }
 
 impl TrackOperation {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackOperation as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.track_combine_planes { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.track_join_blocks { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TrackOperation {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackOperation as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackOperation as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackOperationFields::TrackCombinePlanes(val) => val.v.write_blocking(w)?,
-                TrackOperationFields::TrackJoinBlocks(val) => val.v.write_blocking(w)?,
+                TrackOperationFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackOperationFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackOperationFields::TrackCombinePlanes(val) => val.v.write_with_blocking(w, options)?,
+                TrackOperationFields::TrackJoinBlocks(val) => val.v.write_with_blocking(w, options)?,
+                TrackOperationFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackOperation {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackOperation as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackOperation as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackOperationFields::TrackCombinePlanes(val) => val.v.write(w).await?,
-                TrackOperationFields::TrackJoinBlocks(val) => val.v.write(w).await?,
+                TrackOperationFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackOperationFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackOperationFields::TrackCombinePlanes(val) => val.v.write_with(w, options).await?,
+                TrackOperationFields::TrackJoinBlocks(val) => val.v.write_with(w, options).await?,
+                TrackOperationFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1332,40 +3131,101 @@ impl TrackOperation {
 }
 
 impl TrackCombinePlanes {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackCombinePlanes as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.track_plane { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TrackCombinePlanes {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackCombinePlanes as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackCombinePlanes as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackCombinePlanesFields::TrackPlane(val) => val.v.write_blocking(w)?,
+                TrackCombinePlanesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackCombinePlanesFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackCombinePlanesFields::TrackPlane(val) => val.v.write_with_blocking(w, options)?,
+                TrackCombinePlanesFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackCombinePlanes {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackCombinePlanes as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackCombinePlanes as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackCombinePlanesFields::TrackPlane(val) => val.v.write(w).await?,
+                TrackCombinePlanesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackCombinePlanesFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackCombinePlanesFields::TrackPlane(val) => val.v.write_with(w, options).await?,
+                TrackCombinePlanesFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1373,42 +3233,104 @@ impl TrackCombinePlanes {
 }
 
 impl TrackPlane {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackPlane as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::TrackPlaneUid as u64, gen_uint_encoded(&self.track_plane_uid.v, self.track_plane_uid.encoding.as_ref()).len() as u64, self.track_plane_uid.encoding.as_ref());
+        size += size_el_encoded(EbmlId::TrackPlaneType as u64, gen_uint_encoded(&self.track_plane_type.v.value(), self.track_plane_type.encoding.as_ref()).len() as u64, self.track_plane_type.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TrackPlane {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackPlane as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackPlane as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackPlaneFields::TrackPlaneUid(val) => blocking::write_el_uint(w, EbmlId::TrackPlaneUid as u64, &*val.v)?,
-                TrackPlaneFields::TrackPlaneType(val) => blocking::write_el_uint(w, EbmlId::TrackPlaneType as u64, &*val.v)?,
+                TrackPlaneFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackPlaneFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackPlaneFields::TrackPlaneUid(val) => blocking::write_el_encoded(w, EbmlId::TrackPlaneUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackPlaneFields::TrackPlaneType(val) => blocking::write_el_encoded(w, EbmlId::TrackPlaneType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackPlaneFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackPlane {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackPlane as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackPlane as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackPlaneFields::TrackPlaneUid(val) => async_::write_el_uint(w, EbmlId::TrackPlaneUid as u64, &*val.v).await?,
-                TrackPlaneFields::TrackPlaneType(val) => async_::write_el_uint(w, EbmlId::TrackPlaneType as u64, &*val.v).await?,
+                TrackPlaneFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackPlaneFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackPlaneFields::TrackPlaneUid(val) => async_::write_el_encoded(w, EbmlId::TrackPlaneUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackPlaneFields::TrackPlaneType(val) => async_::write_el_encoded(w, EbmlId::TrackPlaneType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackPlaneFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1416,40 +3338,101 @@ impl TrackPlane {
 }
 
 impl TrackJoinBlocks {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::TrackJoinBlocks as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.track_join_uid { size += size_el_encoded(EbmlId::TrackJoinUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl TrackJoinBlocks {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::TrackJoinBlocks as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::TrackJoinBlocks as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackJoinBlocksFields::TrackJoinUid(val) => blocking::write_el_uint(w, EbmlId::TrackJoinUid as u64, &*val.v)?,
+                TrackJoinBlocksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackJoinBlocksFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TrackJoinBlocksFields::TrackJoinUid(val) => blocking::write_el_encoded(w, EbmlId::TrackJoinUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TrackJoinBlocksFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl TrackJoinBlocks {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::TrackJoinBlocks as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::TrackJoinBlocks as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TrackJoinBlocksFields::TrackJoinUid(val) => async_::write_el_uint(w, EbmlId::TrackJoinUid as u64, &*val.v).await?,
+                TrackJoinBlocksFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TrackJoinBlocksFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TrackJoinBlocksFields::TrackJoinUid(val) => async_::write_el_encoded(w, EbmlId::TrackJoinUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TrackJoinBlocksFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1457,40 +3440,101 @@ impl TrackJoinBlocks {
 }
 
 impl ContentEncodings {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ContentEncodings as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.content_encoding { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ContentEncodings {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ContentEncodings as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ContentEncodings as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncodingsFields::ContentEncoding(val) => val.v.write_blocking(w)?,
+                ContentEncodingsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncodingsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncodingsFields::ContentEncoding(val) => val.v.write_with_blocking(w, options)?,
+                ContentEncodingsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ContentEncodings {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ContentEncodings as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ContentEncodings as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncodingsFields::ContentEncoding(val) => val.v.write(w).await?,
+                ContentEncodingsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncodingsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncodingsFields::ContentEncoding(val) => val.v.write_with(w, options).await?,
+                ContentEncodingsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1498,48 +3542,119 @@ impl ContentEncodings {
 }
 
 impl ContentEncoding {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ContentEncoding as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.content_encoding_order.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.content_encoding_order.defaulted)) { size += size_el_encoded(EbmlId::ContentEncodingOrder as u64, gen_uint_encoded(&self.content_encoding_order.v, self.content_encoding_order.encoding.as_ref()).len() as u64, self.content_encoding_order.encoding.as_ref()); }
+        if self.content_encoding_scope.v.value() != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.content_encoding_scope.defaulted)) { size += size_el_encoded(EbmlId::ContentEncodingScope as u64, gen_uint_encoded(&self.content_encoding_scope.v.value(), self.content_encoding_scope.encoding.as_ref()).len() as u64, self.content_encoding_scope.encoding.as_ref()); }
+        if self.content_encoding_type.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.content_encoding_type.defaulted)) { size += size_el_encoded(EbmlId::ContentEncodingType as u64, gen_uint_encoded(&self.content_encoding_type.v.value(), self.content_encoding_type.encoding.as_ref()).len() as u64, self.content_encoding_type.encoding.as_ref()); }
+        if let Some(val) = &self.content_compression { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.content_encryption { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ContentEncoding {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ContentEncoding as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ContentEncoding as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncodingFields::ContentEncodingOrder(val) => blocking::write_el_uint(w, EbmlId::ContentEncodingOrder as u64, &*val.v)?,
-                ContentEncodingFields::ContentEncodingScope(val) => blocking::write_el_uint(w, EbmlId::ContentEncodingScope as u64, &*val.v)?,
-                ContentEncodingFields::ContentEncodingType(val) => blocking::write_el_uint(w, EbmlId::ContentEncodingType as u64, &*val.v)?,
-                ContentEncodingFields::ContentCompression(val) => val.v.write_blocking(w)?,
-                ContentEncodingFields::ContentEncryption(val) => val.v.write_blocking(w)?,
+                ContentEncodingFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncodingFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncodingFields::ContentEncodingOrder(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ContentEncodingFields::ContentEncodingOrder(val) => blocking::write_el_encoded(w, EbmlId::ContentEncodingOrder as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncodingFields::ContentEncodingScope(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 1 => 0,
+                ContentEncodingFields::ContentEncodingScope(val) => blocking::write_el_encoded(w, EbmlId::ContentEncodingScope as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncodingFields::ContentEncodingType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentEncodingFields::ContentEncodingType(val) => blocking::write_el_encoded(w, EbmlId::ContentEncodingType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncodingFields::ContentCompression(val) => val.v.write_with_blocking(w, options)?,
+                ContentEncodingFields::ContentEncryption(val) => val.v.write_with_blocking(w, options)?,
+                ContentEncodingFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ContentEncoding {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ContentEncoding as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ContentEncoding as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncodingFields::ContentEncodingOrder(val) => async_::write_el_uint(w, EbmlId::ContentEncodingOrder as u64, &*val.v).await?,
-                ContentEncodingFields::ContentEncodingScope(val) => async_::write_el_uint(w, EbmlId::ContentEncodingScope as u64, &*val.v).await?,
-                ContentEncodingFields::ContentEncodingType(val) => async_::write_el_uint(w, EbmlId::ContentEncodingType as u64, &*val.v).await?,
-                ContentEncodingFields::ContentCompression(val) => val.v.write(w).await?,
-                ContentEncodingFields::ContentEncryption(val) => val.v.write(w).await?,
+                ContentEncodingFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncodingFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncodingFields::ContentEncodingOrder(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ContentEncodingFields::ContentEncodingOrder(val) => async_::write_el_encoded(w, EbmlId::ContentEncodingOrder as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncodingFields::ContentEncodingScope(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 1 => 0,
+                ContentEncodingFields::ContentEncodingScope(val) => async_::write_el_encoded(w, EbmlId::ContentEncodingScope as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncodingFields::ContentEncodingType(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentEncodingFields::ContentEncodingType(val) => async_::write_el_encoded(w, EbmlId::ContentEncodingType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncodingFields::ContentCompression(val) => val.v.write_with(w, options).await?,
+                ContentEncodingFields::ContentEncryption(val) => val.v.write_with(w, options).await?,
+                ContentEncodingFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1547,42 +3662,106 @@ impl ContentEncoding {
 }
 
 impl ContentCompression {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ContentCompression as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.content_comp_algo.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.content_comp_algo.defaulted)) { size += size_el_encoded(EbmlId::ContentCompAlgo as u64, gen_uint_encoded(&self.content_comp_algo.v.value(), self.content_comp_algo.encoding.as_ref()).len() as u64, self.content_comp_algo.encoding.as_ref()); }
+        if let Some(val) = &self.content_comp_settings { size += size_el_encoded(EbmlId::ContentCompSettings as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ContentCompression {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ContentCompression as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ContentCompression as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentCompressionFields::ContentCompAlgo(val) => blocking::write_el_uint(w, EbmlId::ContentCompAlgo as u64, &*val.v)?,
-                ContentCompressionFields::ContentCompSettings(val) => blocking::write_el_bin(w, EbmlId::ContentCompSettings as u64, &val.v)?,
+                ContentCompressionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentCompressionFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ContentCompressionFields::ContentCompAlgo(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentCompressionFields::ContentCompAlgo(val) => blocking::write_el_encoded(w, EbmlId::ContentCompAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentCompressionFields::ContentCompSettings(val) => blocking::write_el_encoded(w, EbmlId::ContentCompSettings as u64, &val.v, val.encoding.as_ref())?,
+                ContentCompressionFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ContentCompression {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ContentCompression as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ContentCompression as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentCompressionFields::ContentCompAlgo(val) => async_::write_el_uint(w, EbmlId::ContentCompAlgo as u64, &*val.v).await?,
-                ContentCompressionFields::ContentCompSettings(val) => async_::write_el_bin(w, EbmlId::ContentCompSettings as u64, &val.v).await?,
+                ContentCompressionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentCompressionFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentCompressionFields::ContentCompAlgo(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentCompressionFields::ContentCompAlgo(val) => async_::write_el_encoded(w, EbmlId::ContentCompAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentCompressionFields::ContentCompSettings(val) => async_::write_el_encoded(w, EbmlId::ContentCompSettings as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentCompressionFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1590,52 +3769,121 @@ impl ContentCompression {
 }
 
 impl ContentEncryption {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ContentEncryption as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.content_enc_algo.v.value() != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.content_enc_algo.defaulted)) { size += size_el_encoded(EbmlId::ContentEncAlgo as u64, gen_uint_encoded(&self.content_enc_algo.v.value(), self.content_enc_algo.encoding.as_ref()).len() as u64, self.content_enc_algo.encoding.as_ref()); }
+        if let Some(val) = &self.content_enc_key_id { size += size_el_encoded(EbmlId::ContentEncKeyId as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.content_enc_aes_settings { size += val.v.encoded_size_with(options)?; }
+        if let Some(val) = &self.content_signature { size += size_el_encoded(EbmlId::ContentSignature as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.content_sig_key_id { size += size_el_encoded(EbmlId::ContentSigKeyId as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.content_sig_algo { size += size_el_encoded(EbmlId::ContentSigAlgo as u64, gen_uint_encoded(&val.v.value(), val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.content_sig_hash_algo { size += size_el_encoded(EbmlId::ContentSigHashAlgo as u64, gen_uint_encoded(&val.v.value(), val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ContentEncryption as u64, size)?)
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ContentEncryption {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
+        Ok(size)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ContentEncryption as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncryptionFields::ContentEncAlgo(val) => blocking::write_el_uint(w, EbmlId::ContentEncAlgo as u64, &*val.v)?,
-                ContentEncryptionFields::ContentEncKeyId(val) => blocking::write_el_bin(w, EbmlId::ContentEncKeyId as u64, &val.v)?,
-                ContentEncryptionFields::ContentEncAesSettings(val) => val.v.write_blocking(w)?,
-                ContentEncryptionFields::ContentSignature(val) => blocking::write_el_bin(w, EbmlId::ContentSignature as u64, &val.v)?,
-                ContentEncryptionFields::ContentSigKeyId(val) => blocking::write_el_bin(w, EbmlId::ContentSigKeyId as u64, &val.v)?,
-                ContentEncryptionFields::ContentSigAlgo(val) => blocking::write_el_uint(w, EbmlId::ContentSigAlgo as u64, &*val.v)?,
-                ContentEncryptionFields::ContentSigHashAlgo(val) => blocking::write_el_uint(w, EbmlId::ContentSigHashAlgo as u64, &*val.v)?,
+                ContentEncryptionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncryptionFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentEncAlgo(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentEncryptionFields::ContentEncAlgo(val) => blocking::write_el_encoded(w, EbmlId::ContentEncAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentEncKeyId(val) => blocking::write_el_encoded(w, EbmlId::ContentEncKeyId as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentEncAesSettings(val) => val.v.write_with_blocking(w, options)?,
+                ContentEncryptionFields::ContentSignature(val) => blocking::write_el_encoded(w, EbmlId::ContentSignature as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentSigKeyId(val) => blocking::write_el_encoded(w, EbmlId::ContentSigKeyId as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentSigAlgo(val) => blocking::write_el_encoded(w, EbmlId::ContentSigAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncryptionFields::ContentSigHashAlgo(val) => blocking::write_el_encoded(w, EbmlId::ContentSigHashAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncryptionFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ContentEncryption {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ContentEncryption as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ContentEncryption as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncryptionFields::ContentEncAlgo(val) => async_::write_el_uint(w, EbmlId::ContentEncAlgo as u64, &*val.v).await?,
-                ContentEncryptionFields::ContentEncKeyId(val) => async_::write_el_bin(w, EbmlId::ContentEncKeyId as u64, &val.v).await?,
-                ContentEncryptionFields::ContentEncAesSettings(val) => val.v.write(w).await?,
-                ContentEncryptionFields::ContentSignature(val) => async_::write_el_bin(w, EbmlId::ContentSignature as u64, &val.v).await?,
-                ContentEncryptionFields::ContentSigKeyId(val) => async_::write_el_bin(w, EbmlId::ContentSigKeyId as u64, &val.v).await?,
-                ContentEncryptionFields::ContentSigAlgo(val) => async_::write_el_uint(w, EbmlId::ContentSigAlgo as u64, &*val.v).await?,
-                ContentEncryptionFields::ContentSigHashAlgo(val) => async_::write_el_uint(w, EbmlId::ContentSigHashAlgo as u64, &*val.v).await?,
+                ContentEncryptionFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncryptionFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentEncAlgo(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 0 => 0,
+                ContentEncryptionFields::ContentEncAlgo(val) => async_::write_el_encoded(w, EbmlId::ContentEncAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentEncKeyId(val) => async_::write_el_encoded(w, EbmlId::ContentEncKeyId as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentEncAesSettings(val) => val.v.write_with(w, options).await?,
+                ContentEncryptionFields::ContentSignature(val) => async_::write_el_encoded(w, EbmlId::ContentSignature as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentSigKeyId(val) => async_::write_el_encoded(w, EbmlId::ContentSigKeyId as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentSigAlgo(val) => async_::write_el_encoded(w, EbmlId::ContentSigAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncryptionFields::ContentSigHashAlgo(val) => async_::write_el_encoded(w, EbmlId::ContentSigHashAlgo as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncryptionFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1643,40 +3891,101 @@ impl ContentEncryption {
 }
 
 impl ContentEncAesSettings {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ContentEncAesSettings as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::AesSettingsCipherMode as u64, gen_uint_encoded(&self.aes_settings_cipher_mode.v.value(), self.aes_settings_cipher_mode.encoding.as_ref()).len() as u64, self.aes_settings_cipher_mode.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ContentEncAesSettings {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ContentEncAesSettings as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ContentEncAesSettings as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncAesSettingsFields::AesSettingsCipherMode(val) => blocking::write_el_uint(w, EbmlId::AesSettingsCipherMode as u64, &*val.v)?,
+                ContentEncAesSettingsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncAesSettingsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ContentEncAesSettingsFields::AesSettingsCipherMode(val) => blocking::write_el_encoded(w, EbmlId::AesSettingsCipherMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ContentEncAesSettingsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ContentEncAesSettings {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ContentEncAesSettings as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ContentEncAesSettings as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ContentEncAesSettingsFields::AesSettingsCipherMode(val) => async_::write_el_uint(w, EbmlId::AesSettingsCipherMode as u64, &*val.v).await?,
+                ContentEncAesSettingsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ContentEncAesSettingsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ContentEncAesSettingsFields::AesSettingsCipherMode(val) => async_::write_el_encoded(w, EbmlId::AesSettingsCipherMode as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ContentEncAesSettingsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1684,40 +3993,101 @@ impl ContentEncAesSettings {
 }
 
 impl Cues {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Cues as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.cue_point { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Cues {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Cues as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Cues as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CuesFields::CuePoint(val) => val.v.write_blocking(w)?,
+                CuesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CuesFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                CuesFields::CuePoint(val) => val.v.write_with_blocking(w, options)?,
+                CuesFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Cues {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Cues as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Cues as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CuesFields::CuePoint(val) => val.v.write(w).await?,
+                CuesFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CuesFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                CuesFields::CuePoint(val) => val.v.write_with(w, options).await?,
+                CuesFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1725,42 +4095,104 @@ impl Cues {
 }
 
 impl CuePoint {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::CuePoint as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::CueTime as u64, gen_uint_encoded(&self.cue_time.v, self.cue_time.encoding.as_ref()).len() as u64, self.cue_time.encoding.as_ref());
+        for val in &self.cue_track_positions { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl CuePoint {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::CuePoint as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::CuePoint as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CuePointFields::CueTime(val) => blocking::write_el_uint(w, EbmlId::CueTime as u64, &*val.v)?,
-                CuePointFields::CueTrackPositions(val) => val.v.write_blocking(w)?,
+                CuePointFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CuePointFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                CuePointFields::CueTime(val) => blocking::write_el_encoded(w, EbmlId::CueTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CuePointFields::CueTrackPositions(val) => val.v.write_with_blocking(w, options)?,
+                CuePointFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl CuePoint {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::CuePoint as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::CuePoint as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CuePointFields::CueTime(val) => async_::write_el_uint(w, EbmlId::CueTime as u64, &*val.v).await?,
-                CuePointFields::CueTrackPositions(val) => val.v.write(w).await?,
+                CuePointFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CuePointFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                CuePointFields::CueTime(val) => async_::write_el_encoded(w, EbmlId::CueTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CuePointFields::CueTrackPositions(val) => val.v.write_with(w, options).await?,
+                CuePointFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1768,52 +4200,121 @@ impl CuePoint {
 }
 
 impl CueTrackPositions {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::CueTrackPositions as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::CueTrack as u64, gen_uint_encoded(&self.cue_track.v, self.cue_track.encoding.as_ref()).len() as u64, self.cue_track.encoding.as_ref());
+        size += size_el_encoded(EbmlId::CueClusterPosition as u64, gen_uint_encoded(&self.cue_cluster_position.v, self.cue_cluster_position.encoding.as_ref()).len() as u64, self.cue_cluster_position.encoding.as_ref());
+        if let Some(val) = &self.cue_relative_position { size += size_el_encoded(EbmlId::CueRelativePosition as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.cue_duration { size += size_el_encoded(EbmlId::CueDuration as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.cue_block_number { size += size_el_encoded(EbmlId::CueBlockNumber as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.cue_codec_state.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.cue_codec_state.defaulted)) { size += size_el_encoded(EbmlId::CueCodecState as u64, gen_uint_encoded(&self.cue_codec_state.v, self.cue_codec_state.encoding.as_ref()).len() as u64, self.cue_codec_state.encoding.as_ref()); }
+        for val in &self.cue_reference { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl CueTrackPositions {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::CueTrackPositions as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::CueTrackPositions as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CueTrackPositionsFields::CueTrack(val) => blocking::write_el_uint(w, EbmlId::CueTrack as u64, &*val.v)?,
-                CueTrackPositionsFields::CueClusterPosition(val) => blocking::write_el_uint(w, EbmlId::CueClusterPosition as u64, &*val.v)?,
-                CueTrackPositionsFields::CueRelativePosition(val) => blocking::write_el_uint(w, EbmlId::CueRelativePosition as u64, &*val.v)?,
-                CueTrackPositionsFields::CueDuration(val) => blocking::write_el_uint(w, EbmlId::CueDuration as u64, &*val.v)?,
-                CueTrackPositionsFields::CueBlockNumber(val) => blocking::write_el_uint(w, EbmlId::CueBlockNumber as u64, &*val.v)?,
-                CueTrackPositionsFields::CueCodecState(val) => blocking::write_el_uint(w, EbmlId::CueCodecState as u64, &*val.v)?,
-                CueTrackPositionsFields::CueReference(val) => val.v.write_blocking(w)?,
+                CueTrackPositionsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CueTrackPositionsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueTrack(val) => blocking::write_el_encoded(w, EbmlId::CueTrack as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueClusterPosition(val) => blocking::write_el_encoded(w, EbmlId::CueClusterPosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueRelativePosition(val) => blocking::write_el_encoded(w, EbmlId::CueRelativePosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueDuration(val) => blocking::write_el_encoded(w, EbmlId::CueDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueBlockNumber(val) => blocking::write_el_encoded(w, EbmlId::CueBlockNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueCodecState(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                CueTrackPositionsFields::CueCodecState(val) => blocking::write_el_encoded(w, EbmlId::CueCodecState as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueTrackPositionsFields::CueReference(val) => val.v.write_with_blocking(w, options)?,
+                CueTrackPositionsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl CueTrackPositions {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::CueTrackPositions as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::CueTrackPositions as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CueTrackPositionsFields::CueTrack(val) => async_::write_el_uint(w, EbmlId::CueTrack as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueClusterPosition(val) => async_::write_el_uint(w, EbmlId::CueClusterPosition as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueRelativePosition(val) => async_::write_el_uint(w, EbmlId::CueRelativePosition as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueDuration(val) => async_::write_el_uint(w, EbmlId::CueDuration as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueBlockNumber(val) => async_::write_el_uint(w, EbmlId::CueBlockNumber as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueCodecState(val) => async_::write_el_uint(w, EbmlId::CueCodecState as u64, &*val.v).await?,
-                CueTrackPositionsFields::CueReference(val) => val.v.write(w).await?,
+                CueTrackPositionsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CueTrackPositionsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueTrack(val) => async_::write_el_encoded(w, EbmlId::CueTrack as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueClusterPosition(val) => async_::write_el_encoded(w, EbmlId::CueClusterPosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueRelativePosition(val) => async_::write_el_encoded(w, EbmlId::CueRelativePosition as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueDuration(val) => async_::write_el_encoded(w, EbmlId::CueDuration as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueBlockNumber(val) => async_::write_el_encoded(w, EbmlId::CueBlockNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueCodecState(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                CueTrackPositionsFields::CueCodecState(val) => async_::write_el_encoded(w, EbmlId::CueCodecState as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueTrackPositionsFields::CueReference(val) => val.v.write_with(w, options).await?,
+                CueTrackPositionsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1821,46 +4322,110 @@ impl CueTrackPositions {
 }
 
 impl CueReference {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::CueReference as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::CueRefTime as u64, gen_uint_encoded(&self.cue_ref_time.v, self.cue_ref_time.encoding.as_ref()).len() as u64, self.cue_ref_time.encoding.as_ref());
+        size += size_el_encoded(EbmlId::CueRefCluster as u64, gen_uint_encoded(&self.cue_ref_cluster.v, self.cue_ref_cluster.encoding.as_ref()).len() as u64, self.cue_ref_cluster.encoding.as_ref());
+        if let Some(val) = &self.cue_ref_number { size += size_el_encoded(EbmlId::CueRefNumber as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.cue_ref_codec_state { size += size_el_encoded(EbmlId::CueRefCodecState as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl CueReference {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::CueReference as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::CueReference as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CueReferenceFields::CueRefTime(val) => blocking::write_el_uint(w, EbmlId::CueRefTime as u64, &*val.v)?,
-                CueReferenceFields::CueRefCluster(val) => blocking::write_el_uint(w, EbmlId::CueRefCluster as u64, &*val.v)?,
-                CueReferenceFields::CueRefNumber(val) => blocking::write_el_uint(w, EbmlId::CueRefNumber as u64, &*val.v)?,
-                CueReferenceFields::CueRefCodecState(val) => blocking::write_el_uint(w, EbmlId::CueRefCodecState as u64, &*val.v)?,
+                CueReferenceFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CueReferenceFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                CueReferenceFields::CueRefTime(val) => blocking::write_el_encoded(w, EbmlId::CueRefTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueReferenceFields::CueRefCluster(val) => blocking::write_el_encoded(w, EbmlId::CueRefCluster as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueReferenceFields::CueRefNumber(val) => blocking::write_el_encoded(w, EbmlId::CueRefNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueReferenceFields::CueRefCodecState(val) => blocking::write_el_encoded(w, EbmlId::CueRefCodecState as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                CueReferenceFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl CueReference {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::CueReference as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::CueReference as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                CueReferenceFields::CueRefTime(val) => async_::write_el_uint(w, EbmlId::CueRefTime as u64, &*val.v).await?,
-                CueReferenceFields::CueRefCluster(val) => async_::write_el_uint(w, EbmlId::CueRefCluster as u64, &*val.v).await?,
-                CueReferenceFields::CueRefNumber(val) => async_::write_el_uint(w, EbmlId::CueRefNumber as u64, &*val.v).await?,
-                CueReferenceFields::CueRefCodecState(val) => async_::write_el_uint(w, EbmlId::CueRefCodecState as u64, &*val.v).await?,
+                CueReferenceFields::Crc32(_) if self.computes_crc32(options) => 0,
+                CueReferenceFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                CueReferenceFields::CueRefTime(val) => async_::write_el_encoded(w, EbmlId::CueRefTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueReferenceFields::CueRefCluster(val) => async_::write_el_encoded(w, EbmlId::CueRefCluster as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueReferenceFields::CueRefNumber(val) => async_::write_el_encoded(w, EbmlId::CueRefNumber as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueReferenceFields::CueRefCodecState(val) => async_::write_el_encoded(w, EbmlId::CueRefCodecState as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                CueReferenceFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1868,40 +4433,101 @@ impl CueReference {
 }
 
 impl Attachments {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Attachments as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.attached_file { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Attachments {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Attachments as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Attachments as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AttachmentsFields::AttachedFile(val) => val.v.write_blocking(w)?,
+                AttachmentsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AttachmentsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                AttachmentsFields::AttachedFile(val) => val.v.write_with_blocking(w, options)?,
+                AttachmentsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Attachments {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Attachments as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Attachments as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AttachmentsFields::AttachedFile(val) => val.v.write(w).await?,
+                AttachmentsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AttachmentsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                AttachmentsFields::AttachedFile(val) => val.v.write_with(w, options).await?,
+                AttachmentsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1909,54 +4535,122 @@ impl Attachments {
 }
 
 impl AttachedFile {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::AttachedFile as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.file_description { size += size_el_encoded(EbmlId::FileDescription as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::FileName as u64, self.file_name.v.len() as u64, self.file_name.encoding.as_ref());
+        size += size_el_encoded(EbmlId::FileMediaType as u64, self.file_media_type.v.len() as u64, self.file_media_type.encoding.as_ref());
+        size += size_el_encoded(EbmlId::FileData as u64, self.file_data.v.len() as u64, self.file_data.encoding.as_ref());
+        size += size_el_encoded(EbmlId::FileUid as u64, gen_uint_encoded(&self.file_uid.v, self.file_uid.encoding.as_ref()).len() as u64, self.file_uid.encoding.as_ref());
+        if let Some(val) = &self.file_referral { size += size_el_encoded(EbmlId::FileReferral as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.file_used_start_time { size += size_el_encoded(EbmlId::FileUsedStartTime as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.file_used_end_time { size += size_el_encoded(EbmlId::FileUsedEndTime as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl AttachedFile {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::AttachedFile as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::AttachedFile as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AttachedFileFields::FileDescription(val) => blocking::write_el_utf8(w, EbmlId::FileDescription as u64, &val.v)?,
-                AttachedFileFields::FileName(val) => blocking::write_el_utf8(w, EbmlId::FileName as u64, &val.v)?,
-                AttachedFileFields::FileMediaType(val) => blocking::write_el_string(w, EbmlId::FileMediaType as u64, &val.v)?,
-                AttachedFileFields::FileData(val) => blocking::write_el_bin(w, EbmlId::FileData as u64, &val.v)?,
-                AttachedFileFields::FileUid(val) => blocking::write_el_uint(w, EbmlId::FileUid as u64, &*val.v)?,
-                AttachedFileFields::FileReferral(val) => blocking::write_el_bin(w, EbmlId::FileReferral as u64, &val.v)?,
-                AttachedFileFields::FileUsedStartTime(val) => blocking::write_el_uint(w, EbmlId::FileUsedStartTime as u64, &*val.v)?,
-                AttachedFileFields::FileUsedEndTime(val) => blocking::write_el_uint(w, EbmlId::FileUsedEndTime as u64, &*val.v)?,
+                AttachedFileFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AttachedFileFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                AttachedFileFields::FileDescription(val) => blocking::write_el_encoded(w, EbmlId::FileDescription as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                AttachedFileFields::FileName(val) => blocking::write_el_encoded(w, EbmlId::FileName as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                AttachedFileFields::FileMediaType(val) => blocking::write_el_encoded(w, EbmlId::FileMediaType as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                AttachedFileFields::FileData(val) => blocking::write_el_encoded(w, EbmlId::FileData as u64, &val.v, val.encoding.as_ref())?,
+                AttachedFileFields::FileUid(val) => blocking::write_el_encoded(w, EbmlId::FileUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AttachedFileFields::FileReferral(val) => blocking::write_el_encoded(w, EbmlId::FileReferral as u64, &val.v, val.encoding.as_ref())?,
+                AttachedFileFields::FileUsedStartTime(val) => blocking::write_el_encoded(w, EbmlId::FileUsedStartTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AttachedFileFields::FileUsedEndTime(val) => blocking::write_el_encoded(w, EbmlId::FileUsedEndTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                AttachedFileFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl AttachedFile {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::AttachedFile as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::AttachedFile as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                AttachedFileFields::FileDescription(val) => async_::write_el_utf8(w, EbmlId::FileDescription as u64, &val.v).await?,
-                AttachedFileFields::FileName(val) => async_::write_el_utf8(w, EbmlId::FileName as u64, &val.v).await?,
-                AttachedFileFields::FileMediaType(val) => async_::write_el_string(w, EbmlId::FileMediaType as u64, &val.v).await?,
-                AttachedFileFields::FileData(val) => async_::write_el_bin(w, EbmlId::FileData as u64, &val.v).await?,
-                AttachedFileFields::FileUid(val) => async_::write_el_uint(w, EbmlId::FileUid as u64, &*val.v).await?,
-                AttachedFileFields::FileReferral(val) => async_::write_el_bin(w, EbmlId::FileReferral as u64, &val.v).await?,
-                AttachedFileFields::FileUsedStartTime(val) => async_::write_el_uint(w, EbmlId::FileUsedStartTime as u64, &*val.v).await?,
-                AttachedFileFields::FileUsedEndTime(val) => async_::write_el_uint(w, EbmlId::FileUsedEndTime as u64, &*val.v).await?,
+                AttachedFileFields::Crc32(_) if self.computes_crc32(options) => 0,
+                AttachedFileFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                AttachedFileFields::FileDescription(val) => async_::write_el_encoded(w, EbmlId::FileDescription as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                AttachedFileFields::FileName(val) => async_::write_el_encoded(w, EbmlId::FileName as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                AttachedFileFields::FileMediaType(val) => async_::write_el_encoded(w, EbmlId::FileMediaType as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                AttachedFileFields::FileData(val) => async_::write_el_encoded(w, EbmlId::FileData as u64, &val.v, val.encoding.as_ref()).await?,
+                AttachedFileFields::FileUid(val) => async_::write_el_encoded(w, EbmlId::FileUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AttachedFileFields::FileReferral(val) => async_::write_el_encoded(w, EbmlId::FileReferral as u64, &val.v, val.encoding.as_ref()).await?,
+                AttachedFileFields::FileUsedStartTime(val) => async_::write_el_encoded(w, EbmlId::FileUsedStartTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AttachedFileFields::FileUsedEndTime(val) => async_::write_el_encoded(w, EbmlId::FileUsedEndTime as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                AttachedFileFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -1964,40 +4658,101 @@ impl AttachedFile {
 }
 
 impl Chapters {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Chapters as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.edition_entry { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Chapters {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Chapters as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Chapters as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChaptersFields::EditionEntry(val) => val.v.write_blocking(w)?,
+                ChaptersFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChaptersFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChaptersFields::EditionEntry(val) => val.v.write_with_blocking(w, options)?,
+                ChaptersFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Chapters {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Chapters as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Chapters as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChaptersFields::EditionEntry(val) => val.v.write(w).await?,
+                ChaptersFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChaptersFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChaptersFields::EditionEntry(val) => val.v.write_with(w, options).await?,
+                ChaptersFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2005,50 +4760,122 @@ impl Chapters {
 }
 
 impl EditionEntry {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::EditionEntry as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.edition_uid { size += size_el_encoded(EbmlId::EditionUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.edition_flag_hidden.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.edition_flag_hidden.defaulted)) { size += size_el_encoded(EbmlId::EditionFlagHidden as u64, gen_uint_encoded(&self.edition_flag_hidden.v, self.edition_flag_hidden.encoding.as_ref()).len() as u64, self.edition_flag_hidden.encoding.as_ref()); }
+        if *self.edition_flag_default.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.edition_flag_default.defaulted)) { size += size_el_encoded(EbmlId::EditionFlagDefault as u64, gen_uint_encoded(&self.edition_flag_default.v, self.edition_flag_default.encoding.as_ref()).len() as u64, self.edition_flag_default.encoding.as_ref()); }
+        if *self.edition_flag_ordered.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.edition_flag_ordered.defaulted)) { size += size_el_encoded(EbmlId::EditionFlagOrdered as u64, gen_uint_encoded(&self.edition_flag_ordered.v, self.edition_flag_ordered.encoding.as_ref()).len() as u64, self.edition_flag_ordered.encoding.as_ref()); }
+        for val in &self.edition_display { size += val.v.encoded_size_with(options)?; }
+        for val in &self.chapter_atom { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl EditionEntry {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::EditionEntry as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::EditionEntry as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EditionEntryFields::EditionUid(val) => blocking::write_el_uint(w, EbmlId::EditionUid as u64, &*val.v)?,
-                EditionEntryFields::EditionFlagHidden(val) => blocking::write_el_uint(w, EbmlId::EditionFlagHidden as u64, &*val.v)?,
-                EditionEntryFields::EditionFlagDefault(val) => blocking::write_el_uint(w, EbmlId::EditionFlagDefault as u64, &*val.v)?,
-                EditionEntryFields::EditionFlagOrdered(val) => blocking::write_el_uint(w, EbmlId::EditionFlagOrdered as u64, &*val.v)?,
-                EditionEntryFields::EditionDisplay(val) => val.v.write_blocking(w)?,
-                EditionEntryFields::ChapterAtom(val) => val.v.write_blocking(w)?,
+                EditionEntryFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EditionEntryFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                EditionEntryFields::EditionUid(val) => blocking::write_el_encoded(w, EbmlId::EditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EditionEntryFields::EditionFlagHidden(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagHidden(val) => blocking::write_el_encoded(w, EbmlId::EditionFlagHidden as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EditionEntryFields::EditionFlagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagDefault(val) => blocking::write_el_encoded(w, EbmlId::EditionFlagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EditionEntryFields::EditionFlagOrdered(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagOrdered(val) => blocking::write_el_encoded(w, EbmlId::EditionFlagOrdered as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                EditionEntryFields::EditionDisplay(val) => val.v.write_with_blocking(w, options)?,
+                EditionEntryFields::ChapterAtom(val) => val.v.write_with_blocking(w, options)?,
+                EditionEntryFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl EditionEntry {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::EditionEntry as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::EditionEntry as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EditionEntryFields::EditionUid(val) => async_::write_el_uint(w, EbmlId::EditionUid as u64, &*val.v).await?,
-                EditionEntryFields::EditionFlagHidden(val) => async_::write_el_uint(w, EbmlId::EditionFlagHidden as u64, &*val.v).await?,
-                EditionEntryFields::EditionFlagDefault(val) => async_::write_el_uint(w, EbmlId::EditionFlagDefault as u64, &*val.v).await?,
-                EditionEntryFields::EditionFlagOrdered(val) => async_::write_el_uint(w, EbmlId::EditionFlagOrdered as u64, &*val.v).await?,
-                EditionEntryFields::EditionDisplay(val) => val.v.write(w).await?,
-                EditionEntryFields::ChapterAtom(val) => val.v.write(w).await?,
+                EditionEntryFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EditionEntryFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                EditionEntryFields::EditionUid(val) => async_::write_el_encoded(w, EbmlId::EditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EditionEntryFields::EditionFlagHidden(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagHidden(val) => async_::write_el_encoded(w, EbmlId::EditionFlagHidden as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EditionEntryFields::EditionFlagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagDefault(val) => async_::write_el_encoded(w, EbmlId::EditionFlagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EditionEntryFields::EditionFlagOrdered(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                EditionEntryFields::EditionFlagOrdered(val) => async_::write_el_encoded(w, EbmlId::EditionFlagOrdered as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                EditionEntryFields::EditionDisplay(val) => val.v.write_with(w, options).await?,
+                EditionEntryFields::ChapterAtom(val) => val.v.write_with(w, options).await?,
+                EditionEntryFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2056,42 +4883,104 @@ impl EditionEntry {
 }
 
 impl EditionDisplay {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::EditionDisplay as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::EditionString as u64, self.edition_string.v.len() as u64, self.edition_string.encoding.as_ref());
+        for val in &self.edition_language_ietf { size += size_el_encoded(EbmlId::EditionLanguageIetf as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl EditionDisplay {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::EditionDisplay as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::EditionDisplay as u64, size, self.encoding.as_ref())?)
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EditionDisplayFields::EditionString(val) => blocking::write_el_utf8(w, EbmlId::EditionString as u64, &val.v)?,
-                EditionDisplayFields::EditionLanguageIetf(val) => blocking::write_el_string(w, EbmlId::EditionLanguageIetf as u64, &val.v)?,
+                EditionDisplayFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EditionDisplayFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                EditionDisplayFields::EditionString(val) => blocking::write_el_encoded(w, EbmlId::EditionString as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                EditionDisplayFields::EditionLanguageIetf(val) => blocking::write_el_encoded(w, EbmlId::EditionLanguageIetf as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                EditionDisplayFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl EditionDisplay {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::EditionDisplay as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::EditionDisplay as u64, size, self.encoding.as_ref()).await?)
+    }
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                EditionDisplayFields::EditionString(val) => async_::write_el_utf8(w, EbmlId::EditionString as u64, &val.v).await?,
-                EditionDisplayFields::EditionLanguageIetf(val) => async_::write_el_string(w, EbmlId::EditionLanguageIetf as u64, &val.v).await?,
+                EditionDisplayFields::Crc32(_) if self.computes_crc32(options) => 0,
+                EditionDisplayFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                EditionDisplayFields::EditionString(val) => async_::write_el_encoded(w, EbmlId::EditionString as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                EditionDisplayFields::EditionLanguageIetf(val) => async_::write_el_encoded(w, EbmlId::EditionLanguageIetf as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                EditionDisplayFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2099,64 +4988,141 @@ impl EditionDisplay {
 }
 
 impl ChapterAtom {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapterAtom as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ChapterUid as u64, gen_uint_encoded(&self.chapter_uid.v, self.chapter_uid.encoding.as_ref()).len() as u64, self.chapter_uid.encoding.as_ref());
+        if let Some(val) = &self.chapter_string_uid { size += size_el_encoded(EbmlId::ChapterStringUid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ChapterTimeStart as u64, gen_uint_encoded(&self.chapter_time_start.v, self.chapter_time_start.encoding.as_ref()).len() as u64, self.chapter_time_start.encoding.as_ref());
+        if let Some(val) = &self.chapter_time_end { size += size_el_encoded(EbmlId::ChapterTimeEnd as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if *self.chapter_flag_hidden.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.chapter_flag_hidden.defaulted)) { size += size_el_encoded(EbmlId::ChapterFlagHidden as u64, gen_uint_encoded(&self.chapter_flag_hidden.v, self.chapter_flag_hidden.encoding.as_ref()).len() as u64, self.chapter_flag_hidden.encoding.as_ref()); }
+        if *self.chapter_flag_enabled.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.chapter_flag_enabled.defaulted)) { size += size_el_encoded(EbmlId::ChapterFlagEnabled as u64, gen_uint_encoded(&self.chapter_flag_enabled.v, self.chapter_flag_enabled.encoding.as_ref()).len() as u64, self.chapter_flag_enabled.encoding.as_ref()); }
+        if let Some(val) = &self.chapter_segment_uuid { size += size_el_encoded(EbmlId::ChapterSegmentUuid as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.chapter_skip_type { size += size_el_encoded(EbmlId::ChapterSkipType as u64, gen_uint_encoded(&val.v.value(), val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.chapter_segment_edition_uid { size += size_el_encoded(EbmlId::ChapterSegmentEditionUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.chapter_physical_equiv { size += size_el_encoded(EbmlId::ChapterPhysicalEquiv as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.chapter_track { size += val.v.encoded_size_with(options)?; }
+        for val in &self.chapter_display { size += val.v.encoded_size_with(options)?; }
+        for val in &self.chap_process { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapterAtom {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapterAtom as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapterAtom as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterAtomFields::ChapterUid(val) => blocking::write_el_uint(w, EbmlId::ChapterUid as u64, &*val.v)?,
-                ChapterAtomFields::ChapterStringUid(val) => blocking::write_el_utf8(w, EbmlId::ChapterStringUid as u64, &val.v)?,
-                ChapterAtomFields::ChapterTimeStart(val) => blocking::write_el_uint(w, EbmlId::ChapterTimeStart as u64, &*val.v)?,
-                ChapterAtomFields::ChapterTimeEnd(val) => blocking::write_el_uint(w, EbmlId::ChapterTimeEnd as u64, &*val.v)?,
-                ChapterAtomFields::ChapterFlagHidden(val) => blocking::write_el_uint(w, EbmlId::ChapterFlagHidden as u64, &*val.v)?,
-                ChapterAtomFields::ChapterFlagEnabled(val) => blocking::write_el_uint(w, EbmlId::ChapterFlagEnabled as u64, &*val.v)?,
-                ChapterAtomFields::ChapterSegmentUuid(val) => blocking::write_el_bin(w, EbmlId::ChapterSegmentUuid as u64, &val.v)?,
-                ChapterAtomFields::ChapterSkipType(val) => blocking::write_el_uint(w, EbmlId::ChapterSkipType as u64, &*val.v)?,
-                ChapterAtomFields::ChapterSegmentEditionUid(val) => blocking::write_el_uint(w, EbmlId::ChapterSegmentEditionUid as u64, &*val.v)?,
-                ChapterAtomFields::ChapterPhysicalEquiv(val) => blocking::write_el_uint(w, EbmlId::ChapterPhysicalEquiv as u64, &*val.v)?,
-                ChapterAtomFields::ChapterTrack(val) => val.v.write_blocking(w)?,
-                ChapterAtomFields::ChapterDisplay(val) => val.v.write_blocking(w)?,
-                ChapterAtomFields::ChapProcess(val) => val.v.write_blocking(w)?,
+                ChapterAtomFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterAtomFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterUid(val) => blocking::write_el_encoded(w, EbmlId::ChapterUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterStringUid(val) => blocking::write_el_encoded(w, EbmlId::ChapterStringUid as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterTimeStart(val) => blocking::write_el_encoded(w, EbmlId::ChapterTimeStart as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterTimeEnd(val) => blocking::write_el_encoded(w, EbmlId::ChapterTimeEnd as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterFlagHidden(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ChapterAtomFields::ChapterFlagHidden(val) => blocking::write_el_encoded(w, EbmlId::ChapterFlagHidden as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterFlagEnabled(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                ChapterAtomFields::ChapterFlagEnabled(val) => blocking::write_el_encoded(w, EbmlId::ChapterFlagEnabled as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterSegmentUuid(val) => blocking::write_el_encoded(w, EbmlId::ChapterSegmentUuid as u64, &val.v, val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterSkipType(val) => blocking::write_el_encoded(w, EbmlId::ChapterSkipType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterSegmentEditionUid(val) => blocking::write_el_encoded(w, EbmlId::ChapterSegmentEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterPhysicalEquiv(val) => blocking::write_el_encoded(w, EbmlId::ChapterPhysicalEquiv as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterAtomFields::ChapterTrack(val) => val.v.write_with_blocking(w, options)?,
+                ChapterAtomFields::ChapterDisplay(val) => val.v.write_with_blocking(w, options)?,
+                ChapterAtomFields::ChapProcess(val) => val.v.write_with_blocking(w, options)?,
+                ChapterAtomFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapterAtom {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapterAtom as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapterAtom as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterAtomFields::ChapterUid(val) => async_::write_el_uint(w, EbmlId::ChapterUid as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterStringUid(val) => async_::write_el_utf8(w, EbmlId::ChapterStringUid as u64, &val.v).await?,
-                ChapterAtomFields::ChapterTimeStart(val) => async_::write_el_uint(w, EbmlId::ChapterTimeStart as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterTimeEnd(val) => async_::write_el_uint(w, EbmlId::ChapterTimeEnd as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterFlagHidden(val) => async_::write_el_uint(w, EbmlId::ChapterFlagHidden as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterFlagEnabled(val) => async_::write_el_uint(w, EbmlId::ChapterFlagEnabled as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterSegmentUuid(val) => async_::write_el_bin(w, EbmlId::ChapterSegmentUuid as u64, &val.v).await?,
-                ChapterAtomFields::ChapterSkipType(val) => async_::write_el_uint(w, EbmlId::ChapterSkipType as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterSegmentEditionUid(val) => async_::write_el_uint(w, EbmlId::ChapterSegmentEditionUid as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterPhysicalEquiv(val) => async_::write_el_uint(w, EbmlId::ChapterPhysicalEquiv as u64, &*val.v).await?,
-                ChapterAtomFields::ChapterTrack(val) => val.v.write(w).await?,
-                ChapterAtomFields::ChapterDisplay(val) => val.v.write(w).await?,
-                ChapterAtomFields::ChapProcess(val) => val.v.write(w).await?,
+                ChapterAtomFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterAtomFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterUid(val) => async_::write_el_encoded(w, EbmlId::ChapterUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterStringUid(val) => async_::write_el_encoded(w, EbmlId::ChapterStringUid as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterTimeStart(val) => async_::write_el_encoded(w, EbmlId::ChapterTimeStart as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterTimeEnd(val) => async_::write_el_encoded(w, EbmlId::ChapterTimeEnd as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterFlagHidden(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ChapterAtomFields::ChapterFlagHidden(val) => async_::write_el_encoded(w, EbmlId::ChapterFlagHidden as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterFlagEnabled(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                ChapterAtomFields::ChapterFlagEnabled(val) => async_::write_el_encoded(w, EbmlId::ChapterFlagEnabled as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterSegmentUuid(val) => async_::write_el_encoded(w, EbmlId::ChapterSegmentUuid as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterSkipType(val) => async_::write_el_encoded(w, EbmlId::ChapterSkipType as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterSegmentEditionUid(val) => async_::write_el_encoded(w, EbmlId::ChapterSegmentEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterPhysicalEquiv(val) => async_::write_el_encoded(w, EbmlId::ChapterPhysicalEquiv as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterAtomFields::ChapterTrack(val) => val.v.write_with(w, options).await?,
+                ChapterAtomFields::ChapterDisplay(val) => val.v.write_with(w, options).await?,
+                ChapterAtomFields::ChapProcess(val) => val.v.write_with(w, options).await?,
+                ChapterAtomFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2164,40 +5130,101 @@ impl ChapterAtom {
 }
 
 impl ChapterTrack {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapterTrack as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.chapter_track_uid { size += size_el_encoded(EbmlId::ChapterTrackUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapterTrack {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapterTrack as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapterTrack as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterTrackFields::ChapterTrackUid(val) => blocking::write_el_uint(w, EbmlId::ChapterTrackUid as u64, &*val.v)?,
+                ChapterTrackFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterTrackFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapterTrackFields::ChapterTrackUid(val) => blocking::write_el_encoded(w, EbmlId::ChapterTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapterTrackFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapterTrack {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapterTrack as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapterTrack as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterTrackFields::ChapterTrackUid(val) => async_::write_el_uint(w, EbmlId::ChapterTrackUid as u64, &*val.v).await?,
+                ChapterTrackFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterTrackFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterTrackFields::ChapterTrackUid(val) => async_::write_el_encoded(w, EbmlId::ChapterTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapterTrackFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2205,46 +5232,110 @@ impl ChapterTrack {
 }
 
 impl ChapterDisplay {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapterDisplay as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ChapString as u64, self.chap_string.v.len() as u64, self.chap_string.encoding.as_ref());
+        for val in &self.chap_language { size += size_el_encoded(EbmlId::ChapLanguage as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.chap_language_bcp_47 { size += size_el_encoded(EbmlId::ChapLanguageBcp47 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.chap_country { size += size_el_encoded(EbmlId::ChapCountry as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapterDisplay {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapterDisplay as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapterDisplay as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterDisplayFields::ChapString(val) => blocking::write_el_utf8(w, EbmlId::ChapString as u64, &val.v)?,
-                ChapterDisplayFields::ChapLanguage(val) => blocking::write_el_string(w, EbmlId::ChapLanguage as u64, &val.v)?,
-                ChapterDisplayFields::ChapLanguageBcp47(val) => blocking::write_el_string(w, EbmlId::ChapLanguageBcp47 as u64, &val.v)?,
-                ChapterDisplayFields::ChapCountry(val) => blocking::write_el_string(w, EbmlId::ChapCountry as u64, &val.v)?,
+                ChapterDisplayFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterDisplayFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapterDisplayFields::ChapString(val) => blocking::write_el_encoded(w, EbmlId::ChapString as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                ChapterDisplayFields::ChapLanguage(val) => blocking::write_el_encoded(w, EbmlId::ChapLanguage as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                ChapterDisplayFields::ChapLanguageBcp47(val) => blocking::write_el_encoded(w, EbmlId::ChapLanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                ChapterDisplayFields::ChapCountry(val) => blocking::write_el_encoded(w, EbmlId::ChapCountry as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                ChapterDisplayFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapterDisplay {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapterDisplay as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapterDisplay as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapterDisplayFields::ChapString(val) => async_::write_el_utf8(w, EbmlId::ChapString as u64, &val.v).await?,
-                ChapterDisplayFields::ChapLanguage(val) => async_::write_el_string(w, EbmlId::ChapLanguage as u64, &val.v).await?,
-                ChapterDisplayFields::ChapLanguageBcp47(val) => async_::write_el_string(w, EbmlId::ChapLanguageBcp47 as u64, &val.v).await?,
-                ChapterDisplayFields::ChapCountry(val) => async_::write_el_string(w, EbmlId::ChapCountry as u64, &val.v).await?,
+                ChapterDisplayFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapterDisplayFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapterDisplayFields::ChapString(val) => async_::write_el_encoded(w, EbmlId::ChapString as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                ChapterDisplayFields::ChapLanguage(val) => async_::write_el_encoded(w, EbmlId::ChapLanguage as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                ChapterDisplayFields::ChapLanguageBcp47(val) => async_::write_el_encoded(w, EbmlId::ChapLanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                ChapterDisplayFields::ChapCountry(val) => async_::write_el_encoded(w, EbmlId::ChapCountry as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                ChapterDisplayFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2252,44 +5343,109 @@ impl ChapterDisplay {
 }
 
 impl ChapProcess {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapProcess as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.chap_process_codec_id.v != 0 || (!options.omit_defaults && (self.encoding.is_none() || !self.chap_process_codec_id.defaulted)) { size += size_el_encoded(EbmlId::ChapProcessCodecId as u64, gen_uint_encoded(&self.chap_process_codec_id.v, self.chap_process_codec_id.encoding.as_ref()).len() as u64, self.chap_process_codec_id.encoding.as_ref()); }
+        if let Some(val) = &self.chap_process_private { size += size_el_encoded(EbmlId::ChapProcessPrivate as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.chap_process_command { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapProcess {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapProcess as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapProcess as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapProcessFields::ChapProcessCodecId(val) => blocking::write_el_uint(w, EbmlId::ChapProcessCodecId as u64, &*val.v)?,
-                ChapProcessFields::ChapProcessPrivate(val) => blocking::write_el_bin(w, EbmlId::ChapProcessPrivate as u64, &val.v)?,
-                ChapProcessFields::ChapProcessCommand(val) => val.v.write_blocking(w)?,
+                ChapProcessFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapProcessFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapProcessFields::ChapProcessCodecId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ChapProcessFields::ChapProcessCodecId(val) => blocking::write_el_encoded(w, EbmlId::ChapProcessCodecId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapProcessFields::ChapProcessPrivate(val) => blocking::write_el_encoded(w, EbmlId::ChapProcessPrivate as u64, &val.v, val.encoding.as_ref())?,
+                ChapProcessFields::ChapProcessCommand(val) => val.v.write_with_blocking(w, options)?,
+                ChapProcessFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapProcess {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapProcess as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapProcess as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapProcessFields::ChapProcessCodecId(val) => async_::write_el_uint(w, EbmlId::ChapProcessCodecId as u64, &*val.v).await?,
-                ChapProcessFields::ChapProcessPrivate(val) => async_::write_el_bin(w, EbmlId::ChapProcessPrivate as u64, &val.v).await?,
-                ChapProcessFields::ChapProcessCommand(val) => val.v.write(w).await?,
+                ChapProcessFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapProcessFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapProcessFields::ChapProcessCodecId(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 0 => 0,
+                ChapProcessFields::ChapProcessCodecId(val) => async_::write_el_encoded(w, EbmlId::ChapProcessCodecId as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapProcessFields::ChapProcessPrivate(val) => async_::write_el_encoded(w, EbmlId::ChapProcessPrivate as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapProcessFields::ChapProcessCommand(val) => val.v.write_with(w, options).await?,
+                ChapProcessFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2297,42 +5453,104 @@ impl ChapProcess {
 }
 
 impl ChapProcessCommand {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::ChapProcessCommand as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::ChapProcessTime as u64, gen_uint_encoded(&self.chap_process_time.v.value(), self.chap_process_time.encoding.as_ref()).len() as u64, self.chap_process_time.encoding.as_ref());
+        size += size_el_encoded(EbmlId::ChapProcessData as u64, self.chap_process_data.v.len() as u64, self.chap_process_data.encoding.as_ref());
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl ChapProcessCommand {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::ChapProcessCommand as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::ChapProcessCommand as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapProcessCommandFields::ChapProcessTime(val) => blocking::write_el_uint(w, EbmlId::ChapProcessTime as u64, &*val.v)?,
-                ChapProcessCommandFields::ChapProcessData(val) => blocking::write_el_bin(w, EbmlId::ChapProcessData as u64, &val.v)?,
+                ChapProcessCommandFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapProcessCommandFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                ChapProcessCommandFields::ChapProcessTime(val) => blocking::write_el_encoded(w, EbmlId::ChapProcessTime as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                ChapProcessCommandFields::ChapProcessData(val) => blocking::write_el_encoded(w, EbmlId::ChapProcessData as u64, &val.v, val.encoding.as_ref())?,
+                ChapProcessCommandFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl ChapProcessCommand {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::ChapProcessCommand as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::ChapProcessCommand as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                ChapProcessCommandFields::ChapProcessTime(val) => async_::write_el_uint(w, EbmlId::ChapProcessTime as u64, &*val.v).await?,
-                ChapProcessCommandFields::ChapProcessData(val) => async_::write_el_bin(w, EbmlId::ChapProcessData as u64, &val.v).await?,
+                ChapProcessCommandFields::Crc32(_) if self.computes_crc32(options) => 0,
+                ChapProcessCommandFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapProcessCommandFields::ChapProcessTime(val) => async_::write_el_encoded(w, EbmlId::ChapProcessTime as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                ChapProcessCommandFields::ChapProcessData(val) => async_::write_el_encoded(w, EbmlId::ChapProcessData as u64, &val.v, val.encoding.as_ref()).await?,
+                ChapProcessCommandFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2340,40 +5558,101 @@ impl ChapProcessCommand {
 }
 
 impl Tags {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Tags as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.tag { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32
+    }
+}
+impl Tags {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Tags as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Tags as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TagsFields::Tag(val) => val.v.write_blocking(w)?,
+                TagsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TagsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TagsFields::Tag(val) => val.v.write_with_blocking(w, options)?,
+                TagsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Tags {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Tags as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Tags as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TagsFields::Tag(val) => val.v.write(w).await?,
+                TagsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TagsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TagsFields::Tag(val) => val.v.write_with(w, options).await?,
+                TagsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2381,42 +5660,104 @@ impl Tags {
 }
 
 impl Tag {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Tag as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += self.targets.v.encoded_size_with(options)?;
+        for val in &self.simple_tag { size += val.v.encoded_size_with(options)?; }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Tag {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Tag as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Tag as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TagFields::Targets(val) => val.v.write_blocking(w)?,
-                TagFields::SimpleTag(val) => val.v.write_blocking(w)?,
+                TagFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TagFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TagFields::Targets(val) => val.v.write_with_blocking(w, options)?,
+                TagFields::SimpleTag(val) => val.v.write_with_blocking(w, options)?,
+                TagFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Tag {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Tag as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Tag as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TagFields::Targets(val) => val.v.write(w).await?,
-                TagFields::SimpleTag(val) => val.v.write(w).await?,
+                TagFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TagFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TagFields::Targets(val) => val.v.write_with(w, options).await?,
+                TagFields::SimpleTag(val) => val.v.write_with(w, options).await?,
+                TagFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2424,50 +5765,118 @@ impl Tag {
 }
 
 impl Targets {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::Targets as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if self.target_type_value.v.value() != 50 || (!options.omit_defaults && (self.encoding.is_none() || !self.target_type_value.defaulted)) { size += size_el_encoded(EbmlId::TargetTypeValue as u64, gen_uint_encoded(&self.target_type_value.v.value(), self.target_type_value.encoding.as_ref()).len() as u64, self.target_type_value.encoding.as_ref()); }
+        if let Some(val) = &self.target_type { size += size_el_encoded(EbmlId::TargetType as u64, val.v.as_str().len() as u64, val.encoding.as_ref()); }
+        for val in &self.tag_track_uid { size += size_el_encoded(EbmlId::TagTrackUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.tag_edition_uid { size += size_el_encoded(EbmlId::TagEditionUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.tag_chapter_uid { size += size_el_encoded(EbmlId::TagChapterUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.tag_attachment_uid { size += size_el_encoded(EbmlId::TagAttachmentUid as u64, gen_uint_encoded(&val.v, val.encoding.as_ref()).len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl Targets {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::Targets as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::Targets as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TargetsFields::TargetTypeValue(val) => blocking::write_el_uint(w, EbmlId::TargetTypeValue as u64, &*val.v)?,
-                TargetsFields::TargetType(val) => blocking::write_el_string(w, EbmlId::TargetType as u64, &val.v)?,
-                TargetsFields::TagTrackUid(val) => blocking::write_el_uint(w, EbmlId::TagTrackUid as u64, &*val.v)?,
-                TargetsFields::TagEditionUid(val) => blocking::write_el_uint(w, EbmlId::TagEditionUid as u64, &*val.v)?,
-                TargetsFields::TagChapterUid(val) => blocking::write_el_uint(w, EbmlId::TagChapterUid as u64, &*val.v)?,
-                TargetsFields::TagAttachmentUid(val) => blocking::write_el_uint(w, EbmlId::TagAttachmentUid as u64, &*val.v)?,
+                TargetsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TargetsFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                TargetsFields::TargetTypeValue(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 50 => 0,
+                TargetsFields::TargetTypeValue(val) => blocking::write_el_encoded(w, EbmlId::TargetTypeValue as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref())?,
+                TargetsFields::TargetType(val) => blocking::write_el_encoded(w, EbmlId::TargetType as u64, val.v.as_str().as_bytes(), val.encoding.as_ref())?,
+                TargetsFields::TagTrackUid(val) => blocking::write_el_encoded(w, EbmlId::TagTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TargetsFields::TagEditionUid(val) => blocking::write_el_encoded(w, EbmlId::TagEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TargetsFields::TagChapterUid(val) => blocking::write_el_encoded(w, EbmlId::TagChapterUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TargetsFields::TagAttachmentUid(val) => blocking::write_el_encoded(w, EbmlId::TagAttachmentUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                TargetsFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl Targets {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::Targets as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::Targets as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                TargetsFields::TargetTypeValue(val) => async_::write_el_uint(w, EbmlId::TargetTypeValue as u64, &*val.v).await?,
-                TargetsFields::TargetType(val) => async_::write_el_string(w, EbmlId::TargetType as u64, &val.v).await?,
-                TargetsFields::TagTrackUid(val) => async_::write_el_uint(w, EbmlId::TagTrackUid as u64, &*val.v).await?,
-                TargetsFields::TagEditionUid(val) => async_::write_el_uint(w, EbmlId::TagEditionUid as u64, &*val.v).await?,
-                TargetsFields::TagChapterUid(val) => async_::write_el_uint(w, EbmlId::TagChapterUid as u64, &*val.v).await?,
-                TargetsFields::TagAttachmentUid(val) => async_::write_el_uint(w, EbmlId::TagAttachmentUid as u64, &*val.v).await?,
+                TargetsFields::Crc32(_) if self.computes_crc32(options) => 0,
+                TargetsFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                TargetsFields::TargetTypeValue(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.value() == 50 => 0,
+                TargetsFields::TargetTypeValue(val) => async_::write_el_encoded(w, EbmlId::TargetTypeValue as u64, &gen_uint_encoded(&val.v.value(), val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TargetsFields::TargetType(val) => async_::write_el_encoded(w, EbmlId::TargetType as u64, val.v.as_str().as_bytes(), val.encoding.as_ref()).await?,
+                TargetsFields::TagTrackUid(val) => async_::write_el_encoded(w, EbmlId::TagTrackUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TargetsFields::TagEditionUid(val) => async_::write_el_encoded(w, EbmlId::TagEditionUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TargetsFields::TagChapterUid(val) => async_::write_el_encoded(w, EbmlId::TagChapterUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TargetsFields::TagAttachmentUid(val) => async_::write_el_encoded(w, EbmlId::TagAttachmentUid as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                TargetsFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
@@ -2475,52 +5884,125 @@ impl Targets {
 }
 
 impl SimpleTag {
-    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body_blocking(&mut buf)?;
-        let mut size = self.write_header_blocking(w, buf.len() as u64)?;
-        w.write_all(&buf)?; size += buf.len();
+    /// Size of the element with the header
+    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {
+        self.encoded_size_with(WriteOptions::default())
+    }
+    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        Ok(size_el_encoded(EbmlId::SimpleTag as u64, self.body_size_with(options)?, self.encoding.as_ref()))
+    }
+    pub fn body_size(&self) -> Result<u64, MatroskaError> {
+        self.body_size_with(WriteOptions::default())
+    }
+    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {
+        let mut size = 0;
+        if self.computes_crc32(options) { size += size_el_encoded(EbmlId::Crc32 as u64, 4, self.crc_32.as_ref().and_then(|el| el.encoding.as_ref())); }
+        else if let Some(val) = &self.crc_32 { size += size_el_encoded(EbmlId::Crc32 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        size += size_el_encoded(EbmlId::TagName as u64, self.tag_name.v.len() as u64, self.tag_name.encoding.as_ref());
+        if self.tag_language.v.as_str() != "und" || (!options.omit_defaults && (self.encoding.is_none() || !self.tag_language.defaulted)) { size += size_el_encoded(EbmlId::TagLanguage as u64, self.tag_language.v.len() as u64, self.tag_language.encoding.as_ref()); }
+        if let Some(val) = &self.tag_language_bcp_47 { size += size_el_encoded(EbmlId::TagLanguageBcp47 as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if *self.tag_default.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.tag_default.defaulted)) { size += size_el_encoded(EbmlId::TagDefault as u64, gen_uint_encoded(&self.tag_default.v, self.tag_default.encoding.as_ref()).len() as u64, self.tag_default.encoding.as_ref()); }
+        if *self.tag_default_bogus.v != 1 || (!options.omit_defaults && (self.encoding.is_none() || !self.tag_default_bogus.defaulted)) { size += size_el_encoded(EbmlId::TagDefaultBogus as u64, gen_uint_encoded(&self.tag_default_bogus.v, self.tag_default_bogus.encoding.as_ref()).len() as u64, self.tag_default_bogus.encoding.as_ref()); }
+        if let Some(val) = &self.tag_string { size += size_el_encoded(EbmlId::TagString as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        if let Some(val) = &self.tag_binary { size += size_el_encoded(EbmlId::TagBinary as u64, val.v.len() as u64, val.encoding.as_ref()); }
+        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }
+        Ok(size)
+    }
+    fn computes_crc32(&self, options: WriteOptions) -> bool {
+        options.crc32 && self.crc_32.is_some()
+    }
+}
+impl SimpleTag {
+    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with_blocking(w, WriteOptions::default())
+    }
+    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header_blocking(w, self.body_size_with(options)?)?;
+        size += self.write_body_with_blocking(w, options)?;
         Ok(size)
     }
-    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(blocking::write_element_id_size(w, EbmlId::SimpleTag as u64, size)?)
+    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(blocking::write_element_header(w, EbmlId::SimpleTag as u64, size, self.encoding.as_ref())?)
+    }
+    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with_blocking(w, WriteOptions::default())
     }
-    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with_blocking(w, options);
+        }
+        let mut body = vec![];
+        self.write_children_with_blocking(&mut body, options)?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)?;
+        w.write_all(&body)?;
+        Ok(size + body.len())
+    }
+    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SimpleTagFields::TagName(val) => blocking::write_el_utf8(w, EbmlId::TagName as u64, &val.v)?,
-                SimpleTagFields::TagLanguage(val) => blocking::write_el_string(w, EbmlId::TagLanguage as u64, &val.v)?,
-                SimpleTagFields::TagLanguageBcp47(val) => blocking::write_el_string(w, EbmlId::TagLanguageBcp47 as u64, &val.v)?,
-                SimpleTagFields::TagDefault(val) => blocking::write_el_uint(w, EbmlId::TagDefault as u64, &*val.v)?,
-                SimpleTagFields::TagDefaultBogus(val) => blocking::write_el_uint(w, EbmlId::TagDefaultBogus as u64, &*val.v)?,
-                SimpleTagFields::TagString(val) => blocking::write_el_utf8(w, EbmlId::TagString as u64, &val.v)?,
-                SimpleTagFields::TagBinary(val) => blocking::write_el_bin(w, EbmlId::TagBinary as u64, &val.v)?,
+                SimpleTagFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SimpleTagFields::Crc32(val) => blocking::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref())?,
+                SimpleTagFields::TagName(val) => blocking::write_el_encoded(w, EbmlId::TagName as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                SimpleTagFields::TagLanguage(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.as_str() == "und" => 0,
+                SimpleTagFields::TagLanguage(val) => blocking::write_el_encoded(w, EbmlId::TagLanguage as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                SimpleTagFields::TagLanguageBcp47(val) => blocking::write_el_encoded(w, EbmlId::TagLanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                SimpleTagFields::TagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                SimpleTagFields::TagDefault(val) => blocking::write_el_encoded(w, EbmlId::TagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                SimpleTagFields::TagDefaultBogus(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                SimpleTagFields::TagDefaultBogus(val) => blocking::write_el_encoded(w, EbmlId::TagDefaultBogus as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref())?,
+                SimpleTagFields::TagString(val) => blocking::write_el_encoded(w, EbmlId::TagString as u64, val.v.as_bytes(), val.encoding.as_ref())?,
+                SimpleTagFields::TagBinary(val) => blocking::write_el_encoded(w, EbmlId::TagBinary as u64, &val.v, val.encoding.as_ref())?,
+                SimpleTagFields::Unknown(val) => blocking::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())?,
             }
         }
         Ok(size)
     }
 }
 impl SimpleTag {
-    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
-        let mut buf = vec![]; self.write_body(&mut buf).await?;
-        let mut size = self.write_header(w, buf.len() as u64).await?;
-        w.write_all(&buf).await?; size += buf.len();
+    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        let mut size = self.write_header(w, self.body_size_with(options)?).await?;
+        size += self.write_body_with(w, options).await?;
         Ok(size)
     }
-    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {
-        Ok(async_::write_element_id_size(w, EbmlId::SimpleTag as u64, size).await?)
+    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {
+        Ok(async_::write_element_header(w, EbmlId::SimpleTag as u64, size, self.encoding.as_ref()).await?)
     }
-    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {
+    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {
+        self.write_body_with(w, WriteOptions::default()).await
+    }
+    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
+        if !self.computes_crc32(options) {
+            return self.write_children_with(w, options).await;
+        }
+        let mut body = vec![];
+        self.write_children_with(&mut body, options).await?;
+        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());
+        let size = async_::write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding).await?;
+        w.write_all(&body).await?;
+        Ok(size + body.len())
+    }
+    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {
         let mut size = 0usize;
         for el in self.elements() {
             size += match el {
-                SimpleTagFields::TagName(val) => async_::write_el_utf8(w, EbmlId::TagName as u64, &val.v).await?,
-                SimpleTagFields::TagLanguage(val) => async_::write_el_string(w, EbmlId::TagLanguage as u64, &val.v).await?,
-                SimpleTagFields::TagLanguageBcp47(val) => async_::write_el_string(w, EbmlId::TagLanguageBcp47 as u64, &val.v).await?,
-                SimpleTagFields::TagDefault(val) => async_::write_el_uint(w, EbmlId::TagDefault as u64, &*val.v).await?,
-                SimpleTagFields::TagDefaultBogus(val) => async_::write_el_uint(w, EbmlId::TagDefaultBogus as u64, &*val.v).await?,
-                SimpleTagFields::TagString(val) => async_::write_el_utf8(w, EbmlId::TagString as u64, &val.v).await?,
-                SimpleTagFields::TagBinary(val) => async_::write_el_bin(w, EbmlId::TagBinary as u64, &val.v).await?,
+                SimpleTagFields::Crc32(_) if self.computes_crc32(options) => 0,
+                SimpleTagFields::Crc32(val) => async_::write_el_encoded(w, EbmlId::Crc32 as u64, &val.v, val.encoding.as_ref()).await?,
+                SimpleTagFields::TagName(val) => async_::write_el_encoded(w, EbmlId::TagName as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagLanguage(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && val.v.as_str() == "und" => 0,
+                SimpleTagFields::TagLanguage(val) => async_::write_el_encoded(w, EbmlId::TagLanguage as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagLanguageBcp47(val) => async_::write_el_encoded(w, EbmlId::TagLanguageBcp47 as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagDefault(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                SimpleTagFields::TagDefault(val) => async_::write_el_encoded(w, EbmlId::TagDefault as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagDefaultBogus(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && *val.v == 1 => 0,
+                SimpleTagFields::TagDefaultBogus(val) => async_::write_el_encoded(w, EbmlId::TagDefaultBogus as u64, &gen_uint_encoded(&val.v, val.encoding.as_ref()), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagString(val) => async_::write_el_encoded(w, EbmlId::TagString as u64, val.v.as_bytes(), val.encoding.as_ref()).await?,
+                SimpleTagFields::TagBinary(val) => async_::write_el_encoded(w, EbmlId::TagBinary as u64, &val.v, val.encoding.as_ref()).await?,
+                SimpleTagFields::Unknown(val) => async_::write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref()).await?,
             }
         }
         Ok(size)
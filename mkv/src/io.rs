@@ -3,7 +3,8 @@ use time::OffsetDateTime;
 
 use super::MatroskaError;
 use super::ids::EbmlId;
-use super::ElementSize;
+use super::{ElementEncoding, ElementSize};
+use super::Block;
 
 // https://www.rfc-editor.org/rfc/rfc8794.html#name-variable-size-integer
 // VINT_WIDTH - zero or more bits value 0 followed by VINT_MARKER.
@@ -44,6 +45,23 @@ macro_rules! define_read_element_id_size {
     }
 }
 
+/// Same as `read_element_id_size`, but IDs unknown to the schema are returned as is
+macro_rules! define_read_element_raw_id_size {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        pub fn read_element_raw_id_size<R: std::io::Read>(reader: &mut R) -> Result<(u64, ElementSize, u64), MatroskaError> {
+            let (id, id_len) = read_element_id(reader).map(|await_|await_)?;
+            let (size, size_len) = read_element_size(reader).map(|await_|await_)?;
+            if let (Ok(known), ElementSize::Unknown(_)) = (EbmlId::from_u64(id), size) {
+                if !known.unknown_size_allowed() {
+                    return Err(MatroskaError::InvalidSize(known, size));
+                }
+            }
+            Ok((id, size, id_len + size_len))
+        }
+    }
+}
+
 macro_rules! define_read_vint {
     ($($impl_async:ident)?) => {
         $(#[async_blocking::$impl_async])?
@@ -81,6 +99,15 @@ macro_rules! define_read_vint {
     }
 }
 
+macro_rules! define_read_signed_vint {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        pub fn read_signed_vint<R: std::io::Read>(r: &mut R) -> Result<(/* vint value */ i64, /* len */ u64), MatroskaError> {
+            let (val, len) = read_vint(r).map(|await_|await_)?;
+            Ok((signed_vint_from_raw(val, len), len))
+        }
+    }
+}
 
 macro_rules! define_read_element_id {
     ($($impl_async:ident)?) => {
@@ -166,6 +193,10 @@ macro_rules! define_read_int {
                         val = val << 8;
                         val += r.read_u8().map(|await_|await_)? as i64;
                     }
+                    // two's complement of the value width
+                    if s > 0 && s < 8 {
+                        val = (val << (64 - 8 * s)) >> (64 - 8 * s);
+                    }
                     val
                 }
                 invalid_size => {
@@ -222,7 +253,7 @@ macro_rules! define_read_date {
 
 pub use io::*;
 mod io {
-    use super::{MatroskaError, ElementSize, EbmlId,
+    use super::{MatroskaError, ElementEncoding, ElementSize, EbmlId, Block, SIZE_UNKNOWN,
                 VINT_MAX_FOR_1_BYTES,
                 VINT_MAX_FOR_2_BYTES,
                 VINT_MAX_FOR_3_BYTES,
@@ -234,11 +265,13 @@ mod io {
 
     pub mod blocking {
         use super::*;
-        use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+        use byteorder::{BigEndian, ReadBytesExt};
         use time::OffsetDateTime;
 
         define_read_element_id_size!();
+        define_read_element_raw_id_size!();
         define_read_vint!();
+        define_read_signed_vint!();
         define_read_element_id!();
         define_read_element_size!();
 
@@ -265,11 +298,29 @@ mod io {
             Ok(buf)
         }
 
+        pub fn skip<R: std::io::Read>(r: &mut R, size: u64) -> Result<(), MatroskaError> {
+            let skipped = std::io::copy(&mut <&mut R as std::io::Read>::take(r, size), &mut std::io::sink())?;
+            if skipped != size {
+                return Err(MatroskaError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+            Ok(())
+        }
+
+        pub fn read_block<R: std::io::Read>(r: &mut R, size: u64) -> Result<Block, MatroskaError> {
+            let buf = read_bin(r, size)?;
+            Block::parse(buf)
+        }
+
         pub fn write_element_id_size<W: std::io::Write>(w: &mut W, id: u64, size: u64) -> Result<usize, MatroskaError> {
             let buf = gen_element_id_size(id, size);
             w.write_all(&buf)?;
             Ok(buf.len())
         }
+        pub fn write_element_id_unknown_size<W: std::io::Write>(w: &mut W, id: u64) -> Result<usize, MatroskaError> {
+            let buf = gen_element_id_unknown_size(id);
+            w.write_all(&buf)?;
+            Ok(buf.len())
+        }
         pub fn write_el_uint<W: std::io::Write>(w: &mut W, id: u64, val: &u64) -> Result<usize, MatroskaError> {
             let buf = gen_uint(*val);
             let header_buf = gen_element_id_size(id, buf.len() as u64);
@@ -277,46 +328,33 @@ mod io {
             w.write_all(&buf)?;
             Ok(header_buf.len() + buf.len())
         }
-        pub fn write_el_int<W: std::io::Write>(w: &mut W, id: u64, val: &i64) -> Result<usize, MatroskaError> {
-            let buf = gen_int(*val);
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
+        pub fn write_el_block<W: std::io::Write>(w: &mut W, id: u64, block: &Block) -> Result<usize, MatroskaError> {
+            let block_header = block.header()?;
+            let header_buf = gen_element_id_size(id, (block_header.len() + block.data.len()) as u64);
             w.write_all(&header_buf)?;
-            w.write_all(&buf)?;
-            Ok(header_buf.len() + buf.len())
+            w.write_all(&block_header)?;
+            w.write_all(&block.data)?;
+            Ok(header_buf.len() + block_header.len() + block.data.len())
         }
-        pub fn write_el_float32<W: std::io::Write>(w: &mut W, id: u64, val: &f32) -> Result<usize, MatroskaError> {
-            let mut buf = vec![];
-            buf.write_f32::<BigEndian>(*val)?;
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
-            w.write_all(&header_buf)?;
+        pub fn write_element_header<W: std::io::Write>(w: &mut W, id: u64, size: u64, encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let buf = gen_element_header(id, size, encoding);
             w.write_all(&buf)?;
-            Ok(header_buf.len() + buf.len())
+            Ok(buf.len())
         }
-        pub fn write_el_float64<W: std::io::Write>(w: &mut W, id: u64, val: &f64) -> Result<usize, MatroskaError> {
-            let mut buf = vec![];
-            buf.write_f64::<BigEndian>(*val)?;
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
+        /// Element with the value bytes from `gen_*_encoded`, the header keeps the size width of the encoding
+        pub fn write_el_encoded<W: std::io::Write>(w: &mut W, id: u64, body: &[u8], encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let header_buf = gen_element_header(id, body.len() as u64, encoding);
             w.write_all(&header_buf)?;
-            w.write_all(&buf)?;
-            Ok(header_buf.len() + buf.len())
-        }
-        pub fn write_el_string<W: std::io::Write>(w: &mut W, id: u64, str: &str) -> Result<usize, MatroskaError> {
-            write_el_bin(w, id, str.as_bytes())
+            w.write_all(body)?;
+            Ok(header_buf.len() + body.len())
         }
-        pub fn write_el_utf8<W: std::io::Write>(w: &mut W, id: u64, str: &str) -> Result<usize, MatroskaError> {
-            write_el_bin(w, id, str.as_bytes())
-        }
-        pub fn write_el_bin<W: std::io::Write>(w: &mut W, id: u64, buf: &[u8]) -> Result<usize, MatroskaError> {
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
+        pub fn write_el_block_encoded<W: std::io::Write>(w: &mut W, id: u64, block: &Block, encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let block_header = gen_block_header(block, encoding)?;
+            let header_buf = gen_element_header(id, (block_header.len() + block.data.len()) as u64, encoding);
             w.write_all(&header_buf)?;
-            w.write_all(buf)?;
-            Ok(header_buf.len() + buf.len())
-        }
-        pub fn write_el_date<W: std::io::Write>(w: &mut W, id: u64, val: &OffsetDateTime) -> Result<usize, MatroskaError> {
-            let unix_ts = val.unix_timestamp();
-            // use time::macros::datetime;
-            // read_int(r, size).map(|d| datetime!(2001-01-01 00:00:00 UTC) + time::Duration::nanoseconds(d))
-            write_el_int(w, id, &unix_ts)
+            w.write_all(&block_header)?;
+            w.write_all(&block.data)?;
+            Ok(header_buf.len() + block_header.len() + block.data.len())
         }
     }
 
@@ -326,7 +364,9 @@ mod io {
         use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
         define_read_element_id_size!(impl_async);
+        define_read_element_raw_id_size!(impl_async);
         define_read_vint!(impl_async);
+        define_read_signed_vint!(impl_async);
         define_read_element_id!(impl_async);
         define_read_element_size!(impl_async);
 
@@ -351,58 +391,42 @@ mod io {
             Ok(buf)
         }
 
-        pub async fn write_element_id_size<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, size: u64) -> Result<usize, MatroskaError> {
-            let buf = gen_element_id_size(id, size);
-            w.write_all(&buf).await?;
-            Ok(buf.len())
+        pub async fn skip<R: tokio::io::AsyncRead + Send + Unpin>(r: &mut R, size: u64) -> Result<(), MatroskaError> {
+            let skipped = tokio::io::copy(&mut r.take(size), &mut tokio::io::sink()).await?;
+            if skipped != size {
+                return Err(MatroskaError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+            Ok(())
         }
-        pub async fn write_el_uint<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, val: &u64) -> Result<usize, MatroskaError> {
-            let buf = gen_uint(*val);
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
-            w.write_all(&header_buf).await?;
-            w.write_all(&buf).await?;
-            Ok(header_buf.len() + buf.len())
+        pub async fn read_block<R: tokio::io::AsyncRead + Send + Unpin>(r: &mut R, size: u64) -> Result<Block, MatroskaError> {
+            let buf = read_bin(r, size).await?;
+            Block::parse(buf)
         }
-        pub async fn write_el_int<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, val: &i64) -> Result<usize, MatroskaError> {
-            let buf = gen_int(*val);
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
-            w.write_all(&header_buf).await?;
+
+        pub async fn write_element_id_unknown_size<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64) -> Result<usize, MatroskaError> {
+            let buf = gen_element_id_unknown_size(id);
             w.write_all(&buf).await?;
-            Ok(header_buf.len() + buf.len())
+            Ok(buf.len())
         }
-        pub async fn write_el_float32<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, val: &f32) -> Result<usize, MatroskaError> {
-            let mut buf = vec![];
-            buf.write_f32(*val).await?;
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
-            w.write_all(&header_buf).await?;
+        pub async fn write_element_header<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, size: u64, encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let buf = gen_element_header(id, size, encoding);
             w.write_all(&buf).await?;
-            Ok(header_buf.len() + buf.len())
+            Ok(buf.len())
         }
-        pub async fn write_el_float64<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, val: &f64) -> Result<usize, MatroskaError> {
-            let mut buf = vec![];
-            buf.write_f64(*val).await?;
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
+        /// Element with the value bytes from `gen_*_encoded`, the header keeps the size width of the encoding
+        pub async fn write_el_encoded<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, body: &[u8], encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let header_buf = gen_element_header(id, body.len() as u64, encoding);
             w.write_all(&header_buf).await?;
-            w.write_all(&buf).await?;
-            Ok(header_buf.len() + buf.len())
-        }
-        pub async fn write_el_string<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, str: &str) -> Result<usize, MatroskaError> {
-            write_el_bin(w, id, str.as_bytes()).await
+            w.write_all(body).await?;
+            Ok(header_buf.len() + body.len())
         }
-        pub async fn write_el_utf8<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, str: &str) -> Result<usize, MatroskaError> {
-            write_el_bin(w, id, str.as_bytes()).await
-        }
-        pub async fn write_el_bin<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, buf: &[u8]) -> Result<usize, MatroskaError> {
-            let header_buf = gen_element_id_size(id, buf.len() as u64);
+        pub async fn write_el_block_encoded<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, block: &Block, encoding: Option<&ElementEncoding>) -> Result<usize, MatroskaError> {
+            let block_header = gen_block_header(block, encoding)?;
+            let header_buf = gen_element_header(id, (block_header.len() + block.data.len()) as u64, encoding);
             w.write_all(&header_buf).await?;
-            w.write_all(buf).await?;
-            Ok(header_buf.len() + buf.len())
-        }
-        pub async fn write_el_date<W: tokio::io::AsyncWrite + Send + Unpin>(w: &mut W, id: u64, val: &OffsetDateTime) -> Result<usize, MatroskaError> {
-            let unix_ts = val.unix_timestamp();
-            // use time::macros::datetime;
-            // read_int(r, size).map(|d| datetime!(2001-01-01 00:00:00 UTC) + time::Duration::nanoseconds(d))
-            write_el_int(w, id, &unix_ts).await
+            w.write_all(&block_header).await?;
+            w.write_all(&block.data).await?;
+            Ok(header_buf.len() + block_header.len() + block.data.len())
         }
     }
 
@@ -427,12 +451,154 @@ mod io {
         Ok(r)
     }
 
+    /// Vint with the given length in bytes, used where the width has to be fixed in advance
+    pub fn gen_vint_with_len(val: u64, len: u64) -> Result<Vec<u8>, MatroskaError> {
+        if !(1..=8).contains(&len) || val >= (1u64 << (7 * len)) - 1 {
+            return Err(MatroskaError::InvalidVarIntMoreThanVintMax(val));
+        }
+        let mut r = vec![];
+        for i in 0..len {
+            r.push(((val >> ((len - i - 1) * 8)) & 0xFF) as u8);
+        }
+        r[0] |= 0b_1000_0000 >> (len - 1);
+        Ok(r)
+    }
+
+    // https://www.rfc-editor.org/rfc/rfc9559.html#name-ebml-lacing
+    // Signed vint is stored as the unsigned vint value minus (2^(7*len - 1) - 1)
+    fn signed_vint_bias(len: u64) -> i64 { (1i64 << (7 * len - 1)) - 1 }
+
+    pub fn signed_vint_from_raw(val: u64, len: u64) -> i64 {
+        val as i64 - signed_vint_bias(len)
+    }
+    pub fn gen_signed_vint(val: i64) -> Result<Vec<u8>, MatroskaError> {
+        for len in 1..=8 {
+            let bias = signed_vint_bias(len);
+            if -bias <= val && val <= bias {
+                return gen_vint_with_len((val + bias) as u64, len);
+            }
+        }
+        Err(MatroskaError::InvalidVarIntMoreThanVintMax(val as u64))
+    }
+
+    /// Void element which takes exactly `len` bytes with the header, used to reserve space for later rewriting
+    pub fn gen_void(len: u64) -> Result<Vec<u8>, MatroskaError> {
+        let size_len = if len < 2 + VINT_MAX_FOR_1_BYTES { 1 } else { 8 };
+        if len < 1 + size_len {
+            return Err(MatroskaError::InvalidSize(EbmlId::Void, ElementSize::Sized(len)));
+        }
+        let mut buf = gen_uint(EbmlId::Void as u64);
+        buf.append(&mut gen_vint_with_len(len - 1 - size_len, size_len)?);
+        buf.resize(len as usize, 0);
+        Ok(buf)
+    }
+
+    /// Element followed by a Void filling the rest of `len` bytes, used to rewrite reserved space in place.
+    /// Void can't take a single byte, so the size is widened by one byte instead
+    pub fn gen_element_padded(id: EbmlId, body: &[u8], len: u64) -> Result<Vec<u8>, MatroskaError> {
+        let body_size = body.len() as u64;
+        let mut buf = gen_uint(id as u64);
+        let mut size = gen_vint(body_size)?;
+        if len.checked_sub(buf.len() as u64 + size.len() as u64 + body_size) == Some(1) {
+            size = gen_vint_with_len(body_size, size.len() as u64 + 1)?;
+        }
+        buf.append(&mut size);
+        buf.extend_from_slice(body);
+        let rest = len.checked_sub(buf.len() as u64)
+            .ok_or(MatroskaError::InvalidSize(id, ElementSize::Sized(body_size)))?;
+        if rest > 0 {
+            buf.append(&mut gen_void(rest)?);
+        }
+        Ok(buf)
+    }
+
     pub fn gen_element_id_size(id: u64, size: u64) -> Vec<u8> {
         let mut buf = vec![];
         buf.append(&mut gen_ebml_number(id, true));
         buf.append(&mut gen_ebml_number(size, false));
         buf
     }
+
+    /// Element header with the size vint width of the encoding while the size fits,
+    /// masters read with the unknown size keep it
+    pub fn gen_element_header(id: u64, size: u64, encoding: Option<&ElementEncoding>) -> Vec<u8> {
+        let Some(encoding) = encoding else { return gen_element_id_size(id, size) };
+        let len = encoding.size_len as u64;
+        let mut buf = gen_uint(id);
+        match (encoding.size, gen_vint_with_len(size, len)) {
+            (ElementSize::Unknown(_), _) if (1..=8).contains(&len) => {
+                buf.push(0xFF >> (len - 1));
+                buf.resize(buf.len() + len as usize - 1, 0xFF);
+            }
+            (ElementSize::Sized(_), Ok(mut vint)) => buf.append(&mut vint),
+            _ => buf.append(&mut gen_ebml_number(size, false)),
+        }
+        buf
+    }
+    pub fn size_el_encoded(id: u64, size: u64, encoding: Option<&ElementEncoding>) -> u64 {
+        gen_element_header(id, size, encoding).len() as u64 + size
+    }
+    pub fn size_el_block_encoded(id: u64, block: &Block, encoding: Option<&ElementEncoding>) -> Result<u64, MatroskaError> {
+        let block_header_len = gen_block_header(block, encoding)?.len();
+        Ok(size_el_encoded(id, (block_header_len + block.data.len()) as u64, encoding))
+    }
+
+    /// Width of the value from the encoding, zero-length values are read as 0
+    fn encoded_len(encoding: Option<&ElementEncoding>) -> Option<usize> {
+        match encoding?.size {
+            ElementSize::Sized(size) if size <= 8 => Some(size as usize),
+            _ => None,
+        }
+    }
+    /// Value bytes with the width of the encoding while the value fits, minimal otherwise
+    pub fn gen_uint_encoded(val: &u64, encoding: Option<&ElementEncoding>) -> Vec<u8> {
+        let mut buf = gen_uint(*val);
+        match encoded_len(encoding) {
+            Some(0) if *val == 0 => buf.clear(),
+            Some(len) if len > buf.len() => { buf.splice(0..0, std::iter::repeat_n(0, len - buf.len())); }
+            _ => {}
+        }
+        buf
+    }
+    pub fn gen_int_encoded(val: &i64, encoding: Option<&ElementEncoding>) -> Vec<u8> {
+        let mut buf = gen_int(*val);
+        let sign = if *val < 0 { 0xFF } else { 0 };
+        match encoded_len(encoding) {
+            Some(0) if *val == 0 => buf.clear(),
+            Some(len) if len > buf.len() => { buf.splice(0..0, std::iter::repeat_n(sign, len - buf.len())); }
+            _ => {}
+        }
+        buf
+    }
+    /// Floats read from 4 bytes are written back as `f32` while it keeps the value
+    pub fn gen_float_encoded(val: &f64, encoding: Option<&ElementEncoding>) -> Vec<u8> {
+        match encoded_len(encoding) {
+            Some(4) if (*val as f32) as f64 == *val => (*val as f32).to_be_bytes().to_vec(),
+            _ => val.to_be_bytes().to_vec(),
+        }
+    }
+    /// Nanoseconds since 2001-01-01T00:00:00 UTC, always 8 bytes
+    pub fn gen_date(val: &time::OffsetDateTime) -> Vec<u8> {
+        use time::macros::datetime;
+        let nanoseconds = (*val - datetime!(2001-01-01 00:00:00 UTC)).whole_nanoseconds() as i64;
+        nanoseconds.to_be_bytes().to_vec()
+    }
+    /// Block header with the track number vint width of the encoding while the track number fits
+    pub fn gen_block_header(block: &Block, encoding: Option<&ElementEncoding>) -> Result<Vec<u8>, MatroskaError> {
+        let track_len = encoding.and_then(|encoding| encoding.track_number_len);
+        let mut buf = match track_len.map(|len| gen_vint_with_len(block.track_number, len as u64)) {
+            Some(Ok(vint)) => vint,
+            _ => gen_vint(block.track_number)?,
+        };
+        buf.extend_from_slice(&block.timecode.to_be_bytes());
+        buf.push(block.flags);
+        Ok(buf)
+    }
+    pub fn gen_element_id_unknown_size(id: u64) -> Vec<u8> {
+        let mut buf = gen_ebml_number(id, true);
+        buf.push(0b_1000_0000 | SIZE_UNKNOWN as u8);
+        buf
+    }
     pub fn gen_ebml_number(x : u64, identifier : bool) -> Vec<u8> {
         match identifier {
             true => gen_uint(x),
@@ -506,6 +672,9 @@ mod tests {
     use rand::Rng;
 
     use super::*;
+    use crate::{Ebml, ElementReadBlocking, RawElement, ReadOptions, WriteOptions};
+    use crate::structs::{Cluster, Info, Segment, TrackEntry, Video};
+    use crate::enums::{StereoMode, TargetType, TrackType};
 
     fn to_binary(data: &[u8]) -> String {
         let mut strs = vec![];
@@ -758,6 +927,12 @@ mod tests {
         vint.insert("7 bytes (upper bound)", (vec![0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE], 0xFFFFFFFFFFFF));
         vint.insert("8 bytes (lower bound)", (vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], -0x7FFFFFFFFFFFFF));
         vint.insert("8 bytes (upper bound)", (vec![0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE], 0x7FFFFFFFFFFFFF));
+        for (msg, (data, val)) in vint {
+            let (signed, len) = blocking::read_signed_vint(&mut &data[..]).context(format!("failed to read signed vint '{msg}'"))?;
+            assert_eq!(signed, val, "case: {msg}");
+            assert_eq!(len, data.len() as u64, "case: {msg}");
+            assert_eq!(io::gen_signed_vint(val)?, data, "case: {msg}");
+        }
 
         Ok(())
     }
@@ -822,4 +997,200 @@ mod tests {
             assert_eq!(res.is_err(), true);
         }
     }
+
+    #[test]
+    fn test_unknown_elements() -> Result<(), anyhow::Error> {
+        let body = [
+            &[0x2A, 0xD7, 0xB1, 0x83, 0x0F, 0x42, 0x40][..],
+            // unknown ID
+            &[0x4A, 0xBC, 0x82, 0xAA, 0xBB],
+            &[0x4D, 0x80, 0x81, b'a'],
+            // Void isn't a child of Info
+            &[0xEC, 0x81, 0x00],
+            &[0x57, 0x41, 0x81, b'b'],
+        ].concat();
+        let mut buf = vec![0x15, 0x49, 0xA9, 0x66, 0x80 | body.len() as u8];
+        buf.extend_from_slice(&body);
+
+        let (info, read) = Info::read(&mut &buf[..])?;
+        assert_eq!(read, buf.len());
+        assert_eq!(*info.timestamp_scale.v, 1_000_000);
+        assert_eq!(*info.writing_app.v, "b");
+        assert!(info.unknown.is_empty());
+
+        let options = ReadOptions { keep_unknown: true, ..Default::default() };
+        let (info, _) = Info::read_with(&mut &buf[..], options)?;
+        assert_eq!(info.unknown.len(), 2);
+        assert_eq!(*info.unknown[0].v, RawElement { id: 0x4ABC, data: vec![0xAA, 0xBB], position: 7 });
+        assert_eq!(info.unknown[1].v.id, EbmlId::Void as u64);
+
+        let mut written = vec![];
+        info.write_blocking(&mut written)?;
+        assert_eq!(written, buf);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoded_size() -> Result<(), anyhow::Error> {
+        let mut segment = Segment::default();
+        *segment.info.v.timestamp_scale.v = 1_000_000;
+        *segment.info.v.title.get_or_insert_with(Default::default).v = "title".to_string();
+        for i in 0..3u8 {
+            let mut cluster = Cluster::default();
+            *cluster.timestamp.v = i as u64 * 1000;
+            for j in 0..200u8 {
+                let block = Block::new(1, j as i16, vec![i; j as usize]);
+                cluster.simple_block.push(Ebml::new_index(j as u64, block));
+            }
+            segment.cluster.push(Ebml::new_index(i as u64 + 1, cluster));
+        }
+        segment.unknown.push(Ebml::new(RawElement { id: 0x4ABC, data: vec![0; 300], position: 0 }));
+
+        let mut buf = vec![];
+        let written = segment.write_blocking(&mut buf)?;
+        assert_eq!(written, buf.len());
+        assert_eq!(segment.encoded_size()?, buf.len() as u64);
+        assert_eq!(segment.cluster[0].v.encoded_size()? as usize, {
+            let mut buf = vec![];
+            segment.cluster[0].v.write_blocking(&mut buf)?
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_enums() -> Result<(), anyhow::Error> {
+        assert_eq!(TrackType::from(2), TrackType::Audio);
+        assert_eq!(TrackType::from(99), TrackType::Other(99));
+        assert_eq!(u64::from(TrackType::Subtitle), 17);
+        assert_eq!(TargetType::from("ALBUM".to_string()), TargetType::Album);
+        assert_eq!(TargetType::Other("SERIES".to_string()).as_str(), "SERIES");
+        // Other holding a listed value is the listed variant
+        assert_eq!(TrackType::Other(2), TrackType::Audio);
+        assert_eq!(TargetType::Other("ALBUM".to_string()), TargetType::Album);
+
+        let mut track = TrackEntry::default();
+        *track.track_type.v = TrackType::Other(99);
+        track.video = Some(Ebml::new(Video { stereo_mode: Ebml::new(StereoMode::SideBySideLeftEyeFirst), ..Default::default() }));
+        let mut buf = vec![];
+        track.write_blocking(&mut buf)?;
+        let (read, _) = TrackEntry::read(&mut &buf[..])?;
+        assert_eq!(*read.track_type.v, TrackType::Other(99));
+        assert_eq!(*read.video.unwrap().v.stereo_mode.v, StereoMode::SideBySideLeftEyeFirst);
+        Ok(())
+    }
+
+    #[test]
+    fn test_omit_defaults() -> Result<(), anyhow::Error> {
+        // TrackNumber, TrackUID, TrackType, CodecID and Video with PixelWidth and PixelHeight only
+        let body = [
+            &[0xD7, 0x81, 0x01][..],
+            &[0x73, 0xC5, 0x81, 0x01],
+            &[0x83, 0x81, 0x01],
+            &[0x86, 0x85, b'V', b'_', b'V', b'P', b'9'],
+            &[0xE0, 0x88, 0xB0, 0x82, 0x05, 0x00, 0xBA, 0x82, 0x02, 0xD0],
+        ].concat();
+        let mut buf = vec![0xAE, 0x80 | body.len() as u8];
+        buf.extend_from_slice(&body);
+
+        let (track, _) = TrackEntry::read(&mut &buf[..])?;
+        assert!(!track.track_number.defaulted);
+        assert!(track.flag_lacing.defaulted);
+        assert_eq!(*track.flag_lacing.v, 1);
+        assert_eq!(*track.language.v, "eng");
+        let video = &track.video.as_ref().unwrap().v;
+        assert!(video.stereo_mode.defaulted);
+        assert!(!video.pixel_width.defaulted);
+
+        let mut written = vec![];
+        track.write_blocking(&mut written)?;
+        assert!(written.len() > buf.len());
+
+        let options = WriteOptions { omit_defaults: true, ..Default::default() };
+        let mut written = vec![];
+        assert_eq!(track.write_with_blocking(&mut written, options)?, buf.len());
+        assert_eq!(written, buf);
+        assert_eq!(track.encoded_size_with(options)?, buf.len() as u64);
+        let (read, _) = TrackEntry::read(&mut &written[..])?;
+        assert_eq!(*read.codec_id.v, "V_VP9");
+        assert!(read.flag_lacing.defaulted);
+        assert_eq!(*read.video.unwrap().v.pixel_height.v, 720);
+
+        // values changed from the default are written
+        let mut track = track;
+        *track.flag_lacing.v = 0;
+        let mut written = vec![];
+        track.write_with_blocking(&mut written, options)?;
+        assert_eq!(written.len(), buf.len() + 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lossless() -> Result<(), anyhow::Error> {
+        let options = ReadOptions { lossless: true, ..Default::default() };
+        let body = [
+            // 2 bytes size and value
+            &[0xD7, 0x40, 0x02, 0x00, 0x01][..],
+            &[0x73, 0xC5, 0x81, 0x01],
+            &[0x83, 0x81, 0x01],
+            // float of 4 bytes
+            &[0x23, 0x31, 0x4F, 0x84, 0x3F, 0x80, 0x00, 0x00],
+            &[0xEC, 0x83, 0x00, 0x00, 0x00],
+            &[0x4A, 0xBC, 0x82, 0xAA, 0xBB],
+            // 8 bytes size
+            &[0x86, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, b'V', b'_', b'V', b'P', b'9'],
+        ].concat();
+        let mut buf = vec![0xAE, 0x40, body.len() as u8];
+        buf.extend_from_slice(&body);
+
+        let (track, read) = TrackEntry::read_with(&mut &buf[..], options)?;
+        assert_eq!(read, buf.len());
+        assert_eq!(*track.track_number.v, 1);
+        assert_eq!(track.unknown.len(), 2);
+        let mut written = vec![];
+        assert_eq!(track.write_blocking(&mut written)?, buf.len());
+        assert_eq!(written, buf);
+        assert_eq!(track.encoded_size()?, buf.len() as u64);
+
+        // the widths are kept while the values fit
+        let mut track = track;
+        *track.track_number.v = 0x1234;
+        *track.track_timestamp_scale.v = 0.1;
+        let mut written = vec![];
+        track.write_blocking(&mut written)?;
+        assert_eq!(written.len(), buf.len() + 4);
+        let (read, _) = TrackEntry::read(&mut &written[..])?;
+        assert_eq!(*read.track_number.v, 0x1234);
+        assert_eq!(*read.track_timestamp_scale.v, 0.1);
+
+        let body = [
+            &[0xE7, 0x81, 0x00][..],
+            // track number vint of 2 bytes
+            &[0xA3, 0x87, 0x40, 0x01, 0x00, 0x00, 0x80, 0x01, 0x02],
+            // ReferenceBlock -10 in 2 bytes
+            &[0xA0, 0x8B, 0xA1, 0x85, 0x81, 0x00, 0x00, 0x00, 0x03, 0xFB, 0x82, 0xFF, 0xF6],
+        ].concat();
+        let mut buf = vec![0x1F, 0x43, 0xB6, 0x75, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, body.len() as u8];
+        buf.extend_from_slice(&body);
+
+        let (cluster, _) = Cluster::read_with(&mut &buf[..], options)?;
+        assert_eq!(*cluster.block_group[0].v.reference_block[0].v, -10);
+        let mut written = vec![];
+        cluster.write_blocking(&mut written)?;
+        assert_eq!(written, buf);
+
+        // the track number width is kept when the block data changes
+        let mut cluster = cluster;
+        cluster.simple_block[0].v.data.push(3);
+        let mut written = vec![];
+        cluster.write_blocking(&mut written)?;
+        assert_eq!(written.len(), buf.len() + 1);
+        assert_eq!(&written[15..19], &[0xA3, 0x88, 0x40, 0x01]);
+
+        // without the option the widths are minimal and the defaulted ReferencePriority is written
+        let (cluster, _) = Cluster::read(&mut &buf[..])?;
+        let mut written = vec![];
+        cluster.write_blocking(&mut written)?;
+        assert_eq!(written.len(), buf.len() - 9 + 3);
+        Ok(())
+    }
 }
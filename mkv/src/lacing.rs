@@ -0,0 +1,193 @@
+use super::io;
+use super::{Lacing, MatroskaError};
+
+// https://www.rfc-editor.org/rfc/rfc9559.html#name-block-lacing
+// Lace header: number of frames minus one, then the sizes of all frames except the last one.
+const MAX_FRAMES: usize = 256;
+
+/// Split the data of a laced block into frames
+pub fn decode(lacing: Lacing, data: &[u8]) -> Result<Vec<&[u8]>, MatroskaError> {
+    if lacing == Lacing::None {
+        return Ok(vec![data]);
+    }
+    let (&count, mut r) = data.split_first().ok_or(MatroskaError::InvalidBlock("lace header is truncated"))?;
+    let count = count as usize + 1;
+
+    let mut sizes = Vec::with_capacity(count);
+    match lacing {
+        Lacing::None => unreachable!(),
+        Lacing::Xiph => {
+            for _ in 0..count - 1 {
+                let mut size = 0usize;
+                loop {
+                    let (&byte, rest) = r.split_first().ok_or(MatroskaError::InvalidBlock("xiph lace size is truncated"))?;
+                    r = rest;
+                    size += byte as usize;
+                    if byte != 0xFF { break }
+                }
+                sizes.push(size);
+            }
+        }
+        Lacing::Ebml => {
+            // the first size is unsigned, the others are differences to the previous one
+            let mut size = 0i64;
+            for i in 0..count - 1 {
+                let delta = match i {
+                    0 => io::blocking::read_vint(&mut r)?.0 as i64,
+                    _ => io::blocking::read_signed_vint(&mut r)?.0,
+                };
+                size = size.checked_add(delta).ok_or(MatroskaError::InvalidBlock("ebml lace size overflows"))?;
+                if size < 0 {
+                    return Err(MatroskaError::InvalidBlock("ebml lace size is negative"));
+                }
+                sizes.push(size as usize);
+            }
+        }
+        Lacing::FixedSize => {
+            if r.len() % count != 0 {
+                return Err(MatroskaError::InvalidBlock("fixed-size lacing data is not divisible by frames count"));
+            }
+            sizes.resize(count - 1, r.len() / count);
+        }
+    }
+
+    let laced = sizes.iter().try_fold(0usize, |laced, size| laced.checked_add(*size))
+        .filter(|laced| *laced <= r.len())
+        .ok_or(MatroskaError::InvalidBlock("lace sizes exceed the block size"))?;
+    sizes.push(r.len() - laced);
+
+    let mut frames = Vec::with_capacity(count);
+    for size in sizes {
+        let (frame, rest) = r.split_at(size);
+        frames.push(frame);
+        r = rest;
+    }
+    Ok(frames)
+}
+
+/// Lace frames with the given lacing, returns the block data
+pub fn encode_with(lacing: Lacing, frames: &[&[u8]]) -> Result<Vec<u8>, MatroskaError> {
+    if frames.is_empty() || frames.len() > MAX_FRAMES {
+        return Err(MatroskaError::InvalidBlock("block must contain from 1 to 256 frames"));
+    }
+    let mut buf = match lacing {
+        Lacing::None => {
+            if frames.len() != 1 {
+                return Err(MatroskaError::InvalidBlock("only one frame can be stored without lacing"));
+            }
+            vec![]
+        }
+        Lacing::Xiph => xiph_header(frames),
+        Lacing::Ebml => ebml_header(frames)?,
+        Lacing::FixedSize => {
+            if frames.iter().any(|frame| frame.len() != frames[0].len()) {
+                return Err(MatroskaError::InvalidBlock("fixed-size lacing requires frames of equal size"));
+            }
+            vec![(frames.len() - 1) as u8]
+        }
+    };
+    for frame in frames {
+        buf.extend_from_slice(frame);
+    }
+    Ok(buf)
+}
+
+/// Lace frames with the lacing that gives the smallest block, returns the lacing and the block data
+pub fn encode(frames: &[&[u8]]) -> Result<(Lacing, Vec<u8>), MatroskaError> {
+    let lacing = if frames.len() == 1 {
+        Lacing::None
+    } else if frames.iter().all(|frame| frame.len() == frames[0].len()) {
+        Lacing::FixedSize
+    } else if ebml_header(frames)?.len() < xiph_header(frames).len() {
+        Lacing::Ebml
+    } else {
+        Lacing::Xiph
+    };
+    Ok((lacing, encode_with(lacing, frames)?))
+}
+
+fn xiph_header(frames: &[&[u8]]) -> Vec<u8> {
+    let mut buf = vec![(frames.len() - 1) as u8];
+    for frame in &frames[..frames.len() - 1] {
+        let mut size = frame.len();
+        while size >= 0xFF {
+            buf.push(0xFF);
+            size -= 0xFF;
+        }
+        buf.push(size as u8);
+    }
+    buf
+}
+
+fn ebml_header(frames: &[&[u8]]) -> Result<Vec<u8>, MatroskaError> {
+    let mut buf = vec![(frames.len() - 1) as u8];
+    // a single frame has no sizes, as with Xiph lacing
+    let Some((first, rest)) = frames[..frames.len() - 1].split_first() else { return Ok(buf) };
+    let mut prev = first.len() as i64;
+    buf.append(&mut io::gen_vint(prev as u64)?);
+    for frame in rest {
+        let size = frame.len() as i64;
+        buf.append(&mut io::gen_signed_vint(size - prev)?);
+        prev = size;
+    }
+    Ok(buf)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lacing() {
+        let frames: Vec<Vec<u8>> = vec![vec![1; 800], vec![2; 500], vec![3; 1000], vec![4; 10]];
+        let frames: Vec<&[u8]> = frames.iter().map(|frame| &frame[..]).collect();
+        for lacing in [Lacing::Xiph, Lacing::Ebml] {
+            let data = encode_with(lacing, &frames).unwrap();
+            assert_eq!(decode(lacing, &data).unwrap(), frames, "lacing {lacing:?}");
+        }
+        assert!(encode_with(Lacing::FixedSize, &frames).is_err());
+        assert!(encode_with(Lacing::None, &frames).is_err());
+
+        // xiph: 1 + (4 + 2 + 4) bytes, ebml: 1 + 2 + 2 + 2 bytes
+        let (lacing, data) = encode(&frames).unwrap();
+        assert_eq!(lacing, Lacing::Ebml);
+        assert_eq!(data.len(), 7 + 800 + 500 + 1000 + 10);
+
+        let small: Vec<&[u8]> = vec![&[1; 10], &[2; 20], &[3; 5]];
+        let (lacing, data) = encode(&small).unwrap();
+        assert_eq!(lacing, Lacing::Xiph);
+        assert_eq!(&data[..3], &[2, 10, 20]);
+        assert_eq!(decode(lacing, &data).unwrap(), small);
+
+        let fixed: Vec<&[u8]> = vec![&[1; 4], &[2; 4], &[3; 4]];
+        let (lacing, data) = encode(&fixed).unwrap();
+        assert_eq!(lacing, Lacing::FixedSize);
+        assert_eq!(data.len(), 1 + 12);
+        assert_eq!(decode(lacing, &data).unwrap(), fixed);
+
+        let (lacing, data) = encode(&fixed[..1]).unwrap();
+        assert_eq!(lacing, Lacing::None);
+        assert_eq!(data, vec![1; 4]);
+
+        assert!(decode(Lacing::FixedSize, &[2, 1, 2, 3, 4]).is_err());
+        assert!(decode(Lacing::Xiph, &[1, 0xFF]).is_err());
+        assert!(decode(Lacing::Xiph, &[1, 10, 1]).is_err());
+        assert!(decode(Lacing::Ebml, &[]).is_err());
+
+        // a single frame is the lace count only
+        let single: Vec<&[u8]> = vec![&[1, 2, 3]];
+        for lacing in [Lacing::Xiph, Lacing::Ebml] {
+            assert_eq!(encode_with(lacing, &single).unwrap(), vec![0, 1, 2, 3], "lacing {lacing:?}");
+            assert_eq!(decode(lacing, &[0, 0x81, 0xAA]).unwrap(), vec![&[0x81, 0xAA][..]], "lacing {lacing:?}");
+        }
+
+        // 255 frames of the largest sizes, the sum overflows
+        let mut data = vec![254];
+        data.append(&mut io::gen_vint((1 << 56) - 2).unwrap());
+        for _ in 0..253 {
+            data.append(&mut io::gen_signed_vint(1 << 54).unwrap());
+        }
+        assert!(matches!(decode(Lacing::Ebml, &data), Err(MatroskaError::InvalidBlock(_))));
+    }
+}
@@ -0,0 +1,239 @@
+use super::io;
+use super::structs::*;
+use super::ids::EbmlId;
+use super::demuxer::ends_cluster;
+use super::{ElementSize, MatroskaError, ReadError};
+
+/// Level 1 element of the Segment indexed by `LazySegment`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level1Element {
+    /// `None` for element IDs unknown to the Matroska schema
+    pub id: Option<EbmlId>,
+    /// Position of the element header in the file
+    pub position: u64,
+    pub header_len: u64,
+    /// Size of the body, found by scanning the children for the live clusters with the unknown size
+    pub size: u64,
+}
+impl Level1Element {
+    pub fn body_position(&self) -> u64 { self.position + self.header_len }
+    pub fn end(&self) -> u64 { self.body_position() + self.size }
+}
+
+/// Segment handle for metadata tools: `open` reads only the headers of the level 1 elements,
+/// so the file isn't loaded into memory, and the elements are read on demand.
+///
+/// Clusters with a known size are skipped by a seek, the live ones with the unknown size
+/// are walked by the headers of their children.
+pub struct LazySegment<R> {
+    r: R,
+    header: EbmlHeader,
+    /// Position of the first byte of the Segment data, SeekHead and Cues positions are relative to it
+    segment_start: u64,
+    segment_size: ElementSize,
+    segment_header_len: u64,
+    elements: Vec<Level1Element>,
+}
+
+impl<R> LazySegment<R> {
+    pub fn header(&self) -> &EbmlHeader { &self.header }
+    pub fn segment_start(&self) -> u64 { self.segment_start }
+    pub fn segment_size(&self) -> ElementSize { self.segment_size }
+    pub fn segment_header_len(&self) -> u64 { self.segment_header_len }
+    /// Level 1 elements in the file order
+    pub fn elements(&self) -> &[Level1Element] { &self.elements }
+
+    /// The first level 1 element with the ID
+    pub fn find(&self, id: EbmlId) -> Option<Level1Element> {
+        self.elements.iter().find(|element| element.id == Some(id)).copied()
+    }
+    pub fn clusters(&self) -> impl Iterator<Item = &Level1Element> {
+        self.elements.iter().filter(|element| element.id == Some(EbmlId::Cluster))
+    }
+
+    pub fn get_mut(&mut self) -> &mut R { &mut self.r }
+    pub fn into_inner(self) -> R { self.r }
+}
+
+fn is_eof(err: &MatroskaError) -> bool {
+    matches!(err, MatroskaError::Io(err) if err.kind() == std::io::ErrorKind::UnexpectedEof)
+}
+
+/// Errors of the element read at `element` get the absolute offset and the path in the Segment
+fn located(err: ReadError, element: &Level1Element) -> ReadError {
+    let name = element.id.map(|id| format!("{id:?}")).unwrap_or_default();
+    err.at(&name, element.body_position()).at("Segment", 0)
+}
+
+macro_rules! define_lazy_segment {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        impl<R: std::io::Read + std::io::Seek> LazySegment<R> {
+            pub fn open_blocking(mut r: R) -> Result<Self, ReadError> {
+                let start = r.stream_position().map(|await_|await_).map_err(MatroskaError::Io)?;
+                let (header, header_len) = EbmlHeader::read(&mut r).map(|await_|await_).map_err(|err| err.offset_by(start))?;
+                let (segment_size, segment_header_len) = Segment::read_header(&mut r).map(|await_|await_)
+                    .map_err(|err| err.offset_by(start + header_len as u64))?;
+                let segment_start = start + (header_len + segment_header_len) as u64;
+                let segment_end = match segment_size {
+                    ElementSize::Sized(size) => Some(segment_start + size),
+                    ElementSize::Unknown(_) => None,
+                };
+
+                let mut elements = vec![];
+                let mut pos = segment_start;
+                while segment_end.is_none_or(|end| pos < end) {
+                    let (raw_id, size, header_len) = match read_header_blocking(&mut r, pos, "Segment").map(|await_|await_)? {
+                        Some(header) => header,
+                        None => break,
+                    };
+                    let id = EbmlId::from_u64(raw_id).ok();
+                    let size = match size {
+                        ElementSize::Sized(size) => size,
+                        ElementSize::Unknown(_) if id == Some(EbmlId::Cluster) => cluster_size_blocking(&mut r, pos + header_len).map(|await_|await_)?,
+                        ElementSize::Unknown(_) => return Err(ReadError::new(MatroskaError::UnknownSizeNotAllowed(raw_id), pos, "Segment")),
+                    };
+                    let element = Level1Element { id, position: pos, header_len, size };
+                    elements.push(element);
+                    pos = element.end();
+                    r.seek(SeekFrom::Start(pos)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), pos, "Segment"))?;
+                }
+                Ok(Self { r, header, segment_start, segment_size, segment_header_len: segment_header_len as u64, elements })
+            }
+
+            /// Read the level 1 element, e.g. `segment.read_blocking::<Tracks>(element)`
+            pub fn read_blocking<T: ElementReadBlocking<R>>(&mut self, element: Level1Element) -> Result<T, ReadError> {
+                self.read_with_blocking(element, Default::default()).map(|await_|await_)
+            }
+            pub fn read_with_blocking<T: ElementReadBlocking<R>>(&mut self, element: Level1Element, options: crate::ReadOptions) -> Result<T, ReadError> {
+                self.r.seek(SeekFrom::Start(element.body_position())).map(|await_|await_)
+                    .map_err(|err| ReadError::new(err.into(), element.body_position(), "Segment"))?;
+                let (val, _) = T::read_body_with(&mut self.r, ElementSize::Sized(element.size), options).map(|await_|await_)
+                    .map_err(|err| located(err, &element))?;
+                Ok(val)
+            }
+
+            /// Read the first level 1 element with the ID, `None` if the Segment has none
+            pub fn load_blocking<T: ElementReadBlocking<R>>(&mut self, id: EbmlId) -> Result<Option<T>, ReadError> {
+                self.load_with_blocking(id, Default::default()).map(|await_|await_)
+            }
+            pub fn load_with_blocking<T: ElementReadBlocking<R>>(&mut self, id: EbmlId, options: crate::ReadOptions) -> Result<Option<T>, ReadError> {
+                match self.find(id) {
+                    Some(element) => self.read_with_blocking(element, options).map(|await_|await_).map(Some),
+                    None => Ok(None),
+                }
+            }
+        }
+
+        $(#[async_blocking::$impl_async])?
+        /// Header of the element at `pos` in the master at `path`, `None` at the end of the file
+        fn read_header_blocking<R: std::io::Read>(r: &mut R, pos: u64, path: &str) -> Result<Option<(u64, ElementSize, u64)>, ReadError> {
+            match io::blocking::read_element_raw_id_size(r).map(|await_|await_) {
+                Ok(header) => Ok(Some(header)),
+                Err(err) if is_eof(&err) => Ok(None),
+                Err(err) => Err(ReadError::new(err, pos, path)),
+            }
+        }
+
+        $(#[async_blocking::$impl_async])?
+        /// Size of the Cluster with the unknown size: its children up to the next level 1 element
+        fn cluster_size_blocking<R: std::io::Read + std::io::Seek>(r: &mut R, data: u64) -> Result<u64, ReadError> {
+            let mut pos = data;
+            while let Some((raw_id, size, header_len)) = read_header_blocking(r, pos, "Segment\\Cluster").map(|await_|await_)? {
+                if ends_cluster(EbmlId::from_u64(raw_id).ok()) {
+                    break;
+                }
+                let ElementSize::Sized(size) = size else {
+                    return Err(ReadError::new(MatroskaError::UnknownSizeNotAllowed(raw_id), pos, "Segment\\Cluster"));
+                };
+                pos += header_len + size;
+                r.seek(SeekFrom::Start(pos)).map(|await_|await_).map_err(|err| ReadError::new(err.into(), pos, "Segment\\Cluster"))?;
+            }
+            Ok(pos - data)
+        }
+    }
+}
+
+mod blocking {
+    use std::io::SeekFrom;
+
+    use super::*;
+    use crate::ElementReadBlocking;
+
+    define_lazy_segment!();
+}
+
+mod async_ {
+    use std::io::SeekFrom;
+    use tokio::io::AsyncSeekExt;
+
+    use super::*;
+    use crate::ElementRead;
+
+    define_lazy_segment!(impl_async);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{info, recording};
+
+    #[test]
+    fn test_lazy_segment() -> Result<(), anyhow::Error> {
+        for live in [false, true] {
+            let buf = recording(info(), live)?;
+            let mut segment = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?;
+            assert_eq!(segment.elements()[0].position, segment.segment_start());
+            assert!(segment.elements().windows(2).all(|pair| pair[0].end() == pair[1].position));
+            assert_eq!(segment.elements().last().map(|element| element.end()), Some(buf.len() as u64));
+
+            let info: Info = segment.load_blocking(EbmlId::Info)?.expect("Info");
+            assert_eq!(*info.muxing_app.v, "mkv-rs");
+            let tracks: Tracks = segment.load_blocking(EbmlId::Tracks)?.expect("Tracks");
+            assert_eq!(*tracks.track_entry[0].v.codec_id.v, "V_TEST");
+
+            let clusters: Vec<Level1Element> = segment.clusters().copied().collect();
+            assert!(clusters.len() > 1);
+            let mut blocks = 0;
+            for element in clusters {
+                let cluster: Cluster = segment.read_blocking(element)?;
+                blocks += cluster.simple_block.len() + cluster.block_group.len();
+            }
+            assert_eq!(blocks, 30);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_segment_error() -> Result<(), anyhow::Error> {
+        let mut buf = recording(info(), false)?;
+        let segment = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?;
+        let tracks = segment.find(EbmlId::Tracks).expect("Tracks");
+        // the first child of Tracks gets an invalid size
+        buf[tracks.body_position() as usize + 1] = 0;
+        let mut segment = LazySegment::open_blocking(std::io::Cursor::new(&buf[..]))?;
+        let err = segment.read_blocking::<Tracks>(tracks).expect_err("invalid size");
+        assert_eq!(err.path, "\\Segment\\Tracks");
+        assert_eq!(err.offset, tracks.body_position());
+
+        // the level 1 headers are read by open
+        buf[tracks.position as usize] = 0;
+        let err = LazySegment::open_blocking(std::io::Cursor::new(&buf[..])).err().expect("invalid ID");
+        assert!(matches!(err.kind, MatroskaError::InvalidVarInt));
+        assert_eq!((err.offset, err.path.as_str()), (tracks.position, "\\Segment"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lazy_segment_async() -> Result<(), anyhow::Error> {
+        let buf = recording(info(), true)?;
+        let mut segment = LazySegment::open(std::io::Cursor::new(&buf[..])).await?;
+        let info: Info = segment.load(EbmlId::Info).await?.expect("Info");
+        assert_eq!(*info.muxing_app.v, "mkv-rs");
+        let element = *segment.clusters().last().expect("Cluster");
+        let cluster: Cluster = segment.read(element).await?;
+        assert!(!cluster.simple_block.is_empty());
+        Ok(())
+    }
+}
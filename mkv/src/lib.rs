@@ -4,41 +4,96 @@
 mod gen;
 mod io;
 mod errors;
+mod crc32;
 
 pub mod element;
+pub mod block;
+pub mod lacing;
+pub mod demuxer;
+pub mod muxer;
+pub mod stream;
+pub mod cues;
+pub mod validate;
+pub mod slice;
+pub mod lazy;
+pub mod edit;
+pub mod content_encoding;
+pub mod encryption;
+pub mod codec_private;
+pub mod extract;
+#[cfg(test)]
+mod test_util;
 
-pub use errors::MatroskaError;
+pub use errors::{MatroskaError, ReadError};
+pub use block::{Block, BlockView, SimpleBlock, Lacing};
+pub use demuxer::{Demuxer, Frame, SkippedRange};
+pub use muxer::{Muxer, ClusterPolicy};
+pub use stream::{EbmlStreamParser, Event};
+pub use validate::{Diagnostic, Severity};
+pub use slice::{ElementView, Elements};
+pub use lazy::{LazySegment, Level1Element};
+pub use edit::MetadataEditor;
+pub use content_encoding::Compression;
+pub use codec_private::CodecPrivate;
+pub use extract::AnnexB;
 
 use async_trait::async_trait;
 pub use gen::*;
 
 use gen::ids::EbmlId;
 
+/// Options of the generated readers, `Default` skips the elements unknown to the schema
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReadOptions {
+    /// Keep unknown elements in the `unknown` field of the parent to write them back
+    pub keep_unknown: bool,
+    /// Record the encoding of every element to write an unmodified tree back byte-for-byte,
+    /// unknown elements and Voids are kept as with `keep_unknown`
+    pub lossless: bool,
+    /// Verify the CRC-32 of the masters which start with one, the bodies of the masters
+    /// except Segment are buffered to be checked before they are parsed
+    pub verify_crc32: bool,
+}
+
+/// Options of the generated writers, `Default` writes all elements of the structs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WriteOptions {
+    /// Skip the required elements equal to the spec default, readers restore them
+    pub omit_defaults: bool,
+    /// Write a CRC-32 into the level 1 masters (Info, Tracks, Cluster, Cues, ...) and
+    /// recompute the ones of the other masters
+    pub crc32: bool,
+}
+
 #[async_trait::async_trait]
 pub trait ElementRead<R: tokio::io::AsyncRead + Send + Unpin> {
-    async fn read(r: &mut R) -> Result<(Self, usize), anyhow::Error> where Self: Sized;
-    async fn read_header(r: &mut R) -> Result<(ElementSize, usize), anyhow::Error>;
-    async fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), anyhow::Error> where Self: Sized;
+    async fn read(r: &mut R) -> Result<(Self, usize), ReadError> where Self: Sized;
+    async fn read_with(r: &mut R, options: ReadOptions) -> Result<(Self, usize), ReadError> where Self: Sized;
+    async fn read_header(r: &mut R) -> Result<(ElementSize, usize), ReadError>;
+    async fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), ReadError> where Self: Sized;
+    async fn read_body_with(r: &mut R, size: ElementSize, options: ReadOptions) -> Result<(Self, usize), ReadError> where Self: Sized;
 }
 #[async_trait::async_trait]
 pub trait ElementWrite<W: tokio::io::AsyncWrite + Send + Unpin> {
-    async fn write(&self, w: &mut W) -> Result<usize, anyhow::Error>;
-    async fn write_header(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error>;
-    async fn write_body(&self, w: &mut W) -> Result<usize, anyhow::Error>;
+    async fn write(&self, w: &mut W) -> Result<usize, MatroskaError>;
+    async fn write_header(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError>;
+    async fn write_body(&self, w: &mut W) -> Result<usize, MatroskaError>;
 }
 
 pub trait ElementReadBlocking<R: std::io::Read> {
-    fn read(r: &mut R) -> Result<(Self, usize), anyhow::Error> where Self: Sized;
-    fn read_header(r: &mut R) -> Result<(ElementSize, usize), anyhow::Error>;
-    fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), anyhow::Error> where Self: Sized;
+    fn read(r: &mut R) -> Result<(Self, usize), ReadError> where Self: Sized;
+    fn read_with(r: &mut R, options: ReadOptions) -> Result<(Self, usize), ReadError> where Self: Sized;
+    fn read_header(r: &mut R) -> Result<(ElementSize, usize), ReadError>;
+    fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), ReadError> where Self: Sized;
+    fn read_body_with(r: &mut R, size: ElementSize, options: ReadOptions) -> Result<(Self, usize), ReadError> where Self: Sized;
 }
 pub trait ElementWriteBlocking<W: std::io::Write> {
-    fn write_blocking(&self, w: &mut W) -> Result<usize, anyhow::Error>;
-    fn write_header_blocking(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error>;
-    fn write_body_blocking(&self, w: &mut W) -> Result<usize, anyhow::Error>;
+    fn write_blocking(&self, w: &mut W) -> Result<usize, MatroskaError>;
+    fn write_header_blocking(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError>;
+    fn write_body_blocking(&self, w: &mut W) -> Result<usize, MatroskaError>;
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ElementSize {
     Sized(u64),
     Unknown(u64),
@@ -50,11 +105,10 @@ impl ElementSize {
             ElementSize::Unknown(val) => *val
         }
     }
-    pub fn try_sized(&self, id: EbmlId) -> Result<u64, anyhow::Error> {
+    pub fn try_sized(&self, id: EbmlId) -> Result<u64, MatroskaError> {
         match self {
             ElementSize::Sized(size) => Ok(*size),
-            ElementSize::Unknown(val) =>
-                Err(anyhow::anyhow!("Element ID '{:?}' unknown data size is not allowed", id))
+            ElementSize::Unknown(_) => Err(MatroskaError::UnknownSizeNotAllowed(id as u64)),
         }
     }
 }
@@ -81,23 +135,64 @@ pub enum ElementType {
 pub struct Ebml<T> {
     pub index: Option<u64>,
     pub v: Box<T>,
+    /// The element was absent and the reader filled it with the spec default
+    pub defaulted: bool,
+    /// Encoding of the value read with `ReadOptions::lossless`, masters keep it in their `encoding` field
+    pub encoding: Option<ElementEncoding>,
     id: u64,
 }
 impl<T> Ebml<T> {
     pub fn new(v: T) -> Self {
-        Self { index: None, v: Box::new(v), id: Self::rnd_id() }
+        Self { index: None, v: Box::new(v), defaulted: false, encoding: None, id: Self::rnd_id() }
     }
     pub fn new_index(index: u64, v: T) -> Self {
-        Self { index: Some(index), v: Box::new(v), id: Self::rnd_id() }
+        Self { index: Some(index), v: Box::new(v), defaulted: false, encoding: None, id: Self::rnd_id() }
+    }
+    /// Spec default of an absent element
+    pub fn new_default(v: T) -> Self {
+        Self { index: None, v: Box::new(v), defaulted: true, encoding: None, id: Self::rnd_id() }
     }
     fn rnd_id() -> u64 { use rand::Rng; rand::thread_rng().gen_range(u32::MAX as u64 .. u64::MAX) }
     // fn rnd_id() -> u64 { use rand::Rng; rand::thread_rng().gen_range(1000 .. 9999) }
 }
+/// How the element was encoded in the file, the writers reuse the widths while the values fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementEncoding {
+    /// Width of the data size vint
+    pub size_len: u8,
+    pub size: ElementSize,
+    /// Width of the track number vint of `SimpleBlock` and `Block`
+    pub track_number_len: Option<u8>,
+}
+impl ElementEncoding {
+    /// Encoding of the element from the header length returned by `read_element_raw_id_size`
+    pub fn new(id: u64, header_len: u64, size: ElementSize) -> Self {
+        let id_len = io::gen_uint(id).len() as u64;
+        Self { size_len: (header_len - id_len) as u8, size, track_number_len: None }
+    }
+    /// Encoding of the block just read from `size` bytes, the block header is what precedes its data
+    pub fn new_block(id: u64, header_len: u64, size: u64, block: &Block) -> Self {
+        let track_number_len = (size - block.data.len() as u64 - 3) as u8;
+        Self { track_number_len: Some(track_number_len), ..Self::new(id, header_len, ElementSize::Sized(size)) }
+    }
+}
+
+/// Element with an ID unknown to the schema or not allowed in its parent, stored as is
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawElement {
+    pub id: u64,
+    pub data: Vec<u8>,
+    /// Offset of the element header from the start of the parent body
+    pub position: u64,
+}
+
 impl<T: Default> Default for Ebml<T> {
     fn default() -> Self {
         Self {
             index: None,
             v: Box::new(T::default()),
+            defaulted: false,
+            encoding: None,
             id: Self::rnd_id(),
         }
     }
@@ -118,7 +213,7 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Ebml<T> {
 
 #[macro_export]
 macro_rules! impl_ord {
-    ($type_name:ident) => {
+    ($type_name:ty) => {
         impl PartialEq<Self> for $type_name {
             fn eq(&self, other: &Self) -> bool {
                 let (_, _, self_id) = self.index();
@@ -130,34 +225,23 @@ macro_rules! impl_ord {
 
         impl PartialOrd for $type_name {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                let (_, self_index, self_id) = self.index();
-                let (_, other_index, other_id) = other.index();
-                if let Some(index) = self_index {
-                    if let Some(other_index) = other_index{
-                        match index.partial_cmp(&other_index) {
-                            Some(std::cmp::Ordering::Equal) => {}
-                            None => {},
-                            res => return res,
-                        }
-                    }
-                }
-                self_id.partial_cmp(&other_id)
+                Some(self.cmp(other))
             }
         }
         impl Ord for $type_name {
             fn cmp(&self, other: &Self) -> std::cmp::Ordering {
                 let (_, self_index, self_id) = self.index();
                 let (_, other_index, other_id) = other.index();
-                if let Some(index) = self_index {
-                    if let Some(other_index) = other_index {
-                        match index.cmp(&other_index) {
-                            std::cmp::Ordering::Equal => {}
-                            res => return res,
-                        }
-                    }
-                }
-                self_id.cmp(&other_id)
+                // elements added after reading have no index and go after the read ones
+                let order = match (self_index, other_index) {
+                    (Some(index), Some(other_index)) => index.cmp(&other_index),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                };
+                order.then(self_id.cmp(&other_id))
             }
         }
     }
 }
+
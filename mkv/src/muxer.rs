@@ -0,0 +1,563 @@
+use super::io;
+use super::structs::*;
+use super::ids::EbmlId;
+use super::enums::TrackType;
+use super::content_encoding::{self, Compression};
+use super::encryption::{self, Key};
+use super::{Block, Ebml, Frame, WriteOptions};
+
+/// Space reserved after the Segment header for the SeekHead written on finish
+const SEEK_HEAD_RESERVED: u64 = 128;
+/// Segment size is written with the fixed width to back-patch it on finish
+const SEGMENT_SIZE_LEN: u64 = 8;
+/// Space reserved after the Info for the float Duration of seekable sinks, with a byte to spare
+/// for the wider Info size
+const DURATION_RESERVED: u64 = 12;
+
+/// When the Muxer starts a new Cluster
+#[derive(Debug, Clone, Copy)]
+pub struct ClusterPolicy {
+    /// Maximum duration of a cluster in nanoseconds
+    pub max_duration: u64,
+    /// Maximum size of the blocks of a cluster in bytes
+    pub max_size: usize,
+    /// Start a new cluster at every keyframe of a video track
+    pub keyframe: bool,
+}
+impl Default for ClusterPolicy {
+    fn default() -> Self {
+        Self { max_duration: 5_000_000_000, max_size: 5 * 1024 * 1024, keyframe: true }
+    }
+}
+
+struct PendingCluster {
+    cluster: Cluster,
+    /// Cluster timestamp in TimestampScale units
+    timestamp: u64,
+    size: usize,
+    /// Tracks which got a CuePoint in this cluster
+    cue_tracks: Vec<u64>,
+    cue_points: Vec<CuePoint>,
+}
+
+/// Writes frames of the tracks into SimpleBlocks grouped by clusters.
+///
+/// Cues and SeekHead are written on finish, for seekable sinks the Segment size
+/// and `Info::duration` are back-patched into the space reserved for them. In the live
+/// mode Segment and clusters have the unknown size and blocks are written as soon as they are added.
+pub struct Muxer<W> {
+    w: W,
+    /// Bytes written to the sink so far
+    pos: u64,
+    started: bool,
+
+    header: EbmlHeader,
+    info: Info,
+    tracks: Tracks,
+    policy: ClusterPolicy,
+    write_options: WriteOptions,
+    live: bool,
+    /// A cluster was closed in the live mode and the sink has to be flushed
+    flush: bool,
+    /// Tracks which get CuePoints, video tracks or all tracks for audio-only files
+    cue_tracks: Vec<u64>,
+
+    segment_size_pos: u64,
+    /// Position of the first byte of the Segment data, SeekHead and Cues positions are relative to it
+    segment_start: u64,
+    seek_head_pos: u64,
+    info_pos: u64,
+    tracks_pos: u64,
+    cues_pos: Option<u64>,
+
+    cluster: Option<PendingCluster>,
+    cues: Cues,
+    /// End of the last frame in nanoseconds
+    end_ns: u64,
+    /// Keys of the encrypted tracks by ContentEncKeyID
+    keys: Vec<(Vec<u8>, Key)>,
+}
+
+impl<W> Muxer<W> {
+    pub fn new(w: W, mut info: Info, tracks: Vec<TrackEntry>) -> Self {
+        if *info.timestamp_scale.v == 0 {
+            *info.timestamp_scale.v = 1_000_000;
+        }
+        let mut cue_tracks: Vec<u64> = tracks.iter()
+            .filter(|track| *track.track_type.v == TrackType::Video)
+            .map(|track| *track.track_number.v)
+            .collect();
+        if cue_tracks.is_empty() {
+            cue_tracks = tracks.iter().map(|track| *track.track_number.v).collect();
+        }
+        let mut tracks_ = Tracks::default();
+        for (index, track) in tracks.into_iter().enumerate() {
+            tracks_.track_entry.push(Ebml::new_index(index as u64, track));
+        }
+
+        Self {
+            w,
+            pos: 0,
+            started: false,
+            header: matroska_header(),
+            info,
+            tracks: tracks_,
+            policy: ClusterPolicy::default(),
+            write_options: WriteOptions::default(),
+            live: false,
+            flush: false,
+            cue_tracks,
+            segment_size_pos: 0,
+            segment_start: 0,
+            seek_head_pos: 0,
+            info_pos: 0,
+            tracks_pos: 0,
+            cues_pos: None,
+            cluster: None,
+            cues: Cues::default(),
+            end_ns: 0,
+            keys: vec![],
+        }
+    }
+
+    /// Replace the default `matroska` EBML header, e.g. to write the `webm` DocType
+    pub fn with_header(mut self, header: EbmlHeader) -> Self {
+        self.header = header;
+        self
+    }
+    pub fn with_cluster_policy(mut self, policy: ClusterPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+    /// Options of the header, Info, Tracks, SeekHead, clusters and Cues, e.g. `omit_defaults` to keep
+    /// a remux of the tracks read with the spec defaults as small as the source, or `crc32`,
+    /// the clusters of the live mode are streamed and get no CRC-32
+    pub fn with_write_options(mut self, options: WriteOptions) -> Self {
+        self.write_options = options;
+        self
+    }
+    /// Compress the frames of the track and announce it by the track ContentEncodings,
+    /// the frames of a track with ContentEncodings set by the caller are encoded the same way
+    pub fn with_compression(mut self, track: u64, compression: Compression) -> Self {
+        if let Some(track) = self.tracks.track_entry.iter_mut().find(|entry| *entry.v.track_number.v == track) {
+            track.v.content_encodings = Some(Ebml::new(compression.content_encodings()));
+        }
+        self
+    }
+    /// Encrypt the frames of the track by the WebM Encryption scheme, AES-CTR with a random IV per frame.
+    /// The key isn't written, only `key_id` is stored in the track ContentEncodings, after the compression if any
+    pub fn with_encryption(mut self, track: u64, key_id: Vec<u8>, key: Key) -> Self {
+        if let Some(track) = self.tracks.track_entry.iter_mut().find(|entry| *entry.v.track_number.v == track) {
+            let encodings = &mut *track.v.content_encodings.get_or_insert_with(|| Ebml::new(Default::default())).v;
+            let mut encryption = encryption::content_encoding(&key_id);
+            *encryption.content_encoding_order.v = encodings.content_encoding.iter()
+                .map(|encoding| *encoding.v.content_encoding_order.v + 1)
+                .max().unwrap_or(0);
+            let index = encodings.content_encoding.len() as u64;
+            encodings.content_encoding.push(Ebml::new_index(index, encryption));
+            self.keys.retain(|(id, _)| *id != key_id);
+            self.keys.push((key_id, key));
+        }
+        self
+    }
+    /// Live mode for pipes and sockets: Segment and Cluster sizes are unknown, no Cues and SeekHead.
+    /// Space for the SeekHead is still reserved, so `cues::write_cues` can index the recording later
+    pub fn live(mut self) -> Self {
+        self.live = true;
+        self
+    }
+
+    pub fn info(&self) -> &Info { &self.info }
+    pub fn tracks(&self) -> &Tracks { &self.tracks }
+
+    fn timestamp_scale(&self) -> u64 { *self.info.timestamp_scale.v }
+
+    fn track(&self, number: u64) -> Option<&TrackEntry> {
+        self.tracks.track_entry.iter()
+            .map(|track| &*track.v)
+            .find(|track| *track.track_number.v == number)
+    }
+
+    /// EBML header, Segment header, space for the SeekHead, Info and Tracks
+    fn start(&mut self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut buf = vec![];
+        self.header.write_with_blocking(&mut buf, self.write_options)?;
+        if self.live {
+            Segment::default().write_header_unknown_size_blocking(&mut buf)?;
+            self.segment_start = buf.len() as u64;
+            // Reserved for the SeekHead written when Cues are added to the recording later
+            buf.append(&mut io::gen_void(SEEK_HEAD_RESERVED)?);
+        } else {
+            buf.append(&mut io::gen_uint(EbmlId::Segment as u64));
+            self.segment_size_pos = buf.len() as u64;
+            // Unknown size until it's back-patched
+            buf.push(0x01);
+            buf.resize(buf.len() + SEGMENT_SIZE_LEN as usize - 1, 0xFF);
+            self.segment_start = buf.len() as u64;
+
+            self.seek_head_pos = buf.len() as u64;
+            buf.append(&mut io::gen_void(SEEK_HEAD_RESERVED)?);
+        }
+        self.info_pos = buf.len() as u64;
+        self.info.write_with_blocking(&mut buf, self.write_options)?;
+        if !self.live {
+            buf.append(&mut io::gen_void(DURATION_RESERVED)?);
+        }
+        self.tracks_pos = buf.len() as u64;
+        self.tracks.write_with_blocking(&mut buf, self.write_options)?;
+
+        self.started = true;
+        self.pos = buf.len() as u64;
+        Ok(buf)
+    }
+
+    /// Add the frame to the current cluster, returns the previous cluster if the frame starts a new one
+    fn push_frame(&mut self, frame: Frame) -> Result<Vec<u8>, anyhow::Error> {
+        let track = self.track(frame.track)
+            .ok_or_else(|| anyhow::anyhow!("Unknown track {}", frame.track))?;
+        let video = *track.track_type.v == TrackType::Video;
+        let data = match &track.content_encodings {
+            Some(encodings) => {
+                let keys = |key_id: &[u8]| self.keys.iter().find(|(id, _)| id == key_id).map(|(_, key)| *key);
+                content_encoding::encode_frame(&encodings.v, frame.data, &keys)?
+            }
+            None => frame.data,
+        };
+        if frame.pts_ns < 0 {
+            return Err(anyhow::anyhow!("Negative timestamp {} of track {}", frame.pts_ns, frame.track));
+        }
+        let scale = self.timestamp_scale();
+        let timestamp = frame.pts_ns as u64 / scale;
+        self.end_ns = self.end_ns.max(frame.pts_ns as u64 + frame.duration.unwrap_or(0));
+
+        let mut buf = vec![];
+        let cut = match &self.cluster {
+            None => true,
+            Some(cluster) => {
+                let offset = timestamp as i64 - cluster.timestamp as i64;
+                !(i16::MIN as i64..=i16::MAX as i64).contains(&offset)
+                    || offset.max(0) as u64 * scale >= self.policy.max_duration
+                    || cluster.size + data.len() > self.policy.max_size
+                    || (self.policy.keyframe && video && frame.keyframe)
+            }
+        };
+        if cut {
+            buf = self.flush_cluster()?;
+            let cluster = Cluster { timestamp: Ebml::new_index(0, timestamp), ..Default::default() };
+            if self.live {
+                cluster.write_header_unknown_size_blocking(&mut buf)?;
+                io::blocking::write_el_uint(&mut buf, EbmlId::Timestamp as u64, &timestamp)?;
+            }
+            self.cluster = Some(PendingCluster {
+                cluster, timestamp, size: 0, cue_tracks: vec![], cue_points: vec![],
+            });
+        }
+
+        let cue = !self.live && frame.keyframe && self.cue_tracks.contains(&frame.track);
+        let cluster = self.cluster.as_mut().expect("cluster is started above");
+        let mut block = Block::new(frame.track, (timestamp as i64 - cluster.timestamp as i64) as i16, data);
+        block.set_keyframe(frame.keyframe);
+        cluster.size += block.data.len();
+        if self.live {
+            io::blocking::write_el_block(&mut buf, EbmlId::SimpleBlock as u64, &block)?;
+            self.pos += buf.len() as u64;
+        } else {
+            let index = cluster.cluster.simple_block.len() as u64 + 1;
+            cluster.cluster.simple_block.push(Ebml::new_index(index, block));
+        }
+
+        if cue && !cluster.cue_tracks.contains(&frame.track) {
+            cluster.cue_tracks.push(frame.track);
+            let positions = CueTrackPositions { cue_track: Ebml::new_index(0, frame.track), ..Default::default() };
+            let cue_point = CuePoint {
+                cue_time: Ebml::new_index(0, timestamp),
+                cue_track_positions: vec![Ebml::new_index(1, positions)],
+                ..Default::default()
+            };
+            cluster.cue_points.push(cue_point);
+        }
+        Ok(buf)
+    }
+
+    /// Serialize the current cluster and fill in the cluster position of its CuePoints
+    fn flush_cluster(&mut self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut buf = vec![];
+        let Some(cluster) = self.cluster.take() else { return Ok(buf) };
+        if self.live {
+            // Blocks of the live cluster are already written
+            self.flush = true;
+            return Ok(buf);
+        }
+        let cluster_position = self.pos - self.segment_start;
+        cluster.cluster.write_with_blocking(&mut buf, self.write_options)?;
+        self.pos += buf.len() as u64;
+
+        for mut cue_point in cluster.cue_points {
+            for positions in &mut cue_point.cue_track_positions {
+                positions.v.cue_cluster_position = Ebml::new_index(1, cluster_position);
+            }
+            let index = self.cues.cue_point.len() as u64;
+            self.cues.cue_point.push(Ebml::new_index(index, cue_point));
+        }
+        Ok(buf)
+    }
+
+    /// Last cluster and Cues, the SeekHead is appended for sinks which can't be rewound
+    fn trailer(&mut self, seek_head: bool) -> Result<Vec<u8>, anyhow::Error> {
+        let mut buf = vec![];
+        if !self.started {
+            buf = self.start()?;
+        }
+        buf.append(&mut self.flush_cluster()?);
+
+        if !self.cues.cue_point.is_empty() {
+            self.cues_pos = Some(self.pos);
+            let mut cues = vec![];
+            self.cues.write_with_blocking(&mut cues, self.write_options)?;
+            self.pos += cues.len() as u64;
+            buf.append(&mut cues);
+        }
+        if seek_head && !self.live {
+            let mut seek_head = vec![];
+            self.seek_head().write_with_blocking(&mut seek_head, self.write_options)?;
+            self.pos += seek_head.len() as u64;
+            buf.append(&mut seek_head);
+        }
+        Ok(buf)
+    }
+
+    fn seek_head(&self) -> SeekHead {
+        let mut entries = vec![
+            (EbmlId::Info, self.info_pos),
+            (EbmlId::Tracks, self.tracks_pos),
+        ];
+        if let Some(cues_pos) = self.cues_pos {
+            entries.push((EbmlId::Cues, cues_pos));
+        }
+        let mut seek_head = SeekHead::default();
+        for (index, (id, pos)) in entries.into_iter().enumerate() {
+            let seek = Seek {
+                seek_id: Ebml::new_index(0, io::gen_uint(id as u64)),
+                seek_position: Ebml::new_index(1, pos - self.segment_start),
+                ..Default::default()
+            };
+            seek_head.seek.push(Ebml::new_index(index as u64, seek));
+        }
+        seek_head
+    }
+
+    /// SeekHead followed by a Void filling the rest of the reserved space
+    fn seek_head_reserved(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut body = vec![];
+        self.seek_head().write_body_with_blocking(&mut body, self.write_options)?;
+        io::gen_element_padded(EbmlId::SeekHead, &body, SEEK_HEAD_RESERVED)
+            .map_err(|_| anyhow::anyhow!("SeekHead doesn't fit the reserved space"))
+    }
+
+    /// Positions and bytes rewritten in place for seekable sinks
+    fn patches(&self) -> Result<Vec<(u64, Vec<u8>)>, anyhow::Error> {
+        let mut info = self.info.clone();
+        *info.duration.get_or_insert_with(|| Ebml::new(0.0)).v = self.end_ns as f64 / self.timestamp_scale() as f64;
+        let mut body = vec![];
+        info.write_body_with_blocking(&mut body, self.write_options)?;
+        let info = io::gen_element_padded(EbmlId::Info, &body, self.tracks_pos - self.info_pos)
+            .map_err(|_| anyhow::anyhow!("Info with the Duration doesn't fit the reserved space"))?;
+        let segment_size = io::gen_vint_with_len(self.pos - self.segment_start, SEGMENT_SIZE_LEN)?;
+        Ok(vec![
+            (self.seek_head_pos, self.seek_head_reserved()?),
+            (self.info_pos, info),
+            (self.segment_size_pos, segment_size),
+        ])
+    }
+}
+
+pub(crate) fn matroska_header() -> EbmlHeader {
+    let mut header = EbmlHeader::default();
+    *header.version.v = 1;
+    *header.read_version.v = 1;
+    *header.ebml_max_id_length.v = 4;
+    *header.ebml_max_size_length.v = 8;
+    *header.doc_type.v = "matroska".to_string();
+    *header.doc_type_version.v = 4;
+    *header.doc_type_read_version.v = 2;
+    header
+}
+
+macro_rules! define_muxer {
+    ($($impl_async:ident)?) => {
+        $(#[async_blocking::$impl_async])?
+        impl<W: std::io::Write> Muxer<W> {
+            pub fn write_frame_blocking(&mut self, frame: Frame) -> Result<(), anyhow::Error> {
+                if !self.started {
+                    let buf = self.start()?;
+                    self.w.write_all(&buf).map(|await_|await_)?;
+                }
+                let buf = self.push_frame(frame)?;
+                self.w.write_all(&buf).map(|await_|await_)?;
+                if self.flush {
+                    self.flush = false;
+                    self.w.flush().map(|await_|await_)?;
+                }
+                Ok(())
+            }
+
+            /// Write the last cluster, Cues and SeekHead at the end, the Segment keeps the unknown size
+            /// and the Info has no Duration
+            pub fn finish_blocking(mut self) -> Result<W, anyhow::Error> {
+                let buf = self.trailer(true)?;
+                self.w.write_all(&buf).map(|await_|await_)?;
+                self.w.flush().map(|await_|await_)?;
+                Ok(self.w)
+            }
+        }
+
+        $(#[async_blocking::$impl_async])?
+        impl<W: std::io::Write + std::io::Seek> Muxer<W> {
+            /// Write the last cluster and Cues, then rewrite the SeekHead, `Info::duration` and the Segment size
+            pub fn finish_seekable_blocking(mut self) -> Result<W, anyhow::Error> {
+                if self.live {
+                    return self.finish_blocking().map(|await_|await_);
+                }
+                let start = self.w.stream_position().map(|await_|await_)? - self.pos;
+                let buf = self.trailer(false)?;
+                self.w.write_all(&buf).map(|await_|await_)?;
+                let end = self.w.stream_position().map(|await_|await_)?;
+                for (pos, buf) in self.patches()? {
+                    self.w.seek(SeekFrom::Start(start + pos)).map(|await_|await_)?;
+                    self.w.write_all(&buf).map(|await_|await_)?;
+                }
+                self.w.seek(SeekFrom::Start(end)).map(|await_|await_)?;
+                self.w.flush().map(|await_|await_)?;
+                Ok(self.w)
+            }
+        }
+    }
+}
+
+mod blocking {
+    use std::io::SeekFrom;
+
+    use super::*;
+
+    define_muxer!();
+}
+
+mod async_ {
+    use std::io::SeekFrom;
+    use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+    use super::*;
+
+    define_muxer!(impl_async);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Demuxer;
+    use crate::test_util::{self, info};
+
+    fn tracks() -> Vec<TrackEntry> {
+        let video = test_util::video_track(1, "V_TEST");
+        let mut audio = TrackEntry::default();
+        *audio.track_number.v = 2;
+        *audio.track_uid.v = 2;
+        *audio.track_type.v = TrackType::Audio;
+        *audio.codec_id.v = "A_TEST".to_string();
+        vec![video, audio]
+    }
+
+    fn frames() -> Vec<Frame> {
+        let mut frames = vec![];
+        for i in 0..50i64 {
+            frames.push(Frame {
+                track: 1, pts_ns: i * 40_000_000, duration: Some(40_000_000),
+                keyframe: i % 10 == 0, data: vec![i as u8; 100],
+            });
+            frames.push(Frame {
+                track: 2, pts_ns: i * 40_000_000 + 1_000_000, duration: Some(20_000_000),
+                keyframe: true, data: vec![i as u8; 10],
+            });
+        }
+        frames
+    }
+
+    fn check(buf: Vec<u8>) -> Result<Demuxer<std::io::Cursor<Vec<u8>>>, anyhow::Error> {
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf))?;
+        assert_eq!(*demuxer.info().timestamp_scale.v, 1_000_000);
+        assert_eq!(demuxer.tracks().unwrap().track_entry.len(), 2);
+
+        let frames = frames();
+        for frame in &frames {
+            let demuxed = demuxer.next_frame_blocking()?.unwrap();
+            assert_eq!((demuxed.track, demuxed.pts_ns, demuxed.keyframe), (frame.track, frame.pts_ns, frame.keyframe));
+            assert_eq!(demuxed.data, frame.data);
+        }
+        assert!(demuxer.next_frame_blocking()?.is_none());
+        // SeekHead is either in the reserved space or after the Cues
+        assert_eq!(demuxer.seek_head()[0].seek.len(), 3);
+        Ok(demuxer)
+    }
+
+    #[test]
+    fn test_muxer() -> Result<(), anyhow::Error> {
+        let policy = ClusterPolicy { max_duration: 1_000_000_000, ..Default::default() };
+        let mut muxer = Muxer::new(std::io::Cursor::new(vec![]), info(), tracks()).with_cluster_policy(policy);
+        for frame in frames() {
+            muxer.write_frame_blocking(frame)?;
+        }
+        let buf = muxer.finish_seekable_blocking()?.into_inner();
+
+        // Segment size is back-patched, the Segment ends with the Cues
+        let segment_start = matroska_header().write_blocking(&mut vec![])? as u64 + 4 + SEGMENT_SIZE_LEN;
+        let (size, _) = io::blocking::read_element_size(&mut &buf[segment_start as usize - 8..])?;
+        assert_eq!(size, crate::ElementSize::Sized(buf.len() as u64 - segment_start));
+        let demuxer = check(buf)?;
+        assert_eq!(*demuxer.info().duration.as_ref().unwrap().v, 2000.0);
+
+        let mut muxer = Muxer::new(vec![], info(), tracks());
+        for frame in frames() {
+            muxer.write_frame_blocking(frame)?;
+        }
+        let buf = muxer.finish_blocking()?;
+        let demuxer = check(buf)?;
+        assert!(demuxer.info().duration.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_muxer_async() -> Result<(), anyhow::Error> {
+        let mut muxer = Muxer::new(std::io::Cursor::new(vec![]), info(), tracks());
+        for frame in frames() {
+            muxer.write_frame(frame).await?;
+        }
+        let buf = muxer.finish_seekable().await?.into_inner();
+        let demuxer = check(buf)?;
+        assert_eq!(*demuxer.info().duration.as_ref().unwrap().v, 2000.0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_muxer_live() -> Result<(), anyhow::Error> {
+        let mut muxer = Muxer::new(vec![], info(), tracks()).live();
+        for frame in frames() {
+            muxer.write_frame(frame).await?;
+        }
+        let buf = muxer.finish().await?;
+
+        let segment_start = matroska_header().encoded_size()? as usize + 4;
+        assert_eq!(buf[segment_start], 0xFF);
+
+        let mut demuxer = Demuxer::open_blocking(std::io::Cursor::new(buf))?;
+        assert!(demuxer.info().duration.is_none());
+        assert!(demuxer.seek_head().is_empty());
+        let frames = frames();
+        for frame in &frames {
+            let demuxed = demuxer.next_frame_blocking()?.unwrap();
+            assert_eq!((demuxed.track, demuxed.pts_ns, demuxed.keyframe), (frame.track, frame.pts_ns, frame.keyframe));
+            assert_eq!(demuxed.data, frame.data);
+        }
+        assert!(demuxer.next_frame_blocking()?.is_none());
+        Ok(())
+    }
+}
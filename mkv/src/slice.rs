@@ -0,0 +1,228 @@
+use time::OffsetDateTime;
+
+use super::io;
+use super::ids::EbmlId;
+use super::{BlockView, ElementReadBlocking, ElementSize, MatroskaError, ReadError, ReadOptions};
+
+/// Element of a byte slice, e.g. of a memory-mapped file, its body is borrowed from the slice.
+///
+/// Masters are walked with `children`, binary payloads and blocks are returned without copying.
+#[derive(Debug, Clone)]
+pub struct ElementView<'a> {
+    pub id: u64,
+    /// Position of the element header in the slice passed to `elements`
+    pub position: u64,
+    pub header_len: u64,
+    /// `Unknown` for live Segment and Cluster, `data` then ends at the first element which isn't their child
+    pub size: ElementSize,
+    pub data: &'a [u8],
+    /// EBML path of the element for the errors, e.g. `Segment\Cluster`
+    path: String,
+}
+
+/// Top level elements of the slice, usually EbmlHeader and Segment
+pub fn elements(buf: &[u8]) -> Elements<'_> {
+    Elements { buf, pos: 0, base: 0, path: String::new() }
+}
+
+/// Iterator over the elements of a slice, it stops after the first error
+#[derive(Debug, Clone)]
+pub struct Elements<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    /// Position of `buf` in the slice passed to `elements`
+    base: u64,
+    /// EBML path of the parent, empty for the top level elements
+    path: String,
+}
+
+impl<'a> Iterator for Elements<'a> {
+    type Item = Result<ElementView<'a>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.buf.len() {
+            return None;
+        }
+        let view = self.read_element();
+        self.pos = match &view {
+            Ok(view) => (view.position - self.base) as usize + view.header_len as usize + view.data.len(),
+            Err(_) => self.buf.len(),
+        };
+        Some(view)
+    }
+}
+
+impl<'a> Elements<'a> {
+    fn read_element(&self) -> Result<ElementView<'a>, ReadError> {
+        let position = self.base + self.pos as u64;
+        let path = &self.path;
+        let mut r = &self.buf[self.pos..];
+        let (id, size, header_len) = io::blocking::read_element_raw_id_size(&mut r)
+            .map_err(|err| ReadError::new(err, position, path))?;
+        let start = self.pos + header_len as usize;
+        let end = match size {
+            ElementSize::Sized(size) => start.checked_add(size as usize).filter(|end| *end <= self.buf.len()),
+            ElementSize::Unknown(_) => match EbmlId::from_u64(id) {
+                Ok(master) if master.unknown_size_allowed() => Some(unknown_size_end(self.buf, start, self.base, &name_path(path, id), master)?),
+                _ => return Err(ReadError::new(MatroskaError::UnknownSizeNotAllowed(id), position, path)),
+            },
+        };
+        let Some(end) = end else {
+            let eof = MatroskaError::Io(std::io::ErrorKind::UnexpectedEof.into());
+            return Err(ReadError::new(eof, position, path));
+        };
+        Ok(ElementView { id, position, header_len, size, data: &self.buf[start..end], path: name_path(path, id) })
+    }
+}
+
+/// Path of the element `id` in the parent at `path`
+fn name_path(path: &str, id: u64) -> String {
+    let name = match EbmlId::from_u64(id) {
+        Ok(id) => format!("{id:?}"),
+        Err(_) => format!("0x{id:X}"),
+    };
+    if path.is_empty() { name } else { format!("{path}\\{name}") }
+}
+
+/// End of the master with the unknown size: the first element which isn't its child or the end of the slice
+fn unknown_size_end(buf: &[u8], start: usize, base: u64, path: &str, master: EbmlId) -> Result<usize, ReadError> {
+    let mut children = Elements { buf, pos: start, base, path: path.to_string() };
+    while children.pos < buf.len() {
+        let mut r = &buf[children.pos..];
+        if let Ok((id, _, _)) = io::blocking::read_element_raw_id_size(&mut r) {
+            let child = match EbmlId::from_u64(id) {
+                Ok(EbmlId::Void | EbmlId::Crc32) | Err(_) => true,
+                Ok(id) => id.parent() == Some(master),
+            };
+            if !child {
+                break;
+            }
+        }
+        if let Some(Err(err)) = children.next() {
+            return Err(err);
+        }
+    }
+    Ok(children.pos)
+}
+
+impl<'a> ElementView<'a> {
+    /// `None` for elements unknown to the schema
+    pub fn ebml_id(&self) -> Option<EbmlId> {
+        EbmlId::from_u64(self.id).ok()
+    }
+    /// Position of the body in the slice passed to `elements`
+    pub fn body_position(&self) -> u64 {
+        self.position + self.header_len
+    }
+
+    pub fn children(&self) -> Elements<'a> {
+        Elements { buf: self.data, pos: 0, base: self.body_position(), path: self.path.clone() }
+    }
+    /// First child with the ID
+    pub fn child(&self, id: EbmlId) -> Result<Option<ElementView<'a>>, ReadError> {
+        for child in self.children() {
+            let child = child?;
+            if child.id == id as u64 {
+                return Ok(Some(child));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn uint(&self) -> Result<u64, ReadError> {
+        self.value(io::blocking::read_uint)
+    }
+    pub fn int(&self) -> Result<i64, ReadError> {
+        self.value(io::blocking::read_int)
+    }
+    pub fn float(&self) -> Result<f64, ReadError> {
+        self.value(io::blocking::read_float)
+    }
+    pub fn string(&self) -> Result<String, ReadError> {
+        self.value(io::blocking::read_string)
+    }
+    pub fn utf8(&self) -> Result<String, ReadError> {
+        self.value(io::blocking::read_utf8)
+    }
+    pub fn date(&self) -> Result<OffsetDateTime, ReadError> {
+        self.value(io::blocking::read_date)
+    }
+    /// Body of a binary element, e.g. CodecPrivate or FileData
+    pub fn bin(&self) -> &'a [u8] {
+        self.data
+    }
+    /// `SimpleBlock` or `BlockGroup/Block`
+    pub fn block(&self) -> Result<BlockView<'a>, ReadError> {
+        BlockView::parse(self.data).map_err(|err| self.error(err))
+    }
+
+    /// Parse the master into the generated struct, e.g. Info or Tracks, the payloads are copied
+    pub fn parse<T: ElementReadBlocking<&'a [u8]>>(&self, options: ReadOptions) -> Result<T, ReadError> {
+        let (element, _) = T::read_body_with(&mut &self.data[..], ElementSize::Sized(self.data.len() as u64), options)
+            .map_err(|err| err.at(&self.path, self.body_position()))?;
+        Ok(element)
+    }
+
+    fn value<T>(&self, read: fn(&mut &'a [u8], u64) -> Result<T, MatroskaError>) -> Result<T, ReadError> {
+        read(&mut &self.data[..], self.data.len() as u64).map_err(|err| self.error(err))
+    }
+    fn error(&self, err: MatroskaError) -> ReadError {
+        ReadError::new(err, self.body_position(), &self.path)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, Muxer};
+    use crate::structs::Info;
+
+    #[test]
+    fn test_slice() -> Result<(), anyhow::Error> {
+        let mut track = test_util::video_track(1, "V_TEST");
+        track.codec_private = Some(crate::Ebml::new(vec![1, 2, 3]));
+        // the live muxer writes the Segment and the Clusters with the unknown size
+        let muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![track]).live();
+        let buf = test_util::write_frames(muxer, 1, 3)?;
+
+        let top = elements(&buf).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(top.len(), 2);
+        let segment = &top[1];
+        assert_eq!(segment.ebml_id(), Some(EbmlId::Segment));
+        assert!(matches!(segment.size, ElementSize::Unknown(_)));
+        assert_eq!(segment.position + segment.header_len + segment.data.len() as u64, buf.len() as u64);
+
+        let info: Info = segment.child(EbmlId::Info)?.expect("Info").parse(ReadOptions::default())?;
+        assert_eq!(*info.muxing_app.v, "mkv-rs");
+        let track = segment.child(EbmlId::Tracks)?.expect("Tracks").child(EbmlId::TrackEntry)?.expect("TrackEntry");
+        let codec_private = track.child(EbmlId::CodecPrivate)?.expect("CodecPrivate").bin();
+        assert_eq!(codec_private, &[1, 2, 3]);
+        // borrowed from the file buffer
+        let offset = codec_private.as_ptr() as usize - buf.as_ptr() as usize;
+        assert_eq!(&buf[offset..offset + 3], codec_private);
+
+        let mut frames = vec![];
+        for cluster in segment.children() {
+            let cluster = cluster?;
+            if cluster.ebml_id() != Some(EbmlId::Cluster) {
+                continue;
+            }
+            let timestamp = cluster.child(EbmlId::Timestamp)?.expect("Timestamp").uint()?;
+            for child in cluster.children() {
+                let child = child?;
+                if child.ebml_id() == Some(EbmlId::SimpleBlock) {
+                    let block = child.block()?;
+                    frames.push((timestamp as i64 + block.timecode as i64, block.keyframe(), block.data));
+                }
+            }
+        }
+        assert_eq!(frames, vec![(0, true, &[0u8; 10][..]), (40, false, &[1; 10]), (80, false, &[2; 10])]);
+
+        // truncated file
+        let err = elements(&buf[..buf.len() - 2]).nth(1).expect("Segment").expect_err("truncated SimpleBlock");
+        assert!(matches!(err.kind, MatroskaError::Io(_)));
+        assert_eq!(err.path, "\\Segment\\Cluster");
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use super::io;
+use super::ids::EbmlId;
+use super::element::ElementContent;
+use super::{Block, ElementSize, ElementType, MatroskaError, ReadError};
+
+/// Event emitted by `EbmlStreamParser`
+#[derive(Debug)]
+pub enum Event {
+    /// Start of a master element, the size is unknown for live Segment and Cluster
+    Start { id: EbmlId, size: ElementSize, position: u64 },
+    End { id: EbmlId },
+    Value { id: EbmlId, value: ElementContent },
+    /// `SimpleBlock` or `BlockGroup/Block`
+    Block { id: EbmlId, block: Block },
+    /// Element unknown to the schema, its body is skipped
+    Unknown { id: u64, size: u64, position: u64 },
+}
+
+struct Open {
+    id: EbmlId,
+    /// `None` for masters with the unknown size, they end at the first element which isn't their child
+    end: Option<u64>,
+}
+
+/// Push parser for input arriving in chunks, e.g. from a socket.
+///
+/// Only the element being parsed is buffered: masters are reported by `Start`/`End`
+/// events, leaf elements are kept until they are complete and unknown elements are
+/// dropped as they arrive.
+#[derive(Default)]
+pub struct EbmlStreamParser {
+    buf: Vec<u8>,
+    /// Consumed bytes at the start of `buf`
+    start: usize,
+    /// Position of `buf[start]` in the stream
+    pos: u64,
+    stack: Vec<Open>,
+    /// Bytes of the unknown element body left to drop
+    skip: u64,
+}
+
+impl EbmlStreamParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Position in the stream of the next byte to parse
+    pub fn position(&self) -> u64 { self.pos }
+    /// Masters which are started and not ended yet
+    pub fn depth(&self) -> usize { self.stack.len() }
+
+    pub fn feed(&mut self, data: &[u8]) {
+        if self.start > 0 && self.start * 2 >= self.buf.len() {
+            self.buf.drain(..self.start);
+            self.start = 0;
+        }
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Feed the chunk and parse all events which are complete
+    pub fn push(&mut self, data: &[u8]) -> Result<Vec<Event>, ReadError> {
+        self.feed(data);
+        let mut events = vec![];
+        while let Some(event) = self.next_event()? {
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    /// End of input: close the masters with the unknown size, fails on a truncated element
+    pub fn finish(&mut self) -> Result<Vec<Event>, ReadError> {
+        let truncated = self.stack.iter().any(|open| open.end.is_some_and(|end| end > self.pos));
+        if self.skip > 0 || self.start < self.buf.len() || truncated {
+            return Err(self.error(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into(), self.pos));
+        }
+        let mut events = vec![];
+        while let Some(open) = self.stack.pop() {
+            events.push(Event::End { id: open.id });
+        }
+        Ok(events)
+    }
+
+    /// Next complete event, `None` if more data is needed
+    pub fn next_event(&mut self) -> Result<Option<Event>, ReadError> {
+        if let Some(Open { id, end: Some(end) }) = self.stack.last() {
+            if self.pos >= *end {
+                let id = *id;
+                self.stack.pop();
+                return Ok(Some(Event::End { id }));
+            }
+        }
+        if self.skip > 0 {
+            let len = self.skip.min((self.buf.len() - self.start) as u64);
+            self.consume(len as usize);
+            self.skip -= len;
+            if self.skip > 0 {
+                return Ok(None);
+            }
+        }
+
+        let mut r = &self.buf[self.start..];
+        let (raw_id, size, header_len) = match read_header(&mut r) {
+            Ok(header) => header,
+            Err(MatroskaError::Io(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(self.error(err, self.pos)),
+        };
+        let id = EbmlId::from_u64(raw_id).ok();
+
+        // https://www.rfc-editor.org/rfc/rfc8794.html#name-unknown-data-size
+        if let Some(Open { id: parent, end: None }) = self.stack.last() {
+            let parent = *parent;
+            let child = match id {
+                Some(EbmlId::Void | EbmlId::Crc32) | None => true,
+                Some(id) => id.parent() == Some(parent),
+            };
+            if !child {
+                self.stack.pop();
+                return Ok(Some(Event::End { id: parent }));
+            }
+        }
+
+        let position = self.pos;
+        let body_end = match size {
+            ElementSize::Sized(size) => Some(position + header_len + size),
+            ElementSize::Unknown(_) => None,
+        };
+        if let Some(Open { end: Some(end), .. }) = self.stack.last() {
+            if body_end.is_none_or(|body_end| body_end > *end) {
+                return Err(self.error(MatroskaError::ElementOverflow(raw_id), position));
+            }
+        }
+
+        let Some(id) = id else {
+            let ElementSize::Sized(size) = size else {
+                return Err(self.error(MatroskaError::UnknownSizeNotAllowed(raw_id), position));
+            };
+            self.consume(header_len as usize);
+            self.skip = size;
+            return Ok(Some(Event::Unknown { id: raw_id, size, position }));
+        };
+
+        if id.type_() == ElementType::Struct {
+            self.consume(header_len as usize);
+            self.stack.push(Open { id, end: body_end });
+            return Ok(Some(Event::Start { id, size, position }));
+        }
+
+        let size = size.try_sized(id).map_err(|err| self.error(err, position))?;
+        if ((self.buf.len() - self.start) as u64) < header_len + size {
+            return Ok(None);
+        }
+        let mut r = &self.buf[self.start + header_len as usize..self.start + (header_len + size) as usize];
+        let event = match id {
+            EbmlId::SimpleBlock | EbmlId::Block => io::blocking::read_block(&mut r, size).map(|block| Event::Block { id, block }),
+            _ => read_value(&mut r, id.type_(), size).map(|value| Event::Value { id, value }),
+        };
+        let event = event.map_err(|err| self.error(err, position))?;
+        self.consume((header_len + size) as usize);
+        Ok(Some(event))
+    }
+
+    /// Error of the element at `position` in the masters which are started
+    fn error(&self, kind: MatroskaError, position: u64) -> ReadError {
+        let path: Vec<String> = self.stack.iter().map(|open| format!("{:?}", open.id)).collect();
+        ReadError::new(kind, position, &path.join("\\"))
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.start += len;
+        self.pos += len as u64;
+    }
+}
+
+fn read_header(r: &mut &[u8]) -> Result<(u64, ElementSize, u64), MatroskaError> {
+    let (id, id_len) = io::blocking::read_element_id(r)?;
+    let (size, size_len) = io::blocking::read_element_size(r)?;
+    if let (Ok(known), ElementSize::Unknown(_)) = (EbmlId::from_u64(id), size) {
+        if !known.unknown_size_allowed() {
+            return Err(MatroskaError::InvalidSize(known, size));
+        }
+    }
+    Ok((id, size, id_len + size_len))
+}
+
+fn read_value(r: &mut &[u8], type_: ElementType, size: u64) -> Result<ElementContent, MatroskaError> {
+    Ok(match type_ {
+        ElementType::UInteger => ElementContent::UInteger(io::blocking::read_uint(r, size)?),
+        ElementType::Integer => ElementContent::Integer(io::blocking::read_int(r, size)?),
+        ElementType::Float => ElementContent::Float(io::blocking::read_float(r, size)?),
+        ElementType::String => ElementContent::String(io::blocking::read_string(r, size)?),
+        ElementType::Utf8 => ElementContent::Utf8(io::blocking::read_utf8(r, size)?),
+        ElementType::Binary => ElementContent::Binary(io::blocking::read_bin(r, size)?),
+        ElementType::Date => ElementContent::Date(io::blocking::read_date(r, size)?),
+        ElementType::Struct => unreachable!("masters are reported by Start and End events"),
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    fn parse(buf: &[u8], chunk: usize) -> Result<Vec<Event>, anyhow::Error> {
+        let mut parser = EbmlStreamParser::new();
+        let mut events = vec![];
+        for chunk in buf.chunks(chunk) {
+            events.append(&mut parser.push(chunk)?);
+        }
+        events.append(&mut parser.finish()?);
+        Ok(events)
+    }
+
+    #[test]
+    fn test_stream_parser() -> Result<(), anyhow::Error> {
+        for live in [false, true] {
+            let buf = test_util::recording(test_util::info(), live)?;
+
+            let expected = parse(&buf, buf.len())?;
+            for chunk in [1, 7, 100] {
+                let events = parse(&buf, chunk)?;
+                assert_eq!(format!("{events:?}"), format!("{expected:?}"), "live {live}, chunk {chunk}");
+            }
+
+            let blocks: Vec<&Block> = expected.iter().filter_map(|event| match event {
+                Event::Block { block, .. } => Some(block),
+                _ => None,
+            }).collect();
+            assert_eq!(blocks.len(), 30);
+            assert_eq!(blocks[29].data, vec![29; 10]);
+            let clusters = expected.iter().filter(|event| matches!(event, Event::End { id: EbmlId::Cluster })).count();
+            assert_eq!(clusters, 3);
+            assert!(matches!(expected.last(), Some(Event::End { id: EbmlId::Segment })));
+        }
+
+        let mut parser = EbmlStreamParser::new();
+        let events = parser.push(&[0x4A, 0xBC, 0x84, 1, 2])?;
+        assert!(matches!(events[..], [Event::Unknown { id: 0x4ABC, size: 4, position: 0 }]));
+        assert!(parser.finish().is_err());
+        assert!(parser.push(&[3, 4])?.is_empty());
+        assert!(parser.finish()?.is_empty());
+
+        // TimestampScale of 1 byte doesn't fit the Info of 4 bytes
+        let mut parser = EbmlStreamParser::new();
+        let err = parser.push(&[0x15, 0x49, 0xA9, 0x66, 0x84, 0x2A, 0xD7, 0xB1, 0x81, 1]).expect_err("overflow");
+        assert!(matches!(err.kind, MatroskaError::ElementOverflow(0x2AD7B1)));
+        assert_eq!((err.offset, err.path.as_str()), (5, "\\Info"));
+        Ok(())
+    }
+}
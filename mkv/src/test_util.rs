@@ -0,0 +1,45 @@
+use std::io::Cursor;
+
+use super::enums::TrackType;
+use super::structs::{Info, TrackEntry};
+use super::{Frame, Muxer};
+
+// Recordings shared by the tests of the modules
+
+pub(crate) fn info() -> Info {
+    let mut info = Info::default();
+    *info.timestamp_scale.v = 1_000_000;
+    *info.muxing_app.v = "mkv-rs".to_string();
+    *info.writing_app.v = "mkv-rs".to_string();
+    info
+}
+
+pub(crate) fn video_track(number: u64, codec_id: &str) -> TrackEntry {
+    let mut track = TrackEntry::default();
+    *track.track_number.v = number;
+    *track.track_uid.v = number;
+    *track.track_type.v = TrackType::Video;
+    *track.codec_id.v = codec_id.to_string();
+    *track.track_timestamp_scale.v = 1.0;
+    track
+}
+
+/// Frames `0..count` of the track 40 ms apart with a keyframe every 10 frames, frame `i` is `[i; 10]`
+pub(crate) fn write_frames(mut muxer: Muxer<Cursor<Vec<u8>>>, track: u64, count: u8) -> Result<Vec<u8>, anyhow::Error> {
+    for i in 0..count {
+        let frame = Frame { track, pts_ns: i as i64 * 40_000_000, duration: None, keyframe: i % 10 == 0, data: vec![i; 10] };
+        muxer.write_frame_blocking(frame)?;
+    }
+    Ok(muxer.finish_seekable_blocking()?.into_inner())
+}
+
+/// 30 frames of the video track 1, the live one keeps the unknown Segment and Cluster sizes
+pub(crate) fn recording(info: Info, live: bool) -> Result<Vec<u8>, anyhow::Error> {
+    let muxer = Muxer::new(Cursor::new(vec![]), info, vec![video_track(1, "V_TEST")]);
+    recording_with(if live { muxer.live() } else { muxer })
+}
+
+/// 30 frames of the track 1 written by the muxer, e.g. of another track type or with another header
+pub(crate) fn recording_with(muxer: Muxer<Cursor<Vec<u8>>>) -> Result<Vec<u8>, anyhow::Error> {
+    write_frames(muxer, 1, 30)
+}
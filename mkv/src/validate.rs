@@ -0,0 +1,221 @@
+use super::structs::{EbmlHeader, Tracks};
+use super::{CodecPrivate, Demuxer, ReadError};
+
+/// WebM codecs, https://www.webmproject.org/docs/container/#codec-ids
+const WEBM_CODECS: [&str; 7] = ["V_VP8", "V_VP9", "V_AV1", "A_VORBIS", "A_OPUS", "D_WEBVTT/SUBTITLES", "S_TEXT/WEBVTT"];
+/// Last DocTypeVersion of the WebM specification
+const WEBM_VERSION: u64 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The value breaks the specification
+    Error,
+    /// The value is allowed but readers may not support it
+    Warning,
+}
+
+/// Spec violation found by `validate`, the path is the EBML path of the element,
+/// e.g. `\Segment\Tracks\TrackEntry[1]\TrackNumber`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub path: String,
+    pub message: String,
+}
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}: {}", self.severity, self.path, self.message)
+    }
+}
+
+/// State of the validation pass: the document version from the EBML header and the diagnostics
+#[derive(Debug, Default)]
+pub struct Validation {
+    /// DocTypeVersion, the element versions are not checked without it
+    doc_type_version: Option<u64>,
+    webm: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validation {
+    /// Validation of the elements of the document with the header, the header itself is checked too
+    pub fn new(header: &EbmlHeader) -> Self {
+        let mut v = Self {
+            doc_type_version: Some(*header.doc_type_version.v),
+            webm: header.doc_type.v.as_str() == "webm",
+            diagnostics: vec![],
+        };
+        let path = "\\EBML";
+        header.validate_with(&mut v, path);
+        let (doc_type, version, read_version) = (header.doc_type.v.as_str(), *header.doc_type_version.v, *header.doc_type_read_version.v);
+        if doc_type != "matroska" && doc_type != "webm" {
+            v.error(&format!("{path}\\DocType"), format!("'{doc_type}' is not 'matroska' or 'webm'"));
+        }
+        if version == 0 {
+            v.error(&format!("{path}\\DocTypeVersion"), "must not be 0".to_string());
+        }
+        if read_version == 0 || read_version > version {
+            v.error(&format!("{path}\\DocTypeReadVersion"), format!("{read_version} must be in 1-{version}"));
+        }
+        if v.webm && version > WEBM_VERSION {
+            v.warning(&format!("{path}\\DocTypeVersion"), format!("{version} is newer than the WebM version {WEBM_VERSION}"));
+        }
+        v
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] { &self.diagnostics }
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> { self.diagnostics }
+
+    /// Check the elements of Tracks the schema can't express, e.g. the codecs of WebM or
+    /// the audio parameters against the CodecPrivate
+    pub fn tracks(&mut self, tracks: &Tracks, path: &str) {
+        tracks.validate_with(self, path);
+        for (i, track) in tracks.track_entry.iter().enumerate() {
+            if track.v.codec_private.is_none() {
+                continue;
+            }
+            match CodecPrivate::from_track(&track.v) {
+                Ok(codec_private) => {
+                    for (element, message) in codec_private.map(|codec_private| codec_private.check(&track.v)).unwrap_or_default() {
+                        self.warning(&format!("{path}\\TrackEntry[{i}]\\{element}"), message);
+                    }
+                }
+                Err(err) => self.error(&format!("{path}\\TrackEntry[{i}]\\CodecPrivate"), err.to_string()),
+            }
+        }
+        if !self.webm {
+            return;
+        }
+        for (i, track) in tracks.track_entry.iter().enumerate() {
+            let codec = track.v.codec_id.v.as_str();
+            if !WEBM_CODECS.contains(&codec) {
+                self.error(&format!("{path}\\TrackEntry[{i}]\\CodecID"), format!("codec '{codec}' is not allowed in WebM"));
+            }
+        }
+    }
+
+    pub(crate) fn error(&mut self, path: &str, message: String) {
+        self.diagnostics.push(Diagnostic { severity: Severity::Error, path: path.to_string(), message });
+    }
+    pub(crate) fn warning(&mut self, path: &str, message: String) {
+        self.diagnostics.push(Diagnostic { severity: Severity::Warning, path: path.to_string(), message });
+    }
+    /// `minver`/`maxver` of the element against DocTypeVersion, `maxver` 0 is an element
+    /// which is reserved and not part of Matroska
+    pub(crate) fn version(&mut self, path: &str, minver: u64, maxver: Option<u64>) {
+        if maxver == Some(0) {
+            self.warning(path, "element is not part of the specification".to_string());
+            return;
+        }
+        let Some(version) = self.doc_type_version else { return };
+        if version < minver {
+            self.warning(path, format!("element requires DocTypeVersion {minver}, the document is {version}"));
+        }
+        if let Some(maxver) = maxver.filter(|maxver| version > *maxver) {
+            self.warning(path, format!("element is deprecated after DocTypeVersion {maxver}, the document is {version}"));
+        }
+    }
+    pub(crate) fn not_webm(&mut self, path: &str) {
+        if self.webm {
+            self.error(path, "element is not allowed in WebM".to_string());
+        }
+    }
+
+    fn demuxer<R>(&mut self, demuxer: &Demuxer<R>) {
+        demuxer.info().validate_with(self, "\\Segment\\Info");
+        for (i, seek_head) in demuxer.seek_head().iter().enumerate() {
+            seek_head.validate_with(self, &format!("\\Segment\\SeekHead[{i}]"));
+        }
+        if let Some(tracks) = demuxer.tracks() {
+            self.tracks(tracks, "\\Segment\\Tracks");
+        }
+        if let Some(chapters) = demuxer.chapters() {
+            chapters.validate_with(self, "\\Segment\\Chapters");
+        }
+        for (i, tags) in demuxer.tags().iter().enumerate() {
+            tags.validate_with(self, &format!("\\Segment\\Tags[{i}]"));
+        }
+        if let Some(cues) = demuxer.cues() {
+            cues.validate_with(self, "\\Segment\\Cues");
+        }
+    }
+}
+
+/// Validate the header and the Segment metadata of the file: Info, SeekHead, Tracks,
+/// Chapters, Tags and Cues, the clusters are not read
+pub fn validate_file_blocking<R: std::io::Read + std::io::Seek>(r: R) -> Result<Vec<Diagnostic>, ReadError> {
+    let mut demuxer = Demuxer::open_blocking(r)?;
+    demuxer.load_cues_blocking()?;
+    let mut v = Validation::new(demuxer.header());
+    v.demuxer(&demuxer);
+    Ok(v.into_diagnostics())
+}
+
+/// Validate the header and the Segment metadata of the file: Info, SeekHead, Tracks,
+/// Chapters, Tags and Cues, the clusters are not read
+pub async fn validate_file<R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Unpin>(r: R) -> Result<Vec<Diagnostic>, ReadError> {
+    let mut demuxer = Demuxer::open(r).await?;
+    demuxer.load_cues().await?;
+    let mut v = Validation::new(demuxer.header());
+    v.demuxer(&demuxer);
+    Ok(v.into_diagnostics())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, Muxer};
+    use crate::structs::{Info, TrackEntry};
+    use crate::enums::TrackType;
+
+    /// WebM recording of the video track 1 with the codec
+    fn webm(codec: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let mut header = crate::muxer::matroska_header();
+        *header.doc_type.v = "webm".to_string();
+        let muxer = Muxer::new(std::io::Cursor::new(vec![]), test_util::info(), vec![test_util::video_track(1, codec)]);
+        test_util::recording_with(muxer.with_header(header))
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), anyhow::Error> {
+        let diagnostics = validate_file_blocking(std::io::Cursor::new(test_util::recording(test_util::info(), false)?))?;
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        let mut info = Info::default();
+        *info.timestamp_scale.v = 1_000_000;
+        info.segment_uuid = Some(crate::Ebml::new(vec![0; 8]));
+        let diagnostics = info.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].path, "\\Segment\\Info\\SegmentUUID");
+
+        let mut track = TrackEntry::default();
+        *track.track_uid.v = 1;
+        *track.track_type.v = TrackType::Other(99);
+        *track.track_timestamp_scale.v = 1.0;
+        let mut tracks = Tracks::default();
+        tracks.track_entry.push(crate::Ebml::new(track.clone()));
+        // Complex kept in Other is a listed value
+        *track.track_type.v = TrackType::Other(3);
+        tracks.track_entry.push(crate::Ebml::new(track));
+        let paths: Vec<String> = tracks.validate().into_iter().map(|diagnostic| diagnostic.path).collect();
+        assert_eq!(paths, [
+            "\\Segment\\Tracks\\TrackEntry[0]\\TrackNumber",
+            "\\Segment\\Tracks\\TrackEntry[0]\\TrackType",
+            "\\Segment\\Tracks\\TrackEntry[1]\\TrackNumber",
+        ]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_webm() -> Result<(), anyhow::Error> {
+        let diagnostics = validate_file(std::io::Cursor::new(webm("V_VP9")?)).await?;
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        let diagnostics = validate_file(std::io::Cursor::new(webm("V_MPEG4/ISO/AVC")?)).await?;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "\\Segment\\Tracks\\TrackEntry[0]\\CodecID");
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::parser::ebml;
+use ebml::ElementType;
+
+/// Enums for elements with the `<restriction>` values, unlisted values are kept in `Other`.
+/// `Other` built directly can still hold a listed value, so the enums are compared by the value
+pub fn generate(ebml_matroska: &ebml::EBMLMatroska) -> String {
+    let mut str = String::new();
+    str += "// The code was generated by codegen!\n";
+    str += "\n";
+    for element in &ebml_matroska.sorted_elements() {
+        let Some(name) = element.enum_name() else { continue };
+        let string = element.type_ == ElementType::String;
+        let native = if string { "String" } else { "u64" };
+
+        str += &format!("/// Values of the `{name}` element\n");
+        if string {
+            str += "#[derive(Debug, Clone)]\n";
+        } else {
+            str += "#[derive(Debug, Clone, Copy)]\n";
+        }
+        str += &format!("pub enum {name} {{\n");
+        for value in &element.enums {
+            str += &format!("    /// {}\n", value.label);
+            str += &format!("    {},\n", value.variant);
+        }
+        str += "    /// Value not listed in the specification, `From` never builds it with a listed one\n";
+        str += &format!("    Other({native}),\n");
+        str += "}\n";
+
+        let literal = |value: &str| if string { format!("\"{value}\"") } else { value.to_string() };
+        str += &format!("impl From<{native}> for {name} {{\n");
+        str += &format!("    fn from(val: {native}) -> Self {{\n");
+        if string {
+            str += "        match val.as_str() {\n";
+        } else {
+            str += "        match val {\n";
+        }
+        for value in &element.enums {
+            str += &format!("            {} => Self::{},\n", literal(&value.value), value.variant);
+        }
+        str += "            _ => Self::Other(val),\n";
+        str += "        }\n";
+        str += "    }\n";
+        str += "}\n";
+
+        str += &format!("impl {name} {{\n");
+        if string {
+            str += "    pub fn as_str(&self) -> &str {\n";
+        } else {
+            str += "    pub fn value(&self) -> u64 {\n";
+        }
+        str += "        match self {\n";
+        for value in &element.enums {
+            str += &format!("            Self::{} => {},\n", value.variant, literal(&value.value));
+        }
+        str += &format!("            Self::Other(val) => {},\n", if string { "val.as_str()" } else { "*val" });
+        str += "        }\n";
+        str += "    }\n";
+        str += "}\n";
+
+        let value = if string { "as_str" } else { "value" };
+        str += &format!("impl PartialEq for {name} {{\n");
+        str += &format!("    fn eq(&self, other: &Self) -> bool {{ self.{value}() == other.{value}() }}\n");
+        str += "}\n";
+        str += &format!("impl Eq for {name} {{}}\n");
+        str += &format!("impl std::hash::Hash for {name} {{\n");
+        str += &format!("    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {{ self.{value}().hash(state) }}\n");
+        str += "}\n";
+
+        str += &format!("impl From<{name}> for {native} {{\n");
+        if string {
+            str += &format!("    fn from(val: {name}) -> Self {{ val.as_str().to_string() }}\n");
+        } else {
+            str += &format!("    fn from(val: {name}) -> Self {{ val.value() }}\n");
+        }
+        str += "}\n";
+
+        // same as the zero value of the native type, what the structs default to
+        str += &format!("impl Default for {name} {{\n");
+        str += &format!("    fn default() -> Self {{ Self::from({native}::default()) }}\n");
+        str += "}\n";
+        str += "\n";
+    }
+    str
+}
@@ -46,6 +46,19 @@ impl EbmlId {
     str += "
         }
     }
+    pub fn parent(&self) -> Option<EbmlId> {
+        match self {
+";
+    for element in &ebml_matroska.sorted_elements() {
+        let parent = match element.path.last() {
+            Some(parent) => format!("Some(Self::{})", parent.element.id_enum()),
+            None => "None".to_string(),
+        };
+        str += &format!("            Self::{} => {parent},\n", element.id_enum());
+    }
+    str += "
+        }
+    }
 }
 ";
     // for element in &ebml_matroska.sorted_elements() {
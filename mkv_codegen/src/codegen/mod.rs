@@ -1,9 +1,11 @@
 
 mod ids;
 mod structs;
+mod enums;
 
 mod reader;
 mod writer;
+mod validator;
 
 use crate::parser::ebml;
 use crate::parser::matroska;
@@ -20,8 +22,10 @@ pub fn run(path: &std::path::Path) -> Result<(), anyhow::Error> {
 
     std::fs::write(path.join("mod.rs"), generate_mod(&ebml_matroska))?;
     std::fs::write(path.join("structs.rs"), structs::define_structs(&ebml_matroska))?;
+    std::fs::write(path.join("enums.rs"), enums::generate(&ebml_matroska))?;
     std::fs::write(path.join("reader.rs"), reader::generate(&ebml_matroska)?)?;
     std::fs::write(path.join("writer.rs"), writer::generate(&ebml_matroska)?)?;
+    std::fs::write(path.join("validator.rs"), validator::generate(&ebml_matroska)?)?;
 
 
     std::fs::write(path.join("ids.rs"), ids::generate(&ebml_matroska))?;
@@ -42,6 +46,8 @@ pub mod ids;
 pub mod reader;
 pub mod writer;
 pub mod structs;
+pub mod enums;
+pub mod validator;
 
 use super::*;
 ";
@@ -54,4 +60,17 @@ pub(super) fn to_async(async_: bool, str: &str) -> String {
     } else {
         format!("blocking::{str}")
     }
-}
\ No newline at end of file
+}
+/// Spec default of the element as a Rust expression of the field type
+pub(super) fn default_code(element: &ebml::Element) -> Option<String> {
+    let default = element.default.as_ref()?;
+    let default = match element.type_ {
+        ebml::ElementType::Float => format!("hexf::hexf64!(\"{default}\")"),
+        ebml::ElementType::String | ebml::ElementType::Utf8 => format!("\"{default}\".to_string()"),
+        _ => default.clone(),
+    };
+    Some(match element.enum_name() {
+        Some(enum_name) => format!("{enum_name}::from({default})"),
+        None => default,
+    })
+}
@@ -1,6 +1,6 @@
 use crate::parser::ebml;
 use ebml::{TypeAttr, ElementType};
-use super::to_async;
+use super::{to_async, default_code};
 
 pub fn generate(ebml_matroska: &ebml::EBMLMatroska) -> Result<String, anyhow::Error> {
     let mut str = format!("");
@@ -12,8 +12,11 @@ use std::collections::VecDeque;
 
 use super::io::*;
 use super::structs::*;
+use super::enums::*;
 use super::ids::EbmlId;
-use super::{Ebml, ElementSize};
+use super::block::Block;
+use super::{Ebml, ElementEncoding, ElementSize, MatroskaError, RawElement, ReadError, ReadOptions};
+use super::crc32;
 
 ";
     for struct_ in &ebml_matroska.sorted_strcuts() {
@@ -21,6 +24,7 @@ use super::{Ebml, ElementSize};
         str += &impl_read(struct_, false);
         str += &impl_read_header(struct_, false);
         str += &impl_read_body(struct_, false);
+        str += &impl_read_body_with(struct_, false);
         str += "}\n";
 
         str += &format!("#[async_trait::async_trait]\n");
@@ -28,6 +32,7 @@ use super::{Ebml, ElementSize};
             str += &impl_read(struct_, true);
             str += &impl_read_header(struct_, true);
             str += &impl_read_body(struct_, true);
+            str += &impl_read_body_with(struct_, true);
         str += "}\n";
         str += "\n";
         str += "\n";
@@ -35,13 +40,26 @@ use super::{Ebml, ElementSize};
     Ok(str)
 }
 
-pub fn impl_read(_struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
+pub fn impl_read(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut str = format!("");
+    let name = struct_.type_name();
     let async_key = if async_ { "async " } else { "" };
-    str += &format!("    {async_key}fn read(r: &mut R) -> Result<(Self, usize), anyhow::Error> {{\n");
     let await_ = if async_ { ".await" } else { "" };
+    str += &format!("    {async_key}fn read(r: &mut R) -> Result<(Self, usize), ReadError> {{\n");
+    str += &format!("        Self::read_with(r, ReadOptions::default()){await_}\n");
+    str += &format!("    }}\n");
+    str += &format!("    {async_key}fn read_with(r: &mut R, options: ReadOptions) -> Result<(Self, usize), ReadError> {{\n");
     str += &format!("        let (size, header_len) = Self::read_header(r){await_}?;\n");
-    str += &format!("        let (element, body_len) = Self::read_body(r, size){await_}?;\n");
+    if name == "Segment" {
+        str += &format!("        let (mut element, body_len) = Self::read_body_with(r, size, options){await_}\n");
+    } else {
+        str += &format!("        let (mut element, body_len) = match size {{\n");
+        str += &format!("            ElementSize::Sized(size) if options.verify_crc32 => {},\n", read_verified(async_, &name));
+        str += &format!("            _ => Self::read_body_with(r, size, options){await_},\n");
+        str += &format!("        }}\n");
+    }
+    str += &format!("            .map_err(|err| err.at(\"{name}\", header_len as u64))?;\n");
+    str += &format!("        if options.lossless {{ element.encoding = Some(ElementEncoding::new(EbmlId::{name} as u64, header_len as u64, size)); }}\n");
     str += &format!("        Ok((element, header_len + body_len))\n");
     str += &format!("    }}\n");
     str
@@ -49,124 +67,138 @@ pub fn impl_read(_struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
 
 pub fn impl_read_header(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut str = format!("");
+    let name = struct_.type_name();
     if async_ {
-        str += &format!("    async fn read_header(r: &mut R) -> Result<(ElementSize, usize), anyhow::Error> {{\n");
+        str += &format!("    async fn read_header(r: &mut R) -> Result<(ElementSize, usize), ReadError> {{\n");
     } else {
-        str += &format!("    fn read_header(r: &mut R) -> Result<(ElementSize, usize), anyhow::Error> {{\n");
+        str += &format!("    fn read_header(r: &mut R) -> Result<(ElementSize, usize), ReadError> {{\n");
     }
-    let to_async = |str: &str| if async_ {
-        format!("async_::{str}.await")
-    } else {
-        format!("blocking::{str}")
-    };
-    str += &format!("        let (id, size, header_len) = {}?;\n", to_async("read_element_id_size(r)"));
-    str += &format!("        if id != EbmlId::{} {{ return Err(anyhow::anyhow!(\"{} not found\")); }}\n", struct_.type_name(), struct_.type_name());
+    str += &format!("        let (id, size, header_len) = {}.map_err(|err| ReadError::new(err, 0, \"{name}\"))?;\n", to_async(async_, "read_element_raw_id_size(r)"));
+    str += &format!("        if id != EbmlId::{name} as u64 {{\n");
+    str += &format!("            return Err(ReadError::new(MatroskaError::UnexpectedId {{ expected: EbmlId::{name}, found: id }}, 0, \"{name}\"));\n");
+    str += &format!("        }}\n");
     str += &format!("        Ok((size, header_len as usize))\n");
     str += &format!("    }}\n");
     str += "\n";
     str
 }
 
-pub fn impl_read_body(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
+pub fn impl_read_body(_struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut str = format!("");
-    if async_ {
-        str += &format!("    async fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), anyhow::Error> {{\n");
-    } else {
-        str += &format!("    fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), anyhow::Error> {{\n");
-    }
+    let async_key = if async_ { "async " } else { "" };
+    let await_ = if async_ { ".await" } else { "" };
+    str += &format!("    {async_key}fn read_body(r: &mut R, size: ElementSize) -> Result<(Self, usize), ReadError> {{\n");
+    str += &format!("        Self::read_body_with(r, size, ReadOptions::default()){await_}\n");
+    str += &format!("    }}\n");
+    str
+}
+
+/// Errors carry the offset of the failed bytes from the start of the body and the path
+/// of the failed child, the parents prepend their names
+pub fn impl_read_body_with(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
+    let mut str = format!("");
+    let async_key = if async_ { "async " } else { "" };
+    str += &format!("    {async_key}fn read_body_with(r: &mut R, size: ElementSize, options: ReadOptions) -> Result<(Self, usize), ReadError> {{\n");
     if !struct_.element.unknown_size_allowed {
-        str += &format!("        let size = match size {{\n");
-        str += &format!("            ElementSize::Sized(size) => size,\n");
-        str += &format!("            ElementSize::Unknown(_) => return Err(anyhow::anyhow!(\"Element ID '{}' unknown data size is not allowed\")),\n", struct_.type_name());
-        str += &format!("        }};\n");
+        str += &format!("        let size = size.try_sized(EbmlId::{})?;\n", struct_.type_name());
     }
     for child in &struct_.children {
-        let type_name = child.element.type_name();
-        let type_name = child.element.type_.to_native(type_name);
+        let type_name = child.element.native_type();
         str += &format!("        let mut {}: VecDeque<Ebml<{}>> = VecDeque::new();\n", child.element.var_name(), type_name);
     }
+    str += &format!("        let mut unknown: Vec<Ebml<RawElement>> = Vec::new();\n");
     str += &format!("\n");
     str += &format!("        let mut index = 0;\n");
     str += &format!("        let mut all_size = 0;\n");
 
+    let read_id_size = format!("{}.map_err(|err| ReadError::new(err, all_size as u64, \"\"))?", to_async(async_, "read_element_raw_id_size(r)"));
     str += &format!("        loop {{\n");
     if struct_.element.unknown_size_allowed {
         str += &format!("            if let ElementSize::Sized(size) = size {{ if all_size >= size as usize {{ break }} }}\n");
-        str += &format!("            let (id, size, header_len) = {}?;\n", to_async(async_, "read_element_id_size(r)"));
+        str += &format!("            let (raw_id, size, header_len) = {read_id_size};\n");
         str += &format!("            all_size += header_len as usize;\n");
-        str += &format!("            if let ElementSize::Unknown(_) = size {{ if id == EbmlId::{}  {{ break }} }}\n", struct_.type_name());
+        str += &format!("            let id = EbmlId::from_u64(raw_id).ok();\n");
+        str += &format!("            if let ElementSize::Unknown(_) = size {{ if id == Some(EbmlId::{})  {{ break }} }}\n", struct_.type_name());
     } else {
         str += &format!("            if all_size >= size as usize {{ break }}\n");
-        str += &format!("            let (id, size, header_len) = {}?;\n", to_async(async_, "read_element_id_size(r)"));
+        str += &format!("            let (raw_id, size, header_len) = {read_id_size};\n");
         str += &format!("            all_size += header_len as usize;\n");
+        str += &format!("            let id = EbmlId::from_u64(raw_id).ok();\n");
     }
 
     str += &format!("            match id {{\n");
     for child in &struct_.children {
         let type_name = child.element.type_name();
-        let read_code = read_native(async_, &child.element.type_, type_name);
-        if child.element.unknown_size_allowed {
-            str += &format!("                EbmlId::{} => {{\n", child.element.type_name());
-            str += &format!("                    let (val, read) = {read_code}?; all_size += read;\n");
-            str += &format!("                    {}.push_back(Ebml::new_index(index, val));\n", child.element.var_name());
-            str += &format!("                }},\n");
+        let var = child.element.var_name();
+        let path = match child.element.attr {
+            TypeAttr::Repeated => format!("&format!(\"{type_name}[{{}}]\", {var}.len())"),
+            _ => format!("\"{type_name}\""),
+        };
+        let mut read_code = read_native(async_, &child.element.type_, type_name.clone());
+        str += &format!("                Some(EbmlId::{type_name}) => {{\n");
+        if child.element.type_ == ElementType::Struct {
+            str += &format!("                    let (mut val, read) = match size {{\n");
+            str += &format!("                        ElementSize::Sized(size) if options.verify_crc32 => {},\n", read_verified(async_, &type_name));
+            str += &format!("                        _ => {read_code},\n");
+            str += &format!("                    }}.map_err(|err| err.at({path}, all_size as u64))?;\n");
+            str += &format!("                    if options.lossless {{ val.encoding = Some(ElementEncoding::new(raw_id, header_len, size)); }}\n");
+            str += &format!("                    {var}.push_back(Ebml::new_index(index, val));\n");
+            str += &format!("                    all_size += read;\n");
         } else {
-            str += &format!("                EbmlId::{} => {{\n", child.element.type_name());
-            if child.element.type_ == ElementType::Struct {
-                str += &format!("                    let (val, read) = {read_code}?;\n");
-                str += &format!("                    {}.push_back(Ebml::new_index(index, val));\n", child.element.var_name());
-                str += &format!("                    all_size += read;\n");
-            } else {
-                str += &format!("                    let size = size.try_sized(EbmlId::{})?;\n", child.element.type_name());
-                str += &format!("                    {}.push_back(Ebml::new_index(index, {read_code}?));\n", child.element.var_name());
-                str += &format!("                    all_size += size as usize;\n");
+            read_code = format!("{read_code}.map_err(|err| ReadError::new(err, all_size as u64, {path}))?");
+            if let Some(enum_name) = child.element.enum_name() {
+                read_code = format!("{enum_name}::from({read_code})");
             }
-            str += &format!("                }},\n");
+            str += &format!("                    let size = size.try_sized(EbmlId::{type_name}).map_err(|err| ReadError::new(err, all_size as u64, {path}))?;\n");
+            str += &format!("                    let mut el = Ebml::new_index(index, {read_code});\n");
+            let encoding = match child.element.type_ {
+                ElementType::Block => "ElementEncoding::new_block(raw_id, header_len, size, &el.v)",
+                _ => "ElementEncoding::new(raw_id, header_len, ElementSize::Sized(size))",
+            };
+            str += &format!("                    if options.lossless {{ el.encoding = Some({encoding}); }}\n");
+            str += &format!("                    {var}.push_back(el);\n");
+            str += &format!("                    all_size += size as usize;\n");
         }
+        str += &format!("                }},\n");
     }
-    str += &format!("                id => Err(anyhow::anyhow!(\"unexpected element id '{{:?}}' in '{}'\", id))?,\n", struct_.type_name());
+    // Unknown IDs and IDs which don't belong to this master are skipped or kept as raw elements
+    str += &format!("                _ => {{\n");
+    str += &format!("                    let ElementSize::Sized(size) = size else {{\n");
+    str += &format!("                        return Err(ReadError::new(MatroskaError::UnknownSizeNotAllowed(raw_id), all_size as u64, \"\"));\n");
+    str += &format!("                    }};\n");
+    str += &format!("                    if options.keep_unknown || options.lossless {{\n");
+    str += &format!("                        let data = {}.map_err(|err| ReadError::new(err, all_size as u64, \"\"))?;\n", to_async(async_, "read_bin(r, size)"));
+    str += &format!("                        let position = (all_size - header_len as usize) as u64;\n");
+    str += &format!("                        let mut el = Ebml::new_index(index, RawElement {{ id: raw_id, data, position }});\n");
+    str += &format!("                        if options.lossless {{ el.encoding = Some(ElementEncoding::new(raw_id, header_len, ElementSize::Sized(size))); }}\n");
+    str += &format!("                        unknown.push(el);\n");
+    str += &format!("                    }} else {{\n");
+    str += &format!("                        {}.map_err(|err| ReadError::new(err, all_size as u64, \"\"))?;\n", to_async(async_, "skip(r, size)"));
+    str += &format!("                    }}\n");
+    str += &format!("                    all_size += size as usize;\n");
+    str += &format!("                }},\n");
     str += &format!("            }}\n");
     str += &format!("            index += 1;\n");
     str += &format!("        }}\n");
     str += &format!("\n");
     for child in &struct_.children {
+        let var = child.element.var_name();
+        let type_name = child.element.type_name();
+        let too_many = format!("        if {var}.len() > 1 {{ return Err(MatroskaError::TooManyElements {{ id: EbmlId::{type_name}, count: {var}.len() }}.into()); }}\n");
         match child.element.attr {
             TypeAttr::Optional => {
-                str += &format!("        if {}.len() > 1 {{ Err(anyhow::anyhow!(\"Only zero or one element '{}' in '{}' possible. Found {{}}\", {}.len()))? }}\n",
-                                child.element.var_name(),
-                                child.element.type_name(),
-                                struct_.type_name(),
-                                child.element.var_name()
-                );
-                str += &format!("        let {} = {}.pop_front();\n", child.element.var_name(), child.element.var_name());
+                str += &too_many;
+                str += &format!("        let {var} = {var}.pop_front();\n");
             },
             TypeAttr::Required => {
-                if let Some(default) = &child.element.default {
-                    let default = match child.element.type_ {
-                        ElementType::UInteger => format!("{default}"),
-                        ElementType::Integer => format!("{default}"),
-                        ElementType::Float => format!("hexf::hexf64!(\"{default}\")"),
-                        ElementType::String | ElementType::Utf8 => format!("\"{default}\".to_string()"),
-                        ElementType::Binary => format!("{default}"),
-                        ElementType::Date => format!("{default}"),
-                        ElementType::Struct => format!("{default}"),
-                    };
-                    str += &format!("        if {}.len() == 0 {{ {}.push_back(Ebml::new({default})); }}\n", child.element.var_name(), child.element.var_name());
+                if let Some(default) = default_code(&child.element) {
+                    str += &format!("        if {var}.is_empty() {{ {var}.push_back(Ebml::new_default({default})); }}\n");
                 }
-                str += &format!("        if {}.len() != 1 {{ Err(anyhow::anyhow!(\"One element '{}' must be in '{}'. Found {{}}\", {}.len()))? }}\n",
-                                child.element.var_name(),
-                                child.element.type_name(),
-                                struct_.type_name(),
-                                child.element.var_name()
-                );
-                str += &format!("        let {} = {}.pop_front().ok_or_else(|| anyhow::anyhow!(\"Required element '{}' doesn't exist in '{}'\"))?;\n",
-                                child.element.var_name(), child.element.var_name(),
-                                child.element.type_name(),
-                                struct_.type_name(),
-                );
+                str += &too_many;
+                str += &format!("        let {var} = {var}.pop_front().ok_or(MatroskaError::MissingElement(EbmlId::{type_name}))?;\n");
             },
             TypeAttr::Repeated => {
-                str += &format!("        let {} = Vec::from({});\n", child.element.var_name(), child.element.var_name());
+                str += &format!("        let {var} = Vec::from({var});\n");
             },
         }
     }
@@ -177,14 +209,25 @@ pub fn impl_read_body(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     for child in &struct_.children {
         str += &format!("            {},\n", child.element.var_name());
     }
+    str += &format!("            unknown,\n");
+    str += &format!("            encoding: None,\n");
     str += &format!("        }}, all_size))\n");
     str += &format!("    }}\n");
     str
 }
 
+/// The body is buffered to check its CRC-32, Segment is too large for it
+fn read_verified(async_: bool, type_name: &str) -> String {
+    if async_ {
+        format!("crc32::read_body_verified::<{type_name}, _>(r, EbmlId::{type_name}, size, options).await")
+    } else {
+        format!("crc32::read_body_verified_blocking::<{type_name}, _>(r, EbmlId::{type_name}, size, options)")
+    }
+}
+
 pub fn read_native(async_: bool, type_: &ElementType, type_name: String) -> String {
     if async_ {
-        let type_name = format!("{type_name}::read_body(r, size).await");
+        let type_name = format!("{type_name}::read_body_with(r, size, options).await");
         match type_ {
             ElementType::UInteger => "async_::read_uint(r, size).await",
             ElementType::Integer => "async_::read_int(r, size).await",
@@ -193,10 +236,11 @@ pub fn read_native(async_: bool, type_: &ElementType, type_name: String) -> Stri
             ElementType::Utf8 => "async_::read_utf8(r, size).await",
             ElementType::Date => "async_::read_date(r, size).await",
             ElementType::Binary => "async_::read_bin(r, size).await",
+            ElementType::Block => "async_::read_block(r, size).await",
             ElementType::Struct => type_name.as_str(),
         }.to_string()
     } else {
-        let type_name = format!("{type_name}::read_body(r, size)");
+        let type_name = format!("{type_name}::read_body_with(r, size, options)");
         match type_ {
             ElementType::UInteger => "blocking::read_uint(r, size)",
             ElementType::Integer => "blocking::read_int(r, size)",
@@ -205,6 +249,7 @@ pub fn read_native(async_: bool, type_: &ElementType, type_name: String) -> Stri
             ElementType::Utf8 => "blocking::read_utf8(r, size)",
             ElementType::Date => "blocking::read_date(r, size)",
             ElementType::Binary => "blocking::read_bin(r, size)",
+            ElementType::Block => "blocking::read_block(r, size)",
             ElementType::Struct => type_name.as_str(),
         }.to_string()
     }
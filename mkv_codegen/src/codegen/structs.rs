@@ -2,9 +2,11 @@ use crate::parser::ebml;
 
 pub fn define_structs(ebml_matroska: &ebml::EBMLMatroska) -> String {
     let mut str = format!("");
-    str += "use super::Ebml;\n";
+    str += "use super::{Ebml, ElementEncoding, RawElement};\n";
     str += "use super::ids::EbmlId;\n";
+    str += "use super::enums::*;\n";
     str += "use super::ElementSize;\n";
+    str += "use super::block::Block;\n";
     str += "\n";
 
     for struct_ in &ebml_matroska.sorted_strcuts() {
@@ -15,8 +17,7 @@ pub fn define_structs(ebml_matroska: &ebml::EBMLMatroska) -> String {
 
         for child in &struct_.children {
             str += &format!("    pub {}: ", child.element.var_name());
-            let type_name = child.element.type_name();
-            let type_name = child.element.type_.to_native(type_name);
+            let type_name = child.element.native_type();
             str += &match child.element.attr {
                 ebml::TypeAttr::Required => format!("Ebml<{type_name}>"),
                 ebml::TypeAttr::Optional => format!("Option<Ebml<{type_name}>>"),
@@ -24,47 +25,50 @@ pub fn define_structs(ebml_matroska: &ebml::EBMLMatroska) -> String {
             };
             str += ",\n";
         }
+        str += "    /// Header encoding of the element read with `ReadOptions::lossless`\n";
+        str += "    pub encoding: Option<ElementEncoding>,\n";
+        str += "    /// Elements unknown to the schema, kept with `ReadOptions::keep_unknown`\n";
+        str += "    pub unknown: Vec<Ebml<RawElement>>,\n";
         str += "}\n";
 
-        if !struct_.children.is_empty() {
-
-            str += &format!("impl {} {{\n", struct_.type_name());
-            str += &format!("    pub fn elements(&self) -> std::collections::BTreeSet<{}Fields> {{\n", struct_.type_name());
-            str += &format!("        let mut elements = std::collections::BTreeSet::new();\n");
-            for child in &struct_.children {
-                let name = child.element.var_name();
-                str += &format!("        ");
-                str += &match child.element.attr {
-                    ebml::TypeAttr::Required => format!("elements.insert({}Fields::{}(self.{name}.clone()));", struct_.type_name(), child.element.type_name()),
-                    ebml::TypeAttr::Optional => format!("if let Some(el) = &self.{name} {{ elements.insert({}Fields::{}(el.clone())); }}", struct_.type_name(), child.element.type_name()),
-                    ebml::TypeAttr::Repeated => format!("for el in &self.{name} {{ elements.insert({}Fields::{}(el.clone())); }}", struct_.type_name(), child.element.type_name()),
-                };
-                str += &format!("\n");
-            }
-            str += &format!("        elements\n");
-            str += &format!("    }}\n");
-            str += &format!("}}\n");
-
-            str += &format!("#[derive(Debug)]\n");
-            str += &format!("pub enum {}Fields {{\n", struct_.type_name());
-            for child in &struct_.children {
-                let type_name = child.element.type_name();
-                let type_name = child.element.type_.to_native(type_name);
-                str += &format!("    {}(Ebml<{type_name}>),\n", child.element.type_name());
-            }
-            str += "}\n";
-            str += &format!("impl {}Fields {{\n", struct_.type_name());
-            str += &format!("    pub fn index(&self) -> (EbmlId, Option<u64>, u64) {{\n");
-            str += &format!("        match self {{\n");
-            for child in &struct_.children {
-                str += &format!("            Self::{}(val) => (EbmlId::{}, val.index, val.id),\n", child.element.type_name(), child.element.id_enum());
-            }
-            str += &format!("        }}\n");
-            str += &format!("    }}\n");
-            str += "}\n";
+        str += &format!("impl {} {{\n", struct_.type_name());
+        str += &format!("    pub fn elements(&self) -> std::collections::BTreeSet<{}Fields<'_>> {{\n", struct_.type_name());
+        str += &format!("        let mut elements = std::collections::BTreeSet::new();\n");
+        for child in &struct_.children {
+            let name = child.element.var_name();
+            str += &format!("        ");
+            str += &match child.element.attr {
+                ebml::TypeAttr::Required => format!("elements.insert({}Fields::{}(&self.{name}));", struct_.type_name(), child.element.type_name()),
+                ebml::TypeAttr::Optional => format!("if let Some(el) = &self.{name} {{ elements.insert({}Fields::{}(el)); }}", struct_.type_name(), child.element.type_name()),
+                ebml::TypeAttr::Repeated => format!("for el in &self.{name} {{ elements.insert({}Fields::{}(el)); }}", struct_.type_name(), child.element.type_name()),
+            };
+            str += &format!("\n");
+        }
+        str += &format!("        for el in &self.unknown {{ elements.insert({}Fields::Unknown(el)); }}\n", struct_.type_name());
+        str += &format!("        elements\n");
+        str += &format!("    }}\n");
+        str += &format!("}}\n");
 
-            str += &format!("crate::impl_ord!({}Fields);\n", struct_.type_name());
+        str += &format!("#[derive(Debug)]\n");
+        str += &format!("pub enum {}Fields<'a> {{\n", struct_.type_name());
+        for child in &struct_.children {
+            let type_name = child.element.native_type();
+            str += &format!("    {}(&'a Ebml<{type_name}>),\n", child.element.type_name());
+        }
+        str += "    Unknown(&'a Ebml<RawElement>),\n";
+        str += "}\n";
+        str += &format!("impl {}Fields<'_> {{\n", struct_.type_name());
+        str += &format!("    pub fn index(&self) -> (u64, Option<u64>, u64) {{\n");
+        str += &format!("        match self {{\n");
+        for child in &struct_.children {
+            str += &format!("            Self::{}(val) => (EbmlId::{} as u64, val.index, val.id),\n", child.element.type_name(), child.element.id_enum());
         }
+        str += &format!("            Self::Unknown(val) => (val.v.id, val.index, val.id),\n");
+        str += &format!("        }}\n");
+        str += &format!("    }}\n");
+        str += "}\n";
+
+        str += &format!("crate::impl_ord!({}Fields<'_>);\n", struct_.type_name());
         str += "\n";
     }
     str
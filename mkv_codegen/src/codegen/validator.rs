@@ -0,0 +1,152 @@
+use crate::parser::ebml;
+use ebml::{ElementType, TypeAttr};
+
+/// `validate` checks the schema attributes the reader doesn't enforce: ranges, fixed lengths,
+/// versions and the WebM subset, all problems are collected with the element paths
+pub fn generate(ebml_matroska: &ebml::EBMLMatroska) -> Result<String, anyhow::Error> {
+    let mut str = String::new();
+    str += "\
+// The code was generated by codegen!
+
+use super::structs::*;
+use super::enums::*;
+use super::validate::{Diagnostic, Validation};
+
+";
+    for struct_ in &ebml_matroska.sorted_strcuts() {
+        str += &format!("impl {} {{\n", struct_.type_name());
+        str += &impl_validate(struct_);
+        str += &impl_validate_with(struct_)?;
+        str += "}\n";
+        str += "\n";
+    }
+    Ok(str)
+}
+
+fn impl_validate(struct_: &ebml::EBMLStruct) -> String {
+    let path = spec_path(&struct_.element).replace('\\', "\\\\");
+    let mut str = String::new();
+    str += "    /// Check values against the specification, the element is expected at its path in the file\n";
+    str += "    pub fn validate(&self) -> Vec<Diagnostic> {\n";
+    str += "        let mut validation = Validation::default();\n";
+    str += &format!("        self.validate_with(&mut validation, \"{path}\");\n");
+    str += "        validation.into_diagnostics()\n";
+    str += "    }\n";
+    str
+}
+
+fn impl_validate_with(struct_: &ebml::EBMLStruct) -> Result<String, anyhow::Error> {
+    let mut str = String::new();
+    let mut body = String::new();
+    for child in &struct_.children {
+        let element = &child.element;
+        let mut checks = String::new();
+        let (minver, maxver) = (element.minver.unwrap_or(1), element.maxver);
+        // the readers fill the absent elements with the default, they can't be told from the written ones
+        if (minver > 1 || maxver.is_some()) && element.default.is_none() {
+            let maxver = maxver.map_or("None".to_string(), |maxver| format!("Some({maxver})"));
+            checks += &format!("            v.version(&path, {minver}, {maxver});\n");
+        }
+        if element.webm == Some(false) {
+            checks += "            v.not_webm(&path);\n";
+        }
+        if let Some(range) = &element.range {
+            let condition = range_condition(&element.type_, range)?;
+            let val = match element.enum_name() {
+                Some(_) => "el.v.value()",
+                None => "*el.v",
+            };
+            checks += &format!("            let val = {val};\n");
+            checks += &format!("            let valid = {condition};\n");
+            checks += &format!("            if !valid {{ v.error(&path, format!(\"value {{val}} is out of the range '{range}'\")); }}\n");
+        }
+        if let Some(length) = element.length {
+            checks += &format!("            if el.v.len() != {length} {{ v.error(&path, format!(\"length {{}} must be {length}\", el.v.len())); }}\n");
+        }
+        if let Some(enum_name) = element.enum_name() {
+            // Other built directly can hold a listed value, it's checked by the conversion
+            let (val, native) = match element.type_ {
+                ElementType::String => ("el.v.as_str()", "val.to_string()"),
+                _ => ("el.v.value()", "val"),
+            };
+            checks += &format!("            let val = {val};\n");
+            checks += &format!("            if matches!({enum_name}::from({native}), {enum_name}::Other(_)) {{ v.warning(&path, format!(\"value {{val:?}} is not defined by the specification\")); }}\n");
+        }
+        if element.type_ == ElementType::Struct {
+            checks += "            el.v.validate_with(v, &path);\n";
+        }
+        if checks.is_empty() {
+            continue;
+        }
+
+        let name = spec_path(element).rsplit('\\').next().unwrap_or_default().to_string();
+        let var = element.var_name();
+        // version and WebM checks depend on the presence only
+        let el = if checks.contains("el.") { "el" } else { "_el" };
+        match element.attr {
+            TypeAttr::Required => body += &format!("        for {el} in std::iter::once(&self.{var}) {{\n"),
+            TypeAttr::Optional => body += &format!("        for {el} in self.{var}.iter() {{\n"),
+            TypeAttr::Repeated => body += &format!("        for (i, {el}) in self.{var}.iter().enumerate() {{\n"),
+        }
+        if matches!(element.attr, TypeAttr::Repeated) {
+            body += &format!("            let path = format!(\"{{path}}\\\\{name}[{{i}}]\");\n");
+        } else {
+            body += &format!("            let path = format!(\"{{path}}\\\\{name}\");\n");
+        }
+        body += &checks;
+        body += "        }\n";
+    }
+    let v = if body.is_empty() { "_v" } else { "v" };
+    let path = if body.is_empty() { "_path" } else { "path" };
+    str += &format!("    pub fn validate_with(&self, {v}: &mut Validation, {path}: &str) {{\n");
+    str += &body;
+    str += "    }\n";
+    Ok(str)
+}
+
+/// Path of the element in the specification, the EBML header is `\EBML`
+fn spec_path(element: &ebml::Element) -> String {
+    element.path_src.replace('+', "").replace(ebml::EBML_HEADER_TAG, "EBML")
+}
+
+/// Rust condition on `val` from the schema range, e.g. `not 0`, `1-8`, `>= -0xB4p+0, <= 0xB4p+0`
+fn range_condition(type_: &ElementType, range: &str) -> Result<String, anyhow::Error> {
+    let literal = |str: &str| -> Result<String, anyhow::Error> {
+        let str = str.trim();
+        Ok(match type_ {
+            ElementType::Float => format!("hexf::hexf64!(\"{str}\")"),
+            ElementType::UInteger | ElementType::Integer => {
+                str.parse::<i64>().map_err(|_| anyhow::anyhow!("Unsupported range value '{str}'"))?;
+                str.to_string()
+            }
+            type_ => return Err(anyhow::anyhow!("Unsupported range '{range}' of type {type_:?}")),
+        })
+    };
+    // the only list in the schema is the interval of the projection angles
+    if let Some((min, max)) = range.split_once(',') {
+        if let (Some(min), Some(max)) = (min.trim().strip_prefix(">="), max.trim().strip_prefix("<=")) {
+            return Ok(format!("({}..={}).contains(&val)", literal(min)?, literal(max)?));
+        }
+    }
+    let mut conditions = vec![];
+    for part in range.split(',') {
+        let part = part.trim();
+        let condition = if let Some(val) = part.strip_prefix("not ") {
+            format!("val != {}", literal(val)?)
+        } else if let Some(val) = part.strip_prefix(">=") {
+            format!("val >= {}", literal(val)?)
+        } else if let Some(val) = part.strip_prefix("<=") {
+            format!("val <= {}", literal(val)?)
+        } else if let Some(val) = part.strip_prefix('>') {
+            format!("val > {}", literal(val)?)
+        } else if let Some(val) = part.strip_prefix('<') {
+            format!("val < {}", literal(val)?)
+        } else if let Some((min, max)) = part.get(1..).and_then(|rest| rest.split_once('-')).map(|(min, max)| (&part[..min.len() + 1], max)) {
+            format!("({}..={}).contains(&val)", literal(min)?, literal(max)?)
+        } else {
+            format!("val == {}", literal(part)?)
+        };
+        conditions.push(condition);
+    }
+    Ok(conditions.join(" && "))
+}
@@ -1,5 +1,5 @@
 use crate::parser::ebml;
-use ebml::ElementType;
+use ebml::{ElementType, TypeAttr};
 use super::to_async;
 
 pub fn generate(ebml_matroska: &ebml::EBMLMatroska) -> Result<String, anyhow::Error> {
@@ -9,20 +9,32 @@ use std::collections::VecDeque;
 
 use super::io::*;
 use super::structs::*;
+use super::enums::*;
 use super::ids::EbmlId;
+use super::{MatroskaError, WriteOptions};
+use super::crc32;
 use tokio::io::AsyncWriteExt;
 
 ";
     for struct_ in &ebml_matroska.sorted_strcuts() {
+        str += &format!("impl {} {{\n", struct_.type_name());
+        str += &impl_size(struct_);
+        str += "}\n";
         str += &format!("impl {} {{\n", struct_.type_name());
         str += &impl_write(struct_, false);
         str += &impl_write_header(struct_, false);
         str += &impl_write_body(struct_, false);
+        if struct_.element.unknown_size_allowed {
+            str += &impl_write_unknown_size(struct_, false);
+        }
         str += "}\n";
         str += &format!("impl {} {{\n", struct_.type_name());
         str += &impl_write(struct_, true);
         str += &impl_write_header(struct_, true);
         str += &impl_write_body(struct_, true);
+        if struct_.element.unknown_size_allowed {
+            str += &impl_write_unknown_size(struct_, true);
+        }
         str += "}\n";
         str += "\n";
     }
@@ -34,15 +46,21 @@ fn impl_write(_struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut await_ = ".await";
     let mut blocking = "";
     if async_ {
-        str += &format!("    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub async fn write<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
     } else {
-        str += &format!("    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub fn write_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
         await_ = "";
         blocking = "_blocking";
     }
-    str += &format!("        let mut buf = vec![]; self.write_body{blocking}(&mut buf){await_}?;\n");
-    str += &format!("        let mut size = self.write_header{blocking}(w, buf.len() as u64){await_}?;\n");
-    str += &format!("        w.write_all(&buf){await_}?; size += buf.len();\n");
+    str += &format!("        self.write_with{blocking}(w, WriteOptions::default()){await_}\n");
+    str += &format!("    }}\n");
+    if async_ {
+        str += &format!("    pub async fn write_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    } else {
+        str += &format!("    pub fn write_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    }
+    str += &format!("        let mut size = self.write_header{blocking}(w, self.body_size_with(options)?){await_}?;\n");
+    str += &format!("        size += self.write_body_with{blocking}(w, options){await_}?;\n");
     str += &format!("        Ok(size)\n");
     str += &format!("    }}\n");
     str
@@ -53,13 +71,32 @@ fn impl_write_header(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut await_ = ".await";
     let mut blocking = "";
     if async_ {
-        str += &format!("    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub async fn write_header<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {{\n");
     } else {
-        str += &format!("    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub fn write_header_blocking<W: std::io::Write>(&self, w: &mut W, size: u64) -> Result<usize, MatroskaError> {{\n");
         await_ = "";
         blocking = "_blocking";
     }
-    str += &format!("        Ok({}?)\n", to_async(async_, &format!("write_element_id_size(w, EbmlId::{} as u64, size)", struct_.type_name())));
+    str += &format!("        Ok({}?)\n", to_async(async_, &format!("write_element_header(w, EbmlId::{} as u64, size, self.encoding.as_ref())", struct_.type_name())));
+    str += &format!("    }}\n");
+    str
+}
+
+/// Live streams write masters with the unknown size, the end is found by the next element of the upper level
+fn impl_write_unknown_size(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
+    let mut str = format!("");
+    let (async_key, await_, blocking, bound) = if async_ {
+        ("async ", ".await", "", "tokio::io::AsyncWrite + Send + Unpin")
+    } else {
+        ("", "", "_blocking", "std::io::Write")
+    };
+    str += &format!("    pub {async_key}fn write_unknown_size{blocking}<W: {bound}>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
+    str += &format!("        let mut size = self.write_header_unknown_size{blocking}(w){await_}?;\n");
+    str += &format!("        size += self.write_body{blocking}(w){await_}?;\n");
+    str += &format!("        Ok(size)\n");
+    str += &format!("    }}\n");
+    str += &format!("    pub {async_key}fn write_header_unknown_size{blocking}<W: {bound}>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
+    str += &format!("        Ok({}?)\n", to_async(async_, &format!("write_element_id_unknown_size(w, EbmlId::{} as u64)", struct_.type_name())));
     str += &format!("    }}\n");
     str
 }
@@ -69,23 +106,49 @@ fn impl_write_body(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     let mut await_ = ".await";
     let mut blocking = "";
     if async_ {
-        str += &format!("    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub async fn write_body<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
     } else {
-        str += &format!("    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, anyhow::Error> {{\n");
+        str += &format!("    pub fn write_body_blocking<W: std::io::Write>(&self, w: &mut W) -> Result<usize, MatroskaError> {{\n");
         await_ = "";
         blocking = "_blocking";
     }
+    str += &format!("        self.write_body_with{blocking}(w, WriteOptions::default()){await_}\n");
+    str += &format!("    }}\n");
+    if async_ {
+        str += &format!("    pub async fn write_body_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    } else {
+        str += &format!("    pub fn write_body_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    }
+    // the CRC-32 covers the rest of the body, it's buffered to be written first
+    str += &format!("        if !self.computes_crc32(options) {{\n");
+    str += &format!("            return self.write_children_with{blocking}(w, options){await_};\n");
+    str += &format!("        }}\n");
+    str += &format!("        let mut body = vec![];\n");
+    str += &format!("        self.write_children_with{blocking}(&mut body, options){await_}?;\n");
+    str += &format!("        let encoding = self.crc_32.as_ref().and_then(|el| el.encoding.as_ref());\n");
+    str += &format!("        let size = {}?;\n", to_async(async_, "write_el_encoded(w, EbmlId::Crc32 as u64, &crc32::checksum(&body).to_le_bytes(), encoding)"));
+    str += &format!("        w.write_all(&body){await_}?;\n");
+    str += &format!("        Ok(size + body.len())\n");
+    str += &format!("    }}\n");
+    if async_ {
+        str += &format!("    async fn write_children_with<W: tokio::io::AsyncWrite + Send + Unpin>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    } else {
+        str += &format!("    fn write_children_with_blocking<W: std::io::Write>(&self, w: &mut W, options: WriteOptions) -> Result<usize, MatroskaError> {{\n");
+    }
     str += &format!("        let mut size = 0usize;\n");
-    if !struct_.children.is_empty() {
     str += &format!("        for el in self.elements() {{\n");
     str += &format!("            size += match el {{\n");
+    str += &format!("                {}Fields::Crc32(_) if self.computes_crc32(options) => 0,\n", struct_.type_name());
     for child in &struct_.children {
         let id = format!("EbmlId::{} as u64", child.element.id_enum());
-    str += &format!("                {}Fields::{}(val) => {}?,\n", struct_.type_name(), child.element.type_name(), write_code(async_, &child.element.type_, &id, &format!("val.v")));
+        if let Some(is_default) = compare_default(&child.element, "val.v", "==") {
+            str += &format!("                {}Fields::{}(val) if (options.omit_defaults || (self.encoding.is_some() && val.defaulted)) && {is_default} => 0,\n", struct_.type_name(), child.element.type_name());
+        }
+    str += &format!("                {}Fields::{}(val) => {}?,\n", struct_.type_name(), child.element.type_name(), write_code(async_, &child.element, &id, "val"));
     }
+    str += &format!("                {}Fields::Unknown(val) => {}?,\n", struct_.type_name(), to_async(async_, "write_el_encoded(w, val.v.id, &val.v.data, val.encoding.as_ref())"));
     str += &format!("            }}\n");
     str += &format!("        }}\n");
-    }
     // for child in &struct_.children {
     //     let name = child.element.var_name();
     //     match child.element.attr {
@@ -162,28 +225,103 @@ fn impl_write_body(struct_: &Box<ebml::EBMLStruct>, async_: bool) -> String {
     str
 }
 
-fn write_code(async_: bool, type_: &ElementType, id: &str, name: &str) -> String {
-    if async_ {
-        match type_ {
-            ElementType::UInteger => format!("async_::write_el_uint(w, {id}, &*{name}).await"),
-            ElementType::Integer => format!("async_::write_el_int(w, {id}, &*{name}).await"),
-            ElementType::Float => format!("async_::write_el_float64(w, {id}, &*{name}).await"),
-            ElementType::String => format!("async_::write_el_string(w, {id}, &{name}).await"),
-            ElementType::Utf8 => format!("async_::write_el_utf8(w, {id}, &{name}).await"),
-            ElementType::Binary => format!("async_::write_el_bin(w, {id}, &{name}).await"),
-            ElementType::Date => format!("async_::write_el_date(w, {id}, &{name}).await"),
-            ElementType::Struct => format!("{name}.write(w).await"),
+/// `encoded_size` and `body_size` let the header be written before the body is streamed
+fn impl_size(struct_: &Box<ebml::EBMLStruct>) -> String {
+    let mut str = format!("");
+    str += &format!("    /// Size of the element with the header\n");
+    str += &format!("    pub fn encoded_size(&self) -> Result<u64, MatroskaError> {{\n");
+    str += &format!("        self.encoded_size_with(WriteOptions::default())\n");
+    str += &format!("    }}\n");
+    str += &format!("    pub fn encoded_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {{\n");
+    str += &format!("        Ok(size_el_encoded(EbmlId::{} as u64, self.body_size_with(options)?, self.encoding.as_ref()))\n", struct_.type_name());
+    str += &format!("    }}\n");
+    str += &format!("    pub fn body_size(&self) -> Result<u64, MatroskaError> {{\n");
+    str += &format!("        self.body_size_with(WriteOptions::default())\n");
+    str += &format!("    }}\n");
+    str += &format!("    pub fn body_size_with(&self, options: WriteOptions) -> Result<u64, MatroskaError> {{\n");
+    str += &format!("        let mut size = 0;\n");
+    for child in &struct_.children {
+        let name = child.element.var_name();
+        let id = format!("EbmlId::{} as u64", child.element.id_enum());
+        if child.element.type_name() == "Crc32" {
+            str += &format!("        if self.computes_crc32(options) {{ size += size_el_encoded({id}, 4, self.{name}.as_ref().and_then(|el| el.encoding.as_ref())); }}\n");
+            str += &format!("        else if let Some(val) = &self.{name} {{ size += {}; }}\n", size_code(&child.element, &id, "val"));
+            continue;
         }
+        str += &match child.element.attr {
+            TypeAttr::Required => match compare_default(&child.element, &format!("self.{name}.v"), "!=") {
+                Some(differs) => format!("        if {differs} || (!options.omit_defaults && (self.encoding.is_none() || !self.{name}.defaulted)) {{ size += {}; }}\n", size_code(&child.element, &id, &format!("self.{name}"))),
+                None => format!("        size += {};\n", size_code(&child.element, &id, &format!("self.{name}"))),
+            },
+            TypeAttr::Optional => format!("        if let Some(val) = &self.{name} {{ size += {}; }}\n", size_code(&child.element, &id, "val")),
+            TypeAttr::Repeated => format!("        for val in &self.{name} {{ size += {}; }}\n", size_code(&child.element, &id, "val")),
+        };
+    }
+    str += "        for val in &self.unknown { size += size_el_encoded(val.v.id, val.v.data.len() as u64, val.encoding.as_ref()); }\n";
+    str += &format!("        Ok(size)\n");
+    str += &format!("    }}\n");
+    // mkvmerge writes CRC-32 into the level 1 masters, the stored ones of the other masters are recomputed
+    str += &format!("    fn computes_crc32(&self, options: WriteOptions) -> bool {{\n");
+    if struct_.element.top_level() {
+        str += &format!("        options.crc32\n");
     } else {
-        match type_ {
-            ElementType::UInteger => format!("blocking::write_el_uint(w, {id}, &*{name})"),
-            ElementType::Integer => format!("blocking::write_el_int(w, {id}, &*{name})"),
-            ElementType::Float => format!("blocking::write_el_float64(w, {id}, &*{name})"),
-            ElementType::String => format!("blocking::write_el_string(w, {id}, &{name})"),
-            ElementType::Utf8 => format!("blocking::write_el_utf8(w, {id}, &{name})"),
-            ElementType::Binary => format!("blocking::write_el_bin(w, {id}, &{name})"),
-            ElementType::Date => format!("blocking::write_el_date(w, {id}, &{name})"),
-            ElementType::Struct => format!("{name}.write_blocking(w)"),
-        }
+        str += &format!("        options.crc32 && self.crc_32.is_some()\n");
     }
+    str += &format!("    }}\n");
+    str
+}
+
+/// Comparison of the required element value with the spec default, `op` is `==` or `!=`
+fn compare_default(element: &ebml::Element, name: &str, op: &str) -> Option<String> {
+    if !matches!(element.attr, TypeAttr::Required) {
+        return None;
+    }
+    let default = element.default.as_ref()?;
+    Some(match (&element.type_, element.enum_name()) {
+        (ElementType::String | ElementType::Utf8, _) => format!("{name}.as_str() {op} \"{default}\""),
+        (_, Some(_)) => format!("{name}.value() {op} {default}"),
+        (ElementType::Float, None) => format!("*{name} {op} hexf::hexf64!(\"{default}\")"),
+        (_, None) => format!("*{name} {op} {default}"),
+    })
+}
+
+/// Restricted values are written as the native type
+fn value_code(element: &ebml::Element, name: &str) -> String {
+    match (element.enum_name(), &element.type_) {
+        (Some(_), ElementType::String) => format!("{name}.as_str()"),
+        (Some(_), _) => format!("{name}.value()"),
+        (None, _) => name.to_string(),
+    }
+}
+
+/// `el` is the `Ebml` of the element, the recorded encoding of a lossless read is kept
+fn size_code(element: &ebml::Element, id: &str, el: &str) -> String {
+    let name = value_code(element, &format!("{el}.v"));
+    let encoding = format!("{el}.encoding.as_ref()");
+    match element.type_ {
+        ElementType::UInteger => format!("size_el_encoded({id}, gen_uint_encoded(&{name}, {encoding}).len() as u64, {encoding})"),
+        ElementType::Integer => format!("size_el_encoded({id}, gen_int_encoded(&{name}, {encoding}).len() as u64, {encoding})"),
+        ElementType::Float => format!("size_el_encoded({id}, gen_float_encoded(&{name}, {encoding}).len() as u64, {encoding})"),
+        ElementType::String | ElementType::Utf8 | ElementType::Binary => format!("size_el_encoded({id}, {name}.len() as u64, {encoding})"),
+        ElementType::Block => format!("size_el_block_encoded({id}, &{name}, {encoding})?"),
+        ElementType::Date => format!("size_el_encoded({id}, 8, {encoding})"),
+        ElementType::Struct => format!("{name}.encoded_size_with(options)?"),
+    }
+}
+
+fn write_code(async_: bool, element: &ebml::Element, id: &str, el: &str) -> String {
+    let name = value_code(element, &format!("{el}.v"));
+    let encoding = format!("{el}.encoding.as_ref()");
+    let code = match element.type_ {
+        ElementType::UInteger => format!("write_el_encoded(w, {id}, &gen_uint_encoded(&{name}, {encoding}), {encoding})"),
+        ElementType::Integer => format!("write_el_encoded(w, {id}, &gen_int_encoded(&{name}, {encoding}), {encoding})"),
+        ElementType::Float => format!("write_el_encoded(w, {id}, &gen_float_encoded(&{name}, {encoding}), {encoding})"),
+        ElementType::String | ElementType::Utf8 => format!("write_el_encoded(w, {id}, {name}.as_bytes(), {encoding})"),
+        ElementType::Binary => format!("write_el_encoded(w, {id}, &{name}, {encoding})"),
+        ElementType::Block => format!("write_el_block_encoded(w, {id}, &{name}, {encoding})"),
+        ElementType::Date => format!("write_el_encoded(w, {id}, &gen_date(&{name}), {encoding})"),
+        ElementType::Struct if async_ => return format!("{name}.write_with(w, options).await"),
+        ElementType::Struct => return format!("{name}.write_with_blocking(w, options)"),
+    };
+    to_async(async_, &code)
 }
@@ -3,9 +3,11 @@ use anyhow::{ anyhow, Context };
 
 use serde_derive::Deserialize;
 
-use super::{from_bool, var_name, type_name, id_enum};
+use super::{from_bool, var_name, type_name, id_enum, variant_name};
 
-const EBML_HEADER_TAG: &'static str = "EbmlHeader";
+pub const EBML_HEADER_TAG: &'static str = "EbmlHeader";
+// binary elements with the Matroska block structure, parsed into `mkv::Block`
+const BLOCK_TAGS: [&str; 2] = ["SimpleBlock", "Block"];
 
 #[derive(Debug, Deserialize, PartialEq, Clone)]
 #[serde(deny_unknown_fields)]
@@ -142,6 +144,7 @@ pub enum ElementType {
     Binary,
     Date,
     Struct,
+    Block,
 }
 impl ElementType {
     pub fn to_native(&self, type_name: String) -> String {
@@ -153,6 +156,7 @@ impl ElementType {
             ElementType::Date => "time::OffsetDateTime",
             ElementType::Binary => "Vec<u8>",
             ElementType::Struct => type_name.as_str(),
+            ElementType::Block => "Block",
         }.to_string()
     }
     fn to_rust(&self) -> &'static str {
@@ -165,6 +169,8 @@ impl ElementType {
             ElementType::Binary => "Binary",
             ElementType::Date => "Date",
             ElementType::Struct => "Struct",
+            // blocks are plain binary elements on the wire
+            ElementType::Block => "Binary",
         }
     }
     fn from_xml_str(str: &str) -> Result<Self, anyhow::Error> {
@@ -190,11 +196,20 @@ impl ElementType {
             ElementType::Binary => "binary",
             ElementType::Date => "date",
             ElementType::Struct => "master",
+            ElementType::Block => "binary",
         }.to_string()
     }
 }
 
 
+/// Value allowed by the `<restriction>` of an element, generated as an enum variant
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    pub value: String,
+    pub label: String,
+    pub variant: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct PathSegment {
     pub element: Box<Element>,
@@ -218,6 +233,8 @@ pub struct Element {
     pub type_: ElementType,
     pub default: Option<String>,
     pub range: Option<String>,
+    /// Fixed length of binary and string elements
+    pub length: Option<u32>,
 
     pub min_occurs: u32,
     pub max_occurs: Option<u32>,
@@ -234,6 +251,9 @@ pub struct Element {
     pub recursive: bool,
 
     pub documentation: Vec<DocumentationSrc>,
+    pub enums: Vec<EnumValue>,
+    /// The `webmproject.org` extension, `Some(false)` for elements excluded from WebM
+    pub webm: Option<bool>,
 }
 impl Element {
     // pub fn paths(&self) -> Vec<String> {
@@ -243,6 +263,20 @@ impl Element {
     pub fn type_name(&self) -> String { type_name(&self.name) }
     pub fn rust_type(&self) -> &'static str { self.type_.to_rust() }
     pub fn id_enum(&self) -> String { id_enum(&self.name) }
+    /// Enum type for elements with restricted values, they are read and written as the native type
+    pub fn enum_name(&self) -> Option<String> {
+        match self.type_ {
+            ElementType::UInteger | ElementType::String if !self.enums.is_empty() => Some(self.type_name()),
+            _ => None,
+        }
+    }
+    /// Level 1 element, a direct child of Segment
+    pub fn top_level(&self) -> bool {
+        matches!(self.path.as_slice(), [parent] if parent.element.name == "Segment")
+    }
+    pub fn native_type(&self) -> String {
+        self.enum_name().unwrap_or_else(|| self.type_.to_native(self.type_name()))
+    }
 }
 
 
@@ -360,17 +394,24 @@ pub fn parse() -> Result<EBMLMatroska, anyhow::Error> {
             None => TypeAttr::Repeated
         };
 
+        let type_ = match ElementType::from_xml_str(&element_src.type_)? {
+            ElementType::Binary if BLOCK_TAGS.contains(&element_src.name().as_str()) => ElementType::Block,
+            type_ => type_,
+        };
+        let enums = enum_values(element_src.restriction.as_ref(), type_ == ElementType::String);
+
         if let Some(element) = elements.insert(element_src.name(), Box::new(Element {
             index,
             name: element_src.name(),
             path_src, path: vec![],
             id: u32::from_str_radix(&element_src.id.replace("0x", ""), 16).expect(&format!("id value '{}' is not u32", element_src.id)),
-            type_: ElementType::from_xml_str(&element_src.type_)?,
+            type_,
             // default: element_src.default.map(|v| u32::from_str(&v).expect(&format!("value '{}' is not error", v))),
             default: element_src.default.clone(),
             minver: element_src.minver,
             maxver: element_src.maxver,
             range,
+            length: element_src.length,
             min_occurs,
             max_occurs: element_src.max_occurs,
             attr,
@@ -379,6 +420,10 @@ pub fn parse() -> Result<EBMLMatroska, anyhow::Error> {
             unknown_size_allowed: element_src.unknown_size_allowed.map(|v| v == 1).unwrap_or(false),
             documentation: element_src.documentation.clone(),
             recursive: element_src.recursive,
+            enums,
+            webm: element_src.extensions.iter()
+                .find(|extension| extension.type_ == ExtensionType::WebmProjectOrg)
+                .map(|extension| extension.webm),
         })) {
             return Err(anyhow!("Duplicate element with name '{}'", element.name))
         };
@@ -428,6 +473,15 @@ pub fn parse() -> Result<EBMLMatroska, anyhow::Error> {
         }
     }
 
+    // CRC-32 is a global element, every master can start with it
+    if let Some(crc32) = elements.get("Crc32") {
+        for (_, struct_) in structs.iter_mut() {
+            if !struct_.children.iter().any(|child| child.element.name == crc32.name) {
+                struct_.children.push(Child { element: crc32.clone() });
+            }
+        }
+    }
+
     for (_, struct_) in structs.iter_mut() {
         struct_.children.sort_by(|a, b| a.element.index.cmp(&b.element.index));
     }
@@ -438,6 +492,20 @@ pub fn parse() -> Result<EBMLMatroska, anyhow::Error> {
 }
 
 
+fn enum_values(restriction: Option<&RestrictionSrc>, string: bool) -> Vec<EnumValue> {
+    let mut values: Vec<EnumValue> = vec![];
+    for enum_ in restriction.iter().flat_map(|restriction| &restriction.enums) {
+        // string values are names already, their labels are descriptions
+        let mut variant = variant_name(if string { &enum_.value } else { &enum_.label });
+        // labels like `reserved` are repeated for several values
+        if values.iter().any(|value| value.variant == variant) {
+            variant = format!("{variant}{}", variant_name(&enum_.value));
+        }
+        values.push(EnumValue { value: enum_.value.clone(), label: enum_.label.clone(), variant });
+    }
+    values
+}
+
 fn insert_void_crc32(elements: &mut Vec<ElementSrc>) -> Result<(), anyhow::Error> {
     let segment = "Segment".to_string();
     {
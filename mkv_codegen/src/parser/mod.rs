@@ -19,5 +19,11 @@ use convert_case::{Case, Casing};
 pub fn var_name(name: &str) -> String { name.to_case(Case::Snake) }
 pub fn type_name(name: &str) -> String { name.to_case(Case::UpperCamel) }
 pub fn id_enum(name: &str) -> String { format!("{}", name.to_case(Case::Pascal)) }
+/// Enum variant from a restriction label, e.g. `ITU-R BT.709` -> `ItuRBt709`
+pub fn variant_name(label: &str) -> String {
+    let words: String = label.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' }).collect();
+    let name = words.to_case(Case::UpperCamel);
+    if name.starts_with(|c: char| c.is_ascii_digit()) { format!("V{name}") } else { name }
+}
 
 